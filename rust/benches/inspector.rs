@@ -0,0 +1,126 @@
+//! Benchmarks for the inspector and analyzer over synthetic transaction logs.
+//!
+//! Run with `cargo bench`; pass a filter to run one size, e.g.
+//! `cargo bench -- 100000`. Each log is generated the first time a benchmark
+//! matching the filter needs it; the 1M-file log takes a while.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use deltective::{DeltaTableAnalyzer, DeltaTableInspector, TableStatistics};
+use serde_json::json;
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Number of add actions in each synthetic log.
+const SIZES: [usize; 3] = [1_000, 100_000, 1_000_000];
+
+/// Add actions per commit, so larger logs are spread over many commits like real tables.
+const ADDS_PER_COMMIT: usize = 10_000;
+
+const SCHEMA: &str = r#"{"type":"struct","fields":[{"name":"id","type":"long","nullable":false,"metadata":{}},{"name":"value","type":"string","nullable":true,"metadata":{}},{"name":"date","type":"string","nullable":true,"metadata":{}}]}"#;
+
+/// Write a `_delta_log` with `num_files` add actions (no data files; the
+/// inspector only needs the log) and return the directory holding it.
+fn synthetic_table(num_files: usize) -> TempDir {
+    let dir = TempDir::new().expect("create temp dir");
+    let log = dir.path().join("_delta_log");
+    fs::create_dir(&log).expect("create _delta_log");
+
+    let num_commits = num_files.div_ceil(ADDS_PER_COMMIT);
+    let base_time = 1_700_000_000_000_i64;
+    for version in 0..num_commits {
+        let timestamp = base_time + version as i64 * 60_000;
+        let mut out = BufWriter::new(File::create(commit_path(&log, version)).expect("create commit"));
+
+        let info = json!({ "commitInfo": { "timestamp": timestamp, "operation": "WRITE", "operationParameters": { "mode": "Append" } } });
+        writeln!(out, "{}", info).unwrap();
+        if version == 0 {
+            writeln!(out, "{}", json!({ "protocol": { "minReaderVersion": 1, "minWriterVersion": 2 } })).unwrap();
+            let metadata = json!({ "metaData": {
+                "id": "00000000-0000-0000-0000-000000000000",
+                "format": { "provider": "parquet", "options": {} },
+                "schemaString": SCHEMA,
+                "partitionColumns": ["date"],
+                "configuration": {},
+                "createdTime": base_time,
+            } });
+            writeln!(out, "{}", metadata).unwrap();
+        }
+
+        let first = version * ADDS_PER_COMMIT;
+        for idx in first..num_files.min(first + ADDS_PER_COMMIT) {
+            let date = format!("2024-01-{:02}", idx % 28 + 1);
+            let rows = 1_000 + (idx % 5_000) as i64;
+            let stats = json!({
+                "numRecords": rows,
+                "minValues": { "id": idx * 10_000, "value": "a" },
+                "maxValues": { "id": idx * 10_000 + 9_999, "value": "z" },
+                "nullCount": { "id": 0, "value": idx % 7 },
+            });
+            let add = json!({ "add": {
+                "path": format!("date={}/part-{:08}.parquet", date, idx),
+                "partitionValues": { "date": date },
+                // Mix of small and target-sized files so the analyzer has work to do
+                "size": if idx % 3 == 0 { 2_000_000 } else { 134_217_728 },
+                "modificationTime": timestamp,
+                "dataChange": true,
+                "stats": stats.to_string(),
+            } });
+            writeln!(out, "{}", add).unwrap();
+        }
+        out.flush().unwrap();
+    }
+
+    dir
+}
+
+fn commit_path(log: &Path, version: usize) -> std::path::PathBuf {
+    log.join(format!("{:020}.json", version))
+}
+
+fn benchmarks(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+
+    for num_files in SIZES {
+        // Built on first use, so sizes the filter skips are never generated
+        let table: OnceCell<TempDir> = OnceCell::new();
+        let inspector: OnceCell<DeltaTableInspector> = OnceCell::new();
+        let stats: OnceCell<TableStatistics> = OnceCell::new();
+        let path = || table.get_or_init(|| synthetic_table(num_files)).path().to_string_lossy().to_string();
+        let inspector = || {
+            inspector.get_or_init(|| {
+                runtime
+                    .block_on(DeltaTableInspector::new(&path(), HashMap::new()))
+                    .expect("open synthetic table")
+            })
+        };
+        let stats = || stats.get_or_init(|| runtime.block_on(inspector().get_statistics()).expect("statistics"));
+
+        let mut group = c.benchmark_group("inspector");
+        group.sample_size(10);
+
+        group.bench_function(BenchmarkId::new("log_replay", num_files), |b| {
+            let path = path();
+            b.to_async(&runtime)
+                .iter(|| async { DeltaTableInspector::new(&path, HashMap::new()).await.unwrap() });
+        });
+
+        group.bench_function(BenchmarkId::new("get_statistics", num_files), |b| {
+            let inspector = inspector();
+            b.to_async(&runtime).iter(|| async { inspector.get_statistics().await.unwrap() });
+        });
+
+        group.bench_function(BenchmarkId::new("analyze", num_files), |b| {
+            let stats = stats();
+            b.iter(|| DeltaTableAnalyzer::new(stats.clone()).analyze());
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+//! Records the resolved deltalake version so reports can say which one
+//! produced them.
+
+use std::path::Path;
+
+fn main() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    // The lock file sits next to the manifest, or in a workspace root above it
+    let lock = Path::new(&manifest_dir)
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.is_file());
+
+    let version = lock
+        .as_ref()
+        .and_then(|path| {
+            println!("cargo:rerun-if-changed={}", path.display());
+            std::fs::read_to_string(path).ok()
+        })
+        .and_then(|lock| {
+            lock.split("[[package]]")
+                .find(|package| package.lines().any(|line| line.trim() == "name = \"deltalake\""))
+                .and_then(|package| {
+                    package.lines().find_map(|line| {
+                        line.trim()
+                            .strip_prefix("version = \"")
+                            .and_then(|v| v.strip_suffix('"'))
+                            .map(str::to_string)
+                    })
+                })
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=DELTALAKE_VERSION={}", version);
+    println!("cargo:rerun-if-changed=build.rs");
+}
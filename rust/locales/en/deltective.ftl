@@ -0,0 +1,629 @@
+# English messages for deltective. This locale is the fallback for every
+# other one, so it must define every message.
+#
+# Insight messages carry the title as their value and the rest of the text
+# in `.description` and `.recommendation` attributes. Titles that include
+# values also get a `.fleet-title` without them, used to group the insight
+# across tables in fleet reports.
+
+## Insights
+
+insight-table-looks-good = Table Configuration Looks Good
+    .description = No significant configuration issues detected.
+    .recommendation = Continue monitoring the table as data grows.
+
+insight-small-files-critical = Small Files Problem Detected
+    .description = { $pct_small }% of files ({ $small_files }/{ $total_files }) are smaller than { $small_file_mb }MB. Average file size: { $avg_size_mb }MB. Small files severely impact query performance.
+    .recommendation = Run OPTIMIZE command to compact small files. Target file size is ~{ $target_file_mb }MB. Consider using Auto Optimize for future writes.
+
+insight-small-files-warning = Some Small Files Detected
+    .description = { $pct_small }% of files are smaller than { $small_file_mb }MB. Average file size: { $avg_size_mb }MB.
+    .recommendation = Consider running OPTIMIZE to improve performance. Monitor file sizes and run OPTIMIZE periodically.
+
+insight-suboptimal-file-size = Suboptimal Average File Size
+    .description = Average file size ({ $avg_size_mb }MB) is much smaller than optimal ({ $target_file_mb }MB).
+    .recommendation = Run OPTIMIZE to compact files to optimal size. Configure Auto Optimize for future writes.
+
+insight-high-file-count = High File Count
+    .description = Table has { $num_files } files. Recommended maximum is ~{ $max_files } files. High file count increases metadata overhead and slows queries.
+    .recommendation = Run OPTIMIZE to reduce file count. Consider using Auto Optimize and adjusting partition strategy.
+
+insight-never-vacuumed = Table Has Never Been Vacuumed
+    .description = Table has { $total_versions } versions but has never been vacuumed. Old data files are accumulating, increasing storage costs.{ $reclaimable }
+    .recommendation = Run VACUUM command to remove old data files. Set up periodic VACUUM jobs (weekly or monthly). Note: VACUUM deletes old versions permanently.
+
+insight-not-vacuumed-in-log = No VACUUM in the Retained Log
+    .description = None of the { $total_versions } commits still in the log (versions { $oldest_version } on, delta.logRetentionDuration of { $log_hours } hours) is a VACUUM. Old data files are accumulating, increasing storage costs.{ $reclaimable }
+    .recommendation = Run VACUUM to remove old data files and schedule it more often than the log retention, so each run stays visible in the history. Note: VACUUM deletes old versions permanently.
+
+insight-reclaimable = { " " }VACUUM at the { $retention_hours }-hour retention would delete at least { $files ->
+        [one] { $files } removed file
+       *[other] { $files } removed files
+    } and reclaim at least { $size } (files whose tombstones a checkpoint already dropped aren't counted).{ $cost }
+insight-reclaimable-none = { " " }No removed files in the log are past the { $retention_hours }-hour retention yet; files whose tombstones a checkpoint already dropped aren't counted.
+
+insight-monthly-cost = { " " }That is about { $amount } a month at { $storage_class } storage prices.
+
+insight-reclaimable-storage = At Least { $size } Reclaimable by VACUUM
+    .fleet-title = Storage Reclaimable by VACUUM
+    .description = { $files ->
+        [one] { $files } removed file still takes
+       *[other] { $files } removed files still take
+    } up at least { $size } ({ $pct }% of the table's live size) past the { $retention_hours }-hour retention.{ $cost }
+    .recommendation = Run VACUUM to delete them, and schedule it after large rewrites (OPTIMIZE, overwrites, MERGE) so their leftovers don't linger.
+
+insight-vacuum-overdue = Vacuum Overdue
+    .description = Last vacuum was { $days } days ago. Old data files may be accumulating.{ $reclaimable }
+    .recommendation = Run VACUUM to clean up old files. Recommended vacuum frequency: every { $vacuum_days } days.
+
+insight-not-partitioned = Table Not Partitioned
+    .description = Table is { $size } but has no partitioning. Partitioning can improve query performance by enabling partition pruning.
+    .recommendation = Consider partitioning by frequently filtered columns (e.g., date, region, category). Avoid over-partitioning (too many partitions).
+
+insight-over-partitioned = Over-Partitioned Table
+    .description = Table has { $partitions } partitions with average { $files_per_partition } files per partition. Too many partitions creates excessive metadata overhead.
+    .recommendation = Consider coarser partitioning strategy (e.g., partition by month instead of day). Alternatively, use Z-ordering instead of partitioning.
+
+insight-under-partitioned = Under-Partitioned Table
+    .description = Table has only { $partitions ->
+        [one] { $partitions } partition
+       *[other] { $partitions } partitions
+    } with { $files_per_partition } files per partition on average. More granular partitioning could improve query performance.
+    .recommendation = Consider finer-grained partitioning if queries frequently filter on specific columns.
+
+insight-regular-optimization = Consider Regular Optimization
+    .description = Table has { $total_versions } versions in its log (delta.logRetentionDuration keeps { $log_hours } hours of commits) and { $num_files } files. Regular optimization can maintain performance.
+    .recommendation = Set up periodic OPTIMIZE jobs (weekly or after major writes). Enable Auto Optimize for automatic compaction.
+
+insight-optimize-overdue = OPTIMIZE Overdue
+    .description = OPTIMIZE last ran { $days } days ago while ~{ $small_files } files under { $small_file_mb }MB accumulated since.{ $cadence }
+    .recommendation = Run OPTIMIZE now and schedule it, e.g. every { $optimize_days } days or after large loads. Enable Auto Optimize so writers compact as they go.
+
+insight-cadence-usual = { " " }It usually runs every { $days } days.
+
+insight-irregular-maintenance = Irregular { $operation } Schedule
+    .fleet-title = Irregular Maintenance Schedule
+    .description = { $operation } ran { $runs } times with no steady cadence: a median of { $median } days between runs, but gaps from { $shortest } to { $longest } days.
+    .recommendation = Run { $operation } from a scheduled job rather than by hand, so small files and removed files don't pile up during the long gaps.
+
+insight-data-skew = Data Skew Detected
+    .description = High variance in file sizes detected (CV: { $cv }). File sizes range from { $min_size } to { $max_size }. This indicates data skew which can cause uneven processing.
+    .recommendation = Run OPTIMIZE to balance file sizes. Consider using Z-ordering or different partitioning strategy. Review data distribution in partition columns.
+
+insight-small-writes = Many Small Writes Detected
+    .description = Table has { $total_versions } versions with ~{ $files_per_version } files added per write on average. Frequent small writes create many small files.
+    .recommendation = Batch writes together when possible. Enable Auto Optimize to automatically compact small files. Consider using Delta's MERGE operation for incremental updates.
+
+insight-missing-partitions = Missing Date Partitions
+    .description = { $missing_days ->
+        [one] { $missing_days } day has
+       *[other] { $missing_days } days have
+    } no data in { $column } between { $first } and { $last }: { $gaps }.
+    .recommendation = Check whether the upstream pipeline skipped or failed on these dates and backfill if needed. Gaps in an otherwise continuous range usually indicate a missed run.
+insight-missing-partitions-more = { $gaps } (+{ $more } more)
+
+insight-partition-rewrites = Partitions Rewritten Repeatedly
+    .description = { $partitions ->
+        [one] { $partitions } partition has
+       *[other] { $partitions } partitions have
+    } been overwritten { $threshold } or more times by replaceWhere/dynamic overwrite commits. Most rewritten: { $top }.
+    .recommendation = Repeated overwrites of the same partition usually mean the upstream job is not idempotent or is being retried. Check scheduling and retry logic, and consider MERGE for incremental corrections.
+
+insight-cdf-behind = { $versions ->
+        [one] { $versions } version
+       *[other] { $versions } versions
+    }
+insight-cdf-behind-hours = { $versions ->
+        [one] { $versions } version
+       *[other] { $versions } versions
+    } / { $hours } hours
+
+insight-cdf-at-risk = CDF Consumer '{ $name }' Near Retention Limit
+    .fleet-title = CDF Consumer Near Retention Limit
+    .description = Consumer is { $behind } behind (last processed version { $last_processed }); change data is only kept for { $retention_hours } hours.
+    .recommendation = Catch the consumer up, or raise delta.deletedFileRetentionDuration and delta.logRetentionDuration before its unprocessed changes are cleaned up.
+
+insight-cdf-expired = CDF Consumer '{ $name }' Fell Outside Retention
+    .fleet-title = CDF Consumer Fell Outside Retention
+    .description = Consumer is { $behind } behind (last processed version { $last_processed }), beyond the { $retention_hours }-hour retention window. Changes after version { $last_processed } may already be vacuumed or removed from the log.
+    .recommendation = Reading the change feed from its last processed version may fail or miss changes; rebuild the consumer from a full snapshot of the table.
+
+insight-cdf-unknown = Lag of CDF Consumer '{ $name }' Unknown
+    .fleet-title = Lag of CDF Consumer Unknown
+    .description = Consumer is { $behind } behind (last processed version { $last_processed }), but the commit after it couldn't be read or records no commit time, so the oldest unprocessed change can't be dated.
+    .recommendation = Check that the transaction log is readable with the configured credentials; the consumer's standing against the { $retention_hours }-hour retention window can't be judged until it is.
+
+assumption-truncated-history = History covers { $retained } of { $versions } versions; older commits were removed by log cleanup.
+assumption-missing-commit-metrics = Files per commit are averaged from the snapshot because commits carry no operation metrics.
+assumption-sampled = Projected from { $sampled } of { $total } files ({ $sampled_size }).
+
+insight-checkpoint-stale = Stale Checkpoint
+    .description = The latest checkpoint is at version { $checkpoint_version }, { $commits } commits behind version { $version }. Every reader replays those JSON commits on top of it (more than { $max_commits } counts as stale), although delta.checkpointInterval asks for a checkpoint every { $interval } commits.
+    .recommendation = Write a checkpoint with `deltective checkpoint`, and check that writers honor delta.checkpointInterval; some engines skip checkpoints on append-only or streaming writes.
+
+insight-no-checkpoint = No Checkpoint
+    .description = The table has no checkpoint, so every reader replays all { $commits } JSON commits (more than { $max_commits } counts as stale), although delta.checkpointInterval asks for one every { $interval } commits.
+    .recommendation = Write a checkpoint with `deltective checkpoint`, and check that writers honor delta.checkpointInterval.
+
+insight-log-retention-short = Log Retention Shorter Than File Retention
+    .description = delta.logRetentionDuration ({ $log_hours } hours) is shorter than delta.deletedFileRetentionDuration ({ $file_hours } hours). Time travel stops where the log does, so removed files are kept longer than any version can use them.
+    .recommendation = Set both retentions to the time travel window you need, e.g. ALTER TABLE ... SET TBLPROPERTIES ('delta.logRetentionDuration' = 'interval { $file_hours } hours'), or shorten delta.deletedFileRetentionDuration to save storage.
+
+insight-vacuumed-versions-listed = History Lists Unreadable Versions
+    .description = { $versions } version(s) from { $oldest_logged } are still in the log but VACUUM deleted their files; time travel works from version { $oldest_readable } on. The log is kept { $log_hours } hours, files only { $file_hours } hours.
+    .recommendation = Align delta.deletedFileRetentionDuration with delta.logRetentionDuration if older versions must stay readable, or shorten the log retention so history only lists versions that load.
+
+insight-growth-accelerating = Accelerating Growth
+    .description = The table ({ $size }) grew by { $recent_rate }/day over the newer half of its last { $commits } commits, against { $earlier_rate }/day before ({ $days } days in all). { $date ->
+        [none] No configured size threshold is ahead of it at the current overall rate.
+       *[other] At the overall rate it reaches { $threshold } around { $date }.
+    }
+    .recommendation = Check for new writers or backfills, duplicated loads and skipped VACUUMs, and plan storage budgets for the forecast; set `size_forecast_gb` under `thresholds` to the sizes that matter to you.
+
+insight-write-amplification = High Write Amplification
+    .description = { $commits } MERGE/UPDATE/DELETE commit(s) changed { $rows_changed } rows but copied { $rows_copied } unchanged rows along with them: { $ratio } rows written per row changed (above { $max_ratio }). They rewrote { $bytes_rewritten } to change about { $bytes_changed }.
+    .recommendation = { $deletion_vectors ->
+        [enabled] Deletion vectors are enabled, but writers still rewrite files; check that every engine writing the table supports them. Partition or cluster on the MERGE/UPDATE keys so fewer files are touched.
+       *[other] Enable deletion vectors (ALTER TABLE ... SET TBLPROPERTIES ('delta.enableDeletionVectors' = true)) so deletes and updates mark rows instead of rewriting files, and partition or cluster on the MERGE/UPDATE keys so fewer files are touched.
+    }
+
+insight-write-contention = Concurrent Writers Contend
+    .description = { $overlapping } of { $conflicting } non-append commits ({ $overlap_pct }%) were planned against a version other writers had already moved past, by up to { $max_overlap } commit(s); { $operation } overlaps most. { $bursts } landed within a second of the previous commit. These passed conflict checks; writers that failed them retried or errored without a trace in the log.
+    .recommendation = { $partitioned ->
+        [yes] Give each concurrent job its own partitions and put the partition columns in MERGE/UPDATE/DELETE predicates so conflict checks can tell them apart, or use WriteSerializable isolation; add retries with jittered backoff for ConcurrentAppend/ConcurrentDelete exceptions.
+       *[other] Partition or cluster the table so concurrent jobs touch disjoint files and put those columns in MERGE/UPDATE/DELETE predicates, or serialize the writers; add retries with jittered backoff for ConcurrentAppend/ConcurrentDelete exceptions.
+    }
+
+insight-deletion-vector-density = Dense Deletion Vectors
+    .description = { $files } of { $total_files } files carry deletion vectors marking { $deleted_rows } rows as deleted, { $density_pct }% of the rows in those files (above { $max_pct }%). About { $dead_bytes } of data is still stored and scanned for deleted rows, plus { $dv_bytes } of deletion vectors.
+    .recommendation = Run REORG TABLE ... APPLY (PURGE) or OPTIMIZE to rewrite the files without the deleted rows, then VACUUM once the retention period has passed to delete the old files.
+
+insight-breaking-schema-change = Breaking Schema Change
+    .description = { $commits } commit(s) in the last { $versions } versions dropped a column or changed a column to an incompatible type: { $changes }.
+    .recommendation = Check that downstream jobs, views and streaming readers no longer reference the old columns or types. Streaming readers stop on non-additive schema changes and need schemaTrackingLocation or a restart from a new checkpoint.
+
+insight-partition-on-timestamp = Partitioned on a Raw Timestamp
+    .description = Partition column { $column } is a timestamp, so nearly every distinct value gets its own partition directory with a few small files.
+    .recommendation = Partition on a generated column instead, e.g. { $column }_date DATE GENERATED ALWAYS AS (CAST({ $column } AS DATE)). Queries filtering on { $column } still prune partitions, because Delta derives the partition filter from the generation expression.
+
+insight-generated-partition-unprunable = Generated Partition Column Without Partition Pruning
+    .description = Partition column { $column } is generated as { $expression }, which Delta can't derive partition filters from, so queries filtering on the source column scan every partition.
+    .recommendation = Generate partition columns with CAST(... AS DATE), YEAR, MONTH, DAY, HOUR, DATE_FORMAT, DATE_TRUNC, TRUNC or SUBSTRING, or have queries filter on { $column } directly.
+
+insight-zstd-savings = ZSTD Recompression Would Save Storage
+    .description = Files are written with { $codecs }. Rewriting { $sampled_files } sampled file(s) with ZSTD level { $level } made them { $savings_pct }% smaller, projecting { $projected_savings } saved across the table's { $total_size }.{ $cost }
+    .recommendation = Switch writers to ZSTD (Spark: spark.sql.parquet.compression.codec=zstd; delta-rs: WriterProperties with Compression::ZSTD(level { $level })), then run OPTIMIZE so existing files are rewritten.
+
+insight-data-skipping = Poor Data Skipping
+    .description = Across { $files } files, min/max statistics barely narrow down the files to read for filters on { $poor }: each file spans most of the column's values. Columns where skipping works: { $effective }.
+    .recommendation = If queries filter on these columns, co-locate their values: OPTIMIZE ... ZORDER BY ({ $candidates }), or ALTER TABLE ... CLUSTER BY ({ $candidates }) on engines with liquid clustering. Keep it to the columns queries filter on most; each extra column weakens the others.
+
+insight-uncompressed-files = Uncompressed Parquet Files
+    .description = { $files } of { $audited } audited files store column chunks without compression ({ $bytes }): { $codecs }.
+    .recommendation = Set a codec on the writer (Spark: spark.sql.parquet.compression.codec=zstd or snappy) and run OPTIMIZE to rewrite the uncompressed files.
+
+insight-unexpected-codec = Files Not Written with { $expected }
+    .fleet-title = Files Not Written with the Expected Codec
+    .description = The table is meant to use { $expected }, but { $files } of { $audited } audited files use other codecs: { $codecs }.
+    .recommendation = Find the writers still on the old codec (see the Timeline tab's writer attribution), switch them to { $expected } and run OPTIMIZE to rewrite the existing files.
+
+insight-mixed-codecs = Mixed Compression Codecs
+    .description = Most data is { $dominant }, but { $files } of { $audited } audited files use other codecs: { $codecs }. Different writers are likely configured differently.
+    .recommendation = Pick one codec for every writer of the table and set expected_codec in the config file so deviations are reported as warnings.
+
+insight-late-arriving = Late-Arriving Data Detected
+    .description = { $pct_late }% of files ({ $late_files }, { $late_size }) were written more than { $grace_days } day(s) after their { $column } partition date. 95th percentile delay: { $p95 } days, maximum: { $max } days.
+    .recommendation = Set streaming watermarks and reprocessing windows to cover at least { $window_days } days. Late files land as small files in old partitions, so include recent-but-closed partitions when scheduling OPTIMIZE.
+
+## Diagnosis
+#
+# Links are the titles of the insights in the chain, plus the phrases
+# below; links are joined with " → " and co-causes with " + ", so they
+# should read well in a single line.
+
+diagnosis-slow-queries = Why queries are slow
+    .link = slower queries (more files to list, open and plan)
+    .recommendation = Fix the cause at the start of the chain first: batch writes or enable auto-compaction (delta.autoOptimize.autoCompact) so new files are large enough, then run OPTIMIZE once and schedule it regularly.
+diagnosis-storage-growth = Why storage keeps growing
+    .link = growing storage cost (replaced files are never deleted)
+    .recommendation = Schedule VACUUM after the jobs that rewrite data, with a retention that covers your time-travel needs; files replaced by every commit are otherwise kept forever.
+diagnosis-no-auto-compaction = no auto-compaction or optimized writes
+
+## TUI
+
+severity-critical = CRITICAL
+severity-warning = WARNING
+severity-info = INFO
+severity-good = OK
+
+tui-title = Deltective — { $path }
+tui-title-loading = Deltective — { $path } { $spinner } loading…
+tab-overview = Overview
+tab-history = History
+tab-insights = Insights
+tab-configuration = Configuration
+tab-timeline = Timeline
+tab-partitions = Partitions
+tab-column-stats = Column Stats
+tab-data = Data
+tab-commits = Commits
+tab-schema = Schema
+tui-loading = Loading { $what }...
+tui-load-failed = Failed to load { $what }: { $error }
+tui-tab-panic = This tab failed to render
+tui-error = Error
+tui-what-statistics = statistics
+tui-what-history = history
+tui-what-configuration = configuration
+tui-what-timeline = timeline data
+tui-what-rows = rows
+tui-what-commit = commit
+
+insights-heading = ═══ TABLE HEALTH & RECOMMENDATIONS ═══
+insights-critical = 🔴 CRITICAL ISSUES
+insights-warnings = ⚠️  WARNINGS
+insights-recommendations = ℹ️  RECOMMENDATIONS
+insights-good = ✅ GOOD CONFIGURATION
+insights-summary = ═══ SUMMARY ═══
+insights-summary-critical = Critical:
+insights-summary-warnings = Warnings:
+insights-summary-info = Info:
+insights-block-title = Insights [↑↓ scroll]
+insights-file-sizes-title = File Sizes ({ $files } files)
+insights-diagnosis = 🔎 DIAGNOSIS
+insights-category = Category: { $category }
+what-if-title = What if [t: close]
+what-if-small-file = Small file (MB):
+what-if-target-file = Target file (MB):
+what-if-max-files = Max files:
+what-if-configured = config: { $value }
+what-if-effect = Effect on insights
+what-if-snippet = Config file snippet
+what-if-hint = ↑↓: threshold  ←→: change  0: reset
+insights-confidence = Confidence: { $confidence }, based on partial data:
+insights-recommendation = → Recommendation:
+
+overview-block-title = Overview [↑↓ scroll]
+overview-heading = ═══ TABLE OVERVIEW ═══
+overview-table-path = Table Path:
+overview-current-version = Current Version:
+overview-of-total = (of { $total } total)
+overview-write-activity = ⚠ Table is actively being written; snapshot pinned at version { $version }
+overview-oldest-version = Oldest Available Version:
+overview-time-travel = Time Travel:
+overview-time-travel-window = versions { $oldest }–{ $latest } ({ $count } readable{ $since ->
+        [none] {""}
+       *[other] , back to { $since }
+    })
+overview-time-travel-vacuumed = { $versions } older logged version(s) lost files to VACUUM
+overview-time-travel-retention = log kept { $log_hours }h, removed files { $file_hours }h
+overview-num-files = Number of Files:
+overview-total-size = Total Size:
+overview-num-rows = Number of Rows:
+overview-partition-columns = Partition Columns:
+overview-created = Created:
+overview-table-name = Table Name:
+overview-description = Description:
+overview-protocol-heading = ═══ DELTA PROTOCOL & HISTORY ═══
+overview-min-reader = Min Reader Version:
+overview-min-writer = Min Writer Version:
+overview-reader-features = Reader Features:
+overview-writer-features = Writer Features:
+overview-last-operation = Last Operation:
+overview-time = Time:
+overview-last-vacuum = Last Vacuum:
+overview-never = Never
+overview-write-amplification = Write Amplification:
+overview-deletion-vectors = Deletion Vectors:
+overview-deletion-vectors-detail = { $files } file(s), { $deleted_rows } deleted rows{ $density ->
+        [none] {""}
+       *[other] {" "}({ $density }% of their rows)
+    }, { $dv_bytes } of vectors ({ $inline } inline)
+overview-write-amplification-detail = rows written per row changed over { $commits } MERGE/UPDATE/DELETE commit(s), { $rewritten } rewritten
+overview-schema = Schema:
+overview-schema-columns = { $count } columns{ $nested ->
+        [0] {""}
+       *[other] , { $nested } nested fields
+    }
+overview-schema-see-tab = (see the Schema tab)
+overview-cost-heading = ═══ STORAGE COST ═══
+overview-cost-price = Price:
+overview-cost-price-detail = { $price } per GB-month ({ $storage_class })
+overview-cost-data = Live Data:
+overview-cost-tombstoned = Removed Files:
+overview-cost-reclaimable = at least { $amount }/month of it is past the retention and would be saved by VACUUM
+overview-cost-total = Total:
+overview-cost-monthly = { $amount }/month
+overview-cost-projected = Projected:
+overview-cost-projection = { $amount }/month in { $days } days
+
+schema-block-title = Schema [↑↓ scroll | s: search names | f: filter by type | e: expand nested | Esc: clear]
+schema-heading = ═══ SCHEMA ═══
+schema-summary = { $columns } columns, { $nested } nested fields · { $partitions } partition column(s) · { $indexed } indexed for data skipping · { $with_stats } with file statistics
+schema-search = Column search:
+schema-type-filter = Type:
+schema-matches = ({ $matching } of { $total } columns match)
+schema-no-match = No columns match the search and type filter.
+schema-column = Column
+schema-type = Type
+schema-nullable = Nullable
+schema-annotations = Annotations
+schema-fields = ({ $count } fields)
+schema-partition = [partition]
+schema-indexed = [indexed]
+schema-stats = stats in { $files }/{ $total } files
+schema-nulls = { $pct }% null
+
+replay-playing = ▶ replay v{ $version } of { $latest } · every { $seconds }s
+replay-paused = ⏸ paused at v{ $version } of { $latest } · Space: play
+replay-preparing = ▶ replay: loading versions…
+
+history-block-title = History [Page { $page }/{ $pages } | ↑↓:select Enter:details n:next p:prev r:reverse f:operation d:dates m:search messages | j/k:scroll]
+history-heading = ═══ OPERATION HISTORY ═══
+history-newest-first = (newest first)
+history-refresh-failed = Reload failed, showing the history loaded before: { $error }
+history-oldest-first = (oldest first)
+history-message-search = Message search:
+history-matches = ({ $matching } of { $total } commits match)
+history-dates-prompt = Dates (FROM..TO, YYYY-MM-DD):
+history-filter = Filter:
+history-filter-matches = ({ $matching } of { $total } commits match, Esc clears)
+history-empty = No history entries found.
+history-no-matches = No commits match the search and filters.
+history-unknown-operation = Unknown
+history-version = Version { $version }
+history-message = Message:
+history-partitioned = partitioned
+history-predicate = where: { $predicate }
+history-showing = Showing { $first }-{ $last } of { $total } entries
+
+commit-detail-block-title = Commit Detail [Esc:back to history | ↑↓:scroll]
+commit-detail-heading = ═══ COMMIT { $version } ═══
+commit-detail-operation = Operation:
+commit-detail-time = Time:
+commit-detail-user = User:
+commit-detail-engine = Engine:
+commit-detail-isolation = Isolation level:
+commit-detail-blind-append = Blind append:
+commit-detail-read-version = Read version:
+commit-detail-txn-id = Transaction ID:
+commit-detail-message = Message:
+commit-detail-parameters = Operation Parameters
+commit-detail-metrics = Operation Metrics
+commit-detail-other = Other Fields
+commit-detail-files = Files Touched
+commit-detail-read-failed = Failed to read the commit: { $error }
+commit-detail-reading = Reading the commit...
+commit-detail-no-files = No data files changed
+commit-detail-added = { $count } added ({ $size })
+commit-detail-removed = { $count } removed ({ $size })
+commit-detail-change-data = { $count } change data ({ $size })
+commit-detail-more-files = ... and { $count } more
+
+data-block-title = Data [↑↓ scroll | t: whole table | d on Partitions: preview partition/file]
+data-heading = ═══ DATA PREVIEW ═══
+data-source = Source:
+data-rows = ({ $count } rows)
+data-empty = No rows found.
+
+partitions-title = Partitions
+partitions-explorer-heading = ═══ PARTITION EXPLORER ═══
+partitions-not-partitioned = Table is not partitioned.
+partitions-hierarchy-heading = ═══ PARTITION HIERARCHY ═══
+partitions-columns = Partition Columns:
+partitions-count = Partitions:
+partitions-level = Level { $level }
+partitions-level-summary = { $partitions } partitions  { $files } files/partition  { $size }/partition
+partitions-tree-heading = ═══ PARTITION TREE ═══
+partitions-node-summary = { $files } files, { $size }
+partitions-tree-title = Partitions [↑↓ select | Enter: expand/collapse | v: list view]
+partitions-list-heading = ═══ PARTITIONS ═══
+partitions-sorted-by = (sorted by { $sort })
+partitions-sort-size = size
+partitions-sort-files = files
+partitions-sort-rows = rows
+partitions-sort-modified = last modified
+partitions-sort-name = name
+partitions-col-partition = Partition
+partitions-col-files = Files
+partitions-col-size = Size
+partitions-col-rows = Rows
+partitions-col-modified = Last Modified
+partitions-list-title = Partitions [↑↓ select | Enter: files | d: preview rows | s: sort | v: tree view]
+partitions-files-heading = ═══ PARTITION FILES ═══
+partitions-partition = Partition:
+partitions-files = Files:
+partitions-size = Size:
+partitions-file-rows = { $rows } rows
+partitions-files-title = Partition Files [↑↓ select | d: preview rows | f: Parquet footer | Esc: back]
+footer-title = Parquet Footer [↑↓ scroll | Esc: back]
+footer-reading = Reading Parquet footer...
+footer-read-failed = Failed to read the footer: { $error }
+
+timeline-block-title = Timeline [↑↓ scroll]
+timeline-heading = ═══ TABLE TIMELINE & ACTIVITY ═══
+timeline-activity-summary = 📊 Activity Summary
+timeline-total-operations = Total Operations:
+timeline-version-rate = Version Creation Rate:
+timeline-versions-per-day = { $rate } versions/day
+timeline-unknown-operation = Unknown
+timeline-first-operation = First Operation:
+timeline-latest-operation = Latest Operation:
+timeline-operations-by-type = 📈 Operations by Type
+timeline-no-operations = No operation data available
+timeline-who-writes = 👥 Who Writes
+timeline-engines = Engines
+timeline-users = Users
+timeline-jobs = Jobs
+timeline-more-contributors = ... and { $count } more
+timeline-heatmap-heading = 🗓  Commits by Hour (UTC) and Weekday
+timeline-mon = Mon
+timeline-tue = Tue
+timeline-wed = Wed
+timeline-thu = Thu
+timeline-fri = Fri
+timeline-sat = Sat
+timeline-sun = Sun
+timeline-quietest-hour = Quietest hour:
+timeline-hour-range = { $from }:00–{ $to }:00 UTC
+timeline-quietest-hour-hint = ({ $commits } commit(s)), a candidate window for OPTIMIZE and VACUUM
+timeline-write-patterns = 🔍 Write Pattern Analysis
+timeline-no-write-patterns = No unusual write patterns detected
+timeline-insights = 💡 Timeline Insights
+timeline-rate-very-high = Very high version creation rate
+timeline-rate-very-high-hint = Consider running OPTIMIZE more frequently to manage file growth
+timeline-rate-moderate = Moderate version creation rate
+timeline-rate-moderate-hint = Regular OPTIMIZE operations recommended
+timeline-rate-normal = Normal version creation rate
+timeline-extensive-history = Table has extensive history ({ $operations } operations)
+timeline-extensive-history-hint = Consider periodic VACUUM to manage storage costs
+timeline-activity-title = Commits per Day ({ $from } – { $to }, peak { $peak }/day)
+timeline-growth-title = Table Size, Last { $commits } Commits
+timeline-size-series = size
+timeline-forecast-series = forecast
+timeline-gb = { $size } GB
+timeline-growth = Growth:
+timeline-growth-rate = { $bytes }/day, { $files } files/day
+timeline-growth-window = (last { $commits } commits over { $days } days)
+timeline-recent-vs-earlier = Recent vs Earlier:
+timeline-rate-comparison = { $recent }/day vs { $earlier }/day
+timeline-reaches = Reaches { $size }:
+timeline-already-reached = already reached
+timeline-reached-around = around { $date }
+timeline-not-reached = not at the current rate
+configuration-heading = ═══ TABLE CONFIGURATION ═══
+configuration-properties = 📋 Table Properties
+configuration-no-properties = No custom properties configured
+configuration-metadata = 🏷️  Table Metadata
+configuration-table-id = Table ID:
+configuration-table-name = Table Name:
+configuration-description = Description:
+configuration-partition-columns = Partition Columns:
+configuration-protocol = ⚙️  Protocol Versions
+configuration-min-reader = Min Reader Version:
+configuration-min-writer = Min Writer Version:
+configuration-reader-features = Reader Features:
+configuration-writer-features = Writer Features:
+configuration-advanced = 🚀 Advanced Features
+configuration-auto-compact = auto compact
+configuration-optimize-write = optimize write
+configuration-vacuum-retention = Vacuum Retention:
+configuration-hours = { $hours } hours
+configuration-log-retention = Log Retention:
+configuration-time-travel = (time travel reaches back at most { $hours } hours)
+configuration-checkpoint-interval = Checkpoint Interval:
+configuration-every-commits = every { $commits } commits
+configuration-constraints = 🔒 Constraints
+configuration-no-constraints = No CHECK constraints
+configuration-constraints-hint = Run `deltective constraints --evaluate` to count violating rows
+configuration-domains = 🧩 Domain Metadata
+configuration-no-domains = No domains recorded
+configuration-from-checkpoint = checkpoint
+configuration-version = version { $version }
+configuration-removed = removed
+configuration-checkpoint = 📦 Checkpoint Contents
+configuration-parts = { $parts } parts
+configuration-actions = { $actions } actions
+configuration-none = none
+configuration-commits-since-checkpoint = Commits since checkpoint:
+configuration-checkpoint-hint = Press i to read the latest checkpoint and compare it with the JSON commits
+configuration-reading-checkpoint = Reading checkpoint...
+configuration-checkpoint-failed = Failed to read checkpoint: { $error }
+configuration-block-title = Configuration [i: inspect checkpoint | ↑↓ scroll]
+configuration-column-mapping = Column Mapping:
+configuration-enabled-at = Enabled at:
+configuration-files-before-mapping = { $files } file(s) were written before mapping was enabled: columns under their original names, no Parquet field IDs
+configuration-no-physical-name = (no physical name)
+configuration-pre-mapping-name = (pre-mapping name)
+configuration-clustering = Liquid Clustering:
+configuration-clustering-none = Supported, no clustering columns (CLUSTER BY NONE)
+configuration-from-history = (from history)
+configuration-columns = Columns:
+configuration-on = on { $time }
+configuration-last-cluster-by = Last CLUSTER BY:
+configuration-operation-at = { $operation } at version { $version }
+configuration-not-supported = Not supported
+configuration-suspended = Enabled, suspended
+configuration-row-tracking-off = Supported, not enabled (delta.enableRowTracking)
+configuration-row-tracking = Row Tracking:
+configuration-files-with-row-ids = Files with baseRowId
+configuration-of = { $count } of { $total }
+configuration-row-ids = Row IDs
+configuration-row-commit-versions = defaultRowCommitVersion
+configuration-files-without-row-ids = Files without baseRowId predate row tracking or came from an engine that doesn't assign IDs
+configuration-row-id-column = Row ID column
+configuration-row-commit-version-column = Row commit version column
+configuration-materialized-overhead = Rows rewritten by UPDATE, MERGE or OPTIMIZE keep their IDs in these hidden columns: { $bytes } bytes per row each before compression, up to { $total } for all { $rows } tracked rows
+configuration-enabled = Enabled
+configuration-disabled = Disabled
+configuration-deletion-vectors = Deletion Vectors:
+configuration-auto-optimize = Auto Optimize:
+column-stats-heading = ═══ COLUMN STATISTICS ═══
+column-stats-none = No per-column statistics found in the transaction log.
+column-stats-none-hint = Without min/max stats, queries cannot skip files based on filters.
+column-stats-column = Column
+column-stats-min = Min
+column-stats-max = Max
+column-stats-nulls = Nulls
+column-stats-null-pct = Null %
+column-stats-files = Files
+column-stats-skipped = Skipped
+column-stats-files-hint = Files column shows how many files carry stats for the column; data skipping only works on those.
+column-stats-skipped-hint = Skipped is the share of files an equality filter on a typical value skips, from how the files' min/max ranges overlap.
+column-stats-block-title = Column Stats [↑↓ scroll]
+search-no-matches = no matches · Esc: clear
+search-position = [{ $position }/{ $matches }] n/N: next/prev · Esc: clear
+tui-open-failed = Failed to open Delta table: { $error }
+commits-block-title = Commits [n:next p:prev g:go to version | ↑↓:scroll]
+commits-heading = ═══ RAW COMMIT ═══
+commits-version = Version { $version }
+commits-range = (available { $oldest }–{ $latest })
+commits-goto = Go to version:
+commits-counts = Actions:
+commits-empty = This commit has no actions.
+commits-more = … { $count } more action(s) not shown
+
+maintenance-confirm-title = Run { $action }?
+maintenance-confirm-hint = y: run   n/Esc: cancel
+maintenance-running-title = { $action } in progress
+maintenance-running = Running { $action }… { $seconds }s elapsed
+maintenance-running-hint = The table is reloaded when the commit lands.
+maintenance-done-title = Maintenance finished
+maintenance-failed-title = Maintenance failed
+maintenance-close-hint = Press any key to close.
+maintenance-vacuum-title = VACUUM: choose retention
+maintenance-vacuum-retention = Retention:
+maintenance-vacuum-hours = { $hours } hours ({ $days } days)
+maintenance-vacuum-table-default = Table setting (delta.deletedFileRetentionDuration): { $hours } hours
+maintenance-vacuum-explain = Files removed from the table longer ago than this are deleted from storage. A dry run lists them first; nothing is deleted until you confirm.
+maintenance-vacuum-simulating = Simulating each retention…
+maintenance-vacuum-col-retention = Retention
+maintenance-vacuum-col-files = Files
+maintenance-vacuum-col-space = Freed
+maintenance-vacuum-col-unreadable = Unreadable versions
+maintenance-vacuum-none = none
+maintenance-vacuum-log-expired = Versions ≤ { $version } are already past delta.logRetentionDuration ({ $hours } hours) and leave the log at the next checkpoint.
+maintenance-vacuum-below-table = ! below the table setting: needs the retention check disabled and can break concurrent readers.
+maintenance-vacuum-hint = ←/→: change retention   Enter: dry run   Esc: cancel
+
+picker-block-title = Tables [↑↓ select | Enter: open | /: filter | q: quit]
+picker-heading = ═══ DELTA TABLES ═══
+picker-count = ({ $count ->
+        [one] { $count } table
+       *[other] { $count } tables
+    })
+picker-filter = Filter:
+picker-matches = ({ $matches } of { $total } match)
+picker-no-matches = No tables match the filter.
+
+compare-block-title = Differences (B relative to A) [↑↓ scroll | q: quit]
+compare-side-title = { $side }: { $path } @ v{ $version }
+compare-file-sizes = File sizes
+
+changes-title = New version { $from } → { $to }
+changes-files-added = +{ $count } files
+changes-files-removed = -{ $count } files
+changes-size = size { $delta }
+changes-rows = rows { $delta }
+changes-new = ▲ new:
+changes-resolved = ✓ resolved:
@@ -0,0 +1,86 @@
+//! Python bindings for the deltective inspector and health analyzer.
+//!
+//! ```python
+//! import deltective_rs
+//!
+//! inspector = deltective_rs.DeltaTableInspector("/data/lake/orders")
+//! stats = inspector.get_statistics()
+//! for insight in inspector.get_insights():
+//!     print(insight["severity"], insight["title"])
+//! ```
+
+use deltective::{health_score, DeltaTableAnalyzer};
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pythonize::pythonize;
+use std::collections::HashMap;
+
+fn to_py_err(e: anyhow::Error) -> PyErr {
+    PyRuntimeError::new_err(format!("{:#}", e))
+}
+
+/// Opens a Delta table and exposes its statistics, configuration and insights
+/// as plain Python dicts and lists.
+#[pyclass]
+struct DeltaTableInspector {
+    runtime: tokio::runtime::Runtime,
+    inner: deltective::DeltaTableInspector,
+}
+
+#[pymethods]
+impl DeltaTableInspector {
+    #[new]
+    #[pyo3(signature = (table_path, storage_options = None))]
+    fn new(py: Python<'_>, table_path: &str, storage_options: Option<HashMap<String, String>>) -> PyResult<Self> {
+        let runtime = tokio::runtime::Runtime::new()?;
+        let inner = py
+            .allow_threads(|| {
+                runtime.block_on(deltective::DeltaTableInspector::new(
+                    table_path,
+                    storage_options.unwrap_or_default(),
+                ))
+            })
+            .map_err(to_py_err)?;
+        Ok(Self { runtime, inner })
+    }
+
+    /// Table statistics as a dict (version, files, sizes, schema, ...).
+    fn get_statistics(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let stats = py
+            .allow_threads(|| self.runtime.block_on(self.inner.get_statistics()))
+            .map_err(to_py_err)?;
+        Ok(pythonize(py, &stats)?)
+    }
+
+    /// Table properties, protocol and feature configuration as a dict.
+    fn get_configuration(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let config = py
+            .allow_threads(|| self.runtime.block_on(self.inner.get_configuration()))
+            .map_err(to_py_err)?;
+        Ok(pythonize(py, &config)?)
+    }
+
+    /// Health findings as a list of dicts with severity, category, title,
+    /// description and recommendation.
+    fn get_insights(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let stats = py
+            .allow_threads(|| self.runtime.block_on(self.inner.get_statistics()))
+            .map_err(to_py_err)?;
+        let insights = DeltaTableAnalyzer::new(stats).analyze();
+        Ok(pythonize(py, &insights)?)
+    }
+
+    /// 0-100 health score derived from the insights.
+    fn health_score(&self, py: Python<'_>) -> PyResult<u8> {
+        let stats = py
+            .allow_threads(|| self.runtime.block_on(self.inner.get_statistics()))
+            .map_err(to_py_err)?;
+        Ok(health_score(&DeltaTableAnalyzer::new(stats).analyze()))
+    }
+}
+
+#[pymodule]
+fn deltective_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<DeltaTableInspector>()?;
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::config::AlertsConfig;
+use crate::fleet::{FleetReport, TableSummary};
+use crate::insights::severity_rank;
+use anyhow::{bail, Context, Result};
+use serde_json::json;
+use std::collections::BTreeMap;
+use url::Url;
+
+const DEFAULT_MIN_SEVERITY: &str = "warning";
+
+/// Result of routing and delivering alerts for a scan.
+#[derive(Debug, Default)]
+pub struct AlertOutcome {
+    /// Destination (see [`destination`]) → number of tables alerted through it
+    pub sent: BTreeMap<String, usize>,
+    /// Tables that needed an alert but matched no route and no default webhook
+    pub unrouted: Vec<String>,
+}
+
+/// Post one Slack-compatible message per webhook, listing every table routed
+/// to it that has findings at or above the configured severity.
+pub async fn send(report: &FleetReport, config: &AlertsConfig) -> Result<AlertOutcome> {
+    if !config.is_configured() {
+        bail!("No alert webhooks configured; add an `alerts` section to the config file");
+    }
+    let min_rank = severity_rank(config.min_severity.as_deref().unwrap_or(DEFAULT_MIN_SEVERITY));
+
+    let mut outcome = AlertOutcome::default();
+    let mut by_webhook: BTreeMap<&str, Vec<&TableSummary>> = BTreeMap::new();
+    for table in &report.tables {
+        if !table.insights.iter().any(|i| severity_rank(&i.severity) >= min_rank) {
+            continue;
+        }
+        match config.webhook_for(&table.tags) {
+            Some(webhook) => by_webhook.entry(webhook).or_default().push(table),
+            None => outcome.unrouted.push(table.table_path.clone()),
+        }
+    }
+
+    let client = reqwest::Client::new();
+    for (webhook, tables) in by_webhook {
+        let payload = json!({ "text": format_message(&tables, min_rank) });
+        let destination = destination(config, webhook);
+        client
+            .post(webhook)
+            .json(&payload)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            // reqwest errors quote the URL, which carries the webhook's secret
+            .map_err(reqwest::Error::without_url)
+            .with_context(|| format!("Failed to deliver alert to {}", destination))?;
+        *outcome.sent.entry(destination).or_default() += tables.len();
+    }
+
+    Ok(outcome)
+}
+
+/// How a webhook is named in logs and errors: its route's `name` and host.
+/// Webhook URLs are bearer secrets, so the path is never shown.
+pub fn destination(config: &AlertsConfig, webhook: &str) -> String {
+    let host = Url::parse(webhook)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "invalid URL".to_string());
+    let name = config
+        .routes
+        .iter()
+        .find(|route| route.webhook == webhook)
+        .and_then(|route| route.name.as_deref())
+        .or_else(|| (config.webhook.as_deref() == Some(webhook)).then_some("default"));
+    match name {
+        Some(name) => format!("{} ({})", name, host),
+        None => host,
+    }
+}
+
+fn format_message(tables: &[&TableSummary], min_rank: u8) -> String {
+    let mut text = format!("Deltective found issues in {} Delta table(s):\n", tables.len());
+    for table in tables {
+        text.push_str(&format!("\n*{}* (health {}/100)\n", table.table_path, table.health_score));
+        for insight in table.insights.iter().filter(|i| severity_rank(&i.severity) >= min_rank) {
+            text.push_str(&format!("• [{}] {}\n", insight.severity, insight.title));
+        }
+    }
+    text
+}
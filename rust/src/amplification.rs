@@ -0,0 +1,114 @@
+use crate::inspector::operation_metrics;
+use deltalake::kernel::CommitInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Operations that rewrite whole files to change some of their rows.
+const REWRITING_OPERATIONS: [&str; 3] = ["MERGE", "UPDATE", "DELETE"];
+
+/// Rows and bytes MERGE, UPDATE and DELETE commits changed, against what they
+/// rewrote to do it, from their operation metrics. Without deletion vectors
+/// every touched file is rewritten in full, unchanged rows included.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WriteAmplification {
+    /// Rows updated, deleted or inserted
+    pub rows_changed: i64,
+    /// Unchanged rows copied into the rewritten files
+    pub rows_copied: i64,
+    /// Bytes of the files the commits wrote
+    pub bytes_rewritten: i64,
+    pub by_operation: BTreeMap<String, OperationAmplification>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OperationAmplification {
+    pub commits: usize,
+    pub rows_changed: i64,
+    pub rows_copied: i64,
+    pub bytes_rewritten: i64,
+}
+
+impl OperationAmplification {
+    fn add(&mut self, changed: i64, copied: i64, bytes: i64) {
+        self.commits += 1;
+        self.rows_changed += changed;
+        self.rows_copied += copied;
+        self.bytes_rewritten += bytes;
+    }
+
+    pub fn ratio(&self) -> Option<f64> {
+        ratio(self.rows_changed, self.rows_copied)
+    }
+}
+
+impl WriteAmplification {
+    /// Sum the metrics of the rewriting commits in `history`. `None` when
+    /// none of them recorded row counts.
+    pub fn from_history(history: &[CommitInfo]) -> Option<Self> {
+        let mut amplification = Self::default();
+        for entry in history {
+            let Some(operation) = entry.operation.as_deref().filter(|op| REWRITING_OPERATIONS.contains(op)) else {
+                continue;
+            };
+            let metrics = operation_metrics(entry);
+            let Some((changed, copied, bytes)) = rows_and_bytes(operation, &metrics) else {
+                continue;
+            };
+            amplification.rows_changed += changed;
+            amplification.rows_copied += copied;
+            amplification.bytes_rewritten += bytes;
+            amplification
+                .by_operation
+                .entry(operation.to_string())
+                .or_default()
+                .add(changed, copied, bytes);
+        }
+        (!amplification.by_operation.is_empty()).then_some(amplification)
+    }
+
+    pub fn commits(&self) -> usize {
+        self.by_operation.values().map(|op| op.commits).sum()
+    }
+
+    /// Rows written per row changed; 1.0 means nothing was copied.
+    pub fn ratio(&self) -> Option<f64> {
+        ratio(self.rows_changed, self.rows_copied)
+    }
+
+    /// Estimated bytes of the changed rows among those rewritten, assuming
+    /// rows of even size.
+    pub fn bytes_changed(&self) -> i64 {
+        match self.ratio() {
+            Some(ratio) => (self.bytes_rewritten as f64 / ratio) as i64,
+            None => 0,
+        }
+    }
+}
+
+fn ratio(changed: i64, copied: i64) -> Option<f64> {
+    (changed > 0).then(|| (changed + copied) as f64 / changed as f64)
+}
+
+/// Rows changed, rows copied and bytes written by one commit, under the
+/// metric names Delta writers use for each operation.
+fn rows_and_bytes(operation: &str, metrics: &HashMap<String, Value>) -> Option<(i64, i64, i64)> {
+    let metric = |name: &str| {
+        metrics.get(name).and_then(|value| match value {
+            Value::Number(n) => n.as_i64(),
+            Value::String(s) => s.parse().ok(),
+            _ => None,
+        })
+    };
+    let sum = |names: &[&str]| names.iter().filter_map(|name| metric(name)).reduce(|a, b| a + b);
+    let (changed, copied, bytes) = match operation {
+        "MERGE" => (
+            sum(&["numTargetRowsUpdated", "numTargetRowsDeleted", "numTargetRowsInserted"])?,
+            metric("numTargetRowsCopied"),
+            metric("numTargetBytesAdded"),
+        ),
+        "UPDATE" => (metric("numUpdatedRows")?, metric("numCopiedRows"), metric("numAddedBytes")),
+        _ => (metric("numDeletedRows")?, metric("numCopiedRows"), metric("numAddedBytes")),
+    };
+    Some((changed, copied.unwrap_or(0), bytes.unwrap_or(0)))
+}
@@ -0,0 +1,100 @@
+use crate::inspector::operation_metrics;
+use chrono::{DateTime, Utc};
+use deltalake::kernel::CommitInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Metrics, by operation, holding the bytes a commit wrote.
+const BYTES_WRITTEN_METRICS: [&str; 3] = ["numOutputBytes", "numAddedBytes", "numTargetBytesAdded"];
+
+/// One engine, user or job writing to the table and its share of the commits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contributor {
+    pub name: String,
+    pub commits: usize,
+    /// Bytes written according to operation metrics; commits without them add nothing
+    pub bytes_written: i64,
+    pub last_commit: Option<DateTime<Utc>>,
+}
+
+/// Who writes the table: commits grouped by `engineInfo`, by user and by the
+/// job or cluster recorded in commitInfo, each busiest first. Commits without
+/// the field are counted under "unknown".
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Attribution {
+    pub commits: usize,
+    pub engines: Vec<Contributor>,
+    pub users: Vec<Contributor>,
+    pub jobs: Vec<Contributor>,
+}
+
+impl Attribution {
+    pub fn from_history(history: &[CommitInfo]) -> Self {
+        let mut engines = HashMap::new();
+        let mut users = HashMap::new();
+        let mut jobs = HashMap::new();
+        for entry in history {
+            // Job and cluster fields are Databricks additions deltalake leaves unmodeled
+            let fields = serde_json::to_value(entry).unwrap_or_default();
+            let metrics = operation_metrics(entry);
+            let bytes = BYTES_WRITTEN_METRICS.iter().find_map(|name| metric(&metrics, name)).unwrap_or(0);
+            let timestamp = entry.timestamp.and_then(DateTime::from_timestamp_millis);
+
+            let engine = entry.engine_info.clone().or_else(|| string(&fields["clientVersion"]));
+            let user = entry.user_name.clone().or_else(|| entry.user_id.clone());
+            let job = string(&fields["job"]["jobName"])
+                .or_else(|| string(&fields["job"]["jobId"]))
+                .map(|job| format!("job {}", job))
+                .or_else(|| string(&fields["clusterId"]).map(|id| format!("cluster {}", id)));
+            for (groups, key) in [(&mut engines, engine), (&mut users, user), (&mut jobs, job)] {
+                add(groups, key.unwrap_or_else(|| "unknown".to_string()), bytes, timestamp);
+            }
+        }
+
+        Self {
+            commits: history.len(),
+            engines: ranked(engines),
+            users: ranked(users),
+            jobs: ranked(jobs),
+        }
+    }
+
+    /// Share of all commits, in percent.
+    pub fn share(&self, contributor: &Contributor) -> f64 {
+        if self.commits == 0 {
+            return 0.0;
+        }
+        contributor.commits as f64 / self.commits as f64 * 100.0
+    }
+}
+
+fn add(groups: &mut HashMap<String, Contributor>, name: String, bytes: i64, timestamp: Option<DateTime<Utc>>) {
+    let contributor = groups.entry(name.clone()).or_insert_with(|| Contributor {
+        name,
+        commits: 0,
+        bytes_written: 0,
+        last_commit: None,
+    });
+    contributor.commits += 1;
+    contributor.bytes_written += bytes;
+    contributor.last_commit = contributor.last_commit.max(timestamp);
+}
+
+fn ranked(groups: HashMap<String, Contributor>) -> Vec<Contributor> {
+    let mut contributors: Vec<Contributor> = groups.into_values().collect();
+    contributors.sort_by(|a, b| b.commits.cmp(&a.commits).then_with(|| a.name.cmp(&b.name)));
+    contributors
+}
+
+fn string(value: &Value) -> Option<String> {
+    value.as_str().filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+fn metric(metrics: &HashMap<String, Value>, name: &str) -> Option<i64> {
+    match metrics.get(name)? {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
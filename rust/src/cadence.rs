@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use deltalake::kernel::CommitInfo;
+use serde::{Deserialize, Serialize};
+
+/// Intervals vary by at most this much of their mean on a schedule.
+const REGULAR_INTERVAL_CV: f64 = 0.5;
+
+/// How often OPTIMIZE and VACUUM ran, from the commit history.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MaintenanceCadence {
+    pub optimize: OperationCadence,
+    pub vacuum: OperationCadence,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OperationCadence {
+    /// Runs in the retained history
+    pub runs: usize,
+    pub last_run: Option<DateTime<Utc>>,
+    /// Days between consecutive runs, when there were at least two
+    pub median_interval_days: Option<f64>,
+    pub shortest_interval_days: Option<f64>,
+    pub longest_interval_days: Option<f64>,
+    /// Standard deviation of the intervals over their mean, from three runs
+    /// on: 0 for a fixed schedule
+    pub interval_cv: Option<f64>,
+}
+
+impl MaintenanceCadence {
+    pub fn from_history(history: &[CommitInfo]) -> Self {
+        Self {
+            optimize: OperationCadence::from_history(history, &["OPTIMIZE"]),
+            // Spark and delta-rs commit VACUUM START and VACUUM END around a
+            // run; older writers commit a single VACUUM
+            vacuum: OperationCadence::from_history(history, &["VACUUM END", "VACUUM"]),
+        }
+    }
+}
+
+impl OperationCadence {
+    /// Cadence of the commits in `history` whose operation is one of
+    /// `operations`.
+    pub fn from_history(history: &[CommitInfo], operations: &[&str]) -> Self {
+        let mut runs: Vec<DateTime<Utc>> = history
+            .iter()
+            .filter(|entry| entry.operation.as_deref().is_some_and(|op| operations.contains(&op)))
+            .filter_map(|entry| DateTime::from_timestamp_millis(entry.timestamp?))
+            .collect();
+        runs.sort();
+
+        let mut intervals: Vec<f64> = runs
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).num_seconds() as f64 / 86_400.0)
+            .collect();
+        intervals.sort_by(|a, b| a.total_cmp(b));
+
+        let interval_cv = (intervals.len() >= 2).then(|| {
+            let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
+            let variance = intervals.iter().map(|i| (i - mean).powi(2)).sum::<f64>() / intervals.len() as f64;
+            if mean > 0.0 { variance.sqrt() / mean } else { 0.0 }
+        });
+        Self {
+            runs: runs.len(),
+            last_run: runs.last().copied(),
+            median_interval_days: (!intervals.is_empty()).then(|| intervals[intervals.len() / 2]),
+            shortest_interval_days: intervals.first().copied(),
+            longest_interval_days: intervals.last().copied(),
+            interval_cv,
+        }
+    }
+
+    /// Runs follow a schedule; `false` when there are too few to tell.
+    pub fn is_regular(&self) -> bool {
+        self.interval_cv.is_some_and(|cv| cv <= REGULAR_INTERVAL_CV)
+    }
+
+    /// Days since the last run, if there was one.
+    pub fn days_since_last_run(&self) -> Option<i64> {
+        self.last_run.map(|last| (Utc::now() - last).num_days())
+    }
+
+    /// Days after the last run the next one is late: twice the usual
+    /// interval for runs on a schedule, otherwise `expected_days`.
+    pub fn due_after_days(&self, expected_days: i64) -> i64 {
+        match self.median_interval_days {
+            Some(median) if self.is_regular() => (median * 2.0).ceil().max(1.0) as i64,
+            _ => expected_days,
+        }
+    }
+}
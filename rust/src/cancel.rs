@@ -0,0 +1,34 @@
+use anyhow::Result;
+use std::future::Future;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Reasons an operation can be interrupted before it completes.
+#[derive(Debug, Error)]
+pub enum Interrupted {
+    #[error("Operation timed out after {}s", .0.as_secs())]
+    TimedOut(Duration),
+    #[error("Operation cancelled by user")]
+    Cancelled,
+}
+
+/// Run `fut` until it completes, the optional timeout elapses, or Ctrl-C is
+/// pressed. On interruption the future is dropped, which aborts any in-flight
+/// object store requests it owns.
+pub async fn run<F, T>(fut: F, timeout: Option<Duration>) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    let deadline = async {
+        match timeout {
+            Some(duration) => tokio::time::sleep(duration).await,
+            None => std::future::pending::<()>().await,
+        }
+    };
+
+    tokio::select! {
+        result = fut => result,
+        _ = deadline => Err(Interrupted::TimedOut(timeout.unwrap_or_default()).into()),
+        _ = tokio::signal::ctrl_c() => Err(Interrupted::Cancelled.into()),
+    }
+}
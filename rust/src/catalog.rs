@@ -0,0 +1,275 @@
+use crate::config::{CatalogConfig, CatalogKind};
+use crate::insights::{health_score, Insight};
+use crate::inspector::TableStatistics;
+use crate::schema::{SchemaField, SchemaType};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+const DEFAULT_DATAHUB_PLATFORM: &str = "delta-lake";
+const DEFAULT_DATAHUB_ENV: &str = "PROD";
+
+/// What gets pushed to a data catalog for one table.
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogSummary {
+    pub table_path: String,
+    pub version: i64,
+    pub num_files: usize,
+    pub total_size_bytes: i64,
+    pub num_rows: Option<i64>,
+    /// Columns in table order
+    pub schema: Vec<SchemaField>,
+    pub partition_columns: Vec<String>,
+    pub health_score: u8,
+    pub insights: Vec<Insight>,
+}
+
+impl CatalogSummary {
+    pub fn new(stats: &TableStatistics, insights: Vec<Insight>) -> Self {
+        Self {
+            table_path: stats.table_path.clone(),
+            version: stats.version,
+            num_files: stats.num_files,
+            total_size_bytes: stats.total_size_bytes,
+            num_rows: stats.num_rows,
+            schema: stats.schema.fields.clone(),
+            partition_columns: stats.partition_columns.clone(),
+            health_score: health_score(&insights),
+            insights,
+        }
+    }
+
+    /// Dataset name in the catalog: the table path without its URL scheme
+    /// and trailing slash.
+    fn dataset_name(&self) -> &str {
+        let path = self.table_path.trim_end_matches('/');
+        path.split_once("://").map_or(path, |(_, rest)| rest)
+    }
+
+    /// Insights worth surfacing in a catalog, one line each.
+    fn findings(&self) -> Vec<String> {
+        self.insights
+            .iter()
+            .filter(|i| i.severity != "good")
+            .map(|i| format!("[{}] {}", i.severity, i.title))
+            .collect()
+    }
+}
+
+/// Where a summary ended up in the catalog.
+#[derive(Debug, Clone, Serialize)]
+pub struct PublishOutcome {
+    pub catalog: CatalogKind,
+    /// DataHub URN or OpenMetadata fully qualified name
+    pub entity: String,
+}
+
+/// Push `summary` to the catalog described by `config`.
+pub async fn publish(summary: &CatalogSummary, config: &CatalogConfig) -> Result<PublishOutcome> {
+    let client = reqwest::Client::new();
+    let base = config.url.trim_end_matches('/');
+    let entity = match config.kind {
+        CatalogKind::Datahub => publish_datahub(&client, base, summary, config).await?,
+        CatalogKind::Openmetadata => publish_openmetadata(&client, base, summary, config).await?,
+    };
+    Ok(PublishOutcome { catalog: config.kind, entity })
+}
+
+/// Patch the `deltective.*` custom properties into datasetProperties and add
+/// a datasetProfile through GMS's `ingestProposal` endpoint. Descriptions,
+/// other custom properties and the ingested schemaMetadata are left alone.
+async fn publish_datahub(
+    client: &reqwest::Client,
+    base: &str,
+    summary: &CatalogSummary,
+    config: &CatalogConfig,
+) -> Result<String> {
+    let platform = config.platform.as_deref().unwrap_or(DEFAULT_DATAHUB_PLATFORM);
+    let platform_urn = format!("urn:li:dataPlatform:{}", platform);
+    let urn = format!(
+        "urn:li:dataset:({},{},{})",
+        platform_urn,
+        summary.dataset_name(),
+        config.env.as_deref().unwrap_or(DEFAULT_DATAHUB_ENV)
+    );
+
+    let mut custom_properties = BTreeMap::from([
+        ("deltective.version".to_string(), summary.version.to_string()),
+        ("deltective.num_files".to_string(), summary.num_files.to_string()),
+        ("deltective.total_size_bytes".to_string(), summary.total_size_bytes.to_string()),
+        ("deltective.health_score".to_string(), summary.health_score.to_string()),
+        ("deltective.findings".to_string(), summary.findings().join("\n")),
+    ]);
+    if let Some(rows) = summary.num_rows {
+        custom_properties.insert("deltective.num_rows".to_string(), rows.to_string());
+    }
+
+    // One JSON Patch `add` per key, so properties set by others survive
+    let patch: Vec<Value> = custom_properties
+        .iter()
+        .map(|(key, value)| json!({ "op": "add", "path": format!("/customProperties/{}", key), "value": value }))
+        .collect();
+
+    let mut profile = json!({
+        "timestampMillis": Utc::now().timestamp_millis(),
+        "columnCount": summary.schema.len(),
+        "sizeInBytes": summary.total_size_bytes,
+    });
+    if let Some(rows) = summary.num_rows {
+        profile["rowCount"] = json!(rows);
+    }
+
+    let aspects = [
+        ("datasetProperties", "PATCH", "application/json-patch+json", json!(patch)),
+        ("datasetProfile", "UPSERT", "application/json", profile),
+    ];
+
+    for (name, change_type, content_type, aspect) in aspects {
+        let proposal = json!({
+            "proposal": {
+                "entityType": "dataset",
+                "entityUrn": urn,
+                "changeType": change_type,
+                "aspectName": name,
+                "aspect": { "contentType": content_type, "value": aspect.to_string() },
+            }
+        });
+        send(
+            authorize(client.post(format!("{}/aspects?action=ingestProposal", base)), config).json(&proposal),
+        )
+        .await
+        .with_context(|| format!("Failed to publish {} for {} to DataHub", name, urn))?;
+    }
+    Ok(urn)
+}
+
+/// Create or update the table entity, then add a profile. With
+/// `custom_properties`, the health score and findings also go into the
+/// `deltectiveHealthScore` and `deltectiveFindings` custom properties, which
+/// must be defined on the Table type first.
+async fn publish_openmetadata(
+    client: &reqwest::Client,
+    base: &str,
+    summary: &CatalogSummary,
+    config: &CatalogConfig,
+) -> Result<String> {
+    let schema_fqn = config
+        .database_schema
+        .as_deref()
+        .context("OpenMetadata publishing needs `catalog.database_schema` (service.database.schema)")?;
+    let name = summary.dataset_name().rsplit('/').next().unwrap_or_default();
+    let fqn = format!("{}.{}", schema_fqn, name);
+
+    let columns: Vec<Value> = summary.schema.iter().map(openmetadata_column).collect();
+    let mut table = json!({
+        "name": name,
+        "databaseSchema": schema_fqn,
+        "tableType": "External",
+        "columns": columns,
+    });
+    if config.custom_properties {
+        table["extension"] = json!({
+            "deltectiveHealthScore": summary.health_score,
+            "deltectiveFindings": summary.findings().join("\n"),
+        });
+    }
+    let response: Value = send(authorize(client.put(format!("{}/api/v1/tables", base)), config).json(&table))
+        .await
+        .with_context(|| format!("Failed to publish {} to OpenMetadata", fqn))?
+        .json()
+        .await?;
+    let id = response["id"]
+        .as_str()
+        .with_context(|| format!("OpenMetadata returned no id for {}", fqn))?;
+
+    let mut profile = json!({
+        "timestamp": Utc::now().timestamp_millis(),
+        "columnCount": summary.schema.len(),
+        "sizeInByte": summary.total_size_bytes,
+    });
+    if let Some(rows) = summary.num_rows {
+        profile["rowCount"] = json!(rows);
+    }
+    send(
+        authorize(client.put(format!("{}/api/v1/tables/{}/tableProfile", base, id)), config)
+            .json(&json!({ "tableProfile": profile })),
+    )
+    .await
+    .with_context(|| format!("Failed to publish the profile of {} to OpenMetadata", fqn))?;
+    Ok(fqn)
+}
+
+fn authorize(request: reqwest::RequestBuilder, config: &CatalogConfig) -> reqwest::RequestBuilder {
+    match &config.token {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    }
+}
+
+async fn send(request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+    Ok(request.send().await?.error_for_status()?)
+}
+
+/// OpenMetadata column for a field, with the attributes its type requires:
+/// `arrayDataType` for arrays, `precision` and `scale` for decimals,
+/// `dataLength` for binaries and `children` for nested structs.
+fn openmetadata_column(field: &SchemaField) -> Value {
+    let mut column = json!({
+        "name": field.name,
+        "dataType": openmetadata_type(field.data_type.kind()),
+        "dataTypeDisplay": field.data_type.display(),
+    });
+    if let Some(comment) = &field.comment {
+        column["description"] = json!(comment);
+    }
+    match &field.data_type {
+        SchemaType::Array { element, .. } => column["arrayDataType"] = json!(openmetadata_type(element.kind())),
+        SchemaType::Primitive { name } if name == "binary" => {
+            // Delta binaries have no fixed length; OpenMetadata's own connectors use 1
+            column["dataLength"] = json!(1);
+        }
+        SchemaType::Primitive { name } => {
+            if let Some((precision, scale)) = decimal_precision_scale(name) {
+                column["precision"] = json!(precision);
+                column["scale"] = json!(scale);
+            }
+        }
+        _ => {}
+    }
+    let children = field.data_type.children();
+    if !children.is_empty() {
+        column["children"] = Value::Array(children.iter().map(openmetadata_column).collect());
+    }
+    column
+}
+
+/// Precision and scale of a `decimal(p,s)` type name.
+fn decimal_precision_scale(name: &str) -> Option<(u32, u32)> {
+    let (precision, scale) = name.strip_prefix("decimal(")?.strip_suffix(')')?.split_once(',')?;
+    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+}
+
+/// OpenMetadata column data type for the outermost Delta type, as returned
+/// by [`SchemaType::kind`].
+fn openmetadata_type(kind: &str) -> &'static str {
+    match kind {
+        "boolean" => "BOOLEAN",
+        "byte" => "TINYINT",
+        "short" => "SMALLINT",
+        "integer" => "INT",
+        "long" => "BIGINT",
+        "float" => "FLOAT",
+        "double" => "DOUBLE",
+        "decimal" => "DECIMAL",
+        "string" => "STRING",
+        "binary" => "BINARY",
+        "date" => "DATE",
+        "timestamp" | "timestamp_ntz" => "TIMESTAMP",
+        "array" => "ARRAY",
+        "map" => "MAP",
+        "struct" => "STRUCT",
+        _ => "UNKNOWN",
+    }
+}
@@ -0,0 +1,120 @@
+use crate::render::{Document, Item, Renderable, Tone};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A downstream reader of the table's change data feed, declared in the config
+/// file with the last version it has processed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CdfConsumer {
+    pub name: String,
+    /// Table the consumer reads
+    pub table: String,
+    pub last_processed_version: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LagStatus {
+    Ok,
+    /// The oldest unprocessed change is close to leaving the retention window
+    AtRisk,
+    /// Unprocessed changes are already past retention (or no longer in the log)
+    Expired,
+    /// The next commit to process couldn't be read or has no commit time
+    Unknown,
+}
+
+/// The oldest commit a consumer hasn't processed, as found in the log.
+#[derive(Debug, Clone)]
+pub enum NextCommit {
+    /// Read, with its commit time when it records one
+    Read(Option<DateTime<Utc>>),
+    /// Already cleaned from the log
+    Cleaned,
+    /// Reading it failed
+    Unreadable(String),
+}
+
+/// How far behind one consumer is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsumerLag {
+    pub name: String,
+    pub last_processed_version: i64,
+    pub versions_behind: i64,
+    /// Commit time of the oldest change the consumer hasn't processed
+    pub oldest_unprocessed: Option<DateTime<Utc>>,
+    pub lag_hours: Option<f64>,
+    /// How long change data stays readable (the shorter of log and deleted-file retention)
+    pub retention_hours: i64,
+    pub status: LagStatus,
+    /// Why the next commit couldn't be read
+    pub error: Option<String>,
+}
+
+/// Fraction of the retention window after which a lagging consumer is at risk.
+pub const AT_RISK_FRACTION: f64 = 0.75;
+
+impl ConsumerLag {
+    pub fn new(consumer: &CdfConsumer, current_version: i64, next: NextCommit, retention_hours: i64) -> Self {
+        let versions_behind = (current_version - consumer.last_processed_version).max(0);
+        let oldest_unprocessed = match next {
+            NextCommit::Read(timestamp) => timestamp,
+            _ => None,
+        };
+        let lag_hours = oldest_unprocessed.map(|t| (Utc::now() - t).num_minutes() as f64 / 60.0);
+
+        let status = match (&next, lag_hours) {
+            _ if versions_behind == 0 => LagStatus::Ok,
+            (NextCommit::Cleaned, _) => LagStatus::Expired,
+            (NextCommit::Unreadable(_), _) | (_, None) => LagStatus::Unknown,
+            (_, Some(hours)) if hours >= retention_hours as f64 => LagStatus::Expired,
+            (_, Some(hours)) if hours >= retention_hours as f64 * AT_RISK_FRACTION => LagStatus::AtRisk,
+            (_, Some(_)) => LagStatus::Ok,
+        };
+
+        Self {
+            name: consumer.name.clone(),
+            last_processed_version: consumer.last_processed_version,
+            versions_behind,
+            oldest_unprocessed,
+            lag_hours: if versions_behind == 0 { Some(0.0) } else { lag_hours },
+            retention_hours,
+            status,
+            error: match next {
+                NextCommit::Unreadable(error) => Some(error),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl Renderable for [ConsumerLag] {
+    fn document(&self) -> Document {
+        let mut doc = Document::new("CDF consumers");
+        let items = self
+            .iter()
+            .map(|lag| {
+                let (marker, tone) = match lag.status {
+                    LagStatus::Ok => ("✓", Tone::Good),
+                    LagStatus::AtRisk => ("⚠", Tone::Warning),
+                    LagStatus::Expired => ("✗", Tone::Critical),
+                    LagStatus::Unknown => ("?", Tone::Warning),
+                };
+                let behind = match lag.lag_hours {
+                    Some(hours) => format!("{} version(s) behind, {:.1}h lag", lag.versions_behind, hours),
+                    None => format!("{} version(s) behind, lag unknown", lag.versions_behind),
+                };
+                let mut detail = format!("at v{}, retention {}h", lag.last_processed_version, lag.retention_hours);
+                if let Some(error) = &lag.error {
+                    detail.push_str(&format!("; next commit unreadable: {}", error));
+                }
+                Item::new(marker, format!("{}: {}", lag.name, behind))
+                    .detail(detail)
+                    .tone(tone)
+            })
+            .collect();
+        doc.list(items);
+        doc
+    }
+}
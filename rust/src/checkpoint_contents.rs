@@ -0,0 +1,365 @@
+//! What a checkpoint holds, and whether it agrees with the JSON commits.
+//!
+//! A checkpoint is the state of the table at its version, written as Parquet
+//! so readers don't replay every commit. A buggy or interrupted writer can
+//! leave one that disagrees with the commits it summarizes, and since readers
+//! trust the checkpoint, the table silently gains or loses files.
+
+use crate::insights::DeltaTableAnalyzer;
+use crate::maintenance::last_checkpoint_version;
+use crate::render::{Document, Item, Renderable, Tone};
+use anyhow::{bail, Context, Result};
+use arrow::array::{Array, AsArray};
+use arrow::datatypes::{DataType, Int32Type};
+use deltalake::DeltaTable;
+use futures::stream::TryStreamExt;
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectMeta;
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+
+/// Paths listed individually when the checkpoint and replay disagree.
+const LISTED_PATHS: usize = 20;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointContents {
+    pub table_path: String,
+    pub version: i64,
+    pub parts: Vec<CheckpointPart>,
+    pub total_bytes: i64,
+    /// Rows per action type, e.g. "add" or "remove" (tombstones)
+    pub action_counts: BTreeMap<String, usize>,
+    /// Top-level columns, one per action type
+    pub schema: Vec<CheckpointColumn>,
+    pub comparison: Option<ReplayComparison>,
+    /// Why the checkpoint couldn't be compared, e.g. expired commits
+    pub comparison_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointPart {
+    pub path: String,
+    pub size_bytes: i64,
+    pub rows: i64,
+    pub row_groups: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointColumn {
+    pub name: String,
+    pub data_type: String,
+}
+
+/// The checkpoint next to the state replayed from the previous version plus
+/// the JSON commit at the checkpoint's version.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayComparison {
+    pub checkpoint_files: usize,
+    pub replayed_files: usize,
+    pub only_in_checkpoint: Vec<String>,
+    pub only_in_replay: Vec<String>,
+    /// Reader and writer versions in the checkpoint, then from replay
+    pub checkpoint_protocol: Option<(i32, i32)>,
+    pub replayed_protocol: (i32, i32),
+    pub checkpoint_table_id: Option<String>,
+    pub replayed_table_id: String,
+}
+
+impl ReplayComparison {
+    pub fn agrees(&self) -> bool {
+        self.only_in_checkpoint.is_empty()
+            && self.only_in_replay.is_empty()
+            && self.checkpoint_protocol.is_none_or(|p| p == self.replayed_protocol)
+            && self.checkpoint_table_id.as_ref().is_none_or(|id| *id == self.replayed_table_id)
+    }
+}
+
+/// What the checkpoint's Parquet parts record.
+#[derive(Default)]
+struct Recorded {
+    files: HashSet<String>,
+    protocol: Option<(i32, i32)>,
+    table_id: Option<String>,
+}
+
+/// Summarize the checkpoint at `version`, or the one `_last_checkpoint`
+/// points to, and compare it with the JSON commits.
+pub async fn inspect(table: &DeltaTable, version: Option<i64>) -> Result<CheckpointContents> {
+    let version = match version {
+        Some(version) => version,
+        None => last_checkpoint_version(table)
+            .await?
+            .context("The table has no _last_checkpoint; pass the checkpoint version")?,
+    };
+    let store = table.object_store();
+    let parts = checkpoint_parts(table, version).await?;
+
+    let mut contents = CheckpointContents {
+        table_path: table.table_uri(),
+        version,
+        parts: Vec::new(),
+        total_bytes: parts.iter().map(|p| p.size as i64).sum(),
+        action_counts: BTreeMap::new(),
+        schema: Vec::new(),
+        comparison: None,
+        comparison_error: None,
+    };
+    let mut recorded = Recorded::default();
+    for meta in parts {
+        let path = meta.location.to_string();
+        let size_bytes = meta.size as i64;
+        let builder = ParquetRecordBatchStreamBuilder::new(ParquetObjectReader::new(store.clone(), meta))
+            .await
+            .with_context(|| format!("Failed to read {}", path))?;
+        let file_metadata = builder.metadata().file_metadata();
+        contents.parts.push(CheckpointPart {
+            path: path.clone(),
+            size_bytes,
+            rows: file_metadata.num_rows(),
+            row_groups: builder.metadata().num_row_groups(),
+        });
+        if contents.schema.is_empty() {
+            contents.schema = builder
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| CheckpointColumn {
+                    name: field.name().clone(),
+                    data_type: describe_type(field.data_type()),
+                })
+                .collect();
+        }
+
+        let mut batches = builder.build()?;
+        while let Some(batch) = batches.try_next().await.with_context(|| format!("Failed to read {}", path))? {
+            // Each row holds one action, in the column named after its type
+            for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+                let count = column.len() - column.null_count();
+                if count > 0 {
+                    *contents.action_counts.entry(field.name().clone()).or_default() += count;
+                }
+            }
+            record_batch_state(&batch, &mut recorded);
+        }
+    }
+
+    match replay(table, version).await {
+        Ok((replayed, protocol, table_id)) => {
+            let mut only_in_checkpoint: Vec<String> = recorded.files.difference(&replayed).cloned().collect();
+            let mut only_in_replay: Vec<String> = replayed.difference(&recorded.files).cloned().collect();
+            only_in_checkpoint.sort();
+            only_in_replay.sort();
+            contents.comparison = Some(ReplayComparison {
+                checkpoint_files: recorded.files.len(),
+                replayed_files: replayed.len(),
+                only_in_checkpoint,
+                only_in_replay,
+                checkpoint_protocol: recorded.protocol,
+                replayed_protocol: protocol,
+                checkpoint_table_id: recorded.table_id,
+                replayed_table_id: table_id,
+            });
+        }
+        Err(e) => contents.comparison_error = Some(format!("{:#}", e)),
+    }
+    Ok(contents)
+}
+
+/// The Parquet parts of the checkpoint at `version`, in name order.
+pub(crate) async fn checkpoint_parts(table: &DeltaTable, version: i64) -> Result<Vec<ObjectMeta>> {
+    // Everything from the checkpoint on sorts after this offset; filter by
+    // name since not every store lists in order
+    let prefix = format!("{:020}.checkpoint", version);
+    let offset = ObjectPath::from(format!("_delta_log/{}", prefix));
+    let mut parts: Vec<ObjectMeta> = table
+        .object_store()
+        .list_with_offset(Some(&ObjectPath::from("_delta_log")), &offset)
+        .try_filter(|meta| {
+            let is_part = meta
+                .location
+                .filename()
+                .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".parquet"));
+            async move { is_part }
+        })
+        .try_collect()
+        .await
+        .context("Failed to list _delta_log")?;
+    if parts.is_empty() {
+        bail!("No checkpoint at version {} in _delta_log", version);
+    }
+    parts.sort_by(|a, b| a.location.cmp(&b.location));
+    Ok(parts)
+}
+
+fn record_batch_state(batch: &arrow::record_batch::RecordBatch, recorded: &mut Recorded) {
+    let column = |action: &str, field: &str| {
+        batch
+            .column_by_name(action)
+            .and_then(|a| a.as_struct_opt())
+            .and_then(|a| a.column_by_name(field))
+            .cloned()
+    };
+    if let Some(paths) = column("add", "path") {
+        if let Some(paths) = paths.as_string_opt::<i32>() {
+            recorded.files.extend(paths.iter().flatten().map(str::to_string));
+        }
+    }
+    let first_int = |field: &str| {
+        column("protocol", field).and_then(|column| {
+            let values = column.as_primitive_opt::<Int32Type>()?;
+            (0..values.len()).find(|i| values.is_valid(*i)).map(|i| values.value(i))
+        })
+    };
+    if let (Some(reader), Some(writer)) = (first_int("minReaderVersion"), first_int("minWriterVersion")) {
+        recorded.protocol = Some((reader, writer));
+    }
+    if let Some(ids) = column("metaData", "id") {
+        if let Some(id) = ids.as_string_opt::<i32>().and_then(|ids| ids.iter().flatten().next()) {
+            recorded.table_id = Some(id.to_string());
+        }
+    }
+}
+
+/// Active files, protocol and table ID at `version` without using its
+/// checkpoint: the snapshot at the version before, plus commit `version`.
+async fn replay(table: &DeltaTable, version: i64) -> Result<(HashSet<String>, (i32, i32), String)> {
+    let mut files = HashSet::new();
+    let mut protocol = None;
+    let mut table_id = None;
+    if version > 0 {
+        let mut base = table.clone();
+        base.load_version(version - 1)
+            .await
+            .with_context(|| format!("Failed to load version {} to replay from", version - 1))?;
+        let snapshot = base.snapshot()?;
+        files.extend(snapshot.file_actions()?.into_iter().map(|add| add.path));
+        protocol = Some((snapshot.protocol().min_reader_version, snapshot.protocol().min_writer_version));
+        table_id = Some(snapshot.metadata().id.clone());
+    }
+
+    let bytes = table
+        .log_store()
+        .read_commit_entry(version)
+        .await?
+        .with_context(|| format!("Commit {} is no longer in the log", version))?;
+    let actions: Vec<serde_json::Value> = String::from_utf8_lossy(&bytes)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()
+        .with_context(|| format!("Malformed action in commit {}", version))?;
+    // Actions within a commit are unordered, so removes apply before adds
+    for remove in actions.iter().filter_map(|a| a["remove"]["path"].as_str()) {
+        files.remove(remove);
+    }
+    files.extend(actions.iter().filter_map(|a| a["add"]["path"].as_str()).map(str::to_string));
+    for action in &actions {
+        let p = &action["protocol"];
+        if let (Some(reader), Some(writer)) = (p["minReaderVersion"].as_i64(), p["minWriterVersion"].as_i64()) {
+            protocol = Some((reader as i32, writer as i32));
+        }
+        if let Some(id) = action["metaData"]["id"].as_str() {
+            table_id = Some(id.to_string());
+        }
+    }
+
+    Ok((
+        files,
+        protocol.context("No protocol found while replaying")?,
+        table_id.context("No table metadata found while replaying")?,
+    ))
+}
+
+/// Short type description: struct fields by name rather than in full.
+fn describe_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Struct(fields) => format!(
+            "struct<{}>",
+            fields.iter().map(|f| f.name().as_str()).collect::<Vec<_>>().join(", ")
+        ),
+        other => other.to_string(),
+    }
+}
+
+impl Renderable for CheckpointContents {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new(format!("Checkpoint {} of {}", self.version, self.table_path));
+        doc.fields([
+            ("Parts", self.parts.len().to_string()),
+            ("Size", format_bytes(self.total_bytes)),
+            ("Rows", self.parts.iter().map(|p| p.rows).sum::<i64>().to_string()),
+        ]);
+
+        doc.heading("Actions").table(
+            &["Action", "Count"],
+            self.action_counts.iter().map(|(action, count)| vec![action.clone(), count.to_string()]).collect(),
+        );
+        if self.parts.len() > 1 {
+            doc.heading("Parts").table(
+                &["Path", "Size", "Rows", "Row groups"],
+                self.parts
+                    .iter()
+                    .map(|p| vec![p.path.clone(), format_bytes(p.size_bytes), p.rows.to_string(), p.row_groups.to_string()])
+                    .collect(),
+            );
+        }
+        doc.heading("Schema").table(
+            &["Column", "Type"],
+            self.schema.iter().map(|c| vec![c.name.clone(), c.data_type.clone()]).collect(),
+        );
+
+        doc.heading("Compared with the JSON commits");
+        let Some(comparison) = &self.comparison else {
+            doc.list(vec![Item::new(
+                "[SKIPPED]",
+                format!("Couldn't replay: {}", self.comparison_error.as_deref().unwrap_or("unknown error")),
+            )
+            .tone(Tone::Info)]);
+            return doc;
+        };
+        let verdict = if comparison.agrees() {
+            Item::new("[OK]", "The checkpoint matches the replayed state").tone(Tone::Good)
+        } else {
+            Item::new("[MISMATCH]", "Readers using this checkpoint see a different table than the commits describe")
+                .tone(Tone::Critical)
+        };
+        doc.list(vec![verdict]);
+        let protocol = |p: (i32, i32)| format!("{}/{}", p.0, p.1);
+        doc.fields([
+            ("Files in checkpoint", comparison.checkpoint_files.to_string()),
+            ("Files from replay", comparison.replayed_files.to_string()),
+            (
+                "Protocol (checkpoint / replay)",
+                format!(
+                    "{} / {}",
+                    comparison.checkpoint_protocol.map_or("-".to_string(), protocol),
+                    protocol(comparison.replayed_protocol)
+                ),
+            ),
+            (
+                "Table ID (checkpoint / replay)",
+                format!(
+                    "{} / {}",
+                    comparison.checkpoint_table_id.as_deref().unwrap_or("-"),
+                    comparison.replayed_table_id
+                ),
+            ),
+        ]);
+        for (heading, paths) in [
+            ("Only in the checkpoint", &comparison.only_in_checkpoint),
+            ("Only in the replayed commits", &comparison.only_in_replay),
+        ] {
+            if paths.is_empty() {
+                continue;
+            }
+            let mut items: Vec<Item> = paths.iter().take(LISTED_PATHS).map(|p| Item::new("-", p.clone())).collect();
+            if paths.len() > LISTED_PATHS {
+                items.push(Item::new("…", format!("{} more", paths.len() - LISTED_PATHS)));
+            }
+            doc.heading(heading).list(items);
+        }
+        doc
+    }
+}
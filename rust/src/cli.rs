@@ -1,35 +1,941 @@
-use anyhow::{Context, Result};
-use clap::{Arg, Command};
+use crate::commands;
+use crate::tui_app::{self, theme, TuiOptions};
+use deltective::config::{CatalogKind, Config, DEFAULT_CONFIG_FILE};
+use deltective::fleet::MatrixSort;
+use deltective::i18n;
+use deltective::prescribe::Dialect;
+use deltective::render;
+use deltective::report::Provenance;
+use deltective::requests::{self, RequestUsage};
+use deltective::upgrade;
+use anyhow::{bail, Context, Result};
+use clap::builder::PossibleValue;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-pub fn run() -> Result<()> {
-    let matches = Command::new("deltective")
+/// Options shared by the TUI and every subcommand.
+pub struct GlobalOptions {
+    pub storage_options: HashMap<String, String>,
+    pub timeout: Option<Duration>,
+    pub config: Config,
+    /// Version to open instead of the latest, from `--as-of-report`
+    pub as_of: Option<Provenance>,
+}
+
+impl GlobalOptions {
+    /// `config_path` takes the place of `--config` for subcommands whose
+    /// argument is the config file itself.
+    fn from_matches(matches: &ArgMatches, config_path: Option<&str>) -> Result<Self> {
+        let storage_options = parse_storage_options(
+            matches
+                .get_many::<String>("storage_option")
+                .unwrap_or_default(),
+        )?;
+
+        let timeout = matches
+            .get_one::<String>("timeout")
+            .map(|value| parse_duration(value))
+            .transpose()?;
+
+        let mut config = Config::load(config_path.or(matches.get_one::<String>("config").map(String::as_str)))?;
+
+        // Threshold flags override the config file
+        let thresholds = &mut config.thresholds;
+        if let Some(&value) = matches.get_one::<f64>("small_file_mb") {
+            thresholds.small_file_mb = value;
+        }
+        if let Some(&value) = matches.get_one::<f64>("target_file_mb") {
+            thresholds.target_file_mb = value;
+        }
+        if let Some(&value) = matches.get_one::<usize>("max_files") {
+            thresholds.max_files = value;
+        }
+        if let Some(&value) = matches.get_one::<i64>("vacuum_days") {
+            thresholds.vacuum_days = value;
+        }
+        if let Some(&value) = matches.get_one::<f64>("file_size_cv") {
+            thresholds.file_size_cv = value;
+        }
+        if let Some(&value) = matches.get_one::<i64>("checkpoint_lag_commits") {
+            thresholds.checkpoint_lag_commits = value;
+        }
+        if let Some(values) = matches.get_many::<f64>("size_forecast_gb") {
+            thresholds.size_forecast_gb = values.copied().collect();
+        }
+        if let Some(&level) = matches.get_one::<i32>("estimate_zstd") {
+            config.zstd_estimate_level = Some(level);
+        }
+        if let Some(&files) = matches.get_one::<usize>("audit_codecs") {
+            config.codec_audit_files = Some(files);
+        }
+        if let Some(&budget) = matches.get_one::<u64>("max_requests") {
+            config.requests.budget = Some(budget);
+        }
+        if let Some(&concurrency) = matches.get_one::<usize>("max_concurrent_requests") {
+            config.requests.concurrency = Some(concurrency);
+        }
+        requests::configure(&config.requests);
+        if let Some(locale) = matches.get_one::<String>("locale") {
+            config.locale = Some(locale.clone());
+        }
+        i18n::set_locale(config.locale.as_deref())?;
+
+        let as_of = matches
+            .get_one::<String>("as_of_report")
+            .map(|path| Provenance::from_export(Path::new(path)))
+            .transpose()?;
+
+        Ok(Self {
+            storage_options,
+            timeout,
+            config,
+            as_of,
+        })
+    }
+}
+
+fn build_cli() -> Command {
+    Command::new("deltective")
         .version(VERSION)
         .about("A detective for your Delta tables - inspect, analyze, and optimize")
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
         .arg(
             Arg::new("table_path")
-                .help("Path to the Delta table directory")
+                .help("Path to the Delta table directory, or a directory of tables to pick from")
                 .required(true)
                 .index(1),
         )
-        .get_matches();
+        .arg(
+            Arg::new("storage_option")
+                .long("storage-option")
+                .value_name("KEY=VALUE")
+                .help("Storage option forwarded to the object store (repeatable)")
+                .action(ArgAction::Append)
+                .global(true),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("DURATION")
+                .help("Abort table loading after this long (e.g. 30s, 5m)")
+                .global(true),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("FILE")
+                .help("Config file (defaults to ./deltective.yaml if present)")
+                .global(true),
+        )
+        .arg(
+            Arg::new("small_file_mb")
+                .long("small-file-mb")
+                .value_name("MB")
+                .value_parser(clap::value_parser!(f64))
+                .help("Files below this size count as small [default: 10]")
+                .global(true),
+        )
+        .arg(
+            Arg::new("target_file_mb")
+                .long("target-file-mb")
+                .value_name("MB")
+                .value_parser(clap::value_parser!(f64))
+                .help("Target file size for OPTIMIZE [default: 128]")
+                .global(true),
+        )
+        .arg(
+            Arg::new("max_files")
+                .long("max-files")
+                .value_name("COUNT")
+                .value_parser(clap::value_parser!(usize))
+                .help("File count above which the table is flagged [default: 1000]")
+                .global(true),
+        )
+        .arg(
+            Arg::new("vacuum_days")
+                .long("vacuum-days")
+                .value_name("DAYS")
+                .value_parser(clap::value_parser!(i64))
+                .help("Expected days between VACUUM runs [default: 7]")
+                .global(true),
+        )
+        .arg(
+            Arg::new("file_size_cv")
+                .long("file-size-cv")
+                .value_name("RATIO")
+                .value_parser(clap::value_parser!(f64))
+                .help("File size coefficient of variation that counts as skew [default: 0.5]")
+                .global(true),
+        )
+        .arg(
+            Arg::new("checkpoint_lag_commits")
+                .long("checkpoint-lag-commits")
+                .value_name("COMMITS")
+                .value_parser(clap::value_parser!(i64))
+                .help("JSON commits since the last checkpoint before it counts as stale [default: 100]")
+                .global(true),
+        )
+        .arg(
+            Arg::new("size_forecast_gb")
+                .long("size-forecast-gb")
+                .value_name("GB,...")
+                .value_parser(clap::value_parser!(f64))
+                .value_delimiter(',')
+                .help("Table sizes to forecast reaching when growth accelerates [default: 1024,10240]")
+                .global(true),
+        )
+        .arg(
+            Arg::new("estimate_zstd")
+                .long("estimate-zstd")
+                .value_name("LEVEL")
+                .num_args(0..=1)
+                .default_missing_value("3")
+                .value_parser(clap::value_parser!(i32).range(1..=22))
+                .help("Sample files and estimate savings from recompressing with ZSTD (default level 3)")
+                .global(true),
+        )
+        .arg(
+            Arg::new("audit_codecs")
+                .long("audit-codecs")
+                .value_name("FILES")
+                .num_args(0..=1)
+                .default_missing_value("50")
+                .value_parser(clap::value_parser!(usize))
+                .help("Read the Parquet footers of this many files (default 50, 0 for all) and report their compression codecs")
+                .global(true),
+        )
+        .arg(
+            Arg::new("max_requests")
+                .long("max-requests")
+                .value_name("COUNT")
+                .value_parser(clap::value_parser!(u64))
+                .help("Fail storage requests beyond this many per run, to bound costs of large scans")
+                .global(true),
+        )
+        .arg(
+            Arg::new("max_concurrent_requests")
+                .long("max-concurrent-requests")
+                .value_name("COUNT")
+                .value_parser(clap::value_parser!(usize))
+                .help("Storage requests allowed in flight at once, to stay under throttling limits")
+                .global(true),
+        )
+        .arg(
+            Arg::new("locale")
+                .long("locale")
+                .value_name("LANG")
+                .value_parser(i18n::available_locales())
+                .help("Language for insights and TUI labels (defaults to the config file, then $LANG)")
+                .global(true),
+        )
+        .arg(
+            Arg::new("as_of_report")
+                .long("as-of-report")
+                .value_name("FILE")
+                .help("Analyze the table at the version recorded in an earlier JSON export (report, check or info), for like-for-like comparisons")
+                .global(true),
+        )
+        .arg(
+            Arg::new("refresh_interval")
+                .long("refresh-interval")
+                .value_name("DURATION")
+                .help("Check for new commits this often and reload the TUI when they appear (e.g. 30s)"),
+        )
+        .arg(
+            Arg::new("replay")
+                .long("replay")
+                .value_name("INTERVAL")
+                .num_args(0..=1)
+                .default_missing_value("2s")
+                .conflicts_with("refresh_interval")
+                .help("Step through the table's versions from oldest to latest, one every INTERVAL (default 2s)"),
+        )
+        .arg(
+            Arg::new("replay_from")
+                .long("replay-from")
+                .value_name("VERSION")
+                .value_parser(clap::value_parser!(i64))
+                .requires("replay")
+                .help("Version to start the replay from (defaults to the oldest available)"),
+        )
+        .arg(
+            Arg::new("simulate_vacuum")
+                .long("simulate-vacuum")
+                .value_name("RETENTION")
+                .value_delimiter(',')
+                .action(ArgAction::Append)
+                .conflicts_with_all(["replay", "refresh_interval"])
+                .help("Instead of starting the TUI, show what VACUUM at these retentions (e.g. 72h,7d) would free and which versions it would leave unreadable"),
+        )
+        .arg(
+            Arg::new("simulate_optimize")
+                .long("simulate-optimize")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["replay", "refresh_interval", "simulate_vacuum"])
+                .help("Instead of starting the TUI, show what a bin-packing OPTIMIZE to --target-file-mb would leave: file count, average size and bytes rewritten"),
+        )
+        .arg(
+            Arg::new("palette")
+                .long("palette")
+                .value_name("NAME")
+                .value_parser(theme::PALETTE_NAMES)
+                .help("TUI color palette (defaults to the config file, then default)"),
+        )
+        .arg(
+            Arg::new("diagnostics")
+                .long("diagnostics")
+                .help("Emit JSON-lines progress and diagnostic events on stderr")
+                .action(ArgAction::SetTrue),
+        )
+        .subcommand(
+            Command::new("check")
+                .about("Run the health analysis and exit non-zero when findings reach a severity (for CI gates)")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("fail_on")
+                        .long("fail-on")
+                        .value_name("SEVERITY")
+                        .value_parser(["critical", "warning", "info", "never"])
+                        .default_value("warning")
+                        .help("Lowest severity that fails the check (exit status 2)"),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("checkpoint")
+                .about("Write a checkpoint for the current version so readers stop replaying JSON commits")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("dry_run")
+                        .long("dry-run")
+                        .help("Show how many commits the checkpoint would cover without writing it")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("inspect")
+                        .long("inspect")
+                        .help("Summarize an existing checkpoint (parts, action counts, schema) and compare it with the JSON commits instead of writing one")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with("dry_run"),
+                )
+                .arg(
+                    Arg::new("version")
+                        .long("version")
+                        .value_name("VERSION")
+                        .value_parser(clap::value_parser!(i64))
+                        .requires("inspect")
+                        .help("Checkpoint to inspect; defaults to the one _last_checkpoint points to"),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("column-sizes")
+                .about("Show which columns take up the table's storage, from the column chunk sizes in Parquet footers")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("sample_files")
+                        .long("sample-files")
+                        .value_name("FILES")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("50")
+                        .help("Files whose footers are read, spread over the size range; 0 reads all of them"),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("compare")
+                .about("Compare schema, properties, protocol, size and file distribution of two tables")
+                .arg(table_path_arg().help("Path to the first table (A)"))
+                .arg(
+                    Arg::new("other_path")
+                        .help("Path to the second table (B)")
+                        .required(true)
+                        .index(2),
+                )
+                .arg(
+                    Arg::new("tui")
+                        .long("tui")
+                        .help("Show both tables side by side in a split-screen TUI")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("constraints")
+                .about("List the table's CHECK constraints and optionally evaluate them against a sample of rows")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("evaluate")
+                        .long("evaluate")
+                        .help("Count the rows violating each constraint, using DataFusion")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("sample_rows")
+                        .long("sample-rows")
+                        .value_name("ROWS")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("10000")
+                        .requires("evaluate")
+                        .help("Rows to check the constraints against"),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("Show files, schema and properties that changed between two versions")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("from")
+                        .long("from")
+                        .value_name("VERSION")
+                        .required(true)
+                        .help("Older version to compare from"),
+                )
+                .arg(
+                    Arg::new("to")
+                        .long("to")
+                        .value_name("VERSION")
+                        .help("Newer version to compare to (defaults to the latest)"),
+                )
+                .arg(
+                    format_arg()
+                        .visible_alias("output")
+                        .help("Output format; json has a stable added_files/removed_files/schema_changes/property_changes structure"),
+                )
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("fleet")
+                .about("Inspect every Delta table under a directory and print one row per table")
+                .arg(
+                    Arg::new("table_path")
+                        .help("Root directory to search for Delta tables")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("sort")
+                        .long("sort")
+                        .value_parser(MatrixSort::NAMES)
+                        .default_value("health")
+                        .help("Column to sort by: largest, stalest or unhealthiest first"),
+                )
+                .arg(
+                    Arg::new("reverse")
+                        .long("reverse")
+                        .help("Reverse the sort order")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser(["text", "json", "csv", "markdown", "html"])
+                        .default_value("text")
+                        .help("Output format"),
+                )
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("fsck")
+                .about("Check that every file the current version references exists in storage (exits 2 if any are missing)")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("check_sizes")
+                        .long("check-sizes")
+                        .help("Also compare each file's size in storage with the size recorded in the log")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("concurrency")
+                        .long("concurrency")
+                        .value_name("N")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("32")
+                        .help("Existence checks to run in parallel"),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("history")
+                .about("List the table's commits, newest first, optionally filtered by operation and date")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("operation")
+                        .long("operation")
+                        .value_name("OPERATION")
+                        .help("Only list commits of this operation, e.g. MERGE or OPTIMIZE (repeatable, case-insensitive)")
+                        .action(ArgAction::Append),
+                )
+                .arg(
+                    Arg::new("since")
+                        .long("since")
+                        .value_name("DATE")
+                        .help("Only list commits made on or after this date (YYYY-MM-DD, UTC)"),
+                )
+                .arg(
+                    Arg::new("until")
+                        .long("until")
+                        .value_name("DATE")
+                        .help("Only list commits made on or before this date (YYYY-MM-DD, UTC)"),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("info")
+                .about("Print a one-paragraph summary of the table")
+                .arg(table_path_arg())
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("migration-report")
+                .about("Detect Iceberg or Hudi metadata and report how ready the table is to migrate to Delta")
+                .arg(table_path_arg().help("Path to the Iceberg or Hudi table"))
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("parquet")
+                .about("Degraded inspection of a plain Parquet directory (no _delta_log), e.g. before migrating it to Delta")
+                .arg(table_path_arg().help("Path to the Parquet dataset directory"))
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("prescribe")
+                .about("Turn the table's insights into a maintenance script: property changes, OPTIMIZE, VACUUM and checkpoints")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("dialect")
+                        .long("dialect")
+                        .value_parser(Dialect::NAMES)
+                        .default_value("sql")
+                        .help("Script language: Spark SQL, or Python with the deltalake (delta-rs) package"),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser(["script", "text", "json", "markdown", "html"])
+                        .default_value("script")
+                        .help("Output format; the others list the steps with the insights each addresses"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Write the script to this file instead of stdout"),
+                ),
+        )
+        .subcommand(
+            Command::new("publish")
+                .about("Push size, row count, schema, health score and insights to a DataHub or OpenMetadata catalog")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("catalog")
+                        .long("catalog")
+                        .value_name("KIND")
+                        .value_parser(CatalogKind::NAMES)
+                        .help("Catalog type (overrides `catalog.kind` in the config file)"),
+                )
+                .arg(
+                    Arg::new("catalog_url")
+                        .long("catalog-url")
+                        .value_name("URL")
+                        .help("DataHub GMS or OpenMetadata server URL (overrides `catalog.url`)"),
+                )
+                .arg(
+                    Arg::new("dry_run")
+                        .long("dry-run")
+                        .help("Print the summary that would be published instead of sending it")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("query")
+                .about("Run a SQL query against the table with DataFusion")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("sql")
+                        .help("SQL to execute; the table is registered as `t` unless --table-name is given")
+                        .required(true)
+                        .index(2),
+                )
+                .arg(
+                    Arg::new("table_name")
+                        .long("table-name")
+                        .value_name("NAME")
+                        .default_value("t")
+                        .help("Name to register the table under"),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser([
+                            PossibleValue::new("text").alias("table"),
+                            PossibleValue::new("csv"),
+                            PossibleValue::new("json"),
+                            PossibleValue::new("markdown"),
+                            PossibleValue::new("html"),
+                        ])
+                        .default_value("text")
+                        .help("Output format"),
+                )
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("report")
+                .about("Render a shareable report (overview, protocol, insights, file sizes, timeline, history, schema)")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser(render::FORMATS)
+                        .default_value("html")
+                        .help("Report format; html is a standalone page with embedded charts, markdown suits PRs and wikis"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("Write the report to this file instead of stdout"),
+                ),
+        )
+        .subcommand(
+            Command::new("run-workspace")
+                .about("Run the fleet scans declared in a workspace file on their schedules, checking policies and sending alerts")
+                .arg(
+                    Arg::new("table_path")
+                        .value_name("WORKSPACE")
+                        .help("Workspace config file declaring tables, profiles, policies, alert routes and scans")
+                        .default_value(DEFAULT_CONFIG_FILE)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("scan")
+                        .long("scan")
+                        .value_name("NAME")
+                        .help("Run only this scan"),
+                )
+                .arg(
+                    Arg::new("once")
+                        .long("once")
+                        .help("Run every scan once and exit instead of following the schedules")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("scan")
+                .about("Inspect every Delta table under a directory and report fleet-level findings")
+                .arg(
+                    Arg::new("table_path")
+                        .help("Root directory to search for Delta tables")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("tag")
+                        .long("tag")
+                        .value_name("KEY=VALUE")
+                        .help("Only include tables with this config tag (repeatable)")
+                        .action(ArgAction::Append),
+                )
+                .arg(
+                    Arg::new("group_by")
+                        .long("group-by")
+                        .value_name("TAG")
+                        .help("Group results by the value of a config tag"),
+                )
+                .arg(format_arg())
+                .arg(select_arg())
+                .arg(
+                    Arg::new("alert")
+                        .long("alert")
+                        .help("Notify the owning team's webhook (per the config file's alert routes) about tables with findings")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("serve")
+                .about("Serve table stats, history, insights and config over HTTP as JSON")
+                .arg(
+                    Arg::new("table_path")
+                        .help("Delta table, or a directory whose Delta tables are all served")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("bind")
+                        .long("bind")
+                        .value_name("ADDR")
+                        .default_value("127.0.0.1:8080")
+                        .help("Address to listen on"),
+                ),
+        )
+        .subcommand(
+            Command::new("streams")
+                .about("Correlate Spark structured streaming checkpoints with the table to show pipeline lag")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("checkpoint")
+                        .long("checkpoint")
+                        .value_name("PATH")
+                        .help("Streaming checkpoint location (repeatable); defaults to the config file, then <table>/_checkpoints")
+                        .action(ArgAction::Append),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("upgrade-plan")
+                .about("List the ordered steps needed to safely enable a table feature")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("target_feature")
+                        .long("target-feature")
+                        .value_name("FEATURE")
+                        .required(true)
+                        .value_parser(upgrade::feature_names())
+                        .help("Table feature to adopt, e.g. deletionVectors"),
+                )
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("validate-log")
+                .about("Replay _delta_log and check it for gaps, malformed commits, protocol violations and inconsistent checkpoints (exits 2 on errors)")
+                .arg(table_path_arg())
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+        .subcommand(
+            Command::new("watch")
+                .about("Poll the transaction log and print every new commit as it lands")
+                .arg(table_path_arg())
+                .arg(
+                    Arg::new("interval")
+                        .long("interval")
+                        .value_name("DURATION")
+                        .default_value("5s")
+                        .help("How often to check for new commits"),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser(render::FORMATS)
+                        .default_value("text")
+                        .help("Output format for each commit; json prints one event per line"),
+                ),
+        )
+        .subcommand(
+            Command::new("writers")
+                .about("List the streaming and idempotent writers recording progress in txn actions")
+                .arg(table_path_arg())
+                .arg(format_arg())
+                .arg(select_arg()),
+        )
+}
 
+/// `--format` for subcommands whose results support every output format.
+fn format_arg() -> Arg {
+    Arg::new("format")
+        .long("format")
+        .value_parser(render::FORMATS)
+        .default_value("text")
+        .help("Output format")
+}
+
+/// `--select` for subcommands with JSON output; implies `--format json`.
+fn select_arg() -> Arg {
+    Arg::new("select")
+        .long("select")
+        .value_name("PATHS")
+        .help("Print only these comma-separated JSON fields, e.g. '.num_files,.total_size_bytes' (implies --format json)")
+}
+
+fn table_path_arg() -> Arg {
+    Arg::new("table_path")
+        .help("Path to the Delta table directory")
+        .required(true)
+        .index(1)
+}
+
+pub fn run() -> Result<()> {
+    let matches = build_cli().get_matches();
+
+    if let Some((name, sub_matches)) = matches.subcommand() {
+        let table_path = sub_matches
+            .get_one::<String>("table_path")
+            .context("Table path is required")?;
+        validate_table_path(table_path);
+        let workspace = (name == "run-workspace").then_some(table_path.as_str());
+        let global = GlobalOptions::from_matches(sub_matches, workspace)?;
+
+        let result = match name {
+            "check" => commands::check::run(table_path, sub_matches, &global),
+            "checkpoint" => commands::checkpoint::run(table_path, sub_matches, &global),
+            "column-sizes" => commands::column_sizes::run(table_path, sub_matches, &global),
+            "compare" => commands::compare::run(table_path, sub_matches, &global),
+            "constraints" => commands::constraints::run(table_path, sub_matches, &global),
+            "diff" => commands::diff::run(table_path, sub_matches, &global),
+            "fleet" => commands::fleet::run(table_path, sub_matches, &global),
+            "fsck" => commands::fsck::run(table_path, sub_matches, &global),
+            "history" => commands::history::run(table_path, sub_matches, &global),
+            "info" => commands::info::run(table_path, sub_matches, &global),
+            "migration-report" => commands::migration_report::run(table_path, sub_matches, &global),
+            "parquet" => commands::parquet::run(table_path, sub_matches, &global),
+            "prescribe" => commands::prescribe::run(table_path, sub_matches, &global),
+            "publish" => commands::publish::run(table_path, sub_matches, &global),
+            "query" => commands::query::run(table_path, sub_matches, &global),
+            "report" => commands::report::run(table_path, sub_matches, &global),
+            "run-workspace" => commands::run_workspace::run(table_path, sub_matches, &global),
+            "scan" => commands::scan::run(table_path, sub_matches, &global),
+            "serve" => commands::serve::run(table_path, sub_matches, &global),
+            "streams" => commands::streams::run(table_path, sub_matches, &global),
+            "upgrade-plan" => commands::upgrade_plan::run(table_path, sub_matches, &global),
+            "validate-log" => commands::validate_log::run(table_path, sub_matches, &global),
+            "watch" => commands::watch::run(table_path, sub_matches, &global),
+            "writers" => commands::writers::run(table_path, sub_matches, &global),
+            _ => unreachable!("subcommand {} is not registered", name),
+        };
+        // Report usage whenever limits are in force, so budgets can be tuned
+        let limits = &global.config.requests;
+        if limits.budget.is_some() || limits.concurrency.is_some() {
+            eprintln!("Made {}", RequestUsage::since(0));
+        }
+        return result;
+    }
+
+    let mut global = GlobalOptions::from_matches(&matches, None)?;
+    if let Some(palette) = matches.get_one::<String>("palette") {
+        global.config.palette = Some(palette.clone());
+    }
     let table_path = matches
         .get_one::<String>("table_path")
         .context("Table path is required")?;
+    validate_table_path(table_path);
 
-    // Validate local paths (not Azure storage URLs)
-    if !table_path.starts_with("abfss://") && !table_path.starts_with("az://") {
-        if !std::path::Path::new(table_path).exists() {
-            eprintln!("Error: Path does not exist: {}", table_path);
-            std::process::exit(1);
-        }
+    if let Some(values) = matches.get_many::<String>("simulate_vacuum") {
+        let retentions = values
+            .map(|value| parse_retention_hours(value))
+            .collect::<Result<Vec<i64>>>()?;
+        return commands::simulate_vacuum::run(table_path, &retentions, &global);
+    }
+    if matches.get_flag("simulate_optimize") {
+        return commands::simulate_optimize::run(table_path, &global);
+    }
+
+    if global.as_of.is_some() {
+        bail!("--as-of-report applies to subcommands such as `report` and `check`; use --replay-from to browse an older version in the TUI");
     }
 
     // Launch interactive TUI
-    tui_app::run_tui(table_path)?;
+    tui_app::run_tui(
+        table_path,
+        TuiOptions {
+            storage_options: global.storage_options,
+            timeout: global.timeout,
+            diagnostics: matches.get_flag("diagnostics"),
+            refresh_interval: matches
+                .get_one::<String>("refresh_interval")
+                .map(|value| parse_duration(value))
+                .transpose()?,
+            replay: matches
+                .get_one::<String>("replay")
+                .map(|value| parse_duration(value))
+                .transpose()?,
+            replay_from: matches.get_one::<i64>("replay_from").copied(),
+            config: global.config,
+        },
+    )?;
 
     Ok(())
 }
 
+/// Exit early with a friendly message if a local table path doesn't exist.
+pub fn validate_table_path(table_path: &str) {
+    // Remote storage URLs are validated when the table is opened
+    if !is_remote_path(table_path) && !std::path::Path::new(table_path).exists() {
+        eprintln!("Error: Path does not exist: {}", table_path);
+        std::process::exit(1);
+    }
+}
+
+/// Parse repeated `key=value` pairs into a storage options map.
+fn parse_storage_options<'a>(
+    values: impl Iterator<Item = &'a String>,
+) -> Result<HashMap<String, String>> {
+    Ok(parse_key_values("storage option", values)?.into_iter().collect())
+}
+
+/// Parse repeated `key=value` arguments, keeping their order.
+pub fn parse_key_values<'a>(
+    what: &str,
+    values: impl Iterator<Item = &'a String>,
+) -> Result<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    for value in values {
+        let Some((key, val)) = value.split_once('=') else {
+            bail!("Invalid {} '{}': expected KEY=VALUE", what, value);
+        };
+        if key.trim().is_empty() {
+            bail!("Invalid {} '{}': key must not be empty", what, value);
+        }
+        pairs.push((key.trim().to_string(), val.to_string()));
+    }
+    Ok(pairs)
+}
+
+fn is_remote_path(table_path: &str) -> bool {
+    table_path.contains("://")
+}
+
+/// Parse a duration such as `90`, `30s`, `5m` or `1h`. Bare numbers are seconds.
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(idx) => value.split_at(idx),
+        None => (value, "s"),
+    };
+    let amount: u64 = number
+        .parse()
+        .with_context(|| format!("Invalid duration '{}'", value))?;
+    let unit_seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        _ => bail!("Invalid duration '{}': expected a unit of s, m, h or d", value),
+    };
+    let seconds = amount
+        .checked_mul(unit_seconds)
+        .with_context(|| format!("Invalid duration '{}': too long", value))?;
+    Ok(Duration::from_secs(seconds))
+}
+
+/// Parse a VACUUM retention into hours, the unit Delta retention is set in.
+/// Durations that aren't whole hours are rejected rather than rounded down.
+fn parse_retention_hours(value: &str) -> Result<i64> {
+    let seconds = parse_duration(value)?.as_secs();
+    if seconds % 3600 != 0 {
+        bail!("Invalid retention '{}': VACUUM retention is set in whole hours, such as 36h or 7d", value.trim());
+    }
+    Ok((seconds / 3600) as i64)
+}
@@ -0,0 +1,128 @@
+use crate::domain_metadata::DomainMetadata;
+use crate::inspector::commit_version;
+use chrono::{DateTime, Utc};
+use deltalake::kernel::{CommitInfo, DataType, MetadataValue, StructType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Liquid clustering: the `clustering` writer feature plus the columns in the
+/// `delta.clustering` domain, which OPTIMIZE clusters files by.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusteringInfo {
+    /// The protocol lists the `clustering` writer feature
+    pub supported: bool,
+    /// Logical column paths, dotted for nested fields; empty when clustering
+    /// was dropped with CLUSTER BY NONE
+    pub columns: Vec<String>,
+    /// `columns` were taken from the last CLUSTER BY commit because the
+    /// domain couldn't be read
+    pub columns_from_history: bool,
+    pub last_cluster_by: Option<ClusterByCommit>,
+}
+
+/// The latest commit that set clustering columns: CREATE TABLE ... CLUSTER BY
+/// or ALTER TABLE ... CLUSTER BY.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterByCommit {
+    pub version: i64,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub operation: String,
+    pub columns: Vec<String>,
+}
+
+impl ClusteringInfo {
+    /// `history` is newest first, as from
+    /// [`crate::DeltaTableInspector::get_history`].
+    pub fn new(
+        supported: bool,
+        domain: Option<&DomainMetadata>,
+        schema: Option<&StructType>,
+        history: &[CommitInfo],
+    ) -> Self {
+        let last_cluster_by = history.iter().find_map(|entry| {
+            let columns = cluster_by_columns(entry)?;
+            Some(ClusterByCommit {
+                version: commit_version(entry)?,
+                timestamp: entry.timestamp.and_then(DateTime::from_timestamp_millis),
+                operation: entry.operation.clone().unwrap_or_else(|| "UNKNOWN".to_string()),
+                columns,
+            })
+        });
+
+        let from_domain = domain
+            .filter(|domain| !domain.removed)
+            .and_then(|domain| serde_json::from_str::<Value>(&domain.configuration).ok())
+            .and_then(|config| {
+                let columns = config["clusteringColumns"].as_array()?;
+                Some(
+                    columns
+                        .iter()
+                        .filter_map(|path| {
+                            let path: Vec<&str> = path.as_array()?.iter().filter_map(Value::as_str).collect();
+                            Some(logical_path(schema, &path))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            });
+        let columns_from_history = from_domain.is_none() && supported && last_cluster_by.is_some();
+        let columns = match from_domain {
+            Some(columns) => columns,
+            None if supported => last_cluster_by.as_ref().map(|commit| commit.columns.clone()).unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        Self {
+            supported,
+            columns,
+            columns_from_history,
+            last_cluster_by,
+        }
+    }
+
+    /// Supported and clustering by at least one column.
+    pub fn enabled(&self) -> bool {
+        self.supported && !self.columns.is_empty()
+    }
+}
+
+/// Columns a commit clustered by, from `clusterBy` (CREATE TABLE, REPLACE
+/// TABLE) or `newClusteringColumns` (CLUSTER BY), both JSON arrays of names
+/// serialized as strings.
+fn cluster_by_columns(entry: &CommitInfo) -> Option<Vec<String>> {
+    let parameters = entry.operation_parameters.as_ref()?;
+    let value = parameters.get("newClusteringColumns").or_else(|| match entry.operation.as_deref() {
+        Some(op) if op.starts_with("CREATE") || op.starts_with("REPLACE") => parameters.get("clusterBy"),
+        _ => None,
+    })?;
+    let columns = match value {
+        Value::String(text) => serde_json::from_str::<Value>(text).unwrap_or_else(|_| Value::String(text.clone())),
+        other => other.clone(),
+    };
+    match columns {
+        Value::Array(names) => Some(names.iter().filter_map(Value::as_str).map(str::to_string).collect()),
+        // CLUSTER BY records a comma separated list
+        Value::String(text) => Some(text.split(',').map(str::trim).filter(|name| !name.is_empty()).map(str::to_string).collect()),
+        _ => None,
+    }
+}
+
+/// Dotted logical path of a physical column path. With column mapping the
+/// domain holds physical names; fields without one keep theirs.
+fn logical_path(schema: Option<&StructType>, physical: &[&str]) -> String {
+    let mut names = Vec::with_capacity(physical.len());
+    let mut fields = schema;
+    for part in physical {
+        let field = fields.and_then(|fields| {
+            fields.fields().find(|field| match field.metadata().get("delta.columnMapping.physicalName") {
+                Some(MetadataValue::String(name)) => name.as_str() == *part,
+                _ => field.name().as_str() == *part,
+            })
+        });
+        names.push(field.map_or(part.to_string(), |field| field.name().clone()));
+        fields = field.and_then(|field| match field.data_type() {
+            DataType::Struct(inner) => Some(inner.as_ref()),
+            _ => None,
+        });
+    }
+    names.join(".")
+}
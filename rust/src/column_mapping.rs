@@ -0,0 +1,89 @@
+use crate::inspector::commit_version;
+use chrono::{DateTime, Utc};
+use deltalake::kernel::{CommitInfo, DataType, MetadataValue, StructType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const MODE_PROPERTY: &str = "delta.columnMapping.mode";
+
+/// A column's logical name next to the name and ID its data is stored under
+/// in Parquet files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappedColumn {
+    /// Dotted for nested fields
+    pub logical_name: String,
+    pub physical_name: Option<String>,
+    pub field_id: Option<i64>,
+}
+
+impl MappedColumn {
+    /// Every field of `schema`, nested struct fields included, in schema order.
+    pub fn from_schema(schema: &StructType) -> Vec<Self> {
+        let mut columns = Vec::new();
+        collect(schema, "", &mut columns);
+        columns
+    }
+
+    /// Columns that existed before mapping was enabled keep their logical
+    /// name as physical name; columns added since get a `col-<uuid>` name.
+    pub fn predates_mapping(&self) -> bool {
+        let leaf = self.logical_name.rsplit('.').next().unwrap_or(&self.logical_name);
+        self.physical_name.as_deref() == Some(leaf)
+    }
+}
+
+fn collect(schema: &StructType, prefix: &str, columns: &mut Vec<MappedColumn>) {
+    for field in schema.fields() {
+        let metadata = field.metadata();
+        let logical_name = format!("{}{}", prefix, field.name());
+        columns.push(MappedColumn {
+            logical_name: logical_name.clone(),
+            physical_name: match metadata.get("delta.columnMapping.physicalName") {
+                Some(MetadataValue::String(name)) => Some(name.clone()),
+                _ => None,
+            },
+            field_id: match metadata.get("delta.columnMapping.id") {
+                Some(MetadataValue::Number(id)) => Some(*id as i64),
+                Some(MetadataValue::Other(id)) => id.as_i64(),
+                _ => None,
+            },
+        });
+        if let DataType::Struct(inner) = field.data_type() {
+            collect(inner, &format!("{}.", logical_name), columns);
+        }
+    }
+}
+
+/// The commit that turned column mapping on for an existing table, i.e. a
+/// SET TBLPROPERTIES setting the mode. Tables created with mapping have none.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingEnabled {
+    pub version: i64,
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+impl MappingEnabled {
+    /// `history` is newest first, as from
+    /// [`crate::DeltaTableInspector::get_history`].
+    pub fn find(history: &[CommitInfo]) -> Option<Self> {
+        // The oldest commit setting the mode, in case it was changed again later
+        history.iter().rev().find_map(|entry| {
+            if entry.operation.as_deref() != Some("SET TBLPROPERTIES") {
+                return None;
+            }
+            let properties = entry.operation_parameters.as_ref()?.get("properties")?;
+            let properties: Value = match properties {
+                Value::String(text) => serde_json::from_str(text).ok()?,
+                other => other.clone(),
+            };
+            let mode = properties.get(MODE_PROPERTY)?.as_str()?;
+            if mode == "none" {
+                return None;
+            }
+            Some(Self {
+                version: commit_version(entry)?,
+                timestamp: entry.timestamp.and_then(DateTime::from_timestamp_millis),
+            })
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use crate::inspector::FileInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// Table-wide min/max/null statistics for one column, aggregated from the
+/// per-file `stats` on add actions. Nested struct fields use dotted names.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnStats {
+    pub name: String,
+    pub min: Option<Value>,
+    pub max: Option<Value>,
+    pub null_count: i64,
+    /// Number of files that carry statistics for this column
+    pub files_with_stats: usize,
+    /// Rows covered by those files, when `numRecords` is known
+    pub rows_with_stats: i64,
+}
+
+impl ColumnStats {
+    pub fn null_fraction(&self) -> Option<f64> {
+        if self.rows_with_stats > 0 {
+            Some(self.null_count as f64 / self.rows_with_stats as f64)
+        } else {
+            None
+        }
+    }
+}
+
+/// Aggregate per-file column statistics into one entry per column.
+pub fn aggregate(files: &[FileInfo]) -> Vec<ColumnStats> {
+    let mut columns: BTreeMap<String, ColumnStats> = BTreeMap::new();
+
+    for file in files {
+        let mut names: Vec<&String> = file.min_values.keys()
+            .chain(file.max_values.keys())
+            .chain(file.null_counts.keys())
+            .collect();
+        names.sort();
+        names.dedup();
+
+        for name in names {
+            let entry = columns.entry(name.clone()).or_insert_with(|| ColumnStats {
+                name: name.clone(),
+                min: None,
+                max: None,
+                null_count: 0,
+                files_with_stats: 0,
+                rows_with_stats: 0,
+            });
+
+            entry.files_with_stats += 1;
+            entry.rows_with_stats += file.num_records.unwrap_or(0);
+            entry.null_count += file.null_counts.get(name).copied().unwrap_or(0);
+
+            if let Some(min) = file.min_values.get(name) {
+                if entry.min.as_ref().is_none_or(|cur| compare_values(min, cur) == Some(Ordering::Less)) {
+                    entry.min = Some(min.clone());
+                }
+            }
+            if let Some(max) = file.max_values.get(name) {
+                if entry.max.as_ref().is_none_or(|cur| compare_values(max, cur) == Some(Ordering::Greater)) {
+                    entry.max = Some(max.clone());
+                }
+            }
+        }
+    }
+
+    columns.into_values().collect()
+}
+
+/// Compare two stats values. Numbers compare numerically and strings
+/// lexicographically, which also orders ISO dates and timestamps correctly.
+pub fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// Flatten a (possibly nested) stats object into dotted column names.
+pub fn flatten_stats<T>(value: &Value, prefix: &str, out: &mut std::collections::HashMap<String, T>, convert: &impl Fn(&Value) -> Option<T>) {
+    let Value::Object(map) = value else {
+        return;
+    };
+    for (key, value) in map {
+        let name = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        if value.is_object() {
+            flatten_stats(value, &name, out, convert);
+        } else if let Some(converted) = convert(value) {
+            out.insert(name, converted);
+        }
+    }
+}
+
+/// Render a stats value compactly for display.
+pub fn format_value(value: &Value) -> String {
+    let text = match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    if text.chars().count() > 32 {
+        format!("{}…", text.chars().take(31).collect::<String>())
+    } else {
+        text
+    }
+}
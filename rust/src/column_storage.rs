@@ -0,0 +1,189 @@
+use crate::column_mapping::MappedColumn;
+use crate::insights::DeltaTableAnalyzer;
+use crate::inspector::FileInfo;
+use crate::parquet_footer::read_footers;
+use crate::render::{Document, Renderable};
+use deltalake::DeltaTable;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+
+/// Storage taken by each column, summed from the column chunk sizes in the
+/// Parquet footers of the table's files.
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnStorageReport {
+    pub table_path: String,
+    pub version: i64,
+    pub audited_files: usize,
+    pub total_files: usize,
+    pub audited_bytes: i64,
+    pub total_bytes: i64,
+    /// Largest compressed size first
+    pub columns: Vec<ColumnStorage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnStorage {
+    /// Logical dotted path; Parquet's `list.element` and `key_value` levels
+    /// are kept for nested types
+    pub column: String,
+    pub physical_type: String,
+    pub codecs: Vec<String>,
+    pub compressed_bytes: i64,
+    pub uncompressed_bytes: i64,
+}
+
+impl ColumnStorage {
+    pub fn compression_ratio(&self) -> Option<f64> {
+        (self.compressed_bytes > 0).then(|| self.uncompressed_bytes as f64 / self.compressed_bytes as f64)
+    }
+}
+
+impl ColumnStorageReport {
+    /// Read the footers of `sample_files` files (all when `None`) and sum the
+    /// column chunks by column.
+    pub async fn analyze(table: &DeltaTable, files: &[FileInfo], sample_files: Option<usize>) -> Self {
+        // Files of tables with column mapping name columns by physical name
+        let logical_names = table.get_schema().map(physical_to_logical).unwrap_or_default();
+
+        let mut report = Self {
+            table_path: table.table_uri(),
+            version: table.version(),
+            audited_files: 0,
+            total_files: files.len(),
+            audited_bytes: 0,
+            total_bytes: files.iter().map(|f| f.size_bytes).sum(),
+            columns: Vec::new(),
+        };
+        let mut columns: HashMap<String, (ColumnStorage, BTreeSet<String>)> = HashMap::new();
+        for (file, footer) in read_footers(table.object_store(), files, sample_files).await {
+            let Some(footer) = footer else {
+                continue;
+            };
+            report.audited_files += 1;
+            report.audited_bytes += file.size_bytes;
+            for chunk in footer.columns {
+                let column = logical_path(&chunk.path, &logical_names);
+                let (storage, codecs) = columns.entry(column.clone()).or_insert_with(|| {
+                    (
+                        ColumnStorage {
+                            column,
+                            physical_type: chunk.physical_type.clone(),
+                            codecs: Vec::new(),
+                            compressed_bytes: 0,
+                            uncompressed_bytes: 0,
+                        },
+                        BTreeSet::new(),
+                    )
+                });
+                storage.compressed_bytes += chunk.compressed_bytes;
+                storage.uncompressed_bytes += chunk.uncompressed_bytes;
+                codecs.extend(chunk.codecs);
+            }
+        }
+        report.columns = columns
+            .into_values()
+            .map(|(storage, codecs)| ColumnStorage {
+                codecs: codecs.into_iter().collect(),
+                ..storage
+            })
+            .collect();
+        report.columns.sort_by(|a, b| b.compressed_bytes.cmp(&a.compressed_bytes).then_with(|| a.column.cmp(&b.column)));
+        report
+    }
+
+    pub fn compressed_bytes(&self) -> i64 {
+        self.columns.iter().map(|c| c.compressed_bytes).sum()
+    }
+
+    /// Factor from the audited files to the whole table, by size.
+    pub fn scale(&self) -> f64 {
+        if self.audited_bytes > 0 {
+            self.total_bytes as f64 / self.audited_bytes as f64
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Dotted physical path of every mapped field to its logical path.
+fn physical_to_logical(schema: &deltalake::kernel::StructType) -> HashMap<String, String> {
+    let mapped = MappedColumn::from_schema(schema);
+    let physical: HashMap<&str, &str> = mapped
+        .iter()
+        .filter_map(|c| Some((c.logical_name.as_str(), c.physical_name.as_deref()?)))
+        .collect();
+    mapped
+        .iter()
+        .filter_map(|column| {
+            // Each level of the path is renamed separately
+            let mut prefix = String::new();
+            let mut path = Vec::new();
+            for part in column.logical_name.split('.') {
+                prefix = if prefix.is_empty() { part.to_string() } else { format!("{}.{}", prefix, part) };
+                path.push(*physical.get(prefix.as_str())?);
+            }
+            Some((path.join("."), column.logical_name.clone()))
+        })
+        .collect()
+}
+
+/// Logical name of the longest mapped prefix of a Parquet leaf path, with
+/// the rest of the path (`list.element`, nested fields) kept as is.
+fn logical_path(path: &str, logical_names: &HashMap<String, String>) -> String {
+    let parts: Vec<&str> = path.split('.').collect();
+    for end in (1..=parts.len()).rev() {
+        if let Some(logical) = logical_names.get(&parts[..end].join(".")) {
+            return std::iter::once(logical.as_str()).chain(parts[end..].iter().copied()).collect::<Vec<_>>().join(".");
+        }
+    }
+    path.to_string()
+}
+
+impl Renderable for ColumnStorageReport {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new(format!("Column storage of {}", self.table_path));
+        if self.columns.is_empty() {
+            doc.text("No Parquet footer could be read");
+            return doc;
+        }
+
+        let compressed = self.compressed_bytes();
+        let scale = self.scale();
+        let mut fields = vec![
+            ("Version", self.version.to_string()),
+            ("Files read", format!("{} of {}", self.audited_files, self.total_files)),
+            ("Column data", format_bytes(compressed)),
+        ];
+        if self.audited_files < self.total_files {
+            fields.push(("Projected for the table", format_bytes((compressed as f64 * scale) as i64)));
+        }
+        doc.fields(fields);
+
+        let rows = self
+            .columns
+            .iter()
+            .map(|c| {
+                vec![
+                    c.column.clone(),
+                    c.physical_type.clone(),
+                    c.codecs.join(", "),
+                    format_bytes((c.compressed_bytes as f64 * scale) as i64),
+                    format!("{:.1}%", c.compressed_bytes as f64 / compressed.max(1) as f64 * 100.0),
+                    format_bytes((c.uncompressed_bytes as f64 * scale) as i64),
+                    c.compression_ratio().map(|r| format!("{:.1}×", r)).unwrap_or_else(|| "-".to_string()),
+                ]
+            })
+            .collect();
+        doc.table(&["Column", "Type", "Codec", "Compressed", "Share", "Uncompressed", "Ratio"], rows);
+        if self.audited_files < self.total_files {
+            doc.text(format!(
+                "Sizes are projected from {} sampled files ({}) to the table's {}",
+                self.audited_files,
+                format_bytes(self.audited_bytes),
+                format_bytes(self.total_bytes)
+            ));
+        }
+        doc
+    }
+}
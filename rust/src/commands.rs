@@ -0,0 +1,130 @@
+pub mod check;
+pub mod checkpoint;
+pub mod column_sizes;
+pub mod compare;
+pub mod constraints;
+pub mod diff;
+pub mod fleet;
+pub mod fsck;
+pub mod history;
+pub mod info;
+pub mod migration_report;
+pub mod parquet;
+pub mod prescribe;
+pub mod publish;
+pub mod query;
+pub mod report;
+pub mod run_workspace;
+pub mod scan;
+pub mod serve;
+pub mod simulate_optimize;
+pub mod simulate_vacuum;
+pub mod streams;
+pub mod upgrade_plan;
+pub mod validate_log;
+pub mod watch;
+pub mod writers;
+
+use crate::cli::GlobalOptions;
+use deltective::cancel;
+use deltective::inspector::DeltaTableInspector;
+use deltective::parquet_dataset;
+use deltective::render::{self, Renderable};
+use deltective::select::{format_selected, Selector};
+use anyhow::{bail, Result};
+use clap::ArgMatches;
+use serde::Serialize;
+use std::future::Future;
+
+/// Runtime plus opened table shared by the non-interactive subcommands.
+pub struct CommandContext {
+    pub runtime: tokio::runtime::Runtime,
+    pub inspector: DeltaTableInspector,
+    timeout: Option<std::time::Duration>,
+}
+
+impl CommandContext {
+    pub fn open(table_path: &str, global: &GlobalOptions) -> Result<Self> {
+        let runtime = tokio::runtime::Runtime::new()?;
+        let storage_options = global.config.storage_options_for(table_path, &global.storage_options);
+        let opened = runtime.block_on(cancel::run(
+            DeltaTableInspector::new(table_path, storage_options.clone()),
+            global.timeout,
+        ));
+        let mut inspector = match opened {
+            Ok(inspector) => inspector,
+            // Timeouts and Ctrl-C say nothing about the path, so don't probe it then
+            Err(e) if e.downcast_ref::<cancel::Interrupted>().is_none()
+                && runtime.block_on(parquet_dataset::is_parquet_dataset(table_path, storage_options)) =>
+            {
+                return Err(e.context(format!(
+                    "{} is a plain Parquet dataset, not a Delta table (no _delta_log). \
+                     Run `deltective parquet {}` for a degraded inspection.",
+                    table_path, table_path
+                )));
+            }
+            Err(e) => return Err(e),
+        };
+
+        if let Some(as_of) = &global.as_of {
+            if !as_of.table_path.is_empty() && as_of.table_path != inspector.table_path() {
+                eprintln!(
+                    "Warning: the export was made for {}, not {}",
+                    as_of.table_path,
+                    inspector.table_path()
+                );
+            }
+            inspector = runtime.block_on(cancel::run(inspector.at_version(as_of.version), global.timeout))?;
+        }
+
+        Ok(Self {
+            runtime,
+            inspector,
+            timeout: global.timeout,
+        })
+    }
+
+    /// Open a table for a command that writes to it. `--as-of-report` pins
+    /// the table to an old version, and writing from there would commit
+    /// against stale state (or, for a checkpoint, regress `_last_checkpoint`).
+    pub fn open_for_write(table_path: &str, global: &GlobalOptions) -> Result<Self> {
+        if global.as_of.is_some() {
+            bail!("--as-of-report only applies to read-only analyses; this command writes to the table, so run it without the flag");
+        }
+        Self::open(table_path, global)
+    }
+
+    /// Block on `fut`, honouring the global timeout and Ctrl-C.
+    pub fn block_on<F, T>(&self, fut: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        self.runtime.block_on(cancel::run(fut, self.timeout))
+    }
+}
+
+/// Print `value` in the `--format` chosen on the command line, or just the
+/// fields picked by `--select`.
+pub fn print_output<T: Renderable + ?Sized>(value: &T, matches: &ArgMatches) -> Result<()> {
+    if matches.contains_id("select") {
+        return print_json(&value, matches);
+    }
+    let format = matches.get_one::<String>("format").map_or("text", String::as_str);
+    print!("{}", render::render(value, format)?);
+    Ok(())
+}
+
+/// Print `value` as pretty JSON, or just the fields picked by `--select`,
+/// one per line.
+pub fn print_json<T: Serialize>(value: &T, matches: &ArgMatches) -> Result<()> {
+    match matches.get_one::<String>("select") {
+        Some(expr) => {
+            let selector = Selector::parse(expr)?;
+            for selected in selector.apply(&serde_json::to_value(value)?) {
+                println!("{}", format_selected(&selected));
+            }
+        }
+        None => println!("{}", serde_json::to_string_pretty(value)?),
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::insights::{health_score, severity_rank, Insight};
+use deltective::render::{Document, Item, Renderable, Tone};
+use deltective::report::Provenance;
+use anyhow::Result;
+use clap::ArgMatches;
+use serde::Serialize;
+
+/// Exit status when findings at or above `--fail-on` exist. Errors opening or
+/// reading the table exit with 1.
+const FINDINGS_EXIT_CODE: i32 = 2;
+
+#[derive(Serialize)]
+struct CheckResult<'a> {
+    table_path: &'a str,
+    version: i64,
+    health_score: u8,
+    fail_on: &'a str,
+    passed: bool,
+    /// Findings at or above `fail_on`
+    failing: usize,
+    insights: &'a [Insight],
+    provenance: Provenance,
+}
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let fail_on = matches.get_one::<String>("fail_on").expect("has default");
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let mut stats = ctx.block_on(ctx.inspector.get_statistics())?;
+    ctx.block_on(ctx.inspector.enrich_statistics(&mut stats, &global.config))?;
+    let version = stats.version;
+    let provenance = Provenance::new(&stats);
+    let insights = global.config.analyzer(stats).analyze();
+
+    let failing = insights
+        .iter()
+        .filter(|i| fail_on != "never" && severity_rank(&i.severity) >= severity_rank(fail_on))
+        .count();
+    let result = CheckResult {
+        table_path,
+        version,
+        health_score: health_score(&insights),
+        fail_on,
+        passed: failing == 0,
+        failing,
+        insights: &insights,
+        provenance,
+    };
+
+    print_output(&result, matches)?;
+
+    if !result.passed {
+        std::process::exit(FINDINGS_EXIT_CODE);
+    }
+    Ok(())
+}
+
+impl Renderable for CheckResult<'_> {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("Check {} (version {})", self.table_path, self.version));
+        if let Some(activity) = &self.provenance.write_activity {
+            doc.list(vec![Item::new("[NOTE]", activity.notice()).tone(Tone::Warning)]);
+        }
+        doc.text(format!("Health {}/100", self.health_score));
+        doc.list(
+            self.insights
+                .iter()
+                .filter(|i| i.severity != "good")
+                .map(|i| {
+                    Item::new(format!("[{}]", i.severity.to_uppercase()), format!("{}: {}", i.title, i.description))
+                        .tone(Tone::from_severity(&i.severity))
+                })
+                .collect(),
+        );
+        if self.passed {
+            doc.text(format!("PASSED: no findings at or above '{}'", self.fail_on));
+        } else {
+            doc.text(format!("FAILED: {} finding(s) at or above '{}'", self.failing, self.fail_on));
+        }
+        doc
+    }
+}
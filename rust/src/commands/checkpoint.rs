@@ -0,0 +1,31 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::checkpoint_contents;
+use deltective::maintenance::{self, CheckpointPlan};
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    // Inspecting and planning are read-only and may look at an older version
+    let read_only = matches.get_flag("inspect") || matches.get_flag("dry_run");
+    let ctx = if read_only {
+        CommandContext::open(table_path, global)?
+    } else {
+        CommandContext::open_for_write(table_path, global)?
+    };
+
+    if matches.get_flag("inspect") {
+        let version = matches.get_one::<i64>("version").copied();
+        let contents = ctx.block_on(checkpoint_contents::inspect(ctx.inspector.table(), version))?;
+        return print_output(&contents, matches);
+    }
+
+    let plan = ctx.block_on(CheckpointPlan::new(ctx.inspector.table()))?;
+
+    if matches.get_flag("dry_run") {
+        return print_output(&plan, matches);
+    }
+
+    let outcome = ctx.block_on(maintenance::checkpoint(ctx.inspector.table(), &plan))?;
+    print_output(&outcome, matches)
+}
@@ -0,0 +1,15 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::column_storage::ColumnStorageReport;
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::open(table_path, global)?;
+    let stats = ctx.block_on(ctx.inspector.get_statistics())?;
+    let sample_files = *matches.get_one::<usize>("sample_files").expect("has default");
+    let report = ctx.block_on(async {
+        anyhow::Ok(ColumnStorageReport::analyze(ctx.inspector.table(), &stats.files, (sample_files > 0).then_some(sample_files)).await)
+    })?;
+    print_output(&report, matches)
+}
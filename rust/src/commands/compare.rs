@@ -0,0 +1,23 @@
+use crate::cli::{validate_table_path, GlobalOptions};
+use crate::commands::{print_output, CommandContext};
+use crate::tui_app;
+use deltective::diff;
+use deltective::inspector::DeltaTableInspector;
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let other_path = matches.get_one::<String>("other_path").expect("other_path is required");
+    validate_table_path(other_path);
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let comparison = ctx.block_on(async {
+        let other = DeltaTableInspector::new(other_path, global.config.storage_options_for(other_path, &global.storage_options)).await?;
+        diff::compare(&ctx.inspector, &other).await
+    })?;
+
+    if matches.get_flag("tui") {
+        return tui_app::compare::run(&comparison);
+    }
+    print_output(&comparison, matches)
+}
@@ -0,0 +1,16 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::constraints::ConstraintReport;
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::open(table_path, global)?;
+    let report = if matches.get_flag("evaluate") {
+        let sample_rows = *matches.get_one::<usize>("sample_rows").expect("has default");
+        ctx.block_on(ConstraintReport::evaluate(ctx.inspector.table(), sample_rows))?
+    } else {
+        ConstraintReport::list(ctx.inspector.table())?
+    };
+    print_output(&report, matches)
+}
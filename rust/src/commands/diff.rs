@@ -0,0 +1,28 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::diff;
+use anyhow::{Context, Result};
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let from: i64 = matches
+        .get_one::<String>("from")
+        .expect("from is required")
+        .parse()
+        .context("--from must be a version number")?;
+    let to: Option<i64> = matches
+        .get_one::<String>("to")
+        .map(|v| v.parse().context("--to must be a version number"))
+        .transpose()?;
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let table_diff = ctx.block_on(async {
+        let old = ctx.inspector.at_version(from).await?;
+        match to {
+            Some(to) => diff::diff(&old, &ctx.inspector.at_version(to).await?).await,
+            None => diff::diff(&old, &ctx.inspector).await,
+        }
+    })?;
+
+    print_output(&table_diff, matches)
+}
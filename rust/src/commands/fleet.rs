@@ -0,0 +1,39 @@
+use crate::cli::GlobalOptions;
+use crate::commands::print_output;
+use deltective::cancel;
+use deltective::discovery::discover_tables;
+use deltective::fleet::{self, FleetMatrix, MatrixSort};
+use anyhow::{bail, Context, Result};
+use clap::ArgMatches;
+
+pub fn run(root: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let sort = matches
+        .get_one::<String>("sort")
+        .and_then(|name| MatrixSort::parse(name))
+        .context("Invalid --sort column")?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let discovery = runtime.block_on(cancel::run(
+        discover_tables(root, global.config.storage_options_for(root, &global.storage_options)),
+        global.timeout,
+    ))?;
+    if discovery.tables.is_empty() && discovery.unreadable.is_empty() {
+        bail!("No Delta tables found under {}", root);
+    }
+    eprintln!("Found {} Delta table(s) under {}, inspecting...", discovery.tables.len(), root);
+
+    let report = runtime.block_on(cancel::run(
+        async { Ok(fleet::scan(&discovery.tables, &global.storage_options, &global.config, None).await) },
+        global.timeout,
+    ))?;
+    let report = report.with_unreadable(discovery.unreadable);
+
+    let mut matrix = FleetMatrix::new(&report);
+    matrix.sort(sort, matches.get_flag("reverse"));
+
+    if !matches.contains_id("select") && matches.get_one::<String>("format").is_some_and(|f| f == "csv") {
+        print!("{}", matrix.to_csv());
+        return Ok(());
+    }
+    print_output(&matrix, matches)
+}
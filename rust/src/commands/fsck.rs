@@ -0,0 +1,27 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::fsck;
+use anyhow::Result;
+use clap::ArgMatches;
+
+/// Exit status when the log references files that aren't in storage, as
+/// for `check`. Errors opening the table exit with 1.
+const CORRUPT_EXIT_CODE: i32 = 2;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let check_sizes = matches.get_flag("check_sizes");
+    let concurrency = *matches.get_one::<usize>("concurrency").expect("has default");
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let stats = ctx.block_on(ctx.inspector.get_statistics())?;
+    let report = ctx.block_on(async {
+        Ok(fsck::check(ctx.inspector.table(), &stats.files, check_sizes, concurrency).await)
+    })?;
+
+    print_output(&report, matches)?;
+
+    if !report.is_healthy() {
+        std::process::exit(CORRUPT_EXIT_CODE);
+    }
+    Ok(())
+}
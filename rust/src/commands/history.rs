@@ -0,0 +1,22 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::history::{parse_date, HistoryFilter, HistoryListing};
+use anyhow::{bail, Result};
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let filter = HistoryFilter {
+        operations: matches.get_many::<String>("operation").unwrap_or_default().cloned().collect(),
+        since: matches.get_one::<String>("since").map(|d| parse_date(d)).transpose()?,
+        until: matches.get_one::<String>("until").map(|d| parse_date(d)).transpose()?,
+    };
+    if let (Some(since), Some(until)) = (filter.since, filter.until) {
+        if since > until {
+            bail!("--since {} is after --until {}", since, until);
+        }
+    }
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let history = ctx.block_on(ctx.inspector.get_history(false))?;
+    print_output(&HistoryListing::new(ctx.inspector.table_path(), &history, filter), matches)
+}
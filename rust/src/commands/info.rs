@@ -0,0 +1,109 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use crate::tui_app::format_bytes;
+use deltective::config::Config;
+use deltective::insights::health_score;
+use deltective::inspector::TableStatistics;
+use deltective::partitions::summarize_partitions;
+use deltective::render::{Document, Renderable};
+use deltective::report::Provenance;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::ArgMatches;
+use serde::Serialize;
+
+/// The handful of facts printed by `deltective info`.
+#[derive(Debug, Serialize)]
+pub struct TableInfo {
+    pub table_path: String,
+    pub version: i64,
+    pub total_size_bytes: i64,
+    pub num_files: usize,
+    pub num_rows: Option<i64>,
+    pub partition_columns: Vec<String>,
+    pub num_partitions: usize,
+    pub last_write: Option<DateTime<Utc>>,
+    pub last_operation: Option<String>,
+    pub health_score: u8,
+    pub provenance: Provenance,
+}
+
+impl TableInfo {
+    pub fn from_stats(stats: &TableStatistics, config: &Config) -> Self {
+        let insights = config.analyzer(stats.clone()).analyze();
+        let num_partitions = if stats.partition_columns.is_empty() {
+            0
+        } else {
+            summarize_partitions(stats).len()
+        };
+
+        Self {
+            table_path: stats.table_path.clone(),
+            version: stats.version,
+            total_size_bytes: stats.total_size_bytes,
+            num_files: stats.num_files,
+            num_rows: stats.num_rows,
+            partition_columns: stats.partition_columns.clone(),
+            num_partitions,
+            last_write: stats.last_operation.as_ref().map(|op| op.timestamp),
+            last_operation: stats.last_operation.as_ref().map(|op| op.operation.clone()),
+            health_score: health_score(&insights),
+            provenance: Provenance::new(stats),
+        }
+    }
+
+    /// One compact paragraph, suitable for shell prompts and chat replies.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![
+            format!("{} at version {}", self.table_path, self.version),
+            format!("{} in {} file(s)", format_bytes(self.total_size_bytes), self.num_files),
+        ];
+        if let Some(rows) = self.num_rows {
+            parts.push(format!("{} row(s)", rows));
+        }
+        if self.partition_columns.is_empty() {
+            parts.push("unpartitioned".to_string());
+        } else {
+            parts.push(format!(
+                "{} partition(s) by {}",
+                self.num_partitions,
+                self.partition_columns.join(", ")
+            ));
+        }
+        match (&self.last_write, &self.last_operation) {
+            (Some(time), Some(op)) => {
+                parts.push(format!("last write {} ({})", time.format("%Y-%m-%d %H:%M:%S UTC"), op))
+            }
+            _ => parts.push("no writes recorded".to_string()),
+        }
+        parts.push(format!("health {}/100", self.health_score));
+        if let Some(activity) = &self.provenance.write_activity {
+            parts.push(activity.notice());
+        }
+
+        format!("{}.", parts.join(", "))
+    }
+}
+
+impl Renderable for TableInfo {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("Table info: {}", self.table_path));
+        doc.text(self.summary());
+        doc
+    }
+}
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::open(table_path, global)?;
+    let stats = ctx.block_on(ctx.inspector.get_statistics())?;
+    let info = TableInfo::from_stats(&stats, &global.config);
+
+    // Plain text stays a single line for shell prompts and scripts
+    match matches.get_one::<String>("format").map(String::as_str) {
+        Some("text") if !matches.contains_id("select") => {
+            println!("{}", info.summary());
+            Ok(())
+        }
+        _ => print_output(&info, matches),
+    }
+}
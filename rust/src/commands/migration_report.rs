@@ -0,0 +1,17 @@
+use crate::cli::GlobalOptions;
+use crate::commands::print_output;
+use deltective::cancel;
+use deltective::migration;
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    // Not a Delta table, so no CommandContext
+    let runtime = tokio::runtime::Runtime::new()?;
+    let report = runtime.block_on(cancel::run(
+        migration::report(path, global.config.storage_options_for(path, &global.storage_options)),
+        global.timeout,
+    ))?;
+
+    print_output(&report, matches)
+}
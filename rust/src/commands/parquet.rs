@@ -0,0 +1,17 @@
+use crate::cli::GlobalOptions;
+use crate::commands::print_output;
+use deltective::cancel;
+use deltective::parquet_dataset;
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    // No Delta table to open, so no CommandContext
+    let runtime = tokio::runtime::Runtime::new()?;
+    let report = runtime.block_on(cancel::run(
+        parquet_dataset::inspect(path, global.config.storage_options_for(path, &global.storage_options), &global.config.thresholds),
+        global.timeout,
+    ))?;
+
+    print_output(&report, matches)
+}
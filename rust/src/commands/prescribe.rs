@@ -0,0 +1,32 @@
+use crate::cli::GlobalOptions;
+use crate::commands::CommandContext;
+use deltective::prescribe::{self, Dialect};
+use deltective::render;
+use anyhow::{Context, Result};
+use clap::ArgMatches;
+use std::fs;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let dialect = Dialect::parse(matches.get_one::<String>("dialect").expect("has default"))?;
+    let format = matches.get_one::<String>("format").expect("has default");
+    let output = matches.get_one::<String>("output");
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let mut stats = ctx.block_on(ctx.inspector.get_statistics())?;
+    ctx.block_on(ctx.inspector.enrich_statistics(&mut stats, &global.config))?;
+    let insights = global.config.analyzer(stats.clone()).analyze();
+    let prescription = prescribe::prescribe(&stats, &insights, &global.config.thresholds, dialect);
+
+    let rendered = match format.as_str() {
+        "script" => prescription.script(),
+        other => render::render(&prescription, other)?,
+    };
+    match output {
+        Some(path) => {
+            fs::write(path, rendered).with_context(|| format!("Failed to write script to {}", path))?;
+            eprintln!("Script written to {}", path);
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_json, CommandContext};
+use deltective::catalog::{self, CatalogSummary};
+use deltective::config::{CatalogConfig, CatalogKind};
+use anyhow::{bail, Result};
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::open(table_path, global)?;
+    let mut stats = ctx.block_on(ctx.inspector.get_statistics())?;
+    ctx.block_on(ctx.inspector.enrich_statistics(&mut stats, &global.config))?;
+    let insights = global.config.analyzer(stats.clone()).analyze();
+    let summary = CatalogSummary::new(&stats, insights);
+
+    if matches.get_flag("dry_run") {
+        return print_json(&summary, matches);
+    }
+
+    let config = catalog_config(matches, global)?;
+    let outcome = ctx.block_on(catalog::publish(&summary, &config))?;
+    eprintln!("Published {} to {} as {}", table_path, config.url, outcome.entity);
+    Ok(())
+}
+
+/// The config file's `catalog` section with `--catalog` and `--catalog-url`
+/// applied on top. The token only comes from the file or the environment.
+fn catalog_config(matches: &ArgMatches, global: &GlobalOptions) -> Result<CatalogConfig> {
+    let kind = matches.get_one::<String>("catalog").and_then(|name| CatalogKind::parse(name));
+    let url = matches.get_one::<String>("catalog_url").cloned();
+
+    let mut config = match (global.config.catalog.clone(), kind, url) {
+        (Some(mut config), kind, url) => {
+            config.kind = kind.unwrap_or(config.kind);
+            config.url = url.unwrap_or(config.url);
+            config
+        }
+        (None, Some(kind), Some(url)) => CatalogConfig {
+            kind,
+            url,
+            token: None,
+            platform: None,
+            env: None,
+            database_schema: None,
+            custom_properties: false,
+        },
+        (None, _, _) => bail!(
+            "No catalog configured; pass --catalog and --catalog-url or add a `catalog` section to the config file"
+        ),
+    };
+    if config.token.is_none() {
+        config.token = std::env::var("DELTECTIVE_CATALOG_TOKEN").ok().filter(|t| !t.is_empty());
+    }
+    Ok(config)
+}
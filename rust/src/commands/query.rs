@@ -0,0 +1,98 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::render::{self, Document, Renderable};
+use anyhow::Result;
+use arrow::csv::WriterBuilder as CsvWriterBuilder;
+use arrow::json::ArrayWriter;
+use arrow::record_batch::RecordBatch;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use clap::ArgMatches;
+use datafusion::prelude::SessionContext;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+/// Rows a query returned: JSON objects for `--format json`, a table for the
+/// other formats.
+#[derive(Serialize)]
+#[serde(transparent)]
+struct QueryResult {
+    rows: Vec<Value>,
+    #[serde(skip)]
+    columns: Vec<String>,
+    #[serde(skip)]
+    cells: Vec<Vec<String>>,
+}
+
+impl QueryResult {
+    fn new(batches: &[RecordBatch]) -> Result<Self> {
+        let mut json = Vec::new();
+        let mut writer = ArrayWriter::new(&mut json);
+        writer.write_batches(&batches.iter().collect::<Vec<_>>())?;
+        writer.finish()?;
+        // An empty result writes nothing rather than `[]`
+        let rows = if json.is_empty() { Vec::new() } else { serde_json::from_slice(&json)? };
+
+        let columns = batches
+            .first()
+            .map(|batch| batch.schema().fields().iter().map(|f| f.name().clone()).collect())
+            .unwrap_or_default();
+        let options = FormatOptions::default().with_null("NULL");
+        let mut cells = Vec::new();
+        for batch in batches {
+            let formatters = batch
+                .columns()
+                .iter()
+                .map(|column| ArrayFormatter::try_new(column.as_ref(), &options))
+                .collect::<Result<Vec<_>, _>>()?;
+            for row in 0..batch.num_rows() {
+                cells.push(formatters.iter().map(|f| f.value(row).to_string()).collect());
+            }
+        }
+        Ok(Self { rows, columns, cells })
+    }
+}
+
+impl Renderable for QueryResult {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("{} row(s)", self.cells.len()));
+        let headers: Vec<&str> = self.columns.iter().map(String::as_str).collect();
+        doc.table(&headers, self.cells.clone());
+        doc
+    }
+}
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let sql = matches.get_one::<String>("sql").expect("sql is required");
+    let table_name = matches.get_one::<String>("table_name").expect("has default");
+    let format = matches.get_one::<String>("format").expect("has default");
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let batches = ctx.block_on(async {
+        let session = SessionContext::new();
+        session.register_table(table_name.as_str(), Arc::new(ctx.inspector.table().clone()))?;
+        Ok(session.sql(sql).await?.collect().await?)
+    })?;
+
+    // CSV streams straight from Arrow; it has no document form
+    if format == "csv" && !matches.contains_id("select") {
+        let mut stdout = io::stdout().lock();
+        let mut writer = CsvWriterBuilder::new().with_header(true).build(&mut stdout);
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        drop(writer);
+        stdout.flush()?;
+        return Ok(());
+    }
+
+    let result = QueryResult::new(&batches)?;
+    if matches.contains_id("select") {
+        return print_output(&result, matches);
+    }
+    // `table` is the name this format had before the others were added
+    let format = if format == "table" { "text" } else { format.as_str() };
+    print!("{}", render::render(&result, format)?);
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::cli::GlobalOptions;
+use crate::commands::CommandContext;
+use deltective::diagnosis;
+use deltective::render;
+use deltective::report::{html, markdown, Report};
+use anyhow::{Context, Result};
+use clap::ArgMatches;
+use std::fs;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let format = matches.get_one::<String>("format").expect("has default");
+    let output = matches.get_one::<String>("output");
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let inspector = &ctx.inspector;
+    let (mut stats, timeline, history) = ctx.block_on(async {
+        tokio::try_join!(
+            inspector.get_statistics(),
+            inspector.get_timeline_analysis(),
+            inspector.get_history(false)
+        )
+    })?;
+    ctx.block_on(inspector.enrich_statistics(&mut stats, &global.config))?;
+    let insights = global.config.analyzer(stats.clone()).analyze();
+    let diagnoses = diagnosis::diagnose(&stats, &insights);
+    let report = Report::new(&stats, &timeline, &history, insights, &global.config.run_links).with_diagnoses(diagnoses);
+
+    let rendered = match format.as_str() {
+        "markdown" => markdown::render(&report),
+        "html" => html::render(&report),
+        other => render::render(&report, other)?,
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(path, rendered).with_context(|| format!("Failed to write report to {}", path))?;
+            eprintln!("Report written to {}", path);
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use crate::cli::{parse_duration, GlobalOptions};
+use crate::commands::print_output;
+use deltective::alerts;
+use deltective::cancel::{self, Interrupted};
+use deltective::fleet;
+use deltective::workspace::{self, WorkspaceScan};
+use anyhow::{bail, Context, Result};
+use clap::ArgMatches;
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub fn run(workspace_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    // The workspace file is the config for this run (see cli::run)
+    let config = &global.config;
+    let only = matches.get_one::<String>("scan");
+    let once = matches.get_flag("once");
+
+    let scans: Vec<(&WorkspaceScan, Option<Duration>)> = config
+        .scans
+        .iter()
+        .filter(|scan| only.is_none_or(|name| *name == scan.name))
+        .map(|scan| {
+            let every = scan
+                .every
+                .as_deref()
+                .map(parse_duration)
+                .transpose()
+                .with_context(|| format!("Invalid schedule for scan '{}'", scan.name))?;
+            Ok((scan, every))
+        })
+        .collect::<Result<_>>()?;
+    if scans.is_empty() {
+        match only {
+            Some(name) => bail!("{} has no scan named '{}'", workspace_path, name),
+            None => bail!("{} declares no scans; add a `scans:` section", workspace_path),
+        }
+    }
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let result = runtime.block_on(async {
+        // When each scan is next due; `None` once a scan has nothing left to run
+        let mut next_run: Vec<Option<Instant>> = vec![Some(Instant::now()); scans.len()];
+        loop {
+            for (i, (scan, every)) in scans.iter().enumerate() {
+                if next_run[i].is_none_or(|due| due > Instant::now()) {
+                    continue;
+                }
+                match run_scan(scan, matches, global).await {
+                    Err(e) if matches!(e.downcast_ref::<Interrupted>(), Some(Interrupted::Cancelled)) => return Err(e),
+                    // A scheduled run that fails is retried at its next slot
+                    Err(e) if !once && every.is_some() => eprintln!("[{}] Scan failed: {:#}", scan.name, e),
+                    other => other?,
+                }
+                // A period past what `Instant` can hold never comes round again
+                next_run[i] = every.filter(|_| !once).and_then(|every| Instant::now().checked_add(every));
+            }
+
+            let Some(next) = next_run.iter().flatten().min().copied() else {
+                return Ok(());
+            };
+            cancel::run(
+                async {
+                    tokio::time::sleep_until(next).await;
+                    Ok(())
+                },
+                None,
+            )
+            .await?;
+        }
+    });
+
+    match result {
+        Err(e) if matches!(e.downcast_ref::<Interrupted>(), Some(Interrupted::Cancelled)) => Ok(()),
+        other => other,
+    }
+}
+
+/// Scan the tables `scan` covers, print the report, save it and send alerts
+/// as the scan asks. The global timeout applies to each run.
+async fn run_scan(scan: &WorkspaceScan, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let config = &global.config;
+    let discovery = cancel::run(workspace::resolve_tables(config, scan, &global.storage_options), global.timeout).await?;
+    if discovery.tables.is_empty() && discovery.unreadable.is_empty() {
+        eprintln!("[{}] No matching tables", scan.name);
+        return Ok(());
+    }
+    eprintln!("[{}] Scanning {} table(s)...", scan.name, discovery.tables.len());
+
+    let report = cancel::run(
+        async { Ok(fleet::scan(&discovery.tables, &global.storage_options, config, scan.group_by.as_deref()).await) },
+        global.timeout,
+    )
+    .await?
+    .with_unreadable(discovery.unreadable);
+
+    print_output(&report, matches)?;
+    if let Some(output) = &scan.output {
+        std::fs::write(output, serde_json::to_string_pretty(&report)?)
+            .with_context(|| format!("Failed to write {}", output))?;
+    }
+    if scan.alert {
+        let outcome = alerts::send(&report, &config.alerts).await?;
+        for (destination, count) in &outcome.sent {
+            eprintln!("[{}] Sent alert for {} table(s) to {}", scan.name, count, destination);
+        }
+        for path in &outcome.unrouted {
+            eprintln!("[{}] Warning: no alert route matches {}", scan.name, path);
+        }
+    }
+    eprintln!(
+        "[{}] {} table(s) scanned, {} non-compliant, {} failed",
+        scan.name,
+        report.tables.len(),
+        report.compliance.non_compliant.len(),
+        report.failed.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::cli::{parse_key_values, GlobalOptions};
+use crate::commands::print_output;
+use deltective::alerts;
+use deltective::cancel;
+use deltective::config::matches_tags;
+use deltective::discovery::discover_tables;
+use deltective::fleet;
+use anyhow::{bail, Result};
+use clap::ArgMatches;
+
+pub fn run(root: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let group_by = matches.get_one::<String>("group_by").map(String::as_str);
+    let tag_filters = parse_key_values("tag", matches.get_many::<String>("tag").unwrap_or_default())?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let discovery = runtime.block_on(cancel::run(
+        discover_tables(root, global.config.storage_options_for(root, &global.storage_options)),
+        global.timeout,
+    ))?;
+    let table_paths: Vec<String> = discovery
+        .tables
+        .into_iter()
+        .filter(|path| matches_tags(&global.config.tags_for(path), &tag_filters))
+        .collect();
+    if table_paths.is_empty() && discovery.unreadable.is_empty() {
+        bail!("No matching Delta tables found under {}", root);
+    }
+    eprintln!("Found {} Delta table(s) under {}, inspecting...", table_paths.len(), root);
+
+    let report = runtime.block_on(cancel::run(
+        async { Ok(fleet::scan(&table_paths, &global.storage_options, &global.config, group_by).await) },
+        global.timeout,
+    ))?;
+    let report = report.with_unreadable(discovery.unreadable);
+
+    print_output(&report, matches)?;
+
+    if matches.get_flag("alert") {
+        let outcome = runtime.block_on(alerts::send(&report, &global.config.alerts))?;
+        for (destination, count) in &outcome.sent {
+            eprintln!("Sent alert for {} table(s) to {}", count, destination);
+        }
+        for path in &outcome.unrouted {
+            eprintln!("Warning: no alert route matches {}", path);
+        }
+    }
+
+    Ok(())
+}
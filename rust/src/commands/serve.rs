@@ -0,0 +1,50 @@
+use crate::cli::GlobalOptions;
+use deltective::discovery::discover_tables;
+use deltective::server::{self, ServerState};
+use anyhow::{bail, Result};
+use clap::ArgMatches;
+use std::collections::BTreeMap;
+
+pub fn run(root: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let bind = matches.get_one::<String>("bind").expect("has default");
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let tables = runtime.block_on(table_ids(root, global))?;
+    if tables.is_empty() {
+        bail!("No Delta tables found under {}", root);
+    }
+    eprintln!("Serving {} table(s) on http://{} (Ctrl-C to stop)", tables.len(), bind);
+    for (id, path) in &tables {
+        eprintln!("  {} → {}", id, path);
+    }
+
+    let state = ServerState::new(tables, global.storage_options.clone(), global.config.clone(), global.timeout);
+    runtime.block_on(server::serve(bind, state))
+}
+
+/// A single table is served under its directory name; a directory of tables
+/// under each table's path relative to it.
+async fn table_ids(root: &str, global: &GlobalOptions) -> Result<BTreeMap<String, String>> {
+    let trimmed = root.trim_end_matches('/');
+    let name = trimmed.rsplit('/').next().unwrap_or(trimmed).to_string();
+    let discovery = discover_tables(root, global.config.storage_options_for(root, &global.storage_options)).await?;
+    for dir in &discovery.unreadable {
+        eprintln!("Warning: couldn't list {}: {}", dir.path, dir.error);
+    }
+    if discovery.tables.len() == 1 && discovery.tables[0] == trimmed {
+        return Ok(BTreeMap::from([(name, root.to_string())]));
+    }
+
+    Ok(discovery
+        .tables
+        .into_iter()
+        .map(|path| {
+            let id = path
+                .strip_prefix(trimmed)
+                .unwrap_or(&path)
+                .trim_start_matches('/')
+                .to_string();
+            (id, path)
+        })
+        .collect())
+}
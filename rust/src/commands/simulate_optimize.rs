@@ -0,0 +1,16 @@
+use crate::cli::GlobalOptions;
+use crate::commands::CommandContext;
+use deltective::maintenance::OptimizeSimulation;
+use deltective::render;
+use anyhow::Result;
+
+/// `--simulate-optimize`: bin-pack the current files to the target file size.
+pub fn run(table_path: &str, global: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::open(table_path, global)?;
+    let stats = ctx.block_on(ctx.inspector.get_statistics())?;
+    let target_size_bytes = (global.config.thresholds.target_file_mb * 1024.0 * 1024.0) as u64;
+    let simulation = OptimizeSimulation::new(&stats, target_size_bytes);
+
+    print!("{}", render::render(&simulation, "text")?);
+    Ok(())
+}
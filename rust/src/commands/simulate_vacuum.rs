@@ -0,0 +1,15 @@
+use crate::cli::GlobalOptions;
+use crate::commands::CommandContext;
+use deltective::maintenance;
+use deltective::render;
+use anyhow::Result;
+
+/// `--simulate-vacuum`: compare the given retentions with the table's own.
+pub fn run(table_path: &str, retentions_hours: &[i64], global: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::open(table_path, global)?;
+    let history = ctx.block_on(ctx.inspector.get_history(false))?;
+    let simulation = ctx.block_on(maintenance::simulate_vacuum(ctx.inspector.table(), &history, retentions_hours))?;
+
+    print!("{}", render::render(&simulation, "text")?);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::streaming::{discover_checkpoints, CheckpointState};
+use anyhow::{bail, Result};
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    // Checkpoints from the command line, then the config file, then next to the table
+    let mut checkpoints: Vec<(String, String)> = matches
+        .get_many::<String>("checkpoint")
+        .unwrap_or_default()
+        .map(|path| (path.clone(), path.clone()))
+        .collect();
+    checkpoints.extend(
+        global
+            .config
+            .streaming_checkpoints_for(table_path)
+            .into_iter()
+            .map(|c| (c.name, c.path)),
+    );
+    if checkpoints.is_empty() {
+        checkpoints.extend(discover_checkpoints(table_path).into_iter().map(|path| (path.clone(), path)));
+    }
+    if checkpoints.is_empty() {
+        bail!(
+            "No streaming checkpoints found; pass --checkpoint or add `streaming_checkpoints` to the config file"
+        );
+    }
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let mut streams = Vec::new();
+    for (name, path) in checkpoints {
+        let lag = ctx.block_on(async {
+            let checkpoint = CheckpointState::read(&path, &global.storage_options).await?;
+            ctx.inspector.get_stream_lag(&name, checkpoint).await
+        });
+        match lag {
+            Ok(lag) => streams.push(lag),
+            Err(e) => eprintln!("Warning: could not read checkpoint {}: {}", path, e),
+        }
+    }
+
+    print_output(streams.as_slice(), matches)
+}
@@ -0,0 +1,15 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use deltective::upgrade;
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let feature = matches.get_one::<String>("target_feature").expect("target feature is required");
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let config = ctx.block_on(ctx.inspector.get_configuration())?;
+    let plan = upgrade::plan(table_path, &config, feature)?;
+
+    print_output(&plan, matches)
+}
@@ -0,0 +1,26 @@
+use crate::cli::GlobalOptions;
+use crate::commands::print_output;
+use deltective::cancel;
+use deltective::log_validation;
+use anyhow::Result;
+use clap::ArgMatches;
+
+/// Exit status when the log has errors, as for `check`. Errors reaching the
+/// log exit with 1.
+const INVALID_EXIT_CODE: i32 = 2;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    // Reads the log directly, so a table deltalake can't load is still checked
+    let runtime = tokio::runtime::Runtime::new()?;
+    let report = runtime.block_on(cancel::run(
+        log_validation::validate(table_path, global.config.storage_options_for(table_path, &global.storage_options)),
+        global.timeout,
+    ))?;
+
+    print_output(&report, matches)?;
+
+    if !report.is_valid() {
+        std::process::exit(INVALID_EXIT_CODE);
+    }
+    Ok(())
+}
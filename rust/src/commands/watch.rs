@@ -0,0 +1,105 @@
+use crate::cli::{parse_duration, GlobalOptions};
+use crate::commands::CommandContext;
+use crate::tui_app::format_bytes;
+use deltective::cancel::{self, Interrupted};
+use deltective::commits::CommitSummary;
+use deltective::render::{self, Document, Item, Renderable};
+use deltective::run_links::{self, RunLink, RunLinkTemplate};
+use anyhow::Result;
+use clap::ArgMatches;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{self, Write};
+
+/// One new commit: a line of `--format json` output, or a document in the
+/// other formats.
+#[derive(Serialize)]
+struct CommitEvent<'a> {
+    #[serde(flatten)]
+    commit: &'a CommitSummary,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    links: Vec<RunLink>,
+}
+
+impl Renderable for CommitEvent<'_> {
+    fn document(&self) -> Document {
+        let commit = self.commit;
+        let delta = commit.size_delta();
+        let mut doc = Document::new(format!(
+            "v{} {}",
+            commit.version,
+            commit.operation.as_deref().unwrap_or("UNKNOWN")
+        ));
+        doc.fields([
+            (
+                "Time",
+                commit
+                    .timestamp
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                    .unwrap_or_else(|| "unknown".to_string()),
+            ),
+            ("Files", format!("+{} / -{}", commit.files_added, commit.files_removed)),
+            ("Size", format!("{}{}", if delta < 0 { "-" } else { "+" }, format_bytes(delta.abs()))),
+        ]);
+        if !self.links.is_empty() {
+            doc.list(self.links.iter().map(|link| Item::new("↗", format!("{}: {}", link.name, link.url))).collect());
+        }
+        doc
+    }
+}
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let interval = parse_duration(matches.get_one::<String>("interval").expect("has default"))?;
+    let format = matches.get_one::<String>("format").expect("has default");
+
+    let ctx = CommandContext::open(table_path, global)?;
+    let inspector = &ctx.inspector;
+    let log_store = inspector.table().log_store();
+    let mut last_seen = inspector.table().version();
+    eprintln!("Watching {} from version {} (Ctrl-C to stop)...", table_path, last_seen);
+
+    // Runs until Ctrl-C; the global --timeout only applies to opening the table
+    let result = ctx.runtime.block_on(cancel::run(
+        async {
+            loop {
+                tokio::time::sleep(interval).await;
+
+                let latest = log_store.get_latest_version(last_seen).await?;
+                for version in (last_seen + 1)..=latest {
+                    match inspector.read_commit_actions(version).await {
+                        Ok(actions) => print_commit(version, &actions, &global.config.run_links, format)?,
+                        Err(e) => eprintln!("Warning: could not read commit {}: {}", version, e),
+                    }
+                }
+                last_seen = last_seen.max(latest);
+            }
+        },
+        None,
+    ));
+
+    match result {
+        Err(e) if matches!(e.downcast_ref::<Interrupted>(), Some(Interrupted::Cancelled)) => Ok(()),
+        other => other,
+    }
+}
+
+fn print_commit(version: i64, actions: &[Value], templates: &[RunLinkTemplate], format: &str) -> Result<()> {
+    let commit = CommitSummary::from_actions(version, actions);
+    let links = actions
+        .iter()
+        .find_map(|action| action.get("commitInfo"))
+        .map(|info| run_links::resolve(templates, info))
+        .unwrap_or_default();
+    let event = CommitEvent { commit: &commit, links };
+
+    let mut stdout = io::stdout().lock();
+    // JSON is one compact event per line so the stream can be piped into jq
+    if format == "json" {
+        writeln!(stdout, "{}", serde_json::to_string(&event)?)?;
+    } else {
+        writeln!(stdout, "{}", render::render(&event, format)?)?;
+    }
+    // Keep output flowing when piped
+    stdout.flush()?;
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use crate::cli::GlobalOptions;
+use crate::commands::{print_output, CommandContext};
+use anyhow::Result;
+use clap::ArgMatches;
+
+pub fn run(table_path: &str, matches: &ArgMatches, global: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::open(table_path, global)?;
+    let writers = ctx.block_on(ctx.inspector.get_streaming_writers())?;
+    print_output(writers.as_slice(), matches)
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// File-level effect of a single commit, derived from its raw log actions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitSummary {
+    pub version: i64,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub operation: Option<String>,
+    pub files_added: usize,
+    pub files_removed: usize,
+    pub bytes_added: i64,
+    pub bytes_removed: i64,
+}
+
+impl CommitSummary {
+    /// Summarize the actions returned by `DeltaTableInspector::read_commit_actions`.
+    pub fn from_actions(version: i64, actions: &[Value]) -> Self {
+        let mut summary = Self {
+            version,
+            timestamp: None,
+            operation: None,
+            files_added: 0,
+            files_removed: 0,
+            bytes_added: 0,
+            bytes_removed: 0,
+        };
+
+        for action in actions {
+            if let Some(info) = action.get("commitInfo") {
+                summary.operation = info.get("operation").and_then(Value::as_str).map(String::from);
+                summary.timestamp = info
+                    .get("timestamp")
+                    .and_then(Value::as_i64)
+                    .and_then(DateTime::from_timestamp_millis);
+            } else if let Some(add) = action.get("add") {
+                summary.files_added += 1;
+                summary.bytes_added += add.get("size").and_then(Value::as_i64).unwrap_or(0);
+            } else if let Some(remove) = action.get("remove") {
+                summary.files_removed += 1;
+                summary.bytes_removed += remove.get("size").and_then(Value::as_i64).unwrap_or(0);
+            }
+        }
+
+        summary
+    }
+
+    /// Net change in table size caused by this commit.
+    pub fn size_delta(&self) -> i64 {
+        self.bytes_added - self.bytes_removed
+    }
+}
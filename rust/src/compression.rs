@@ -0,0 +1,236 @@
+use crate::inspector::FileInfo;
+use crate::parquet_footer::read_footers;
+use anyhow::Result;
+use arrow::record_batch::RecordBatch;
+use deltalake::storage::ObjectStoreRef;
+use deltalake::DeltaTable;
+use futures::TryStreamExt;
+use object_store::path::Path as ObjectPath;
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::RangeInclusive;
+
+/// Files read and rewritten to estimate savings.
+const SAMPLE_FILES: usize = 3;
+/// Most row group bytes read from one sampled file; at least one row group
+/// is read, however large
+const SAMPLE_BYTES_PER_FILE: i64 = 64 * 1024 * 1024;
+
+/// Levels ZSTD accepts.
+pub const ZSTD_LEVELS: RangeInclusive<i32> = 1..=22;
+
+/// Projected effect of rewriting the table's files with ZSTD.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressionEstimate {
+    /// Codecs found in the sampled files, e.g. "SNAPPY"
+    pub current_codecs: Vec<String>,
+    pub zstd_level: i32,
+    pub sampled_files: usize,
+    /// Compressed size of the row groups read from the sampled files
+    pub sampled_bytes: i64,
+    /// Size of those row groups after rewriting them with ZSTD
+    pub estimated_bytes: i64,
+}
+
+impl CompressionEstimate {
+    /// Estimated size under ZSTD relative to today, e.g. 0.7.
+    pub fn ratio(&self) -> f64 {
+        if self.sampled_bytes == 0 {
+            1.0
+        } else {
+            self.estimated_bytes as f64 / self.sampled_bytes as f64
+        }
+    }
+}
+
+/// Rewrite the leading row groups of a few sampled files with ZSTD at
+/// `level` and compare sizes. Files that can't be read are skipped. Returns
+/// `None` when no sampled file is SNAPPY or uncompressed.
+pub async fn estimate_zstd_savings(table: &DeltaTable, files: &[FileInfo], level: i32) -> Option<CompressionEstimate> {
+    // Sample evenly across the size distribution
+    let mut by_size: Vec<&FileInfo> = files.iter().collect();
+    by_size.sort_by_key(|f| f.size_bytes);
+    let step = (by_size.len() / SAMPLE_FILES).max(1);
+    let sample: Vec<&FileInfo> = by_size.into_iter().step_by(step).take(SAMPLE_FILES).collect();
+
+    let store = table.object_store();
+    let mut estimate = CompressionEstimate {
+        current_codecs: Vec::new(),
+        zstd_level: level,
+        sampled_files: 0,
+        sampled_bytes: 0,
+        estimated_bytes: 0,
+    };
+    let mut codecs = BTreeSet::new();
+
+    for file in sample {
+        let Ok(Some((file_codecs, original, rewritten))) = recompress(store.clone(), &file.path, level).await else {
+            continue;
+        };
+        codecs.extend(file_codecs);
+        estimate.sampled_files += 1;
+        estimate.sampled_bytes += original;
+        estimate.estimated_bytes += rewritten;
+    }
+
+    if estimate.sampled_files == 0 {
+        return None;
+    }
+    estimate.current_codecs = codecs.into_iter().collect();
+    Some(estimate)
+}
+
+/// Codecs, compressed size and ZSTD size of the leading row groups of one
+/// file, up to [`SAMPLE_BYTES_PER_FILE`], or `None` if the file already uses
+/// a codec other than SNAPPY/UNCOMPRESSED. Only those row groups are fetched.
+async fn recompress(store: ObjectStoreRef, path: &str, level: i32) -> Result<Option<(BTreeSet<String>, i64, i64)>> {
+    let meta = store.head(&ObjectPath::from(path)).await?;
+    let builder = ParquetRecordBatchStreamBuilder::new(ParquetObjectReader::new(store, meta)).await?;
+
+    let codecs: BTreeSet<String> = builder
+        .metadata()
+        .row_groups()
+        .iter()
+        .flat_map(|rg| rg.columns().iter().map(|col| col.compression()))
+        .map(|codec| codec.to_string())
+        .collect();
+    if !codecs.iter().all(|c| c == "SNAPPY" || c == "UNCOMPRESSED") {
+        return Ok(None);
+    }
+
+    let mut row_groups = Vec::new();
+    let mut original = 0;
+    for (idx, row_group) in builder.metadata().row_groups().iter().enumerate() {
+        if !row_groups.is_empty() && original + row_group.compressed_size() > SAMPLE_BYTES_PER_FILE {
+            break;
+        }
+        row_groups.push(idx);
+        original += row_group.compressed_size();
+    }
+    let batches: Vec<RecordBatch> = builder.with_row_groups(row_groups).build()?.try_collect().await?;
+    let schema = batches.first().map(|batch| batch.schema());
+    let Some(schema) = schema else {
+        return Ok(None);
+    };
+
+    // Encoding is CPU bound; keep it off the async workers
+    let rewritten = tokio::task::spawn_blocking(move || -> Result<i64> {
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::try_new(level)?))
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(props))?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        // Column chunk sizes, like the original's, leaving out the footer
+        let metadata = writer.close()?;
+        Ok(metadata
+            .row_groups
+            .iter()
+            .flat_map(|rg| &rg.columns)
+            .filter_map(|chunk| chunk.meta_data.as_ref())
+            .map(|meta| meta.total_compressed_size)
+            .sum())
+    })
+    .await??;
+
+    Ok(Some((codecs, original, rewritten)))
+}
+
+/// Compression codecs of the table's files according to their Parquet
+/// footers, read for every file or a sample spread over the size range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodecAudit {
+    pub audited_files: usize,
+    pub audited_bytes: i64,
+    /// Most bytes first. Files mixing codecs across columns are listed under
+    /// each codec joined with `+`, e.g. `SNAPPY+UNCOMPRESSED`
+    pub codecs: Vec<CodecUsage>,
+    /// Codec the table is meant to be written with, from the config file
+    pub expected: Option<String>,
+    /// Footers that couldn't be read
+    pub unreadable_files: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodecUsage {
+    pub codec: String,
+    pub files: usize,
+    pub bytes: i64,
+}
+
+impl CodecAudit {
+    /// Files with at least one uncompressed column chunk.
+    pub fn uncompressed(&self) -> impl Iterator<Item = &CodecUsage> {
+        self.codecs.iter().filter(|usage| usage.codec.split('+').any(|codec| codec == "UNCOMPRESSED"))
+    }
+
+    /// Codecs other than the expected one, or than the one holding most of
+    /// the data when nothing is expected. Uncompressed files are left to
+    /// [`Self::uncompressed`].
+    pub fn off_target(&self) -> Vec<&CodecUsage> {
+        let target = match &self.expected {
+            Some(expected) => expected.to_uppercase(),
+            None => match self.codecs.first() {
+                Some(dominant) => dominant.codec.clone(),
+                None => return Vec::new(),
+            },
+        };
+        self.codecs
+            .iter()
+            .filter(|usage| !usage.codec.split('+').any(|codec| codec == "UNCOMPRESSED") && usage.codec != target)
+            .collect()
+    }
+}
+
+/// Read the footers of `sample_files` files spread over the size range, or of
+/// every file when `None`, and tally their codecs.
+pub async fn audit_codecs(
+    table: &DeltaTable,
+    files: &[FileInfo],
+    sample_files: Option<usize>,
+    expected: Option<String>,
+) -> CodecAudit {
+    let footers: Vec<(i64, Option<String>)> = read_footers(table.object_store(), files, sample_files)
+        .await
+        .into_iter()
+        .map(|(file, footer)| {
+            let codec = footer.map(|footer| {
+                let codecs: BTreeSet<&str> =
+                    footer.columns.iter().flat_map(|c| c.codecs.iter().map(String::as_str)).collect();
+                codecs.into_iter().collect::<Vec<_>>().join("+")
+            });
+            (file.size_bytes, codec)
+        })
+        .collect();
+
+    let mut audit = CodecAudit {
+        audited_files: 0,
+        audited_bytes: 0,
+        codecs: Vec::new(),
+        expected,
+        unreadable_files: 0,
+    };
+    let mut usage: BTreeMap<String, (usize, i64)> = BTreeMap::new();
+    for (size, codec) in footers {
+        let Some(codec) = codec else {
+            audit.unreadable_files += 1;
+            continue;
+        };
+        audit.audited_files += 1;
+        audit.audited_bytes += size;
+        let entry = usage.entry(codec).or_default();
+        entry.0 += 1;
+        entry.1 += size;
+    }
+    audit.codecs = usage
+        .into_iter()
+        .map(|(codec, (files, bytes))| CodecUsage { codec, files, bytes })
+        .collect();
+    audit.codecs.sort_by_key(|usage| std::cmp::Reverse(usage.bytes));
+    audit
+}
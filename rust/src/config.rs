@@ -0,0 +1,333 @@
+use crate::cdf::CdfConsumer;
+use crate::compression::ZSTD_LEVELS;
+use crate::cost::CostModel;
+use crate::insights::{AnalyzerThresholds, DeltaTableAnalyzer};
+use crate::inspector::TableStatistics;
+use crate::requests::RequestLimits;
+use crate::rules::Rule;
+use crate::run_links::RunLinkTemplate;
+use crate::streaming::StreamingCheckpointConfig;
+use crate::workspace::WorkspaceScan;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Config file picked up from the working directory when `--config` isn't given.
+pub const DEFAULT_CONFIG_FILE: &str = "deltective.yaml";
+
+/// Contents of the `deltective.yaml` workspace/config file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub tables: Vec<TableEntry>,
+    #[serde(default)]
+    pub alerts: AlertsConfig,
+    /// URL templates linking commits to the orchestrator runs that wrote them
+    #[serde(default)]
+    pub run_links: Vec<RunLinkTemplate>,
+    /// Analyzer limits; CLI flags override individual values
+    #[serde(default)]
+    pub thresholds: AnalyzerThresholds,
+    /// Estimate ZSTD recompression savings at this level (samples and rewrites files)
+    pub zstd_estimate_level: Option<i32>,
+    /// Audit compression codecs from the footers of this many files; 0 reads all of them
+    pub codec_audit_files: Option<usize>,
+    /// Codec tables are meant to be written with, e.g. "zstd"; the codec audit
+    /// flags files using anything else
+    pub expected_codec: Option<String>,
+    /// Team-defined checks evaluated alongside the built-in analyzers
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+    /// Change data feed readers and the last version each has processed
+    #[serde(default)]
+    pub cdf_consumers: Vec<CdfConsumer>,
+    /// Spark structured streaming checkpoints of queries reading or writing tables
+    #[serde(default)]
+    pub streaming_checkpoints: Vec<StreamingCheckpointConfig>,
+    /// Language for insights and TUI labels, e.g. "en"; defaults to $LANG
+    pub locale: Option<String>,
+    /// TUI color palette: default, high-contrast, deuteranopia or protanopia
+    pub palette: Option<String>,
+    /// Data catalog that `publish` pushes summaries to
+    pub catalog: Option<CatalogConfig>,
+    /// Object storage request budget and concurrency cap
+    #[serde(default)]
+    pub requests: RequestLimits,
+    /// Named sets of storage options (credentials, endpoints) that `tables`
+    /// entries refer to with `profile`
+    #[serde(default)]
+    pub profiles: BTreeMap<String, BTreeMap<String, String>>,
+    /// Scans run by `run-workspace`, each on its own schedule
+    #[serde(default)]
+    pub scans: Vec<WorkspaceScan>,
+    /// Storage prices the cost estimates are based on
+    #[serde(default)]
+    pub cost: CostModel,
+}
+
+/// Tags and credentials for one table (`path`) or for every table under a
+/// `prefix`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TableEntry {
+    pub path: Option<String>,
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+    /// Entry of `profiles` to open the tables with
+    pub profile: Option<String>,
+    /// Storage class of `cost.prices` the tables are billed at
+    pub storage_class: Option<String>,
+}
+
+/// Where `scan --alert` sends notifications. Tables are routed to the first
+/// route whose tags they all carry, falling back to the default `webhook`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlertsConfig {
+    pub webhook: Option<String>,
+    /// Lowest insight severity that triggers an alert: critical, warning,
+    /// info or good (everything)
+    pub min_severity: Option<String>,
+    #[serde(default)]
+    pub routes: Vec<AlertRoute>,
+}
+
+/// Sends alerts for tables carrying all of `tags` to `webhook`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlertRoute {
+    /// Shown in logs in place of the webhook, whose URL is a secret
+    pub name: Option<String>,
+    pub tags: BTreeMap<String, String>,
+    pub webhook: String,
+}
+
+/// Data catalog that `publish` pushes table summaries to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogConfig {
+    pub kind: CatalogKind,
+    /// DataHub GMS or OpenMetadata server URL
+    pub url: String,
+    /// Bearer token; `$DELTECTIVE_CATALOG_TOKEN` is used when unset. There is
+    /// no CLI flag for it, since arguments show up in `ps` and shell history
+    pub token: Option<String>,
+    /// DataHub platform of the dataset URNs (default "delta-lake")
+    pub platform: Option<String>,
+    /// DataHub environment/fabric of the dataset URNs (default "PROD")
+    pub env: Option<String>,
+    /// OpenMetadata schema the tables belong to, as "service.database.schema"
+    pub database_schema: Option<String>,
+    /// OpenMetadata only: also set the `deltectiveHealthScore` and
+    /// `deltectiveFindings` custom properties, which must be defined on the
+    /// Table type first
+    #[serde(default)]
+    pub custom_properties: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CatalogKind {
+    Datahub,
+    Openmetadata,
+}
+
+impl CatalogKind {
+    pub const NAMES: [&'static str; 2] = ["datahub", "openmetadata"];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "datahub" => Some(Self::Datahub),
+            "openmetadata" => Some(Self::Openmetadata),
+            _ => None,
+        }
+    }
+}
+
+impl AlertsConfig {
+    const SEVERITIES: [&'static str; 4] = ["critical", "warning", "info", "good"];
+
+    /// Webhook that alerts for a table with these tags should go to.
+    pub fn webhook_for(&self, tags: &BTreeMap<String, String>) -> Option<&str> {
+        self.routes
+            .iter()
+            .find(|route| route.tags.iter().all(|(key, value)| tags.get(key) == Some(value)))
+            .map(|route| route.webhook.as_str())
+            .or(self.webhook.as_deref())
+    }
+
+    pub fn is_configured(&self) -> bool {
+        self.webhook.is_some() || !self.routes.is_empty()
+    }
+
+    /// Check `min_severity`: an unknown value would rank below every
+    /// severity and alert on everything.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(severity) = &self.min_severity {
+            if !Self::SEVERITIES.contains(&severity.as_str()) {
+                bail!(
+                    "Unknown alerts.min_severity '{}'; expected one of {}",
+                    severity,
+                    Self::SEVERITIES.join(", ")
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Config {
+    /// Load an explicit config file, or the default one if it exists.
+    pub fn load(path: Option<&str>) -> Result<Self> {
+        let path = match path {
+            Some(path) => Path::new(path),
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => Path::new(DEFAULT_CONFIG_FILE),
+            None => return Ok(Self::default()),
+        };
+
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        let config: Self = serde_yaml::from_str(&text)
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+        for rule in &config.rules {
+            rule.validate()
+                .with_context(|| format!("Invalid config file {}", path.display()))?;
+        }
+        config.alerts.validate()
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+        config.validate_workspace()
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+        Ok(config)
+    }
+
+    /// Check that profiles and storage classes referenced by tables exist,
+    /// scans are named uniquely and the ZSTD level is one ZSTD has.
+    fn validate_workspace(&self) -> Result<()> {
+        for profile in self.tables.iter().filter_map(|e| e.profile.as_deref()) {
+            if !self.profiles.contains_key(profile) {
+                bail!("Table entry refers to unknown profile '{}'", profile);
+            }
+        }
+        let classes = self.tables.iter().filter_map(|e| e.storage_class.as_deref());
+        for class in std::iter::once(self.cost.storage_class.as_str()).chain(classes) {
+            if self.cost.price(class).is_none() {
+                bail!("Storage class '{}' has no price in cost.prices", class);
+            }
+        }
+        for (i, scan) in self.scans.iter().enumerate() {
+            if self.scans[..i].iter().any(|other| other.name == scan.name) {
+                bail!("Scan '{}' is defined more than once", scan.name);
+            }
+        }
+        if let Some(level) = self.zstd_estimate_level.filter(|level| !ZSTD_LEVELS.contains(level)) {
+            bail!(
+                "zstd_estimate_level must be between {} and {}, not {}",
+                ZSTD_LEVELS.start(),
+                ZSTD_LEVELS.end(),
+                level
+            );
+        }
+        Ok(())
+    }
+
+    /// Storage options to open a table with: its profile's options, then
+    /// `overrides` (from `--storage-option`) on top.
+    pub fn storage_options_for(&self, table_path: &str, overrides: &HashMap<String, String>) -> HashMap<String, String> {
+        let mut options: HashMap<String, String> = self
+            .entries_for(table_path)
+            .into_iter()
+            .filter_map(|e| e.profile.as_deref())
+            .next_back()
+            .and_then(|profile| self.profiles.get(profile))
+            .map(|profile| profile.clone().into_iter().collect())
+            .unwrap_or_default();
+        options.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+        options
+    }
+
+    /// Storage class a table is billed at: its most specific entry's, else
+    /// the `cost` default.
+    pub fn storage_class_for(&self, table_path: &str) -> String {
+        self.entries_for(table_path)
+            .into_iter()
+            .filter_map(|e| e.storage_class.clone())
+            .next_back()
+            .unwrap_or_else(|| self.cost.storage_class.clone())
+    }
+
+    /// CDF consumers declared for a table.
+    pub fn cdf_consumers_for(&self, table_path: &str) -> Vec<CdfConsumer> {
+        let table_path = table_path.trim_end_matches('/');
+        self.cdf_consumers
+            .iter()
+            .filter(|c| c.table.trim_end_matches('/') == table_path)
+            .cloned()
+            .collect()
+    }
+
+    /// Streaming checkpoints declared for a table.
+    pub fn streaming_checkpoints_for(&self, table_path: &str) -> Vec<StreamingCheckpointConfig> {
+        let table_path = table_path.trim_end_matches('/');
+        self.streaming_checkpoints
+            .iter()
+            .filter(|c| c.table.trim_end_matches('/') == table_path)
+            .cloned()
+            .collect()
+    }
+
+    /// Analyzer for `stats` using this config's thresholds and the rules that
+    /// apply to the table's tags.
+    pub fn analyzer(&self, stats: TableStatistics) -> DeltaTableAnalyzer {
+        let tags = self.tags_for(&stats.table_path);
+        let rules = self.rules.iter().filter(|r| r.applies_to(&tags)).cloned().collect();
+        DeltaTableAnalyzer::with_thresholds(stats, self.thresholds.clone()).with_rules(rules)
+    }
+
+    /// Tags that apply to a table. Prefix entries apply first (shortest
+    /// prefix first) and exact `path` entries override them.
+    pub fn tags_for(&self, table_path: &str) -> BTreeMap<String, String> {
+        let mut tags = BTreeMap::new();
+        for entry in self.entries_for(table_path) {
+            tags.extend(entry.tags.clone());
+        }
+        tags
+    }
+
+    /// Table entries covering a table, least specific first: prefixes by
+    /// length, then exact `path` entries.
+    fn entries_for(&self, table_path: &str) -> Vec<&TableEntry> {
+        let table_path = table_path.trim_end_matches('/');
+        let mut prefixes: Vec<&TableEntry> = self
+            .tables
+            .iter()
+            .filter(|e| e.prefix.as_deref().is_some_and(|p| covers(p, table_path)))
+            .collect();
+        prefixes.sort_by_key(|e| e.prefix.as_deref().map_or(0, str::len));
+
+        let exact = self
+            .tables
+            .iter()
+            .filter(|e| e.path.as_deref().map(|p| p.trim_end_matches('/')) == Some(table_path));
+
+        prefixes.into_iter().chain(exact).collect()
+    }
+}
+
+/// Whether `table_path` is `prefix` or lies below it. Whole path components
+/// are compared, so `.../sales` doesn't cover `.../salesforce`.
+fn covers(prefix: &str, table_path: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    table_path
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Whether `tags` contains every `key=value` pair in `filters`.
+pub fn matches_tags(tags: &BTreeMap<String, String>, filters: &[(String, String)]) -> bool {
+    filters
+        .iter()
+        .all(|(key, value)| tags.get(key) == Some(value))
+}
@@ -0,0 +1,162 @@
+use crate::inspector::table_properties;
+use crate::render::{Document, Item, Renderable, Tone};
+use anyhow::{Context, Result};
+use arrow::array::{Array, AsArray};
+use arrow::datatypes::Int64Type;
+use datafusion::prelude::SessionContext;
+use deltalake::DeltaTable;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const CONSTRAINT_PREFIX: &str = "delta.constraints.";
+
+/// A CHECK constraint from a `delta.constraints.<name>` table property.
+/// Writers reject rows for which the expression is false; NULL passes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckConstraint {
+    pub name: String,
+    pub expression: String,
+}
+
+impl CheckConstraint {
+    /// The constraints in `config`, by name.
+    pub fn from_config(config: &HashMap<String, String>) -> Vec<Self> {
+        let mut constraints: Vec<Self> = config
+            .iter()
+            .filter_map(|(key, expression)| {
+                Some(Self {
+                    name: key.strip_prefix(CONSTRAINT_PREFIX)?.to_string(),
+                    expression: expression.clone(),
+                })
+            })
+            .collect();
+        constraints.sort_by(|a, b| a.name.cmp(&b.name));
+        constraints
+    }
+}
+
+/// Constraints evaluated against the first rows of the table. Writers enforce
+/// them, so violations point at rows written before the constraint was added
+/// with a check that was skipped, or at an engine that ignores constraints.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConstraintReport {
+    pub table_path: String,
+    pub version: i64,
+    /// Rows the constraints were checked against; `None` when they were only listed
+    pub sample_rows: Option<usize>,
+    pub constraints: Vec<ConstraintResult>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConstraintResult {
+    #[serde(flatten)]
+    pub constraint: CheckConstraint,
+    pub violations: Option<i64>,
+    /// Why DataFusion couldn't evaluate the expression, e.g. a Spark-only function
+    pub error: Option<String>,
+}
+
+impl ConstraintReport {
+    /// List the constraints of `table` without evaluating them.
+    pub fn list(table: &DeltaTable) -> Result<Self> {
+        let config = table_properties(table.metadata()?);
+        Ok(Self {
+            table_path: table.table_uri(),
+            version: table.version(),
+            sample_rows: None,
+            constraints: CheckConstraint::from_config(&config)
+                .into_iter()
+                .map(|constraint| ConstraintResult {
+                    constraint,
+                    violations: None,
+                    error: None,
+                })
+                .collect(),
+        })
+    }
+
+    /// Count, per constraint, the rows among the first `sample_rows` that
+    /// violate it.
+    pub async fn evaluate(table: &DeltaTable, sample_rows: usize) -> Result<Self> {
+        let mut report = Self::list(table)?;
+        report.sample_rows = Some(sample_rows);
+        if report.constraints.is_empty() {
+            return Ok(report);
+        }
+
+        let ctx = SessionContext::new();
+        ctx.register_table("delta_table", Arc::new(table.clone()))?;
+        let sample = ctx.sql(&format!("SELECT * FROM delta_table LIMIT {}", sample_rows)).await?.cache().await?;
+        ctx.register_table("sample", sample.into_view())?;
+
+        for result in &mut report.constraints {
+            match count_violations(&ctx, &result.constraint.expression).await {
+                Ok(violations) => result.violations = Some(violations),
+                Err(e) => result.error = Some(format!("{:#}", e)),
+            }
+        }
+        Ok(report)
+    }
+
+    pub fn violated(&self) -> usize {
+        self.constraints.iter().filter(|c| c.violations.is_some_and(|v| v > 0)).count()
+    }
+}
+
+async fn count_violations(ctx: &SessionContext, expression: &str) -> Result<i64> {
+    let sql = format!("SELECT COUNT(*) FROM sample WHERE NOT ({})", expression);
+    let batches = ctx.sql(&sql).await?.collect().await?;
+    let counts = batches
+        .first()
+        .and_then(|batch| batch.column(0).as_primitive_opt::<Int64Type>().cloned())
+        .context("COUNT(*) returned no rows")?;
+    Ok(if counts.is_empty() || counts.is_null(0) { 0 } else { counts.value(0) })
+}
+
+impl Renderable for ConstraintReport {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("Check constraints of {}", self.table_path));
+        if self.constraints.is_empty() {
+            doc.text("No delta.constraints.* properties; the table has no CHECK constraints");
+            return doc;
+        }
+
+        let Some(sample_rows) = self.sample_rows else {
+            let rows = self
+                .constraints
+                .iter()
+                .map(|c| vec![c.constraint.name.clone(), c.constraint.expression.clone()])
+                .collect();
+            doc.table(&["Name", "Expression"], rows);
+            return doc;
+        };
+
+        let rows = self
+            .constraints
+            .iter()
+            .map(|c| {
+                let outcome = match (&c.violations, &c.error) {
+                    (Some(0), _) => "ok".to_string(),
+                    (Some(violations), _) => format!("{} violating rows", violations),
+                    (None, Some(e)) => format!("not evaluated: {}", e),
+                    (None, None) => "not evaluated".to_string(),
+                };
+                vec![c.constraint.name.clone(), c.constraint.expression.clone(), outcome]
+            })
+            .collect();
+        doc.table(&["Name", "Expression", "Result"], rows);
+        let violated = self.violated();
+        let item = if violated > 0 {
+            Item::new(
+                "[VIOLATED]",
+                format!("{} constraint(s) violated within the first {} rows of version {}", violated, sample_rows, self.version),
+            )
+            .tone(Tone::Critical)
+        } else {
+            Item::new("[OK]", format!("No violations within the first {} rows of version {}", sample_rows, self.version)).tone(Tone::Good)
+        };
+        doc.list(vec![item]);
+        doc
+    }
+}
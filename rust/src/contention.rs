@@ -0,0 +1,76 @@
+use crate::inspector::commit_version;
+use deltalake::kernel::CommitInfo;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Commits this close to the previous one count as a burst.
+const BURST_WINDOW_MILLIS: i64 = 1000;
+
+/// Signs of writers racing each other, from commitInfo alone. A commit whose
+/// `readVersion` is older than the version before it was planned against a
+/// stale snapshot and only landed after conflict checks against the commits in
+/// between; writers that lost those checks retried or failed without leaving
+/// a trace, so this is a lower bound.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WriteContention {
+    pub commits_examined: usize,
+    /// Commits that aren't blind appends, i.e. can conflict
+    pub conflicting_commits: usize,
+    /// Conflict-prone commits that read an older version than the one before them
+    pub overlapping_commits: usize,
+    /// Most commits landing between a commit's read and its own version
+    pub max_overlap: i64,
+    /// Conflict-prone commits within a second of the previous commit
+    pub burst_commits: usize,
+    /// Overlapping commits per operation
+    pub by_operation: BTreeMap<String, usize>,
+}
+
+impl WriteContention {
+    /// Examine `history`, newest first as from
+    /// [`crate::DeltaTableInspector::get_history`]. `None` when nothing in it
+    /// can conflict.
+    pub fn from_history(history: &[CommitInfo]) -> Option<Self> {
+        let mut contention = Self {
+            commits_examined: history.len(),
+            ..Self::default()
+        };
+        for (idx, entry) in history.iter().enumerate() {
+            if entry.is_blind_append == Some(true) {
+                continue;
+            }
+            contention.conflicting_commits += 1;
+
+            if let (Some(version), Some(read_version)) = (commit_version(entry), entry.read_version) {
+                let overlap = version - 1 - read_version;
+                if overlap > 0 {
+                    contention.overlapping_commits += 1;
+                    contention.max_overlap = contention.max_overlap.max(overlap);
+                    let operation = entry.operation.clone().unwrap_or_else(|| "UNKNOWN".to_string());
+                    *contention.by_operation.entry(operation).or_default() += 1;
+                }
+            }
+
+            let previous = history.get(idx + 1).and_then(|previous| previous.timestamp);
+            if let (Some(time), Some(previous)) = (entry.timestamp, previous) {
+                if (time - previous).abs() < BURST_WINDOW_MILLIS {
+                    contention.burst_commits += 1;
+                }
+            }
+        }
+        (contention.conflicting_commits > 0).then_some(contention)
+    }
+
+    /// Share of the conflict-prone commits that overlapped another writer, in percent.
+    pub fn overlap_pct(&self) -> f64 {
+        if self.conflicting_commits == 0 {
+            return 0.0;
+        }
+        self.overlapping_commits as f64 / self.conflicting_commits as f64 * 100.0
+    }
+
+    /// Operation with the most overlapping commits.
+    pub fn busiest_operation(&self) -> Option<&str> {
+        self.by_operation.iter().max_by_key(|(_, count)| **count).map(|(op, _)| op.as_str())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::inspector::TableStatistics;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Days ahead the monthly cost is projected at the current growth rate.
+pub const PROJECTION_DAYS: [i64; 3] = [30, 90, 365];
+
+/// What storage costs, set from the `cost` section of the config file.
+/// Defaults are S3 list prices in us-east-1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CostModel {
+    /// Class tables are billed at unless their `tables` entry says otherwise
+    pub storage_class: String,
+    /// Price per GB-month of each storage class
+    pub prices: BTreeMap<String, f64>,
+    /// Put in front of amounts, e.g. "$" or "€"
+    pub currency: String,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            storage_class: "standard".to_string(),
+            prices: BTreeMap::from([
+                ("standard".to_string(), 0.023),
+                ("infrequent".to_string(), 0.0125),
+                ("archive".to_string(), 0.004),
+            ]),
+            currency: "$".to_string(),
+        }
+    }
+}
+
+impl CostModel {
+    /// Price per GB-month of `storage_class`, if it has one.
+    pub fn price(&self, storage_class: &str) -> Option<f64> {
+        self.prices.get(storage_class).copied()
+    }
+}
+
+/// Monthly cost of the bytes a table keeps in storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostEstimate {
+    pub storage_class: String,
+    pub price_per_gb_month: f64,
+    pub currency: String,
+    /// Live data files
+    pub data_bytes: i64,
+    pub data_monthly: f64,
+    /// Removed files kept until VACUUM deletes them; unknown when the
+    /// tombstones couldn't be read
+    pub tombstoned_bytes: Option<i64>,
+    pub tombstoned_monthly: Option<f64>,
+    /// The tombstoned files past the retention, which VACUUM would delete
+    /// today; a lower bound, see [`crate::inspector::ReclaimableStorage`]
+    pub reclaimable_bytes: Option<i64>,
+    pub reclaimable_monthly: Option<f64>,
+    /// Live data at the current growth rate; empty when growth is unknown
+    pub projections: Vec<CostProjection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostProjection {
+    pub days: i64,
+    pub bytes: i64,
+    pub monthly: f64,
+}
+
+impl CostEstimate {
+    /// Price what `stats` stores at `storage_class`, which `model` must have a
+    /// price for (checked when the config is loaded; unknown classes cost 0).
+    pub fn new(stats: &TableStatistics, model: &CostModel, storage_class: &str) -> Self {
+        let price_per_gb_month = model.price(storage_class).unwrap_or(0.0);
+        let monthly = |bytes: i64| bytes as f64 / GB * price_per_gb_month;
+        let tombstoned_bytes = stats.reclaimable.as_ref().map(|r| r.tombstoned_bytes);
+        let reclaimable_bytes = stats.reclaimable.as_ref().map(|r| r.bytes);
+
+        let projections = match &stats.growth {
+            Some(growth) => PROJECTION_DAYS
+                .iter()
+                .map(|&days| {
+                    let bytes = (stats.total_size_bytes as f64 + growth.bytes_per_day * days as f64).max(0.0) as i64;
+                    CostProjection { days, bytes, monthly: monthly(bytes) }
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Self {
+            storage_class: storage_class.to_string(),
+            price_per_gb_month,
+            currency: model.currency.clone(),
+            data_bytes: stats.total_size_bytes,
+            data_monthly: monthly(stats.total_size_bytes),
+            tombstoned_bytes,
+            tombstoned_monthly: tombstoned_bytes.map(monthly),
+            reclaimable_bytes,
+            reclaimable_monthly: reclaimable_bytes.map(monthly),
+            projections,
+        }
+    }
+
+    /// Monthly cost of `bytes` at this estimate's price.
+    pub fn monthly(&self, bytes: i64) -> f64 {
+        bytes as f64 / GB * self.price_per_gb_month
+    }
+
+    /// Live data and tombstoned files together: what the bill is for today.
+    pub fn stored_bytes(&self) -> i64 {
+        self.data_bytes + self.tombstoned_bytes.unwrap_or(0)
+    }
+
+    /// Monthly cost of [`Self::stored_bytes`].
+    pub fn total_monthly(&self) -> f64 {
+        self.data_monthly + self.tombstoned_monthly.unwrap_or(0.0)
+    }
+
+    /// Priced lines for reports: live data, removed files, the part of them
+    /// VACUUM reclaims, the total and the projections.
+    pub fn breakdown(&self) -> Vec<(String, i64, f64)> {
+        let mut rows = vec![("Live data".to_string(), self.data_bytes, self.data_monthly)];
+        if let (Some(bytes), Some(monthly)) = (self.tombstoned_bytes, self.tombstoned_monthly) {
+            rows.push(("Removed files".to_string(), bytes, monthly));
+        }
+        if let (Some(bytes), Some(monthly)) = (self.reclaimable_bytes, self.reclaimable_monthly) {
+            rows.push(("Reclaimable by VACUUM (at least)".to_string(), bytes, monthly));
+        }
+        rows.push(("Total today".to_string(), self.stored_bytes(), self.total_monthly()));
+        rows.extend(self.projections.iter().map(|p| (format!("Projected in {} days", p.days), p.bytes, p.monthly)));
+        rows
+    }
+
+    /// `amount` with the currency, in cents below 100 and whole units above.
+    pub fn format(&self, amount: f64) -> String {
+        if amount >= 100.0 {
+            format!("{}{:.0}", self.currency, amount)
+        } else {
+            format!("{}{:.2}", self.currency, amount)
+        }
+    }
+}
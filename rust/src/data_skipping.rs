@@ -0,0 +1,127 @@
+use crate::column_stats::compare_values;
+use crate::inspector::FileInfo;
+use crate::schema::TableSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// Values probed per column: the minima of files spread over the range.
+const PROBES: usize = 1000;
+
+/// Z-ORDER loses locality on each column beyond a handful
+pub const MAX_ZORDER_COLUMNS: usize = 4;
+
+/// How well file min/max statistics on one column let a reader skip files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnSkipping {
+    pub column: String,
+    pub files_with_stats: usize,
+    /// Files whose min/max range contains a typical value, on average. Files
+    /// without statistics for the column always count.
+    pub avg_files_matched: f64,
+    /// Share of files an equality filter on a typical value skips, in percent
+    pub skip_pct: f64,
+}
+
+impl ColumnSkipping {
+    pub fn rating(&self) -> SkippingRating {
+        if self.skip_pct >= 80.0 {
+            SkippingRating::Effective
+        } else if self.skip_pct >= 30.0 {
+            SkippingRating::Partial
+        } else {
+            SkippingRating::Poor
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkippingRating {
+    Effective,
+    Partial,
+    Poor,
+}
+
+impl SkippingRating {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SkippingRating::Effective => "effective",
+            SkippingRating::Partial => "partial",
+            SkippingRating::Poor => "poor",
+        }
+    }
+}
+
+/// Estimate, for each of `columns`, how many files an equality filter has to
+/// read: probe values are taken from the files' own minima, and a file is
+/// read when its [min, max] range contains the value. Sorted or clustered
+/// columns give narrow, disjoint ranges and high skip rates; randomly
+/// distributed ones give every file nearly the full range.
+pub fn analyze(files: &[FileInfo], columns: &[String]) -> Vec<ColumnSkipping> {
+    columns.iter().filter_map(|column| analyze_column(files, column)).collect()
+}
+
+fn analyze_column(files: &[FileInfo], column: &str) -> Option<ColumnSkipping> {
+    let mut mins: Vec<&Value> = Vec::new();
+    let mut maxes: Vec<&Value> = Vec::new();
+    for file in files {
+        if let (Some(min), Some(max)) = (file.min_values.get(column), file.max_values.get(column)) {
+            mins.push(min);
+            maxes.push(max);
+        }
+    }
+    if mins.is_empty() {
+        return None;
+    }
+    let without_stats = files.len() - mins.len();
+    let order = |a: &&Value, b: &&Value| compare_values(a, b).unwrap_or(Ordering::Equal);
+
+    let step = (mins.len() / PROBES).max(1);
+    let probes: Vec<&Value> = mins.iter().step_by(step).copied().collect();
+    mins.sort_by(order);
+    maxes.sort_by(order);
+
+    // Files containing v: those starting at or before v, minus those ending before it
+    let matched: usize = probes
+        .iter()
+        .map(|probe| {
+            let started = mins.partition_point(|min| order(min, probe) != Ordering::Greater);
+            let ended = maxes.partition_point(|max| order(max, probe) == Ordering::Less);
+            started - ended.min(started) + without_stats
+        })
+        .sum();
+    let avg_files_matched = matched as f64 / probes.len() as f64;
+
+    Some(ColumnSkipping {
+        column: column.to_string(),
+        files_with_stats: mins.len(),
+        avg_files_matched,
+        skip_pct: (1.0 - avg_files_matched / files.len() as f64) * 100.0,
+    })
+}
+
+/// The ratings of columns whose min/max can narrow a filter down at all,
+/// which rules out booleans and binaries.
+pub fn rated<'a>(skipping: &'a [ColumnSkipping], schema: &TableSchema) -> Vec<&'a ColumnSkipping> {
+    let columns = schema.columns();
+    skipping
+        .iter()
+        .filter(|skipping| {
+            columns
+                .iter()
+                .find(|(path, _, _)| *path == skipping.column)
+                .is_some_and(|(_, _, field)| !matches!(field.data_type.kind(), "boolean" | "binary"))
+        })
+        .collect()
+}
+
+/// Poorly skipping columns to Z-ORDER or cluster by, in schema order.
+pub fn zorder_candidates(skipping: &[ColumnSkipping], schema: &TableSchema) -> Vec<String> {
+    rated(skipping, schema)
+        .into_iter()
+        .filter(|s| s.rating() == SkippingRating::Poor)
+        .take(MAX_ZORDER_COLUMNS)
+        .map(|s| s.column.clone())
+        .collect()
+}
@@ -0,0 +1,60 @@
+use crate::inspector::FileInfo;
+use serde::{Deserialize, Serialize};
+
+/// Deletion vector descriptor of one add action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileDeletionVector {
+    /// Rows the vector marks as deleted
+    pub cardinality: i64,
+    pub size_bytes: i64,
+    /// Stored in the log itself rather than in a separate file
+    pub inline: bool,
+}
+
+/// What deletion vectors cost the table: rows still stored but deleted, and
+/// the vector files readers fetch alongside the data files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeletionVectorStats {
+    pub files_with_dvs: usize,
+    pub deleted_rows: i64,
+    /// Rows in the files carrying vectors, deleted ones included; files
+    /// without a row count are left out
+    pub rows_in_dv_files: i64,
+    pub dv_bytes: i64,
+    pub inline_dvs: usize,
+    /// Data bytes of the files carrying vectors
+    pub data_bytes_with_dvs: i64,
+}
+
+impl DeletionVectorStats {
+    /// `None` when no file carries a deletion vector.
+    pub fn from_files(files: &[FileInfo]) -> Option<Self> {
+        let mut stats = Self::default();
+        for file in files {
+            let Some(dv) = &file.deletion_vector else {
+                continue;
+            };
+            stats.files_with_dvs += 1;
+            stats.deleted_rows += dv.cardinality;
+            stats.dv_bytes += dv.size_bytes;
+            stats.inline_dvs += usize::from(dv.inline);
+            stats.data_bytes_with_dvs += file.size_bytes;
+            if let Some(rows) = file.num_records {
+                stats.rows_in_dv_files += rows;
+            }
+        }
+        (stats.files_with_dvs > 0).then_some(stats)
+    }
+
+    /// Deleted rows as a share of the rows in the files carrying vectors, in
+    /// percent. `numRecords` counts deleted rows too, so this is what a
+    /// rewrite would drop from those files.
+    pub fn density_pct(&self) -> Option<f64> {
+        (self.rows_in_dv_files > 0).then(|| self.deleted_rows as f64 / self.rows_in_dv_files as f64 * 100.0)
+    }
+
+    /// Data bytes still stored for deleted rows, assuming rows of even size.
+    pub fn dead_bytes(&self) -> i64 {
+        self.density_pct().map_or(0, |pct| (self.data_bytes_with_dvs as f64 * pct / 100.0) as i64)
+    }
+}
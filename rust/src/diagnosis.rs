@@ -0,0 +1,258 @@
+//! Root-cause narratives that chain related findings.
+//!
+//! Insights report each problem on its own. A diagnosis follows the
+//! dependencies between them: starting from an impact (slow queries, growing
+//! storage), it walks back through the findings that lead to it and reads
+//! the chain from cause to effect, e.g. "Many Small Writes Detected + no
+//! auto-compaction → Small Files Problem Detected → OPTIMIZE Overdue →
+//! slower queries". Each link is an insight the analyzer emitted, so a
+//! diagnosis never claims more than the insights it chains.
+//!
+//! ```no_run
+//! # fn example(stats: &deltective::TableStatistics) {
+//! let insights = deltective::DeltaTableAnalyzer::new(stats.clone()).analyze();
+//! for diagnosis in deltective::diagnosis::diagnose(stats, &insights) {
+//!     println!("{}: {}", diagnosis.title, diagnosis.narrative);
+//! }
+//! # }
+//! ```
+
+use crate::insights::Insight;
+use crate::inspector::TableStatistics;
+use crate::tr;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Insights that establish each finding, in order of preference for the
+/// link text.
+const INSIGHT_FINDINGS: &[(&str, Finding)] = &[
+    ("insight-small-writes", Finding::FrequentWrites),
+    ("insight-over-partitioned", Finding::OverPartitioned),
+    ("insight-partition-rewrites", Finding::RepeatedOverwrites),
+    ("insight-small-files-critical", Finding::SmallFiles),
+    ("insight-small-files-warning", Finding::SmallFiles),
+    ("insight-high-file-count", Finding::SmallFiles),
+    ("insight-optimize-overdue", Finding::NeverOptimized),
+    ("insight-regular-optimization", Finding::NeverOptimized),
+    ("insight-never-vacuumed", Finding::NeverVacuumed),
+    ("insight-not-vacuumed-in-log", Finding::NeverVacuumed),
+    ("insight-vacuum-overdue", Finding::NeverVacuumed),
+];
+
+/// One link of a diagnosis chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Finding {
+    FrequentWrites,
+    NoAutoCompaction,
+    OverPartitioned,
+    RepeatedOverwrites,
+    SmallFiles,
+    NeverOptimized,
+    NeverVacuumed,
+    SlowQueries,
+    StorageGrowth,
+}
+
+impl Finding {
+    /// Findings that lead to this one. A cause that doesn't hold is looked
+    /// through to its own causes, so one missing link doesn't break a chain.
+    fn causes(self) -> &'static [Finding] {
+        match self {
+            Finding::SlowQueries => &[Finding::NeverOptimized],
+            Finding::NeverOptimized => &[Finding::SmallFiles],
+            Finding::SmallFiles => &[Finding::FrequentWrites, Finding::NoAutoCompaction, Finding::OverPartitioned],
+            Finding::StorageGrowth => &[Finding::NeverVacuumed],
+            Finding::NeverVacuumed => &[Finding::RepeatedOverwrites, Finding::FrequentWrites],
+            _ => &[],
+        }
+    }
+}
+
+/// Impacts a diagnosis can end in, the insight category that must have a
+/// warning or critical insight for the impact to hold, the finding the chain
+/// must contain to be worth telling, and the message ids of its title and
+/// recommendation.
+const IMPACTS: &[(Finding, &str, Finding, &str)] = &[
+    (Finding::SlowQueries, "performance", Finding::SmallFiles, "diagnosis-slow-queries"),
+    (Finding::StorageGrowth, "cost", Finding::NeverVacuumed, "diagnosis-storage-growth"),
+];
+
+/// A chain of findings from root causes to their impact.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnosis {
+    pub title: String,
+    /// Links from cause to impact; findings of one link contribute together
+    pub chain: Vec<Vec<Finding>>,
+    /// The chain as text, links joined with "→" and co-causes with "+"
+    pub narrative: String,
+    /// Fix for the root of the chain, so the same problem doesn't come back
+    pub recommendation: String,
+}
+
+/// Build the diagnoses that follow from the `insights` emitted for `stats`.
+/// Impacts whose chain has no root cause beyond the finding an insight
+/// already reports are left out.
+pub fn diagnose(stats: &TableStatistics, insights: &[Insight]) -> Vec<Diagnosis> {
+    let facts = facts(stats, insights);
+
+    IMPACTS
+        .iter()
+        .filter_map(|&(impact, category, required, message)| {
+            let evident = insights
+                .iter()
+                .any(|i| i.category == category && (i.severity == "warning" || i.severity == "critical"));
+            if !evident {
+                return None;
+            }
+            let mut facts = facts.clone();
+            facts.insert(impact, tr!(&format!("{}.link", message)));
+            let chain = chain(impact, &facts);
+            if chain.len() < 3 || !chain.iter().flatten().any(|f| *f == required) {
+                return None;
+            }
+            let narrative = chain
+                .iter()
+                .map(|link| link.iter().map(|f| facts[f].as_str()).collect::<Vec<_>>().join(" + "))
+                .collect::<Vec<_>>()
+                .join(" → ");
+            Some(Diagnosis {
+                title: tr!(message),
+                chain,
+                narrative,
+                recommendation: tr!(&format!("{}.recommendation", message)),
+            })
+        })
+        .collect()
+}
+
+/// Walk back from `impact` to the findings that hold, returning the links in
+/// cause-to-effect order.
+fn chain(impact: Finding, facts: &HashMap<Finding, String>) -> Vec<Vec<Finding>> {
+    let mut links = vec![vec![impact]];
+    let mut seen: HashSet<Finding> = HashSet::from([impact]);
+    let mut frontier = vec![impact];
+
+    loop {
+        let mut candidates: Vec<Finding> = frontier.iter().flat_map(|f| f.causes()).copied().collect();
+        let held = loop {
+            candidates.retain(|f| seen.insert(*f));
+            if candidates.is_empty() {
+                break Vec::new();
+            }
+            let held: Vec<Finding> = candidates.iter().copied().filter(|f| facts.contains_key(f)).collect();
+            if !held.is_empty() {
+                break held;
+            }
+            candidates = candidates.iter().flat_map(|f| f.causes()).copied().collect();
+        };
+        if held.is_empty() {
+            break;
+        }
+        links.push(held.clone());
+        frontier = held;
+    }
+
+    links.reverse();
+    links
+}
+
+/// Findings that hold for the table, each with the text of its link: the
+/// title of the insight reporting it. Auto-compaction has no insight of its
+/// own and is read from the table properties.
+fn facts(stats: &TableStatistics, insights: &[Insight]) -> HashMap<Finding, String> {
+    let mut facts = HashMap::new();
+    for &(id, finding) in INSIGHT_FINDINGS {
+        if let Some(insight) = insights.iter().find(|i| i.id == id) {
+            facts.entry(finding).or_insert_with(|| insight.title.clone());
+        }
+    }
+
+    let enabled = |key: &str| stats.properties.get(key).is_some_and(|v| v.eq_ignore_ascii_case("true"));
+    if !enabled("delta.autoOptimize.autoCompact") && !enabled("delta.autoOptimize.optimizeWrite") {
+        facts.insert(Finding::NoAutoCompaction, tr!("diagnosis-no-auto-compaction"));
+    }
+
+    facts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::insights::Confidence;
+
+    fn facts(findings: &[Finding]) -> HashMap<Finding, String> {
+        findings.iter().map(|f| (*f, format!("{:?}", f))).collect()
+    }
+
+    fn insight(id: &str, severity: &str, category: &str) -> Insight {
+        Insight {
+            id: id.to_string(),
+            severity: severity.to_string(),
+            category: category.to_string(),
+            title: id.to_string(),
+            description: String::new(),
+            recommendation: String::new(),
+            confidence: Confidence::High,
+            assumptions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn chains_from_root_causes_to_the_impact() {
+        let facts = facts(&[
+            Finding::FrequentWrites,
+            Finding::NoAutoCompaction,
+            Finding::SmallFiles,
+            Finding::NeverOptimized,
+            Finding::SlowQueries,
+        ]);
+        assert_eq!(
+            chain(Finding::SlowQueries, &facts),
+            vec![
+                vec![Finding::FrequentWrites, Finding::NoAutoCompaction],
+                vec![Finding::SmallFiles],
+                vec![Finding::NeverOptimized],
+                vec![Finding::SlowQueries],
+            ]
+        );
+    }
+
+    #[test]
+    fn looks_through_findings_that_do_not_hold() {
+        // OPTIMIZE is on schedule, so small files link straight to the impact
+        let facts = facts(&[Finding::OverPartitioned, Finding::SmallFiles, Finding::SlowQueries]);
+        assert_eq!(
+            chain(Finding::SlowQueries, &facts),
+            vec![vec![Finding::OverPartitioned], vec![Finding::SmallFiles], vec![Finding::SlowQueries]]
+        );
+    }
+
+    #[test]
+    fn an_impact_with_no_causes_is_a_chain_of_one() {
+        assert_eq!(chain(Finding::StorageGrowth, &facts(&[Finding::StorageGrowth])), vec![vec![Finding::StorageGrowth]]);
+    }
+
+    #[test]
+    fn diagnoses_only_impacts_the_insights_support() {
+        let stats = TableStatistics::default();
+        let insights = [
+            insight("insight-small-writes", "info", "performance"),
+            insight("insight-small-files-critical", "critical", "performance"),
+        ];
+        let diagnoses = diagnose(&stats, &insights);
+        assert_eq!(diagnoses.len(), 1);
+        assert_eq!(
+            diagnoses[0].chain,
+            vec![
+                vec![Finding::FrequentWrites, Finding::NoAutoCompaction],
+                vec![Finding::SmallFiles],
+                vec![Finding::SlowQueries],
+            ]
+        );
+        assert!(diagnoses[0].narrative.starts_with("insight-small-writes + "));
+
+        // Without a performance warning there's no slow-query impact to explain
+        assert!(diagnose(&stats, &[insight("insight-small-writes", "info", "performance")]).is_empty());
+    }
+}
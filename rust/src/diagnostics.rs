@@ -0,0 +1,58 @@
+use serde_json::{json, Value};
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// Opt-in JSON-lines event stream on stderr.
+///
+/// The TUI owns stdout, so wrappers that want to monitor a long inspection
+/// can redirect stderr (`2>events.log`) and read one JSON object per line.
+pub struct Diagnostics {
+    enabled: bool,
+    started: Instant,
+    last_heartbeat: Instant,
+}
+
+impl Diagnostics {
+    pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn new(enabled: bool) -> Self {
+        let now = Instant::now();
+        Self {
+            enabled,
+            started: now,
+            last_heartbeat: now,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Emit a single event. `fields` must be a JSON object; its keys are
+    /// merged alongside `event` and `elapsed_ms`.
+    pub fn emit(&self, event: &str, fields: Value) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut record = json!({
+            "event": event,
+            "elapsed_ms": self.started.elapsed().as_millis() as u64,
+        });
+        if let (Some(record), Value::Object(fields)) = (record.as_object_mut(), fields) {
+            record.extend(fields);
+        }
+
+        let mut stderr = std::io::stderr().lock();
+        let _ = writeln!(stderr, "{}", record);
+    }
+
+    /// Emit a heartbeat if the interval has elapsed since the last one.
+    pub fn heartbeat(&mut self, fields: Value) {
+        if !self.enabled || self.last_heartbeat.elapsed() < Self::HEARTBEAT_INTERVAL {
+            return;
+        }
+        self.last_heartbeat = Instant::now();
+        self.emit("heartbeat", fields);
+    }
+}
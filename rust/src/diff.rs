@@ -0,0 +1,435 @@
+use crate::insights::DeltaTableAnalyzer;
+use crate::inspector::{table_properties, DeltaTableInspector, TableStatistics};
+use crate::render::{Document, Item, Renderable, Tone};
+use crate::report::{size_histogram, SizeBucket};
+use crate::schema::TableSchema;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// Differences between two snapshots. Field names are a stable interface for
+/// `--format json` consumers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableDiff {
+    pub from: DiffSide,
+    pub to: DiffSide,
+    pub added_files: Vec<FileChange>,
+    pub removed_files: Vec<FileChange>,
+    pub schema_changes: Vec<SchemaChange>,
+    pub property_changes: Vec<PropertyChange>,
+}
+
+/// Which table and version one side of a diff was taken from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffSide {
+    pub table_path: String,
+    pub version: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChange {
+    pub path: String,
+    pub size_bytes: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaChange {
+    pub column: String,
+    pub change: ChangeKind,
+    pub old_type: Option<String>,
+    pub new_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropertyChange {
+    pub key: String,
+    pub change: ChangeKind,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl TableDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_files.is_empty()
+            && self.removed_files.is_empty()
+            && self.schema_changes.is_empty()
+            && self.property_changes.is_empty()
+    }
+}
+
+impl Renderable for TableDiff {
+    fn document(&self) -> Document {
+        let side = |s: &DiffSide| format!("{}@v{}", s.table_path, s.version);
+        let mut doc = Document::new(format!("Diff {} → {}", side(&self.from), side(&self.to)));
+        if self.is_empty() {
+            doc.text("No differences.");
+            return doc;
+        }
+
+        let added: i64 = self.added_files.iter().map(|f| f.size_bytes).sum();
+        let removed: i64 = self.removed_files.iter().map(|f| f.size_bytes).sum();
+        doc.fields([
+            (
+                "Files added",
+                format!("{} ({})", self.added_files.len(), DeltaTableAnalyzer::format_bytes(added)),
+            ),
+            (
+                "Files removed",
+                format!("{} ({})", self.removed_files.len(), DeltaTableAnalyzer::format_bytes(removed)),
+            ),
+        ]);
+
+        if !self.schema_changes.is_empty() {
+            doc.heading("Schema changes").list(
+                self.schema_changes
+                    .iter()
+                    .map(|c| change_item(&c.change, &c.column, c.old_type.as_deref(), c.new_type.as_deref()))
+                    .collect(),
+            );
+        }
+        if !self.property_changes.is_empty() {
+            doc.heading("Property changes").list(
+                self.property_changes
+                    .iter()
+                    .map(|c| change_item(&c.change, &c.key, c.old_value.as_deref(), c.new_value.as_deref()))
+                    .collect(),
+            );
+        }
+        doc
+    }
+}
+
+/// Two different tables side by side, e.g. prod and staging copies. Changes
+/// describe `b` relative to `a`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TableComparison {
+    pub a: ComparedTable,
+    pub b: ComparedTable,
+    pub schema_changes: Vec<SchemaChange>,
+    pub property_changes: Vec<PropertyChange>,
+    pub protocol_changes: Vec<PropertyChange>,
+}
+
+/// The figures compared for one table.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparedTable {
+    pub table_path: String,
+    pub version: i64,
+    pub num_files: usize,
+    pub total_size_bytes: i64,
+    pub num_rows: Option<i64>,
+    pub partition_columns: Vec<String>,
+    pub min_reader_version: i32,
+    pub min_writer_version: i32,
+    pub file_sizes: Vec<SizeBucket>,
+}
+
+impl ComparedTable {
+    fn new(stats: &TableStatistics) -> Self {
+        Self {
+            table_path: stats.table_path.clone(),
+            version: stats.version,
+            num_files: stats.num_files,
+            total_size_bytes: stats.total_size_bytes,
+            num_rows: stats.num_rows,
+            partition_columns: stats.partition_columns.clone(),
+            min_reader_version: stats.min_reader_version,
+            min_writer_version: stats.min_writer_version,
+            file_sizes: size_histogram(&stats.files),
+        }
+    }
+
+    /// Label and value of each compared figure, in display order.
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        let avg_file_size = if self.num_files > 0 { self.total_size_bytes / self.num_files as i64 } else { 0 };
+        vec![
+            ("Version", self.version.to_string()),
+            ("Files", self.num_files.to_string()),
+            ("Size", DeltaTableAnalyzer::format_bytes(self.total_size_bytes)),
+            ("Avg file size", DeltaTableAnalyzer::format_bytes(avg_file_size)),
+            ("Rows", self.num_rows.map_or("-".to_string(), |rows| rows.to_string())),
+            ("Partition columns", self.partition_columns.join(", ")),
+            ("Min reader version", self.min_reader_version.to_string()),
+            ("Min writer version", self.min_writer_version.to_string()),
+        ]
+    }
+}
+
+impl TableComparison {
+    /// True when schema, properties and protocol all match.
+    pub fn is_identical_structure(&self) -> bool {
+        self.schema_changes.is_empty() && self.property_changes.is_empty() && self.protocol_changes.is_empty()
+    }
+
+    /// Schema, property and protocol changes as a document, without the
+    /// side-by-side figures.
+    pub fn changes_document(&self) -> Document {
+        let mut doc = Document::new("Differences");
+        if self.is_identical_structure() {
+            doc.text("Schema, properties and protocol are identical.");
+            return doc;
+        }
+        let property_items = |changes: &[PropertyChange]| -> Vec<Item> {
+            changes
+                .iter()
+                .map(|c| change_item(&c.change, &c.key, c.old_value.as_deref(), c.new_value.as_deref()))
+                .collect()
+        };
+        if !self.schema_changes.is_empty() {
+            doc.heading("Schema changes").list(
+                self.schema_changes
+                    .iter()
+                    .map(|c| change_item(&c.change, &c.column, c.old_type.as_deref(), c.new_type.as_deref()))
+                    .collect(),
+            );
+        }
+        if !self.property_changes.is_empty() {
+            doc.heading("Property changes").list(property_items(&self.property_changes));
+        }
+        if !self.protocol_changes.is_empty() {
+            doc.heading("Protocol changes").list(property_items(&self.protocol_changes));
+        }
+        doc
+    }
+}
+
+impl Renderable for TableComparison {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("Compare {} ↔ {}", self.a.table_path, self.b.table_path));
+
+        let rows = self
+            .a
+            .fields()
+            .into_iter()
+            .zip(self.b.fields())
+            .map(|((label, a), (_, b))| {
+                let marker = if a == b { "" } else { "≠" };
+                vec![label.to_string(), a, b, marker.to_string()]
+            })
+            .collect();
+        doc.table(&["", "A", "B", ""], rows);
+
+        let rows = self
+            .a
+            .file_sizes
+            .iter()
+            .zip(&self.b.file_sizes)
+            .map(|(a, b)| vec![a.label.clone(), a.files.to_string(), b.files.to_string()])
+            .collect();
+        doc.heading("File size distribution").table(&["Size", "A files", "B files"], rows);
+
+        doc.blocks.extend(self.changes_document().blocks);
+        doc
+    }
+}
+
+fn change_item(kind: &ChangeKind, name: &str, old: Option<&str>, new: Option<&str>) -> Item {
+    let (marker, tone) = match kind {
+        ChangeKind::Added => ("+", Tone::Good),
+        ChangeKind::Removed => ("-", Tone::Critical),
+        ChangeKind::Changed => ("~", Tone::Warning),
+    };
+    let text = match (old, new) {
+        (Some(old), Some(new)) => format!("{}: {} → {}", name, old, new),
+        (None, Some(value)) | (Some(value), None) => format!("{}: {}", name, value),
+        (None, None) => name.to_string(),
+    };
+    Item::new(marker, text).tone(tone)
+}
+
+/// Compare the snapshots held by two inspectors, e.g. one table at two
+/// versions from `DeltaTableInspector::at_version`.
+pub async fn diff(old: &DeltaTableInspector, new: &DeltaTableInspector) -> Result<TableDiff> {
+    let old_files = active_files(old)?;
+    let new_files = active_files(new)?;
+    let added_files = new_files
+        .iter()
+        .filter(|(path, _)| !old_files.contains_key(*path))
+        .map(|(path, &size_bytes)| FileChange { path: path.clone(), size_bytes })
+        .collect::<Vec<_>>();
+    let removed_files = old_files
+        .iter()
+        .filter(|(path, _)| !new_files.contains_key(*path))
+        .map(|(path, &size_bytes)| FileChange { path: path.clone(), size_bytes })
+        .collect::<Vec<_>>();
+
+    let schema_changes = schema_changes(&old.get_schema()?, &new.get_schema()?);
+
+    let property_changes = changes(&properties(old)?, &properties(new)?)
+        .into_iter()
+        .map(|(key, change, old_value, new_value)| PropertyChange { key, change, old_value, new_value })
+        .collect();
+
+    let mut diff = TableDiff {
+        from: DiffSide { table_path: old.table_path().to_string(), version: old.table().version() },
+        to: DiffSide { table_path: new.table_path().to_string(), version: new.table().version() },
+        added_files,
+        removed_files,
+        schema_changes,
+        property_changes,
+    };
+    diff.added_files.sort_by(|a, b| a.path.cmp(&b.path));
+    diff.removed_files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(diff)
+}
+
+/// Compare two different tables' schema, properties, protocol, sizes and
+/// file size distributions.
+pub async fn compare(a: &DeltaTableInspector, b: &DeltaTableInspector) -> Result<TableComparison> {
+    let (stats_a, stats_b) = futures::try_join!(a.get_statistics(), b.get_statistics())?;
+
+    let schema_changes = schema_changes(&stats_a.schema, &stats_b.schema);
+    let property_changes = changes(&properties(a)?, &properties(b)?)
+        .into_iter()
+        .map(|(key, change, old_value, new_value)| PropertyChange { key, change, old_value, new_value })
+        .collect();
+    let protocol_changes = changes(&protocol(&stats_a), &protocol(&stats_b))
+        .into_iter()
+        .map(|(key, change, old_value, new_value)| PropertyChange { key, change, old_value, new_value })
+        .collect();
+
+    Ok(TableComparison {
+        a: ComparedTable::new(&stats_a),
+        b: ComparedTable::new(&stats_b),
+        schema_changes,
+        property_changes,
+        protocol_changes,
+    })
+}
+
+/// Protocol versions and features as key/value pairs so they diff like properties.
+fn protocol(stats: &TableStatistics) -> HashMap<String, String> {
+    let mut protocol = HashMap::from([
+        ("minReaderVersion".to_string(), stats.min_reader_version.to_string()),
+        ("minWriterVersion".to_string(), stats.min_writer_version.to_string()),
+    ]);
+    for feature in &stats.reader_features {
+        protocol.insert(format!("readerFeature.{}", feature), "supported".to_string());
+    }
+    for feature in &stats.writer_features {
+        protocol.insert(format!("writerFeature.{}", feature), "supported".to_string());
+    }
+    protocol
+}
+
+fn active_files(inspector: &DeltaTableInspector) -> Result<HashMap<String, i64>> {
+    Ok(inspector
+        .table()
+        .snapshot()?
+        .file_actions()?
+        .into_iter()
+        .map(|add| (add.path, add.size))
+        .collect())
+}
+
+fn properties(inspector: &DeltaTableInspector) -> Result<HashMap<String, String>> {
+    Ok(table_properties(inspector.table().metadata()?))
+}
+
+/// Keys added, removed or changed between two maps, sorted by key.
+/// Column changes in the order the columns appear: the new schema's order,
+/// then removed columns in the old one's.
+fn schema_changes(old: &TableSchema, new: &TableSchema) -> Vec<SchemaChange> {
+    let types = |schema: &TableSchema| -> HashMap<String, String> {
+        schema.fields.iter().map(|field| (field.name.clone(), field.data_type.display())).collect()
+    };
+    let position = |column: &str| {
+        let in_new = new.fields.iter().position(|field| field.name == column);
+        in_new.or_else(|| old.fields.iter().position(|field| field.name == column).map(|i| new.len() + i))
+    };
+    let mut ordered: Vec<SchemaChange> = changes(&types(old), &types(new))
+        .into_iter()
+        .map(|(column, change, old_type, new_type)| SchemaChange { column, change, old_type, new_type })
+        .collect();
+    ordered.sort_by_key(|change| position(&change.column));
+    ordered
+}
+
+fn changes(
+    old: &HashMap<String, String>,
+    new: &HashMap<String, String>,
+) -> Vec<(String, ChangeKind, Option<String>, Option<String>)> {
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let change = match (old.get(key), new.get(key)) {
+                (None, Some(_)) => ChangeKind::Added,
+                (Some(_), None) => ChangeKind::Removed,
+                (Some(a), Some(b)) if a != b => ChangeKind::Changed,
+                _ => return None,
+            };
+            Some((key.clone(), change, old.get(key).cloned(), new.get(key).cloned()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{SchemaField, SchemaType};
+
+    fn map(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn schema(columns: &[(&str, &str)]) -> TableSchema {
+        TableSchema {
+            fields: columns
+                .iter()
+                .map(|(name, data_type)| SchemaField {
+                    name: name.to_string(),
+                    data_type: SchemaType::Primitive { name: data_type.to_string() },
+                    nullable: true,
+                    comment: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn changes_lists_added_removed_and_changed_keys_in_order() {
+        let old = map(&[("a", "1"), ("b", "2"), ("c", "3")]);
+        let new = map(&[("a", "1"), ("b", "20"), ("d", "4")]);
+        let changes: Vec<(String, &str, Option<String>, Option<String>)> = changes(&old, &new)
+            .into_iter()
+            .map(|(key, kind, old, new)| {
+                let kind = match kind {
+                    ChangeKind::Added => "added",
+                    ChangeKind::Removed => "removed",
+                    ChangeKind::Changed => "changed",
+                };
+                (key, kind, old, new)
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("b".to_string(), "changed", Some("2".to_string()), Some("20".to_string())),
+                ("c".to_string(), "removed", Some("3".to_string()), None),
+                ("d".to_string(), "added", None, Some("4".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn identical_maps_have_no_changes() {
+        let same = map(&[("delta.appendOnly", "true")]);
+        assert!(changes(&same, &same).is_empty());
+    }
+
+    #[test]
+    fn schema_changes_follow_the_new_column_order_then_removed_columns() {
+        let old = schema(&[("id", "long"), ("dropped", "string"), ("amount", "integer")]);
+        let new = schema(&[("added", "date"), ("id", "long"), ("amount", "long")]);
+        let columns: Vec<String> = schema_changes(&old, &new).into_iter().map(|change| change.column).collect();
+        assert_eq!(columns, vec!["added", "amount", "dropped"]);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::requests;
+use anyhow::{bail, Context, Result};
+use deltalake::DeltaTableBuilder;
+use futures::stream::{self, StreamExt};
+use object_store::path::Path as ObjectPath;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Directories listed at once while walking a remote root.
+const LIST_CONCURRENCY: usize = 16;
+
+/// Tables found under a root, and the directories that couldn't be listed.
+#[derive(Debug, Clone, Default)]
+pub struct Discovery {
+    pub tables: Vec<String>,
+    pub unreadable: Vec<UnreadableDirectory>,
+}
+
+impl Discovery {
+    /// Whether the walk found `root` itself to be a table, rather than a
+    /// directory holding tables.
+    pub fn is_table(&self, root: &str) -> bool {
+        self.tables.iter().any(|table| table == root.trim_end_matches('/'))
+    }
+}
+
+/// A directory the walk skipped because listing it failed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnreadableDirectory {
+    pub path: String,
+    pub error: String,
+}
+
+/// Find every Delta table under `root`, a local directory or an object store
+/// URL, identified by a `_delta_log` directory. Tables are not searched for
+/// nested tables. A directory that can't be listed is recorded and the walk
+/// goes on; only the root failing is an error.
+pub async fn discover_tables(root: &str, storage_options: HashMap<String, String>) -> Result<Discovery> {
+    // The builder creates missing local directories, so check first
+    if !root.contains("://") && !Path::new(root).is_dir() {
+        bail!("{} is not a directory", root);
+    }
+    let store = requests::metered(
+        DeltaTableBuilder::from_uri(root)
+            .with_storage_options(storage_options)
+            .build_storage()
+            .with_context(|| format!("Failed to open {}", root))?
+            .object_store(),
+    );
+    let root = root.trim_end_matches('/');
+    let display = |dir: &Option<ObjectPath>| match dir {
+        Some(dir) => format!("{}/{}", root, dir),
+        None => root.to_string(),
+    };
+
+    let mut discovery = Discovery::default();
+    let mut level: Vec<Option<ObjectPath>> = vec![None];
+    while !level.is_empty() {
+        let listings: Vec<_> = stream::iter(level)
+            .map(|dir| {
+                let store = store.clone();
+                async move {
+                    let listing = store.list_with_delimiter(dir.as_ref()).await;
+                    (dir, listing)
+                }
+            })
+            .buffer_unordered(LIST_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut next = Vec::new();
+        for (dir, listing) in listings {
+            let listing = match listing {
+                Ok(listing) => listing,
+                Err(e) if dir.is_none() => return Err(e).with_context(|| format!("Failed to list {}", root)),
+                Err(e) => {
+                    discovery.unreadable.push(UnreadableDirectory { path: display(&dir), error: e.to_string() });
+                    continue;
+                }
+            };
+            if listing.common_prefixes.iter().any(|p| p.filename() == Some("_delta_log")) {
+                discovery.tables.push(display(&dir));
+                continue;
+            }
+            // Skip hidden and underscore-prefixed directories (e.g. _checkpoints)
+            next.extend(
+                listing
+                    .common_prefixes
+                    .into_iter()
+                    .filter(|p| p.filename().is_some_and(|name| !name.starts_with('.') && !name.starts_with('_')))
+                    .map(Some),
+            );
+        }
+        level = next;
+    }
+
+    discovery.tables.sort();
+    discovery.unreadable.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(discovery)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn a_table_root_is_found_as_itself() {
+        let dir = TempDir::new().expect("create temp dir");
+        fs::create_dir_all(dir.path().join("_delta_log")).unwrap();
+        fs::create_dir_all(dir.path().join("date=2024-01-01")).unwrap();
+        let root = format!("{}/", dir.path().display());
+        let discovery = discover_tables(&root, HashMap::new()).await.expect("discovery");
+        assert!(discovery.is_table(&root));
+        assert_eq!(discovery.tables.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_directory_of_tables_is_not_a_table() {
+        let dir = TempDir::new().expect("create temp dir");
+        for table in ["sales/orders", "sales/returns", "_staging/tmp"] {
+            fs::create_dir_all(dir.path().join(table).join("_delta_log")).unwrap();
+        }
+        let root = dir.path().display().to_string();
+        let discovery = discover_tables(&root, HashMap::new()).await.expect("discovery");
+        assert!(!discovery.is_table(&root));
+        assert_eq!(discovery.tables, vec![format!("{}/sales/orders", root), format!("{}/sales/returns", root)]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::checkpoint_contents::checkpoint_parts;
+use crate::maintenance::last_checkpoint_version;
+use anyhow::{Context, Result};
+use arrow::array::{Array, AsArray};
+use deltalake::DeltaTable;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Commit files read at once while replaying.
+const COMMIT_READ_CONCURRENCY: usize = 16;
+
+/// Domain the clustering columns of a liquid clustered table are stored under.
+pub const CLUSTERING_DOMAIN: &str = "delta.clustering";
+
+/// The latest `domainMetadata` action for one domain. Features such as
+/// clustering keep their state here rather than in table properties.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DomainMetadata {
+    pub domain: String,
+    /// Free-form, usually a JSON document owned by the feature
+    pub configuration: String,
+    /// Removed domains only show up when the removal is newer than the checkpoint
+    pub removed: bool,
+    /// Commit that wrote it; `None` when it came from the checkpoint
+    pub version: Option<i64>,
+}
+
+/// Replay the domain metadata of `table` at its loaded version: the latest
+/// checkpoint, then every commit after it. deltalake's snapshot doesn't keep
+/// these actions.
+pub async fn read_domain_metadata(table: &DeltaTable) -> Result<BTreeMap<String, DomainMetadata>> {
+    let version = table.version();
+    let mut domains = BTreeMap::new();
+
+    let checkpoint = last_checkpoint_version(table).await?.filter(|checkpoint| *checkpoint <= version);
+    if let Some(checkpoint) = checkpoint {
+        read_checkpoint(table, checkpoint, &mut domains).await?;
+    }
+
+    let first = checkpoint.map_or(0, |checkpoint| checkpoint + 1);
+    let log_store = table.log_store();
+    let mut commits = stream::iter(first..=version)
+        .map(|version| {
+            let log_store = log_store.clone();
+            async move {
+                let bytes = log_store
+                    .read_commit_entry(version)
+                    .await?
+                    .with_context(|| format!("Commit {} is no longer in the log", version))?;
+                anyhow::Ok((version, bytes))
+            }
+        })
+        .buffered(COMMIT_READ_CONCURRENCY);
+    while let Some((version, bytes)) = commits.try_next().await? {
+        for line in String::from_utf8_lossy(&bytes).lines().filter(|line| line.contains("\"domainMetadata\"")) {
+            let action: serde_json::Value =
+                serde_json::from_str(line).with_context(|| format!("Malformed action in commit {}", version))?;
+            let metadata = &action["domainMetadata"];
+            let Some(domain) = metadata["domain"].as_str() else {
+                continue;
+            };
+            domains.insert(
+                domain.to_string(),
+                DomainMetadata {
+                    domain: domain.to_string(),
+                    configuration: metadata["configuration"].as_str().unwrap_or_default().to_string(),
+                    removed: metadata["removed"].as_bool().unwrap_or(false),
+                    version: Some(version),
+                },
+            );
+        }
+    }
+    Ok(domains)
+}
+
+async fn read_checkpoint(table: &DeltaTable, version: i64, domains: &mut BTreeMap<String, DomainMetadata>) -> Result<()> {
+    let store = table.object_store();
+    for meta in checkpoint_parts(table, version).await? {
+        let path = meta.location.to_string();
+        let builder = ParquetRecordBatchStreamBuilder::new(ParquetObjectReader::new(store.clone(), meta))
+            .await
+            .with_context(|| format!("Failed to read {}", path))?;
+        // Checkpoints written before the feature existed have no such column
+        if builder.schema().field_with_name("domainMetadata").is_err() {
+            continue;
+        }
+        let mut batches = builder.build()?;
+        while let Some(batch) = batches.try_next().await.with_context(|| format!("Failed to read {}", path))? {
+            let Some(actions) = batch.column_by_name("domainMetadata").and_then(|a| a.as_struct_opt()) else {
+                continue;
+            };
+            let strings = |field: &str| actions.column_by_name(field).and_then(|c| c.as_string_opt::<i32>()).cloned();
+            let (Some(names), Some(configurations)) = (strings("domain"), strings("configuration")) else {
+                continue;
+            };
+            let removed = actions.column_by_name("removed").and_then(|c| c.as_boolean_opt()).cloned();
+            for row in (0..actions.len()).filter(|row| actions.is_valid(*row) && names.is_valid(*row)) {
+                let domain = names.value(row).to_string();
+                domains.insert(
+                    domain.clone(),
+                    DomainMetadata {
+                        domain,
+                        configuration: if configurations.is_valid(row) { configurations.value(row).to_string() } else { String::new() },
+                        removed: removed.as_ref().is_some_and(|r| r.is_valid(row) && r.value(row)),
+                        version: None,
+                    },
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,533 @@
+use crate::config::Config;
+use crate::discovery::UnreadableDirectory;
+use crate::inspector::DeltaTableInspector;
+use crate::insights::{health_score, DeltaTableAnalyzer, Insight};
+use crate::render::{Document, Item, Renderable, Tone};
+use crate::requests::{self, RequestUsage};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Inspection result for one table in a multi-table scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableSummary {
+    pub table_path: String,
+    pub tags: BTreeMap<String, String>,
+    pub version: i64,
+    pub num_files: usize,
+    pub total_size_bytes: i64,
+    pub total_versions: usize,
+    pub last_write: Option<DateTime<Utc>>,
+    pub health_score: u8,
+    /// What VACUUM would free at least, from the log's tombstones
+    pub reclaimable_bytes: Option<i64>,
+    pub insights: Vec<Insight>,
+}
+
+/// Fleet-level rollup across every table found by a scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FleetReport {
+    pub tables: Vec<TableSummary>,
+    pub failed: Vec<ScanFailure>,
+    pub total_size_bytes: i64,
+    pub total_reclaimable_bytes: i64,
+    /// Lowest health scores first, at most `WORST_TABLES_LIMIT`
+    pub worst_tables: Vec<(String, u8)>,
+    /// Insight titles and how many tables they were raised on, most common first
+    pub common_issues: Vec<(String, usize)>,
+    pub compliance: ComplianceSummary,
+    /// Per-group rollups when the scan was grouped by a tag
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<GroupSummary>,
+    /// Object storage requests the scan made
+    #[serde(default)]
+    pub storage_requests: RequestUsage,
+}
+
+/// Rollup of the tables sharing one value of the group-by tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupSummary {
+    pub tag: String,
+    /// Tag value, or `None` for tables without the tag
+    pub value: Option<String>,
+    pub num_tables: usize,
+    pub total_size_bytes: i64,
+    pub total_reclaimable_bytes: i64,
+    pub avg_health_score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanFailure {
+    pub table_path: String,
+    pub error: String,
+}
+
+/// A table is compliant when it has no critical or warning findings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceSummary {
+    pub compliant: usize,
+    pub non_compliant: Vec<String>,
+}
+
+pub const WORST_TABLES_LIMIT: usize = 10;
+const SCAN_CONCURRENCY: usize = 8;
+
+/// Open and analyze a single table, using the tags and analyzer settings
+/// from `config`.
+pub async fn summarize_table(
+    table_path: &str,
+    storage_options: HashMap<String, String>,
+    config: &Config,
+) -> Result<TableSummary> {
+    let inspector = DeltaTableInspector::new(table_path, storage_options).await?;
+    let mut stats = inspector.get_statistics().await?;
+    let reclaimable_bytes = stats.reclaimable.as_ref().map(|r| r.bytes);
+    // The ZSTD estimate and CDF lag are advisory; a file or commit that can't
+    // be read shouldn't fail the scan
+    let _ = inspector.enrich_statistics(&mut stats, config).await;
+
+    let last_write = stats.last_operation.as_ref().map(|op| op.timestamp);
+    let insights = config.analyzer(stats.clone()).analyze();
+
+    Ok(TableSummary {
+        table_path: table_path.to_string(),
+        tags: config.tags_for(table_path),
+        version: stats.version,
+        num_files: stats.num_files,
+        total_size_bytes: stats.total_size_bytes,
+        total_versions: stats.total_versions,
+        last_write,
+        health_score: health_score(&insights),
+        reclaimable_bytes,
+        insights,
+    })
+}
+
+/// Inspect every table concurrently and aggregate fleet-level findings,
+/// optionally grouped by the value of a config tag.
+pub async fn scan(
+    table_paths: &[String],
+    storage_options: &HashMap<String, String>,
+    config: &Config,
+    group_by: Option<&str>,
+) -> FleetReport {
+    let requests_before = requests::count();
+    let results: Vec<(String, Result<TableSummary>)> = stream::iter(table_paths.iter().cloned())
+        .map(|path| {
+            let options = config.storage_options_for(&path, storage_options);
+            async move {
+                let result = summarize_table(&path, options, config).await;
+                (path, result)
+            }
+        })
+        .buffer_unordered(SCAN_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut tables = Vec::new();
+    let mut failed = Vec::new();
+    for (table_path, result) in results {
+        match result {
+            Ok(summary) => tables.push(summary),
+            Err(e) => failed.push(ScanFailure {
+                table_path,
+                error: e.to_string(),
+            }),
+        }
+    }
+    tables.sort_by(|a, b| a.table_path.cmp(&b.table_path));
+    failed.sort_by(|a, b| a.table_path.cmp(&b.table_path));
+
+    FleetReport {
+        storage_requests: RequestUsage::since(requests_before),
+        ..build_report(tables, failed, group_by)
+    }
+}
+
+impl FleetReport {
+    /// Add the directories discovery couldn't list to the failures, so a
+    /// partial walk doesn't pass for a complete one.
+    pub fn with_unreadable(mut self, unreadable: Vec<UnreadableDirectory>) -> Self {
+        self.failed.extend(unreadable.into_iter().map(|dir| ScanFailure {
+            table_path: dir.path,
+            error: format!("Couldn't list directory: {}", dir.error),
+        }));
+        self.failed.sort_by(|a, b| a.table_path.cmp(&b.table_path));
+        self
+    }
+}
+
+fn build_report(tables: Vec<TableSummary>, failed: Vec<ScanFailure>, group_by: Option<&str>) -> FleetReport {
+    let mut worst_tables: Vec<(String, u8)> = tables
+        .iter()
+        .map(|t| (t.table_path.clone(), t.health_score))
+        .collect();
+    worst_tables.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    worst_tables.truncate(WORST_TABLES_LIMIT);
+
+    // Group by insight id: titles can carry per-table values such as sizes
+    let mut issue_counts: HashMap<&str, (String, usize)> = HashMap::new();
+    for table in &tables {
+        let mut seen = HashSet::new();
+        for insight in table.insights.iter().filter(|i| i.severity != "good") {
+            let key = if insight.id.is_empty() { &insight.title } else { &insight.id };
+            if seen.insert(key.as_str()) {
+                issue_counts
+                    .entry(key)
+                    .or_insert_with(|| (fleet_title(insight), 0))
+                    .1 += 1;
+            }
+        }
+    }
+    let mut common_issues: Vec<(String, usize)> = issue_counts.into_values().collect();
+    common_issues.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let non_compliant: Vec<String> = tables
+        .iter()
+        .filter(|t| {
+            t.insights
+                .iter()
+                .any(|i| i.severity == "critical" || i.severity == "warning")
+        })
+        .map(|t| t.table_path.clone())
+        .collect();
+
+    let groups = group_by
+        .map(|tag| group_tables(&tables, tag))
+        .unwrap_or_default();
+
+    FleetReport {
+        groups,
+        total_size_bytes: tables.iter().map(|t| t.total_size_bytes).sum(),
+        total_reclaimable_bytes: tables.iter().filter_map(|t| t.reclaimable_bytes).sum(),
+        worst_tables,
+        common_issues,
+        compliance: ComplianceSummary {
+            compliant: tables.len() - non_compliant.len(),
+            non_compliant,
+        },
+        tables,
+        failed,
+        storage_requests: RequestUsage::default(),
+    }
+}
+
+/// Title of an insight without the values of any one table, from its
+/// `.fleet-title` attribute when it has one.
+fn fleet_title(insight: &Insight) -> String {
+    let id = format!("{}.fleet-title", insight.id);
+    match crate::tr!(&id) {
+        title if title != id => title,
+        _ => insight.title.clone(),
+    }
+}
+
+fn group_tables(tables: &[TableSummary], tag: &str) -> Vec<GroupSummary> {
+    let mut groups: BTreeMap<Option<String>, Vec<&TableSummary>> = BTreeMap::new();
+    for table in tables {
+        groups.entry(table.tags.get(tag).cloned()).or_default().push(table);
+    }
+
+    groups
+        .into_iter()
+        .map(|(value, members)| GroupSummary {
+            tag: tag.to_string(),
+            value,
+            num_tables: members.len(),
+            total_size_bytes: members.iter().map(|t| t.total_size_bytes).sum(),
+            total_reclaimable_bytes: members.iter().filter_map(|t| t.reclaimable_bytes).sum(),
+            avg_health_score: members.iter().map(|t| t.health_score as f64).sum::<f64>()
+                / members.len() as f64,
+        })
+        .collect()
+}
+
+/// Column the `fleet` matrix can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixSort {
+    Path,
+    Size,
+    Files,
+    Versions,
+    LastWrite,
+    Health,
+}
+
+impl MatrixSort {
+    pub const NAMES: [&'static str; 6] = ["path", "size", "files", "versions", "last-write", "health"];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "path" => Some(MatrixSort::Path),
+            "size" => Some(MatrixSort::Size),
+            "files" => Some(MatrixSort::Files),
+            "versions" => Some(MatrixSort::Versions),
+            "last-write" => Some(MatrixSort::LastWrite),
+            "health" => Some(MatrixSort::Health),
+            _ => None,
+        }
+    }
+}
+
+/// One row per table, for auditing a whole lake at a glance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FleetMatrix {
+    pub tables: Vec<MatrixRow>,
+    pub failed: Vec<ScanFailure>,
+    #[serde(default)]
+    pub storage_requests: RequestUsage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatrixRow {
+    pub table_path: String,
+    pub total_size_bytes: i64,
+    pub num_files: usize,
+    pub total_versions: usize,
+    pub last_write: Option<DateTime<Utc>>,
+    pub health_score: u8,
+}
+
+impl FleetMatrix {
+    pub fn new(report: &FleetReport) -> Self {
+        Self {
+            tables: report
+                .tables
+                .iter()
+                .map(|t| MatrixRow {
+                    table_path: t.table_path.clone(),
+                    total_size_bytes: t.total_size_bytes,
+                    num_files: t.num_files,
+                    total_versions: t.total_versions,
+                    last_write: t.last_write,
+                    health_score: t.health_score,
+                })
+                .collect(),
+            failed: report.failed.clone(),
+            storage_requests: report.storage_requests,
+        }
+    }
+
+    /// Sort rows by `by`: largest first for sizes and counts, stalest first
+    /// for last write and unhealthiest first for health. `reverse` flips it.
+    pub fn sort(&mut self, by: MatrixSort, reverse: bool) {
+        self.tables.sort_by(|a, b| {
+            let order = match by {
+                MatrixSort::Path => a.table_path.cmp(&b.table_path),
+                MatrixSort::Size => b.total_size_bytes.cmp(&a.total_size_bytes),
+                MatrixSort::Files => b.num_files.cmp(&a.num_files),
+                MatrixSort::Versions => b.total_versions.cmp(&a.total_versions),
+                MatrixSort::LastWrite => a.last_write.cmp(&b.last_write),
+                MatrixSort::Health => a.health_score.cmp(&b.health_score),
+            };
+            let order = order.then_with(|| a.table_path.cmp(&b.table_path));
+            if reverse {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+    }
+
+    /// Rows as CSV with a header line; sizes are raw bytes and times RFC 3339.
+    /// Tables that failed follow with only their path and `error` filled in.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("table_path,total_size_bytes,num_files,total_versions,last_write,health_score,error\n");
+        for row in &self.tables {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},\n",
+                csv_field(&row.table_path),
+                row.total_size_bytes,
+                row.num_files,
+                row.total_versions,
+                row.last_write.map(|t| t.to_rfc3339()).unwrap_or_default(),
+                row.health_score
+            ));
+        }
+        for failure in &self.failed {
+            csv.push_str(&format!("{},,,,,,{}\n", csv_field(&failure.table_path), csv_field(&failure.error)));
+        }
+        csv
+    }
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl Renderable for FleetMatrix {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new("Fleet");
+
+        let rows = self
+            .tables
+            .iter()
+            .map(|row| {
+                vec![
+                    row.table_path.clone(),
+                    format_bytes(row.total_size_bytes),
+                    row.num_files.to_string(),
+                    row.total_versions.to_string(),
+                    row.last_write
+                        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                    format!("{}/100", row.health_score),
+                ]
+            })
+            .collect();
+        doc.table(&["Table", "Size", "Files", "Versions", "Last write", "Health"], rows);
+        doc.text(format!("Made {}.", self.storage_requests));
+
+        if !self.failed.is_empty() {
+            doc.heading("Failed to inspect").list(
+                self.failed
+                    .iter()
+                    .map(|f| Item::new("✗", format!("{}: {}", f.table_path, f.error)).tone(Tone::Critical))
+                    .collect(),
+            );
+        }
+        doc
+    }
+}
+
+impl Renderable for FleetReport {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new("Fleet summary");
+
+        let mut fields = vec![("Tables inspected", self.tables.len().to_string())];
+        if !self.failed.is_empty() {
+            fields.push(("Tables failed", self.failed.len().to_string()));
+        }
+        fields.push(("Total size", format_bytes(self.total_size_bytes)));
+        fields.push(("Reclaimable bytes (at least)", format_bytes(self.total_reclaimable_bytes)));
+        fields.push(("Storage requests", self.storage_requests.to_string()));
+        doc.fields(fields);
+
+        if let Some(first) = self.groups.first() {
+            let rows = self
+                .groups
+                .iter()
+                .map(|group| {
+                    vec![
+                        group.value.clone().unwrap_or_else(|| "(untagged)".to_string()),
+                        group.num_tables.to_string(),
+                        format_bytes(group.total_size_bytes),
+                        format_bytes(group.total_reclaimable_bytes),
+                        format!("{:.1}", group.avg_health_score),
+                    ]
+                })
+                .collect();
+            doc.heading(format!("By {}", first.tag))
+                .table(&[first.tag.as_str(), "Tables", "Size", "Reclaimable (at least)", "Avg health"], rows);
+        }
+
+        doc.heading("Worst health scores").list(
+            self.worst_tables
+                .iter()
+                .map(|(path, score)| Item::new(format!("{:>3}/100", score), path.clone()))
+                .collect(),
+        );
+
+        doc.heading("Common issues");
+        if self.common_issues.is_empty() {
+            doc.text("No issues found across the fleet.");
+        } else {
+            doc.list(
+                self.common_issues
+                    .iter()
+                    .map(|(title, count)| Item::new(format!("{:>4} table(s)", count), title.clone()))
+                    .collect(),
+            );
+        }
+
+        doc.heading("Policy compliance").text(format!(
+            "{}/{} table(s) have no critical or warning findings",
+            self.compliance.compliant,
+            self.tables.len()
+        ));
+        if !self.compliance.non_compliant.is_empty() {
+            doc.list(
+                self.compliance
+                    .non_compliant
+                    .iter()
+                    .map(|path| Item::new("✗", path.clone()).tone(Tone::Warning))
+                    .collect(),
+            );
+        }
+
+        if !self.failed.is_empty() {
+            doc.heading("Failed to inspect").list(
+                self.failed
+                    .iter()
+                    .map(|f| Item::new("", format!("{}: {}", f.table_path, f.error)).tone(Tone::Critical))
+                    .collect(),
+            );
+        }
+        doc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(path: &str, insights: Vec<Insight>) -> TableSummary {
+        TableSummary {
+            table_path: path.to_string(),
+            tags: BTreeMap::new(),
+            version: 0,
+            num_files: 1,
+            total_size_bytes: 1000,
+            total_versions: 1,
+            last_write: None,
+            health_score: health_score(&insights),
+            reclaimable_bytes: None,
+            insights,
+        }
+    }
+
+    fn reclaimable(title: &str) -> Insight {
+        Insight {
+            id: "insight-reclaimable-storage".to_string(),
+            severity: "info".to_string(),
+            category: "cost".to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            recommendation: String::new(),
+            confidence: Default::default(),
+            assumptions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn common_issues_group_insights_whose_titles_carry_values() {
+        let tables = vec![
+            summary("s3://lake/orders", vec![reclaimable("At Least 1.5 GB Reclaimable by VACUUM")]),
+            summary("s3://lake/events", vec![reclaimable("At Least 20.0 MB Reclaimable by VACUUM")]),
+        ];
+        let report = build_report(tables, Vec::new(), None);
+        assert_eq!(
+            report.common_issues,
+            vec![("Storage Reclaimable by VACUUM".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("s3://lake/orders"), "s3://lake/orders");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_field("carriage\rreturn"), "\"carriage\rreturn\"");
+    }
+}
@@ -0,0 +1,261 @@
+//! Integrity check of a table's active files against storage.
+//!
+//! Every file added by the current snapshot must exist for reads to
+//! succeed. Files deleted by hand, a lifecycle rule or a VACUUM run by a
+//! misconfigured engine leave the log pointing at nothing, and the table only
+//! fails once a query touches them.
+
+use crate::insights::DeltaTableAnalyzer;
+use crate::inspector::FileInfo;
+use crate::render::{Document, Item, Renderable, Tone};
+use deltalake::DeltaTable;
+use futures::stream::{self, StreamExt};
+use object_store::path::Path as ObjectPath;
+use serde::Serialize;
+
+/// Problems listed individually in the text output; JSON has all of them.
+const LISTED_PROBLEMS: usize = 50;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FsckReport {
+    pub table_path: String,
+    pub version: i64,
+    pub checked: usize,
+    /// Whether sizes in storage were compared with the log
+    pub checked_sizes: bool,
+    pub missing: Vec<MissingFile>,
+    pub size_mismatches: Vec<SizeMismatch>,
+    /// Files whose existence couldn't be determined, e.g. permission errors
+    pub errors: Vec<CheckError>,
+    /// Absolute paths outside the table root (shallow clones), not checked
+    pub external: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingFile {
+    pub path: String,
+    pub size_bytes: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeMismatch {
+    pub path: String,
+    pub expected_bytes: i64,
+    pub actual_bytes: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckError {
+    pub path: String,
+    pub error: String,
+}
+
+impl FsckReport {
+    /// Whether reading the table would hit a missing or truncated file.
+    pub fn is_healthy(&self) -> bool {
+        self.missing.is_empty() && self.size_mismatches.is_empty()
+    }
+
+    /// Sort each checked file into the list its outcome belongs to, by path.
+    fn record(&mut self, outcomes: Vec<(&FileInfo, Outcome)>) {
+        for (file, outcome) in outcomes {
+            let path = file.path.clone();
+            match outcome {
+                Outcome::Present => {}
+                Outcome::Missing => self.missing.push(MissingFile {
+                    path,
+                    size_bytes: file.size_bytes,
+                }),
+                Outcome::SizeMismatch(actual_bytes) => self.size_mismatches.push(SizeMismatch {
+                    path,
+                    expected_bytes: file.size_bytes,
+                    actual_bytes,
+                }),
+                Outcome::Error(error) => self.errors.push(CheckError { path, error }),
+            }
+        }
+        self.missing.sort_by(|a, b| a.path.cmp(&b.path));
+        self.size_mismatches.sort_by(|a, b| a.path.cmp(&b.path));
+        self.errors.sort_by(|a, b| a.path.cmp(&b.path));
+    }
+}
+
+enum Outcome {
+    Present,
+    Missing,
+    SizeMismatch(i64),
+    Error(String),
+}
+
+/// HEAD every active file of `table`, `concurrency` at a time, comparing the
+/// size in storage with the log when `check_sizes` is set.
+pub async fn check(
+    table: &DeltaTable,
+    files: &[FileInfo],
+    check_sizes: bool,
+    concurrency: usize,
+) -> FsckReport {
+    let store = table.object_store();
+    let (external, local): (Vec<&FileInfo>, Vec<&FileInfo>) =
+        files.iter().partition(|file| file.path.contains("://"));
+
+    let outcomes: Vec<(&FileInfo, Outcome)> = stream::iter(local)
+        .map(|file| {
+            let store = store.clone();
+            async move {
+                // Paths in the log are URL-encoded
+                let outcome = match ObjectPath::from_url_path(&file.path) {
+                    Err(e) => Outcome::Error(e.to_string()),
+                    Ok(location) => match store.head(&location).await {
+                        Ok(meta) if check_sizes && meta.size as i64 != file.size_bytes => {
+                            Outcome::SizeMismatch(meta.size as i64)
+                        }
+                        Ok(_) => Outcome::Present,
+                        Err(object_store::Error::NotFound { .. }) => Outcome::Missing,
+                        Err(e) => Outcome::Error(e.to_string()),
+                    },
+                };
+                (file, outcome)
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut report = FsckReport {
+        table_path: table.table_uri(),
+        version: table.version(),
+        checked: outcomes.len(),
+        checked_sizes: check_sizes,
+        missing: Vec::new(),
+        size_mismatches: Vec::new(),
+        errors: Vec::new(),
+        external: external.len(),
+    };
+    report.record(outcomes);
+    report
+}
+
+impl Renderable for FsckReport {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new(format!("fsck {} (version {})", self.table_path, self.version));
+        let verdict = if self.is_healthy() {
+            Item::new("[OK]", "Every active file is present in storage").tone(Tone::Good)
+        } else {
+            Item::new("[CORRUPT]", "Reads touching the files below will fail").tone(Tone::Critical)
+        };
+        doc.list(vec![verdict]);
+        doc.fields([
+            ("Files checked", self.checked.to_string()),
+            ("Missing", self.missing.len().to_string()),
+            (
+                "Size mismatches",
+                if self.checked_sizes { self.size_mismatches.len().to_string() } else { "not checked".to_string() },
+            ),
+            ("Errors", self.errors.len().to_string()),
+            ("External (not checked)", self.external.to_string()),
+        ]);
+
+        let more = |total: usize| (total > LISTED_PROBLEMS).then(|| format!("… {} more", total - LISTED_PROBLEMS));
+        if !self.missing.is_empty() {
+            let mut rows: Vec<Vec<String>> = self
+                .missing
+                .iter()
+                .take(LISTED_PROBLEMS)
+                .map(|f| vec![f.path.clone(), format_bytes(f.size_bytes)])
+                .collect();
+            rows.extend(more(self.missing.len()).map(|m| vec![m, String::new()]));
+            doc.heading("Missing files").table(&["Path", "Size in log"], rows);
+        }
+        if !self.size_mismatches.is_empty() {
+            let mut rows: Vec<Vec<String>> = self
+                .size_mismatches
+                .iter()
+                .take(LISTED_PROBLEMS)
+                .map(|f| vec![f.path.clone(), f.expected_bytes.to_string(), f.actual_bytes.to_string()])
+                .collect();
+            rows.extend(more(self.size_mismatches.len()).map(|m| vec![m, String::new(), String::new()]));
+            doc.heading("Size mismatches").table(&["Path", "Bytes in log", "Bytes in storage"], rows);
+        }
+        if !self.errors.is_empty() {
+            let mut rows: Vec<Vec<String>> = self
+                .errors
+                .iter()
+                .take(LISTED_PROBLEMS)
+                .map(|f| vec![f.path.clone(), f.error.clone()])
+                .collect();
+            rows.extend(more(self.errors.len()).map(|m| vec![m, String::new()]));
+            doc.heading("Errors").table(&["Path", "Error"], rows);
+        }
+        if !self.is_healthy() {
+            doc.text(
+                "Restore the files from a backup or storage versioning if possible. Otherwise RESTORE the table \
+                 to a version that doesn't reference them, or remove them from the log with \
+                 FSCK REPAIR TABLE (Databricks), accepting the loss of their rows.",
+            );
+        }
+        doc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn file(path: &str, size_bytes: i64) -> FileInfo {
+        serde_json::from_value(json!({
+            "path": path,
+            "size_bytes": size_bytes,
+            "modification_time": "2024-01-01T00:00:00Z",
+            "partition_values": {},
+            "num_records": null,
+        }))
+        .expect("valid file")
+    }
+
+    fn report() -> FsckReport {
+        FsckReport {
+            table_path: "/t".to_string(),
+            version: 3,
+            checked: 0,
+            checked_sizes: true,
+            missing: Vec::new(),
+            size_mismatches: Vec::new(),
+            errors: Vec::new(),
+            external: 0,
+        }
+    }
+
+    #[test]
+    fn buckets_outcomes_by_kind_and_path() {
+        let files = [file("b.parquet", 10), file("a.parquet", 20), file("c.parquet", 30), file("d.parquet", 40), file("e.parquet", 50)];
+        let mut report = report();
+        report.record(vec![
+            (&files[0], Outcome::Missing),
+            (&files[1], Outcome::Missing),
+            (&files[2], Outcome::SizeMismatch(7)),
+            (&files[3], Outcome::Error("permission denied".to_string())),
+            (&files[4], Outcome::Present),
+        ]);
+
+        let missing: Vec<(&str, i64)> = report.missing.iter().map(|f| (f.path.as_str(), f.size_bytes)).collect();
+        assert_eq!(missing, vec![("a.parquet", 20), ("b.parquet", 10)]);
+        assert_eq!(report.size_mismatches.len(), 1);
+        assert_eq!(report.size_mismatches[0].expected_bytes, 30);
+        assert_eq!(report.size_mismatches[0].actual_bytes, 7);
+        assert_eq!(report.errors[0].path, "d.parquet");
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn errors_alone_leave_the_table_healthy() {
+        let files = [file("a.parquet", 1), file("b.parquet", 2)];
+        let mut report = report();
+        report.record(vec![(&files[0], Outcome::Present), (&files[1], Outcome::Error("timeout".to_string()))]);
+        assert!(report.missing.is_empty() && report.size_mismatches.is_empty());
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.is_healthy());
+    }
+}
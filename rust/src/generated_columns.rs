@@ -0,0 +1,94 @@
+use deltalake::kernel::{MetadataValue, StructType};
+use serde::{Deserialize, Serialize};
+
+/// Functions Delta can turn a filter on the source column into a partition
+/// filter for, when a partition column is generated with them.
+const PRUNABLE_FUNCTIONS: [&str; 9] = [
+    "CAST", "YEAR", "MONTH", "DAY", "HOUR", "DATE_FORMAT", "DATE_TRUNC", "TRUNC", "SUBSTRING",
+];
+
+/// A column whose values Delta computes: a generated column
+/// (`delta.generationExpression`) or an identity column (`delta.identity.*`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedColumn {
+    pub name: String,
+    pub generation_expression: Option<String>,
+    pub identity: Option<IdentityColumn>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityColumn {
+    pub start: i64,
+    pub step: i64,
+    /// Highest value handed out so far; `None` until the first insert
+    pub high_water_mark: Option<i64>,
+    /// GENERATED BY DEFAULT, which lets writers supply their own values
+    pub allow_explicit_insert: bool,
+}
+
+impl GeneratedColumn {
+    /// Top-level generated and identity columns of `schema`, in schema order.
+    pub fn from_schema(schema: &StructType) -> Vec<Self> {
+        schema
+            .fields()
+            .filter_map(|field| {
+                let metadata = field.metadata();
+                let generation_expression = match metadata.get("delta.generationExpression") {
+                    Some(MetadataValue::String(expression)) => Some(expression.clone()),
+                    _ => None,
+                };
+                let long = |key: &str| metadata.get(key).and_then(as_i64);
+                let identity = long("delta.identity.start").map(|start| IdentityColumn {
+                    start,
+                    step: long("delta.identity.step").unwrap_or(1),
+                    high_water_mark: long("delta.identity.highWaterMark"),
+                    allow_explicit_insert: matches!(
+                        metadata.get("delta.identity.allowExplicitInsert"),
+                        Some(MetadataValue::Boolean(true))
+                    ),
+                });
+                (generation_expression.is_some() || identity.is_some()).then(|| Self {
+                    name: field.name().clone(),
+                    generation_expression,
+                    identity,
+                })
+            })
+            .collect()
+    }
+
+    /// The column definition as it would read in DDL, e.g.
+    /// `GENERATED ALWAYS AS (CAST(ts AS DATE))`.
+    pub fn describe(&self) -> String {
+        match (&self.generation_expression, &self.identity) {
+            (Some(expression), _) => format!("GENERATED ALWAYS AS ({})", expression),
+            (None, Some(identity)) => format!(
+                "GENERATED {} AS IDENTITY (START WITH {} INCREMENT BY {})",
+                if identity.allow_explicit_insert { "BY DEFAULT" } else { "ALWAYS" },
+                identity.start,
+                identity.step
+            ),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// Whether filters on the column the expression reads can prune
+    /// partitions of this column; `false` for identity columns.
+    pub fn is_prunable(&self) -> bool {
+        let Some(expression) = &self.generation_expression else {
+            return false;
+        };
+        let expression = expression.trim_start().to_ascii_uppercase();
+        PRUNABLE_FUNCTIONS
+            .iter()
+            .any(|function| expression.strip_prefix(function).is_some_and(|rest| rest.trim_start().starts_with('(')))
+    }
+}
+
+fn as_i64(value: &MetadataValue) -> Option<i64> {
+    match value {
+        MetadataValue::Number(n) => Some(*n as i64),
+        MetadataValue::String(s) => s.parse().ok(),
+        MetadataValue::Other(v) => v.as_i64(),
+        MetadataValue::Boolean(_) => None,
+    }
+}
@@ -0,0 +1,133 @@
+use crate::commits::CommitSummary;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+const MILLIS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+/// Points each half of the history needs before their rates are compared
+const MIN_POINTS_PER_HALF: usize = 3;
+
+/// Table size and file count right after a commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrowthPoint {
+    pub version: i64,
+    pub timestamp: DateTime<Utc>,
+    pub size_bytes: i64,
+    pub num_files: i64,
+}
+
+/// Size of the table over its recent commits, rebuilt backwards from the
+/// current snapshot with each commit's add and remove sizes, and the linear
+/// trends fitted to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrowthTrend {
+    /// Oldest first; commits without a timestamp are left out
+    pub points: Vec<GrowthPoint>,
+    /// Least-squares slope over all points
+    pub bytes_per_day: f64,
+    pub files_per_day: f64,
+    /// Slopes of the older and newer half of the points; `None` when either
+    /// half is too short to fit
+    pub earlier_bytes_per_day: Option<f64>,
+    pub recent_bytes_per_day: Option<f64>,
+}
+
+/// When the table is expected to reach a size at the current linear rate.
+#[derive(Debug, Clone, Serialize)]
+pub struct GrowthForecast {
+    pub threshold_bytes: i64,
+    /// `None` when the table isn't growing
+    pub reached_at: Option<DateTime<Utc>>,
+    pub already_reached: bool,
+}
+
+impl GrowthTrend {
+    /// Rebuild the size history from `commits`, the most recent commits
+    /// newest first, ending at a table of `size_bytes` in `num_files` files.
+    pub fn from_commits(size_bytes: i64, num_files: i64, commits: &[CommitSummary]) -> Self {
+        let mut points = Vec::with_capacity(commits.len());
+        let (mut size, mut files) = (size_bytes, num_files);
+        for commit in commits {
+            if let Some(timestamp) = commit.timestamp {
+                points.push(GrowthPoint {
+                    version: commit.version,
+                    timestamp,
+                    size_bytes: size,
+                    num_files: files,
+                });
+            }
+            size -= commit.size_delta();
+            files -= commit.files_added as i64 - commit.files_removed as i64;
+        }
+        points.reverse();
+
+        let half = points.len() / 2;
+        let fit_half = |points: &[GrowthPoint]| {
+            (points.len() >= MIN_POINTS_PER_HALF).then(|| slope(points, |p| p.size_bytes)).flatten()
+        };
+        Self {
+            bytes_per_day: slope(&points, |p| p.size_bytes).unwrap_or(0.0),
+            files_per_day: slope(&points, |p| p.num_files).unwrap_or(0.0),
+            earlier_bytes_per_day: fit_half(&points[..half]),
+            recent_bytes_per_day: fit_half(&points[half..]),
+            points,
+        }
+    }
+
+    pub fn current_size(&self) -> Option<i64> {
+        self.points.last().map(|p| p.size_bytes)
+    }
+
+    /// Days covered by the points.
+    pub fn span_days(&self) -> f64 {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (last.timestamp - first.timestamp).num_milliseconds() as f64 / MILLIS_PER_DAY,
+            _ => 0.0,
+        }
+    }
+
+    /// Whether the newer half of the history grows at least `factor` times
+    /// as fast as the older half.
+    pub fn is_accelerating(&self, factor: f64) -> bool {
+        match (self.earlier_bytes_per_day, self.recent_bytes_per_day) {
+            (Some(earlier), Some(recent)) => recent > 0.0 && recent >= earlier.max(0.0) * factor,
+            _ => false,
+        }
+    }
+
+    /// Extrapolate the overall trend from the latest point to `threshold_bytes`.
+    pub fn forecast(&self, threshold_bytes: i64) -> Option<GrowthForecast> {
+        let last = self.points.last()?;
+        if last.size_bytes >= threshold_bytes {
+            return Some(GrowthForecast {
+                threshold_bytes,
+                reached_at: Some(last.timestamp),
+                already_reached: true,
+            });
+        }
+        let reached_at = (self.bytes_per_day > 0.0).then(|| {
+            let days = (threshold_bytes - last.size_bytes) as f64 / self.bytes_per_day;
+            last.timestamp + Duration::milliseconds((days * MILLIS_PER_DAY).min(i64::MAX as f64 / 2.0) as i64)
+        });
+        Some(GrowthForecast {
+            threshold_bytes,
+            reached_at,
+            already_reached: false,
+        })
+    }
+}
+
+/// Least-squares slope of `value` per day; `None` with fewer than two points
+/// or when they all share a timestamp.
+fn slope(points: &[GrowthPoint], value: impl Fn(&GrowthPoint) -> i64) -> Option<f64> {
+    let origin = points.first()?.timestamp;
+    let xy: Vec<(f64, f64)> = points
+        .iter()
+        .map(|p| ((p.timestamp - origin).num_milliseconds() as f64 / MILLIS_PER_DAY, value(p) as f64))
+        .collect();
+    let n = xy.len() as f64;
+    let mean_x = xy.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = xy.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance: f64 = xy.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = xy.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    (variance > 0.0).then(|| covariance / variance)
+}
@@ -0,0 +1,164 @@
+use crate::inspector::{commit_version, operation_metrics};
+use crate::render::{Document, Renderable};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use deltalake::kernel::CommitInfo;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Commits kept by a history filter: any of `operations` (all when empty),
+/// committed between `since` and `until`, both inclusive, in UTC.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HistoryFilter {
+    pub operations: Vec<String>,
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+impl HistoryFilter {
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty() && self.since.is_none() && self.until.is_none()
+    }
+
+    /// Operations compare case-insensitively, so `merge` matches `MERGE`.
+    /// Commits without a timestamp only pass when no dates are set.
+    pub fn matches(&self, entry: &CommitInfo) -> bool {
+        let operation_matches = self.operations.is_empty()
+            || entry
+                .operation
+                .as_deref()
+                .is_some_and(|op| self.operations.iter().any(|wanted| wanted.eq_ignore_ascii_case(op)));
+        if !operation_matches {
+            return false;
+        }
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        let Some(date) = entry.timestamp.and_then(DateTime::from_timestamp_millis).map(|t| t.date_naive()) else {
+            return false;
+        };
+        self.since.is_none_or(|since| date >= since) && self.until.is_none_or(|until| date <= until)
+    }
+
+    /// Set the dates from `FROM..TO`, where either side may be left out, or
+    /// a single date for one day.
+    pub fn set_date_range(&mut self, range: &str) -> Result<()> {
+        let range = range.trim();
+        let (since, until) = match range.split_once("..") {
+            Some((since, until)) => (since.trim(), until.trim()),
+            None => (range, range),
+        };
+        let since = (!since.is_empty()).then(|| parse_date(since)).transpose()?;
+        let until = (!until.is_empty()).then(|| parse_date(until)).transpose()?;
+        if let (Some(since), Some(until)) = (since, until) {
+            if since > until {
+                bail!("Date range {} ends before it starts", range);
+            }
+        }
+        self.since = since;
+        self.until = until;
+        Ok(())
+    }
+
+    /// Short description such as `MERGE, WRITE · 2024-01-01..2024-01-31`.
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.operations.is_empty() {
+            parts.push(self.operations.join(", "));
+        }
+        match (self.since, self.until) {
+            (None, None) => {}
+            (Some(since), Some(until)) if since == until => parts.push(since.to_string()),
+            (since, until) => parts.push(format!(
+                "{}..{}",
+                since.map(|d| d.to_string()).unwrap_or_default(),
+                until.map(|d| d.to_string()).unwrap_or_default()
+            )),
+        }
+        parts.join(" · ")
+    }
+}
+
+/// Parse a `YYYY-MM-DD` date.
+pub fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").with_context(|| format!("Invalid date '{}'; expected YYYY-MM-DD", value))
+}
+
+/// One commit as printed by `deltective history`.
+#[derive(Debug, Serialize)]
+pub struct HistoryEntry {
+    pub version: Option<i64>,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub operation: Option<String>,
+    pub user: Option<String>,
+    pub engine: Option<String>,
+    /// `userMetadata`
+    pub message: Option<String>,
+    pub parameters: HashMap<String, Value>,
+    pub metrics: HashMap<String, Value>,
+}
+
+impl HistoryEntry {
+    pub fn new(entry: &CommitInfo) -> Self {
+        Self {
+            version: commit_version(entry),
+            timestamp: entry.timestamp.and_then(DateTime::from_timestamp_millis),
+            operation: entry.operation.clone(),
+            user: entry.user_name.clone().or_else(|| entry.user_id.clone()),
+            engine: entry.engine_info.clone(),
+            message: entry.user_metadata.clone().filter(|m| !m.is_empty()),
+            parameters: entry.operation_parameters.clone().unwrap_or_default(),
+            metrics: operation_metrics(entry),
+        }
+    }
+}
+
+/// The commits of a table that pass a filter, newest first.
+#[derive(Debug, Serialize)]
+pub struct HistoryListing {
+    pub table_path: String,
+    pub filter: HistoryFilter,
+    /// Commits before filtering
+    pub total_commits: usize,
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl HistoryListing {
+    pub fn new(table_path: &str, history: &[CommitInfo], filter: HistoryFilter) -> Self {
+        Self {
+            table_path: table_path.to_string(),
+            total_commits: history.len(),
+            entries: history.iter().filter(|entry| filter.matches(entry)).map(HistoryEntry::new).collect(),
+            filter,
+        }
+    }
+}
+
+impl Renderable for HistoryListing {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("History: {}", self.table_path));
+        let shown = if self.filter.is_empty() {
+            format!("{} commit(s)", self.total_commits)
+        } else {
+            format!("{} of {} commit(s) match {}", self.entries.len(), self.total_commits, self.filter.describe())
+        };
+        doc.text(shown);
+
+        let rows = self
+            .entries
+            .iter()
+            .map(|entry| {
+                vec![
+                    entry.version.map(|v| v.to_string()).unwrap_or_default(),
+                    entry.timestamp.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap_or_default(),
+                    entry.operation.clone().unwrap_or_default(),
+                    entry.user.clone().unwrap_or_default(),
+                    entry.message.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        doc.table(&["Version", "Time", "Operation", "User", "Message"], rows);
+        doc
+    }
+}
@@ -0,0 +1,212 @@
+//! Localized user-facing strings.
+//!
+//! Insight text and TUI labels are Fluent messages kept in
+//! `locales/<locale>/deltective.ftl` and compiled into the binary. The active
+//! locale is process-wide: the CLI picks it from `--locale`, the config
+//! file's `locale` or `$LANG`, and library users call [`set_locale`]. Messages
+//! missing from a locale fall back to English.
+//!
+//! ```no_run
+//! deltective::i18n::set_locale(Some("en"))?;
+//! let title = deltective::tr!("insight-vacuum-overdue");
+//! let loading = deltective::tr!("tui-loading", what = "history");
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use anyhow::{bail, Result};
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::FluentResource;
+use std::sync::{Arc, RwLock};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::{FluentArgs, FluentValue};
+
+/// Locale used when nothing else is requested, and as the fallback for
+/// messages a translation doesn't define.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Bundled translations: locale and the contents of its Fluent file.
+const LOCALES: &[(&str, &str)] = &[("en", include_str!("../locales/en/deltective.ftl"))];
+
+static ACTIVE: RwLock<Option<Arc<Localizer>>> = RwLock::new(None);
+
+/// Locales with a bundled translation.
+pub fn available_locales() -> Vec<&'static str> {
+    LOCALES.iter().map(|(locale, _)| *locale).collect()
+}
+
+/// Switch every later message lookup to `locale`. `None` picks the locale
+/// from the environment (`LC_ALL`, `LC_MESSAGES`, `LANG`), falling back to
+/// English when it isn't translated.
+pub fn set_locale(locale: Option<&str>) -> Result<()> {
+    let locale = match locale {
+        Some(requested) => match resolve(requested) {
+            Some(locale) => locale,
+            None => bail!(
+                "Unsupported locale '{}' (available: {})",
+                requested,
+                available_locales().join(", ")
+            ),
+        },
+        None => ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| resolve(&value))
+            .unwrap_or(DEFAULT_LOCALE),
+    };
+
+    let localizer = Arc::new(Localizer::new(locale)?);
+    *ACTIVE.write().unwrap_or_else(|e| e.into_inner()) = Some(localizer);
+    Ok(())
+}
+
+/// Locale currently used for messages.
+pub fn current_locale() -> &'static str {
+    active().locale
+}
+
+/// Message `id` in the active locale. `id` may name an attribute as
+/// `message.attribute`.
+pub fn tr(id: &str) -> String {
+    active().format(id, None)
+}
+
+/// Message `id` in the active locale with its `{ $variables }` filled in.
+pub fn tr_args(id: &str, args: &FluentArgs) -> String {
+    active().format(id, Some(args))
+}
+
+/// Look up a localized message, optionally passing `name = value` variables:
+/// `tr!("tui-load-failed", what = "history", error = e.to_string())`.
+#[macro_export]
+macro_rules! tr {
+    ($id:expr) => {
+        $crate::i18n::tr($id)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::tr_args($id, &args)
+    }};
+}
+
+fn active() -> Arc<Localizer> {
+    if let Some(localizer) = ACTIVE.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        return localizer.clone();
+    }
+    let localizer = Arc::new(Localizer::new(DEFAULT_LOCALE).expect("bundled default locale is valid"));
+    ACTIVE
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert(localizer)
+        .clone()
+}
+
+/// Map a requested locale such as `de`, `de-AT` or `de_AT.UTF-8` to a
+/// bundled one, trying the full tag before the bare language.
+fn resolve(requested: &str) -> Option<&'static str> {
+    let tag = requested.split(['.', '@']).next().unwrap_or_default().replace('_', "-");
+    let language = tag.split('-').next().unwrap_or_default();
+    [tag.as_str(), language].iter().find_map(|wanted| {
+        LOCALES
+            .iter()
+            .map(|(locale, _)| *locale)
+            .find(|locale| locale.eq_ignore_ascii_case(wanted))
+    })
+}
+
+/// Bundles for one locale, most specific first and English last.
+struct Localizer {
+    locale: &'static str,
+    bundles: Vec<FluentBundle<FluentResource>>,
+}
+
+impl Localizer {
+    fn new(locale: &'static str) -> Result<Self> {
+        let mut chain = vec![locale];
+        if locale != DEFAULT_LOCALE {
+            chain.push(DEFAULT_LOCALE);
+        }
+
+        let bundles = chain
+            .into_iter()
+            .map(|locale| {
+                let source = LOCALES
+                    .iter()
+                    .find(|(name, _)| *name == locale)
+                    .map(|(_, source)| *source)
+                    .unwrap_or_default();
+                let resource = FluentResource::try_new(source.to_string()).map_err(|(_, errors)| {
+                    anyhow::anyhow!("Invalid messages for locale {}: {:?}", locale, errors)
+                })?;
+                let langid: LanguageIdentifier = locale.parse()?;
+                let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+                // Terminals and JSON consumers would show the bidi isolation marks
+                bundle.set_use_isolating(false);
+                bundle.add_resource(resource).map_err(|errors| {
+                    anyhow::anyhow!("Duplicate messages for locale {}: {:?}", locale, errors)
+                })?;
+                Ok(bundle)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { locale, bundles })
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        let (message_id, attribute) = match id.split_once('.') {
+            Some((message_id, attribute)) => (message_id, Some(attribute)),
+            None => (id, None),
+        };
+
+        for bundle in &self.bundles {
+            let Some(message) = bundle.get_message(message_id) else {
+                continue;
+            };
+            let pattern = match attribute {
+                Some(name) => message.get_attribute(name).map(|a| a.value()),
+                None => message.value(),
+            };
+            if let Some(pattern) = pattern {
+                let mut errors = Vec::new();
+                return bundle.format_pattern(pattern, args, &mut errors).into_owned();
+            }
+        }
+        // Show the id rather than nothing so a missing message is easy to spot
+        id.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_tags_and_posix_locales_to_bundled_ones() {
+        assert_eq!(resolve("en"), Some("en"));
+        assert_eq!(resolve("EN"), Some("en"));
+        assert_eq!(resolve("en-GB"), Some("en"));
+        assert_eq!(resolve("en_US.UTF-8"), Some("en"));
+        assert_eq!(resolve("en_US@euro"), Some("en"));
+    }
+
+    #[test]
+    fn unbundled_locales_do_not_resolve() {
+        assert_eq!(resolve("de_DE.UTF-8"), None);
+        assert_eq!(resolve("C"), None);
+        assert_eq!(resolve(""), None);
+        assert!(set_locale(Some("xx")).is_err());
+    }
+
+    #[test]
+    fn formats_messages_attributes_and_variables() {
+        let localizer = Localizer::new(DEFAULT_LOCALE).expect("bundled locale");
+        assert_eq!(localizer.format("severity-critical", None), "CRITICAL");
+        assert!(!localizer.format("diagnosis-slow-queries.link", None).is_empty());
+        let mut args = FluentArgs::new();
+        args.set("error", "boom");
+        assert!(localizer.format("tui-open-failed", Some(&args)).ends_with(": boom"));
+        assert_eq!(localizer.format("no-such-message", None), "no-such-message");
+    }
+}
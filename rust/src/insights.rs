@@ -1,35 +1,217 @@
-use crate::inspector::TableStatistics;
-use chrono::Utc;
+use crate::cdf::LagStatus;
+use crate::data_skipping::{self, ColumnSkipping, SkippingRating};
+use crate::i18n::{self, FluentArgs};
+use crate::inspector::{checkpoint_interval, TableStatistics, DEFAULT_LOG_RETENTION_HOURS};
+use crate::partitions::partition_key;
+use crate::rules::Rule;
+use chrono::{Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Insight {
+    /// Message id of built-in insights (e.g. `insight-small-files-critical`),
+    /// the rule name for custom rules
+    #[serde(default)]
+    pub id: String,
     pub severity: String, // "critical", "warning", "info", "good"
-    pub category: String, // "performance", "cost", "maintenance", "reliability"
+    pub category: String, // "performance", "cost", "maintenance", "reliability", "data-quality"
     pub title: String,
     pub description: String,
     pub recommendation: String,
+    /// How far the finding can be trusted given the data it rests on
+    #[serde(default)]
+    pub confidence: Confidence,
+    /// Gaps in that data: sampling, missing statistics, truncated history
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assumptions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+impl Confidence {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Confidence::Low => "low",
+            Confidence::Medium => "medium",
+            Confidence::High => "high",
+        }
+    }
+}
+
+/// Data an insight is computed from, beyond the current snapshot's file list
+/// (which is always complete).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Basis {
+    /// Commit history: operation counts, last VACUUM, partition rewrites
+    History,
+    /// Per-commit operation metrics such as the number of files added
+    CommitMetrics,
+    /// Parquet files sampled by the compression estimate
+    Sample,
+    /// Parquet footers read by the codec audit
+    FooterSample,
+}
+
+/// Build an [`Insight`] from a Fluent message in the active locale: the
+/// message value is the title, its `.description` and `.recommendation`
+/// attributes the rest. Trailing `name = value` pairs fill in its variables.
+macro_rules! localized_insight {
+    ($severity:expr, $category:expr, $id:literal $(, $name:ident = $value:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut args = FluentArgs::new();
+        $(args.set(stringify!($name), $value);)*
+        Insight {
+            id: $id.to_string(),
+            severity: $severity.to_string(),
+            category: $category.to_string(),
+            title: i18n::tr_args($id, &args),
+            description: i18n::tr_args(concat!($id, ".description"), &args),
+            recommendation: i18n::tr_args(concat!($id, ".recommendation"), &args),
+            confidence: Confidence::High,
+            assumptions: Vec::new(),
+        }
+    }};
+}
+
+/// Order severities so thresholds like "warning or worse" can be compared.
+pub fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "critical" => 3,
+        "warning" => 2,
+        "info" => 1,
+        _ => 0,
+    }
+}
+
+/// Collapse a set of insights into a 0-100 health score. Every finding costs
+/// points according to its severity; a table with no issues scores 100.
+pub fn health_score(insights: &[Insight]) -> u8 {
+    let penalty: u32 = insights
+        .iter()
+        .map(|i| match i.severity.as_str() {
+            "critical" => 25,
+            "warning" => 10,
+            "info" => 2,
+            _ => 0,
+        })
+        .sum();
+    100u32.saturating_sub(penalty) as u8
+}
+
+/// Tunable limits used by `DeltaTableAnalyzer`. Set from the `thresholds`
+/// section of the config file and overridden by CLI flags.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AnalyzerThresholds {
+    /// Files below this size count as small
+    pub small_file_mb: f64,
+    /// Target file size that OPTIMIZE should produce
+    pub target_file_mb: f64,
+    /// File count above which metadata overhead becomes a concern
+    pub max_files: usize,
+    /// Expected days between VACUUM runs
+    pub vacuum_days: i64,
+    /// Expected days between OPTIMIZE runs, unless the history shows a schedule of its own
+    pub optimize_days: i64,
+    /// Coefficient of variation of file sizes above which data is considered skewed
+    pub file_size_cv: f64,
+    /// JSON commits readers may replay on top of the latest checkpoint
+    pub checkpoint_lag_commits: i64,
+    /// Table sizes, in GB, to forecast reaching at the current growth rate
+    pub size_forecast_gb: Vec<f64>,
+    /// How many times faster recent growth must be than earlier growth to
+    /// count as accelerating
+    pub growth_acceleration: f64,
+    /// Rows MERGE/UPDATE/DELETE may rewrite per row changed
+    pub write_amplification: f64,
+    /// Share of rows in files with deletion vectors that may be deleted before a rewrite is due
+    pub deletion_vector_pct: f64,
+    /// Versions back a dropped column or incompatible type change still warrants a warning
+    pub schema_change_versions: i64,
+}
+
+impl Default for AnalyzerThresholds {
+    fn default() -> Self {
+        Self {
+            small_file_mb: 10.0,
+            target_file_mb: 128.0,
+            max_files: 1000,
+            vacuum_days: 7,
+            optimize_days: 7,
+            file_size_cv: 0.5,
+            checkpoint_lag_commits: 100,
+            size_forecast_gb: vec![1024.0, 10240.0],
+            growth_acceleration: 2.0,
+            write_amplification: 10.0,
+            deletion_vector_pct: 20.0,
+            schema_change_versions: 20,
+        }
+    }
 }
 
 pub struct DeltaTableAnalyzer {
     stats: TableStatistics,
+    thresholds: AnalyzerThresholds,
+    rules: Vec<Rule>,
     insights: Vec<Insight>,
 }
 
 impl DeltaTableAnalyzer {
-    const SMALL_FILE_THRESHOLD_MB: f64 = 10.0;
-    const OPTIMAL_FILE_SIZE_MB: f64 = 128.0;
-    const MAX_RECOMMENDED_FILES: usize = 1000;
-    const MIN_FILE_SIZE_VARIANCE: f64 = 0.5;
-    const VACUUM_RECOMMENDATION_DAYS: i64 = 7;
+    const MAX_LISTED_GAPS: usize = 10;
+    const HOT_PARTITION_REWRITES: usize = 20;
+    const LATE_ARRIVAL_GRACE_DAYS: i64 = 1;
+    const LATE_ARRIVAL_WARNING_PCT: f64 = 10.0;
+    const ZSTD_MIN_SAVINGS_PCT: f64 = 10.0;
+    const ZSTD_WARNING_SAVINGS_PCT: f64 = 30.0;
+    /// Reclaimable bytes, as a share of the table's size, worth a VACUUM on their own
+    const RECLAIMABLE_INFO_PCT: f64 = 20.0;
+    const RECLAIMABLE_WARNING_PCT: f64 = 50.0;
+    /// Share of the data a partial input must cover to still count as medium confidence
+    const MEDIUM_CONFIDENCE_COVERAGE: f64 = 0.5;
+    /// A size threshold forecast within this many days makes accelerating growth a warning
+    const GROWTH_WARNING_DAYS: i64 = 30;
+    /// Share of conflict-prone commits that overlapped another writer worth reporting
+    const CONTENTION_INFO_PCT: f64 = 5.0;
+    const CONTENTION_WARNING_PCT: f64 = 20.0;
+    /// Conflict-prone commits needed before the share means anything
+    const CONTENTION_MIN_COMMITS: usize = 20;
+    /// Small files written since the last OPTIMIZE worth another run
+    const OPTIMIZE_BACKLOG_FILES: usize = 100;
+    /// Spread of the intervals between maintenance runs, over their mean,
+    /// beyond which they follow no schedule
+    const IRREGULAR_CADENCE_CV: f64 = 1.0;
+    /// Files needed before skipping rates say anything about the layout
+    const SKIPPING_MIN_FILES: usize = 20;
+    /// Tables this large with no selective column are worth a warning
+    const SKIPPING_WARNING_FILES: usize = 100;
 
     pub fn new(stats: TableStatistics) -> Self {
+        Self::with_thresholds(stats, AnalyzerThresholds::default())
+    }
+
+    pub fn with_thresholds(stats: TableStatistics, thresholds: AnalyzerThresholds) -> Self {
         Self {
             stats,
+            thresholds,
+            rules: Vec::new(),
             insights: Vec::new(),
         }
     }
 
+    /// Evaluate these user-defined rules alongside the built-in analyzers.
+    pub fn with_rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules = rules;
+        self
+    }
+
     pub fn analyze(mut self) -> Vec<Insight> {
         self.insights.clear();
 
@@ -37,21 +219,49 @@ impl DeltaTableAnalyzer {
         self.analyze_file_count();
         self.analyze_vacuum_history();
         self.analyze_partitioning();
+        self.analyze_generated_partitions();
         self.analyze_optimization_history();
+        self.analyze_maintenance_cadence();
         self.analyze_data_skew();
+        self.analyze_data_skipping();
         self.analyze_write_patterns();
+        self.analyze_partition_completeness();
+        self.analyze_partition_rewrites();
+        self.analyze_late_arriving_data();
+        self.analyze_compression();
+        self.analyze_codecs();
+        self.analyze_cdf_consumers();
+        self.analyze_checkpoint_lag();
+        self.analyze_time_travel();
+        self.analyze_growth();
+        self.analyze_write_amplification();
+        self.analyze_write_contention();
+        self.analyze_deletion_vectors();
+        self.analyze_schema_evolution();
+        self.apply_rules();
+
+        self.finish()
+    }
 
-        // Add positive feedback if no issues found
+    /// Only the checks that need nothing but the file listing: sizes, count,
+    /// partitioning and skew. Used for datasets without a transaction log.
+    pub fn analyze_layout(mut self) -> Vec<Insight> {
+        self.insights.clear();
+
+        self.analyze_file_sizes();
+        self.analyze_file_count();
+        self.analyze_partitioning();
+        self.analyze_data_skew();
+
+        self.finish()
+    }
+
+    /// Add positive feedback if nothing is wrong, then order by severity.
+    fn finish(mut self) -> Vec<Insight> {
         if !self.insights.iter().any(|i| {
             i.severity == "critical" || i.severity == "warning"
         }) {
-            self.insights.push(Insight {
-                severity: "good".to_string(),
-                category: "performance".to_string(),
-                title: "Table Configuration Looks Good".to_string(),
-                description: "No significant configuration issues detected.".to_string(),
-                recommendation: "Continue monitoring the table as data grows.".to_string(),
-            });
+            self.insights.push(localized_insight!("good", "performance", "insight-table-looks-good"));
         }
 
         // Sort by severity
@@ -72,6 +282,74 @@ impl DeltaTableAnalyzer {
         self.insights
     }
 
+    /// Record `insight`, qualified by the gaps in the data it is based on.
+    fn push(&mut self, mut insight: Insight, basis: &[Basis]) {
+        for &basis in basis {
+            if let Some((confidence, assumption)) = self.assumption(basis) {
+                insight.confidence = insight.confidence.min(confidence);
+                insight.assumptions.push(assumption);
+            }
+        }
+        self.insights.push(insight);
+    }
+
+    /// How complete the data behind `basis` is for this table, or `None` when
+    /// nothing is missing.
+    fn assumption(&self, basis: Basis) -> Option<(Confidence, String)> {
+        let confidence = |coverage: f64| {
+            if coverage >= Self::MEDIUM_CONFIDENCE_COVERAGE {
+                Confidence::Medium
+            } else {
+                Confidence::Low
+            }
+        };
+        match basis {
+            Basis::History => {
+                let versions = self.stats.version + 1;
+                let retained = self.stats.total_versions as i64;
+                (retained < versions).then(|| {
+                    (
+                        confidence(retained as f64 / versions as f64),
+                        crate::tr!("assumption-truncated-history", retained = retained, versions = versions),
+                    )
+                })
+            }
+            // Without them per-commit figures are averaged from the snapshot
+            Basis::CommitMetrics => {
+                let has_metrics = self.stats.last_operation.as_ref().is_some_and(|op| !op.metrics.is_empty());
+                (!has_metrics).then(|| (Confidence::Medium, crate::tr!("assumption-missing-commit-metrics")))
+            }
+            Basis::Sample => {
+                let estimate = self.stats.compression_estimate.as_ref()?;
+                (estimate.sampled_files < self.stats.num_files).then(|| {
+                    (
+                        confidence(estimate.sampled_bytes as f64 / self.stats.total_size_bytes.max(1) as f64),
+                        crate::tr!(
+                            "assumption-sampled",
+                            sampled = estimate.sampled_files,
+                            total = self.stats.num_files,
+                            sampled_size = Self::format_bytes(estimate.sampled_bytes),
+                        ),
+                    )
+                })
+            }
+            Basis::FooterSample => {
+                let audit = self.stats.codec_audit.as_ref()?;
+                (audit.audited_files < self.stats.num_files).then(|| {
+                    (
+                        confidence(audit.audited_bytes as f64 / self.stats.total_size_bytes.max(1) as f64),
+                        crate::tr!(
+                            "assumption-sampled",
+                            sampled = audit.audited_files,
+                            total = self.stats.num_files,
+                            sampled_size = Self::format_bytes(audit.audited_bytes),
+                        ),
+                    )
+                })
+            }
+        }
+    }
+
     fn analyze_file_sizes(&mut self) {
         if self.stats.files.is_empty() {
             return;
@@ -87,7 +365,7 @@ impl DeltaTableAnalyzer {
         let avg_size_mb = file_sizes_mb.iter().sum::<f64>() / file_sizes_mb.len() as f64;
         let small_files: Vec<f64> = file_sizes_mb
             .iter()
-            .filter(|&&s| s < Self::SMALL_FILE_THRESHOLD_MB)
+            .filter(|&&s| s < self.thresholds.small_file_mb)
             .copied()
             .collect();
 
@@ -95,133 +373,138 @@ impl DeltaTableAnalyzer {
             let pct_small = (small_files.len() as f64 / file_sizes_mb.len() as f64) * 100.0;
 
             if pct_small > 50.0 {
-                self.insights.push(Insight {
-                    severity: "critical".to_string(),
-                    category: "performance".to_string(),
-                    title: "Small Files Problem Detected".to_string(),
-                    description: format!(
-                        "{:.1}% of files ({}/{}) are smaller than {}MB. Average file size: {:.2}MB. Small files severely impact query performance.",
-                        pct_small,
-                        small_files.len(),
-                        file_sizes_mb.len(),
-                        Self::SMALL_FILE_THRESHOLD_MB,
-                        avg_size_mb
-                    ),
-                    recommendation: format!(
-                        "Run OPTIMIZE command to compact small files. Target file size is ~{}MB. Consider using Auto Optimize for future writes.",
-                        Self::OPTIMAL_FILE_SIZE_MB
-                    ),
-                });
+                self.insights.push(localized_insight!(
+                    "critical",
+                    "performance",
+                    "insight-small-files-critical",
+                    pct_small = format!("{:.1}", pct_small),
+                    small_files = small_files.len(),
+                    total_files = file_sizes_mb.len(),
+                    small_file_mb = self.thresholds.small_file_mb.to_string(),
+                    avg_size_mb = format!("{:.2}", avg_size_mb),
+                    target_file_mb = self.thresholds.target_file_mb.to_string(),
+                ));
             } else if pct_small > 20.0 {
-                self.insights.push(Insight {
-                    severity: "warning".to_string(),
-                    category: "performance".to_string(),
-                    title: "Some Small Files Detected".to_string(),
-                    description: format!(
-                        "{:.1}% of files are smaller than {}MB. Average file size: {:.2}MB.",
-                        pct_small,
-                        Self::SMALL_FILE_THRESHOLD_MB,
-                        avg_size_mb
-                    ),
-                    recommendation: "Consider running OPTIMIZE to improve performance. Monitor file sizes and run OPTIMIZE periodically.".to_string(),
-                });
+                self.insights.push(localized_insight!(
+                    "warning",
+                    "performance",
+                    "insight-small-files-warning",
+                    pct_small = format!("{:.1}", pct_small),
+                    small_file_mb = self.thresholds.small_file_mb.to_string(),
+                    avg_size_mb = format!("{:.2}", avg_size_mb),
+                ));
             }
         }
 
         // Check if average file size is far from optimal
-        if avg_size_mb < Self::OPTIMAL_FILE_SIZE_MB / 2.0 {
-            self.insights.push(Insight {
-                severity: "warning".to_string(),
-                category: "performance".to_string(),
-                title: "Suboptimal Average File Size".to_string(),
-                description: format!(
-                    "Average file size ({:.2}MB) is much smaller than optimal ({}MB).",
-                    avg_size_mb,
-                    Self::OPTIMAL_FILE_SIZE_MB
-                ),
-                recommendation: "Run OPTIMIZE to compact files to optimal size. Configure Auto Optimize for future writes.".to_string(),
-            });
+        if avg_size_mb < self.thresholds.target_file_mb / 2.0 {
+            self.insights.push(localized_insight!(
+                "warning",
+                "performance",
+                "insight-suboptimal-file-size",
+                avg_size_mb = format!("{:.2}", avg_size_mb),
+                target_file_mb = self.thresholds.target_file_mb.to_string(),
+            ));
         }
     }
 
     fn analyze_file_count(&mut self) {
-        if self.stats.num_files > Self::MAX_RECOMMENDED_FILES {
-            self.insights.push(Insight {
-                severity: "warning".to_string(),
-                category: "performance".to_string(),
-                title: "High File Count".to_string(),
-                description: format!(
-                    "Table has {} files. Recommended maximum is ~{} files. High file count increases metadata overhead and slows queries.",
-                    self.stats.num_files,
-                    Self::MAX_RECOMMENDED_FILES
-                ),
-                recommendation: "Run OPTIMIZE to reduce file count. Consider using Auto Optimize and adjusting partition strategy.".to_string(),
-            });
+        if self.stats.num_files > self.thresholds.max_files {
+            self.insights.push(localized_insight!(
+                "warning",
+                "performance",
+                "insight-high-file-count",
+                num_files = self.stats.num_files,
+                max_files = self.thresholds.max_files,
+            ));
         }
     }
 
     fn analyze_vacuum_history(&mut self) {
-        if self.stats.last_vacuum.is_none() {
-            if self.stats.total_versions > 10 {
-                self.insights.push(Insight {
-                    severity: "warning".to_string(),
-                    category: "cost".to_string(),
-                    title: "Table Has Never Been Vacuumed".to_string(),
-                    description: format!(
-                        "Table has {} versions but has never been vacuumed. Old data files are accumulating, increasing storage costs.",
-                        self.stats.total_versions
-                    ),
-                    recommendation: "Run VACUUM command to remove old data files. Set up periodic VACUUM jobs (weekly or monthly). Note: VACUUM deletes old versions permanently.".to_string(),
-                });
-            }
-        } else {
-            let days_since_vacuum = (Utc::now() - self.stats.last_vacuum.unwrap())
-                .num_days();
-            if days_since_vacuum > Self::VACUUM_RECOMMENDATION_DAYS * 4 {
-                self.insights.push(Insight {
-                    severity: "warning".to_string(),
-                    category: "cost".to_string(),
-                    title: "Vacuum Overdue".to_string(),
-                    description: format!(
-                        "Last vacuum was {} days ago. Old data files may be accumulating.",
-                        days_since_vacuum
-                    ),
-                    recommendation: format!(
-                        "Run VACUUM to clean up old files. Recommended vacuum frequency: every {} days.",
-                        Self::VACUUM_RECOMMENDATION_DAYS
-                    ),
-                });
+        // What a VACUUM would free right now, when the tombstones could be read
+        let reclaimable = match &self.stats.reclaimable {
+            Some(r) if r.files > 0 => crate::tr!(
+                "insight-reclaimable",
+                files = r.files,
+                size = Self::format_bytes(r.bytes),
+                retention_hours = r.retention_hours,
+                cost = self.monthly_cost(r.bytes),
+            ),
+            Some(r) => crate::tr!("insight-reclaimable-none", retention_hours = r.retention_hours),
+            None => String::new(),
+        };
+
+        if let Some(last_vacuum) = self.stats.last_vacuum {
+            let days_since_vacuum = (Utc::now() - last_vacuum).num_days();
+            if days_since_vacuum > self.thresholds.vacuum_days * 4 {
+                self.insights.push(localized_insight!(
+                    "warning",
+                    "cost",
+                    "insight-vacuum-overdue",
+                    days = days_since_vacuum,
+                    vacuum_days = self.thresholds.vacuum_days,
+                    reclaimable = reclaimable.as_str(),
+                ));
+                return;
             }
+        } else if self.stats.total_versions > 10 {
+            // Log cleanup may have dropped an older VACUUM along with its commit
+            let insight = match &self.stats.time_travel {
+                Some(window) if window.oldest_logged_version > 0 => localized_insight!(
+                    "warning",
+                    "cost",
+                    "insight-not-vacuumed-in-log",
+                    total_versions = self.stats.total_versions,
+                    oldest_version = window.oldest_logged_version,
+                    log_hours = window.log_retention_hours,
+                    reclaimable = reclaimable.as_str(),
+                ),
+                _ => localized_insight!(
+                    "warning",
+                    "cost",
+                    "insight-never-vacuumed",
+                    total_versions = self.stats.total_versions,
+                    reclaimable = reclaimable.as_str(),
+                ),
+            };
+            self.push(insight, &[Basis::History]);
+            return;
+        }
+
+        // Vacuumed recently enough, but heavy rewrites since can still leave a lot behind
+        let Some(r) = &self.stats.reclaimable else {
+            return;
+        };
+        let pct = r.bytes as f64 / self.stats.total_size_bytes.max(1) as f64 * 100.0;
+        if r.files > 0 && pct >= Self::RECLAIMABLE_INFO_PCT {
+            self.insights.push(localized_insight!(
+                if pct >= Self::RECLAIMABLE_WARNING_PCT { "warning" } else { "info" },
+                "cost",
+                "insight-reclaimable-storage",
+                files = r.files,
+                size = Self::format_bytes(r.bytes),
+                pct = format!("{:.0}", pct),
+                retention_hours = r.retention_hours,
+                cost = self.monthly_cost(r.bytes),
+            ));
         }
     }
 
     fn analyze_partitioning(&mut self) {
         if self.stats.partition_columns.is_empty() {
             if self.stats.total_size_bytes > 10 * 1024 * 1024 * 1024 {
-                self.insights.push(Insight {
-                    severity: "info".to_string(),
-                    category: "performance".to_string(),
-                    title: "Table Not Partitioned".to_string(),
-                    description: format!(
-                        "Table is {} but has no partitioning. Partitioning can improve query performance by enabling partition pruning.",
-                        Self::format_bytes(self.stats.total_size_bytes)
-                    ),
-                    recommendation: "Consider partitioning by frequently filtered columns (e.g., date, region, category). Avoid over-partitioning (too many partitions).".to_string(),
-                });
+                self.insights.push(localized_insight!(
+                    "info",
+                    "performance",
+                    "insight-not-partitioned",
+                    size = Self::format_bytes(self.stats.total_size_bytes),
+                ));
             }
         } else {
             if !self.stats.files.is_empty() {
-                use std::collections::HashMap;
                 let mut partition_counts: HashMap<String, usize> = HashMap::new();
                 for file in &self.stats.files {
-                    let mut partition_parts: Vec<String> = file
-                        .partition_values
-                        .iter()
-                        .map(|(k, v)| format!("{}={}", k, v))
-                        .collect();
-                    partition_parts.sort();
-                    let partition_key = partition_parts.join(",");
-                    *partition_counts.entry(partition_key).or_insert(0) += 1;
+                    *partition_counts.entry(partition_key(&file.partition_values)).or_insert(0) += 1;
                 }
 
                 let num_partitions = partition_counts.len();
@@ -230,49 +513,138 @@ impl DeltaTableAnalyzer {
 
                 // Too many partitions
                 if num_partitions > 1000 && avg_files_per_partition < 5.0 {
-                    self.insights.push(Insight {
-                        severity: "warning".to_string(),
-                        category: "performance".to_string(),
-                        title: "Over-Partitioned Table".to_string(),
-                        description: format!(
-                            "Table has {} partitions with average {:.1} files per partition. Too many partitions creates excessive metadata overhead.",
-                            num_partitions,
-                            avg_files_per_partition
-                        ),
-                        recommendation: "Consider coarser partitioning strategy (e.g., partition by month instead of day). Alternatively, use Z-ordering instead of partitioning.".to_string(),
-                    });
+                    self.insights.push(localized_insight!(
+                        "warning",
+                        "performance",
+                        "insight-over-partitioned",
+                        partitions = num_partitions,
+                        files_per_partition = format!("{:.1}", avg_files_per_partition),
+                    ));
                 } else if num_partitions < 10 && avg_files_per_partition > 100.0 {
-                    self.insights.push(Insight {
-                        severity: "info".to_string(),
-                        category: "performance".to_string(),
-                        title: "Under-Partitioned Table".to_string(),
-                        description: format!(
-                            "Table has only {} partition(s) with {:.0} files per partition on average. More granular partitioning could improve query performance.",
-                            num_partitions,
-                            avg_files_per_partition
-                        ),
-                        recommendation: "Consider finer-grained partitioning if queries frequently filter on specific columns.".to_string(),
-                    });
+                    self.insights.push(localized_insight!(
+                        "info",
+                        "performance",
+                        "insight-under-partitioned",
+                        partitions = num_partitions,
+                        files_per_partition = format!("{:.0}", avg_files_per_partition),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Partition columns should be generated from the column queries filter
+    /// on, with an expression Delta can derive partition filters from.
+    fn analyze_generated_partitions(&mut self) {
+        for column in &self.stats.partition_columns {
+            let generated = self.stats.generated_columns.iter().find(|c| &c.name == column);
+            match generated {
+                Some(generated) if !generated.is_prunable() => {
+                    let Some(expression) = &generated.generation_expression else {
+                        continue;
+                    };
+                    self.insights.push(localized_insight!(
+                        "warning",
+                        "performance",
+                        "insight-generated-partition-unprunable",
+                        column = column.clone(),
+                        expression = expression.clone(),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    let is_timestamp = self.stats.schema.field(column).is_some_and(|f| f.data_type.kind().starts_with("timestamp"));
+                    if is_timestamp {
+                        self.insights.push(localized_insight!(
+                            "warning",
+                            "performance",
+                            "insight-partition-on-timestamp",
+                            column = column.clone(),
+                        ));
+                    }
                 }
             }
         }
     }
 
     fn analyze_optimization_history(&mut self) {
-        if self.stats.total_versions > 20 {
-            if self.stats.num_files > Self::MAX_RECOMMENDED_FILES {
-                self.insights.push(Insight {
-                    severity: "info".to_string(),
-                    category: "maintenance".to_string(),
-                    title: "Consider Regular Optimization".to_string(),
-                    description: format!(
-                        "Table has {} versions and {} files. Regular optimization can maintain performance.",
-                        self.stats.total_versions,
-                        self.stats.num_files
+        if self.stats.total_versions > 20
+            && self.stats.num_files > self.thresholds.max_files {
+                self.push(
+                    localized_insight!(
+                        "info",
+                        "maintenance",
+                        "insight-regular-optimization",
+                        total_versions = self.stats.total_versions,
+                        num_files = self.stats.num_files,
+                        // The versions counted are those still in the log
+                        log_hours = self
+                            .stats
+                            .time_travel
+                            .as_ref()
+                            .map_or(DEFAULT_LOG_RETENTION_HOURS, |window| window.log_retention_hours),
                     ),
-                    recommendation: "Set up periodic OPTIMIZE jobs (weekly or after major writes). Enable Auto Optimize for automatic compaction.".to_string(),
-                });
+                    &[Basis::History],
+                );
             }
+
+        // Overdue by the table's own cadence, with the small files that piled up meanwhile
+        let optimize = &self.stats.maintenance_cadence.optimize;
+        let (Some(last_run), Some(days)) = (optimize.last_run, optimize.days_since_last_run()) else {
+            return;
+        };
+        let small_bytes = (self.thresholds.small_file_mb * 1024.0 * 1024.0) as i64;
+        let accumulated = self
+            .stats
+            .files
+            .iter()
+            .filter(|f| f.modification_time > last_run && f.size_bytes < small_bytes)
+            .count();
+        if days > optimize.due_after_days(self.thresholds.optimize_days) && accumulated >= Self::OPTIMIZE_BACKLOG_FILES {
+            let cadence = match optimize.median_interval_days {
+                Some(median) if optimize.is_regular() => {
+                    crate::tr!("insight-cadence-usual", days = format!("{:.0}", median))
+                }
+                _ => String::new(),
+            };
+            self.push(
+                localized_insight!(
+                    "warning",
+                    "maintenance",
+                    "insight-optimize-overdue",
+                    days = days,
+                    small_files = accumulated,
+                    small_file_mb = self.thresholds.small_file_mb.to_string(),
+                    cadence = cadence,
+                    optimize_days = self.thresholds.optimize_days,
+                ),
+                &[Basis::History],
+            );
+        }
+    }
+
+    /// OPTIMIZE and VACUUM runs with no schedule to them: long gaps between
+    /// bursts let small files and tombstones pile up unnoticed.
+    fn analyze_maintenance_cadence(&mut self) {
+        let cadence = &self.stats.maintenance_cadence;
+        let irregular: Vec<_> = [("OPTIMIZE", &cadence.optimize), ("VACUUM", &cadence.vacuum)]
+            .into_iter()
+            .filter(|(_, c)| c.interval_cv.is_some_and(|cv| cv > Self::IRREGULAR_CADENCE_CV))
+            .filter_map(|(operation, c)| {
+                Some(localized_insight!(
+                    "info",
+                    "maintenance",
+                    "insight-irregular-maintenance",
+                    operation = operation,
+                    runs = c.runs,
+                    median = format!("{:.1}", c.median_interval_days?),
+                    shortest = format!("{:.1}", c.shortest_interval_days?),
+                    longest = format!("{:.1}", c.longest_interval_days?),
+                ))
+            })
+            .collect();
+        for insight in irregular {
+            self.push(insight, &[Basis::History]);
         }
     }
 
@@ -298,21 +670,17 @@ impl DeltaTableAnalyzer {
             0.0
         };
 
-        if coef_variation > Self::MIN_FILE_SIZE_VARIANCE {
+        if coef_variation > self.thresholds.file_size_cv {
             let min_size = *file_sizes.iter().min().unwrap();
             let max_size = *file_sizes.iter().max().unwrap();
-            self.insights.push(Insight {
-                severity: "warning".to_string(),
-                category: "performance".to_string(),
-                title: "Data Skew Detected".to_string(),
-                description: format!(
-                    "High variance in file sizes detected (CV: {:.2}). File sizes range from {} to {}. This indicates data skew which can cause uneven processing.",
-                    coef_variation,
-                    Self::format_bytes(min_size),
-                    Self::format_bytes(max_size)
-                ),
-                recommendation: "Run OPTIMIZE to balance file sizes. Consider using Z-ordering or different partitioning strategy. Review data distribution in partition columns.".to_string(),
-            });
+            self.insights.push(localized_insight!(
+                "warning",
+                "performance",
+                "insight-data-skew",
+                cv = format!("{:.2}", coef_variation),
+                min_size = Self::format_bytes(min_size),
+                max_size = Self::format_bytes(max_size),
+            ));
         }
     }
 
@@ -321,22 +689,630 @@ impl DeltaTableAnalyzer {
             let files_per_version =
                 self.stats.num_files as f64 / self.stats.total_versions as f64;
             if files_per_version < 5.0 && self.stats.total_versions > 10 {
-                self.insights.push(Insight {
-                    severity: "info".to_string(),
-                    category: "performance".to_string(),
-                    title: "Many Small Writes Detected".to_string(),
-                    description: format!(
-                        "Table has {} versions with ~{:.1} files added per write on average. Frequent small writes create many small files.",
-                        self.stats.total_versions,
-                        files_per_version
+                self.push(
+                    localized_insight!(
+                        "info",
+                        "performance",
+                        "insight-small-writes",
+                        total_versions = self.stats.total_versions,
+                        files_per_version = format!("{:.1}", files_per_version),
                     ),
-                    recommendation: "Batch writes together when possible. Enable Auto Optimize to automatically compact small files. Consider using Delta's MERGE operation for incremental updates.".to_string(),
-                });
+                    &[Basis::History, Basis::CommitMetrics],
+                );
             }
         }
     }
 
-    fn format_bytes(bytes_value: i64) -> String {
+    fn analyze_partition_completeness(&mut self) {
+        let Some((label, dates)) = self.partition_dates() else {
+            return;
+        };
+        if dates.len() < 3 {
+            return;
+        }
+
+        let first = *dates.iter().next().unwrap();
+        let last = *dates.iter().next_back().unwrap();
+        let mut gaps: Vec<(NaiveDate, NaiveDate)> = Vec::new();
+        let mut missing_days = 0;
+        let mut day = first;
+        while day < last {
+            if !dates.contains(&day) {
+                missing_days += 1;
+                match gaps.last_mut() {
+                    Some((_, end)) if *end + Duration::days(1) == day => *end = day,
+                    _ => gaps.push((day, day)),
+                }
+            }
+            day += Duration::days(1);
+        }
+
+        if gaps.is_empty() {
+            return;
+        }
+
+        let gap_list: Vec<String> = gaps
+            .iter()
+            .take(Self::MAX_LISTED_GAPS)
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}..{}", start, end)
+                }
+            })
+            .collect();
+        let gap_list = if gaps.len() > Self::MAX_LISTED_GAPS {
+            crate::tr!(
+                "insight-missing-partitions-more",
+                gaps = gap_list.join(", "),
+                more = gaps.len() - Self::MAX_LISTED_GAPS,
+            )
+        } else {
+            gap_list.join(", ")
+        };
+
+        self.insights.push(localized_insight!(
+            "warning",
+            "data-quality",
+            "insight-missing-partitions",
+            missing_days = missing_days,
+            column = label,
+            first = first.to_string(),
+            last = last.to_string(),
+            gaps = gap_list,
+        ));
+    }
+
+    fn analyze_partition_rewrites(&mut self) {
+        let mut hot: Vec<(&String, &usize)> = self
+            .stats
+            .partition_rewrites
+            .iter()
+            .filter(|(_, &count)| count >= Self::HOT_PARTITION_REWRITES)
+            .collect();
+        if hot.is_empty() {
+            return;
+        }
+        hot.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+        let top: Vec<String> = hot
+            .iter()
+            .take(5)
+            .map(|(key, count)| format!("{} ({}x)", key, count))
+            .collect();
+
+        self.push(
+            localized_insight!(
+                "warning",
+                "reliability",
+                "insight-partition-rewrites",
+                partitions = hot.len(),
+                threshold = Self::HOT_PARTITION_REWRITES,
+                top = top.join(", "),
+            ),
+            &[Basis::History],
+        );
+    }
+
+    fn apply_rules(&mut self) {
+        let fired: Vec<(Insight, &[Basis])> = self
+            .rules
+            .iter()
+            .filter_map(|rule| {
+                let insight = rule.evaluate(&self.stats, &self.thresholds)?;
+                let basis: &[Basis] = match rule.metric.as_str() {
+                    "total_versions" | "days_since_vacuum" => &[Basis::History],
+                    _ => &[],
+                };
+                Some((insight, basis))
+            })
+            .collect();
+        for (insight, basis) in fired {
+            self.push(insight, basis);
+        }
+    }
+
+    fn analyze_cdf_consumers(&mut self) {
+        for lag in &self.stats.cdf_consumers {
+            let behind = match lag.lag_hours {
+                Some(hours) => crate::tr!(
+                    "insight-cdf-behind-hours",
+                    versions = lag.versions_behind,
+                    hours = format!("{:.1}", hours),
+                ),
+                None => crate::tr!("insight-cdf-behind", versions = lag.versions_behind),
+            };
+            let insight = match lag.status {
+                LagStatus::Ok => continue,
+                LagStatus::AtRisk => localized_insight!(
+                    "warning",
+                    "reliability",
+                    "insight-cdf-at-risk",
+                    name = lag.name.as_str(),
+                    behind = behind,
+                    last_processed = lag.last_processed_version,
+                    retention_hours = lag.retention_hours,
+                ),
+                LagStatus::Expired => localized_insight!(
+                    "critical",
+                    "reliability",
+                    "insight-cdf-expired",
+                    name = lag.name.as_str(),
+                    behind = behind,
+                    last_processed = lag.last_processed_version,
+                    retention_hours = lag.retention_hours,
+                ),
+                LagStatus::Unknown => localized_insight!(
+                    "info",
+                    "reliability",
+                    "insight-cdf-unknown",
+                    name = lag.name.as_str(),
+                    behind = behind,
+                    last_processed = lag.last_processed_version,
+                    retention_hours = lag.retention_hours,
+                ),
+            };
+            self.insights.push(insight);
+        }
+    }
+
+    /// Log and file retentions that differ: a shorter log retention keeps
+    /// files no version can reach any more, a shorter file retention leaves
+    /// versions in the history that VACUUM has already broken.
+    fn analyze_time_travel(&mut self) {
+        let Some(window) = &self.stats.time_travel else {
+            return;
+        };
+        if window.log_retention_hours < window.deleted_file_retention_hours {
+            self.insights.push(localized_insight!(
+                "warning",
+                "cost",
+                "insight-log-retention-short",
+                log_hours = window.log_retention_hours,
+                file_hours = window.deleted_file_retention_hours,
+            ));
+        } else if window.unreadable_logged_versions() > 0 {
+            self.insights.push(localized_insight!(
+                "info",
+                "reliability",
+                "insight-vacuumed-versions-listed",
+                versions = window.unreadable_logged_versions(),
+                oldest_logged = window.oldest_logged_version,
+                oldest_readable = window.oldest_readable_version,
+                log_hours = window.log_retention_hours,
+                file_hours = window.deleted_file_retention_hours,
+            ));
+        }
+    }
+
+    /// Readers load the latest checkpoint and replay every JSON commit after
+    /// it, so a checkpoint far behind the table slows down every open.
+    fn analyze_checkpoint_lag(&mut self) {
+        let Some(lag) = &self.stats.checkpoint_lag else {
+            return;
+        };
+        // Writers checkpoint every delta.checkpointInterval commits, so a lag
+        // within the interval is what the table asks for
+        let interval = checkpoint_interval(&self.stats.properties);
+        let max_commits = self.thresholds.checkpoint_lag_commits.max(interval);
+        if lag.commits_since_checkpoint <= max_commits {
+            return;
+        }
+        let insight = match lag.checkpoint_version {
+            Some(checkpoint_version) => localized_insight!(
+                "warning",
+                "performance",
+                "insight-checkpoint-stale",
+                checkpoint_version = checkpoint_version,
+                version = self.stats.version,
+                commits = lag.commits_since_checkpoint,
+                max_commits = max_commits,
+                interval = interval,
+            ),
+            None => localized_insight!(
+                "warning",
+                "performance",
+                "insight-no-checkpoint",
+                commits = lag.commits_since_checkpoint,
+                max_commits = max_commits,
+                interval = interval,
+            ),
+        };
+        self.insights.push(insight);
+    }
+
+    /// Flag a table growing faster in its recent commits than before, with
+    /// the next configured size it will reach at the overall rate.
+    fn analyze_growth(&mut self) {
+        let Some(growth) = &self.stats.growth else {
+            return;
+        };
+        if !growth.is_accelerating(self.thresholds.growth_acceleration) {
+            return;
+        }
+        let mut thresholds: Vec<i64> = self
+            .thresholds
+            .size_forecast_gb
+            .iter()
+            .map(|gb| (gb * 1024.0 * 1024.0 * 1024.0) as i64)
+            .collect();
+        thresholds.sort_unstable();
+        let next = thresholds
+            .into_iter()
+            .filter_map(|bytes| growth.forecast(bytes))
+            .find(|forecast| !forecast.already_reached && forecast.reached_at.is_some());
+        let reached_at = next.as_ref().and_then(|forecast| forecast.reached_at);
+        let soon = reached_at.is_some_and(|at| at - Utc::now() < Duration::days(Self::GROWTH_WARNING_DAYS));
+
+        let insight = localized_insight!(
+            if soon { "warning" } else { "info" },
+            "cost",
+            "insight-growth-accelerating",
+            recent_rate = Self::format_bytes(growth.recent_bytes_per_day.unwrap_or(0.0) as i64),
+            earlier_rate = Self::format_bytes(growth.earlier_bytes_per_day.unwrap_or(0.0).max(0.0) as i64),
+            commits = growth.points.len(),
+            days = format!("{:.0}", growth.span_days()),
+            size = Self::format_bytes(growth.current_size().unwrap_or(self.stats.total_size_bytes)),
+            threshold = next.as_ref().map_or("none".to_string(), |forecast| Self::format_bytes(forecast.threshold_bytes)),
+            date = reached_at.map_or("none".to_string(), |at| at.format("%Y-%m-%d").to_string()),
+        );
+        self.insights.push(insight);
+    }
+
+    /// Copy-on-write MERGE/UPDATE/DELETE rewrite every file holding a changed
+    /// row; many unchanged rows copied per changed row means wasted I/O.
+    fn analyze_write_amplification(&mut self) {
+        let Some(amplification) = &self.stats.write_amplification else {
+            return;
+        };
+        let Some(ratio) = amplification.ratio() else {
+            return;
+        };
+        let max_ratio = self.thresholds.write_amplification;
+        if ratio <= max_ratio {
+            return;
+        }
+        let deletion_vectors = self
+            .stats
+            .properties
+            .get("delta.enableDeletionVectors")
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+
+        self.insights.push(localized_insight!(
+            if ratio > max_ratio * 10.0 { "critical" } else { "warning" },
+            "cost",
+            "insight-write-amplification",
+            ratio = format!("{:.1}", ratio),
+            max_ratio = format!("{:.1}", max_ratio),
+            commits = amplification.commits(),
+            rows_changed = amplification.rows_changed,
+            rows_copied = amplification.rows_copied,
+            bytes_rewritten = Self::format_bytes(amplification.bytes_rewritten),
+            bytes_changed = Self::format_bytes(amplification.bytes_changed()),
+            deletion_vectors = if deletion_vectors { "enabled" } else { "disabled" },
+        ));
+    }
+
+    /// Commits planned against a snapshot other writers had already moved
+    /// past; each one passed conflict checks, and others likely failed them.
+    fn analyze_write_contention(&mut self) {
+        let Some(contention) = &self.stats.write_contention else {
+            return;
+        };
+        let overlap_pct = contention.overlap_pct();
+        if contention.conflicting_commits < Self::CONTENTION_MIN_COMMITS || overlap_pct < Self::CONTENTION_INFO_PCT {
+            return;
+        }
+
+        self.insights.push(localized_insight!(
+            if overlap_pct >= Self::CONTENTION_WARNING_PCT { "warning" } else { "info" },
+            "reliability",
+            "insight-write-contention",
+            overlapping = contention.overlapping_commits,
+            conflicting = contention.conflicting_commits,
+            overlap_pct = format!("{:.1}", overlap_pct),
+            max_overlap = contention.max_overlap,
+            bursts = contention.burst_commits,
+            operation = contention.busiest_operation().unwrap_or("UNKNOWN"),
+            partitioned = if self.stats.partition_columns.is_empty() { "no" } else { "yes" },
+        ));
+    }
+
+    /// Rows deleted through deletion vectors stay in the data files until they
+    /// are rewritten; readers scan them and then filter them out again.
+    fn analyze_deletion_vectors(&mut self) {
+        let Some(dvs) = &self.stats.deletion_vectors else {
+            return;
+        };
+        let Some(density_pct) = dvs.density_pct() else {
+            return;
+        };
+        let max_pct = self.thresholds.deletion_vector_pct;
+        if density_pct <= max_pct {
+            return;
+        }
+
+        self.insights.push(localized_insight!(
+            "warning",
+            "performance",
+            "insight-deletion-vector-density",
+            files = dvs.files_with_dvs,
+            total_files = self.stats.num_files,
+            deleted_rows = dvs.deleted_rows,
+            density_pct = format!("{:.1}", density_pct),
+            max_pct = format!("{:.0}", max_pct),
+            dead_bytes = Self::format_bytes(dvs.dead_bytes()),
+            dv_bytes = Self::format_bytes(dvs.dv_bytes),
+        ));
+    }
+
+    /// Columns dropped or changed to an incompatible type in the last
+    /// `schema_change_versions` versions, which break readers and jobs still
+    /// expecting the old schema.
+    fn analyze_schema_evolution(&mut self) {
+        let window = self.thresholds.schema_change_versions;
+        let breaking: Vec<String> = self
+            .stats
+            .schema_evolution
+            .iter()
+            .filter(|change| self.stats.version - change.version < window && change.is_breaking())
+            .map(|change| {
+                let mut parts: Vec<String> = change.dropped.iter().map(|column| format!("dropped {}", column)).collect();
+                parts.extend(
+                    change
+                        .incompatible_changes()
+                        .map(|c| format!("{} {} → {}", c.column, c.old_type, c.new_type)),
+                );
+                format!(
+                    "v{} ({}): {}",
+                    change.version,
+                    change.operation.as_deref().unwrap_or("unknown operation"),
+                    parts.join(", ")
+                )
+            })
+            .collect();
+        if breaking.is_empty() {
+            return;
+        }
+
+        self.insights.push(localized_insight!(
+            "warning",
+            "reliability",
+            "insight-breaking-schema-change",
+            commits = breaking.len(),
+            versions = window,
+            changes = breaking.join("; "),
+        ));
+    }
+
+    fn analyze_compression(&mut self) {
+        let Some(estimate) = &self.stats.compression_estimate else {
+            return;
+        };
+        let savings_pct = (1.0 - estimate.ratio()) * 100.0;
+        if savings_pct < Self::ZSTD_MIN_SAVINGS_PCT {
+            return;
+        }
+        let projected_savings = (self.stats.total_size_bytes as f64 * (1.0 - estimate.ratio())) as i64;
+
+        self.push(
+            localized_insight!(
+                if savings_pct >= Self::ZSTD_WARNING_SAVINGS_PCT { "warning" } else { "info" },
+                "cost",
+                "insight-zstd-savings",
+                codecs = estimate.current_codecs.join("/"),
+                sampled_files = estimate.sampled_files,
+                level = estimate.zstd_level,
+                savings_pct = format!("{:.0}", savings_pct),
+                projected_savings = Self::format_bytes(projected_savings),
+                total_size = Self::format_bytes(self.stats.total_size_bytes),
+                cost = self.monthly_cost(projected_savings),
+            ),
+            &[Basis::Sample],
+        );
+    }
+
+    /// Columns whose file min/max ranges overlap so much that filters on
+    /// them read most files, with Z-ORDER or clustering candidates.
+    fn analyze_data_skipping(&mut self) {
+        if self.stats.num_files < Self::SKIPPING_MIN_FILES {
+            return;
+        }
+        let rated = data_skipping::rated(&self.stats.data_skipping, &self.stats.schema);
+        let poor: Vec<&ColumnSkipping> =
+            rated.iter().copied().filter(|s| s.rating() == SkippingRating::Poor).collect();
+        if poor.is_empty() {
+            return;
+        }
+        let effective: Vec<String> = rated
+            .iter()
+            .filter(|s| s.rating() == SkippingRating::Effective)
+            .map(|s| format!("{} ({:.0}%)", s.column, s.skip_pct))
+            .collect();
+        let all_poor = poor.len() == rated.len();
+
+        self.insights.push(localized_insight!(
+            if all_poor && self.stats.num_files >= Self::SKIPPING_WARNING_FILES { "warning" } else { "info" },
+            "performance",
+            "insight-data-skipping",
+            files = self.stats.num_files,
+            poor = poor
+                .iter()
+                .map(|s| format!("{} ({:.0}% skipped)", s.column, s.skip_pct))
+                .collect::<Vec<_>>()
+                .join(", "),
+            effective = if effective.is_empty() { "none".to_string() } else { effective.join(", ") },
+            candidates = data_skipping::zorder_candidates(&self.stats.data_skipping, &self.stats.schema).join(", "),
+        ));
+    }
+
+    /// Uncompressed files, and files written with another codec than the
+    /// expected one (or than the bulk of the table when none is configured).
+    fn analyze_codecs(&mut self) {
+        let Some(audit) = &self.stats.codec_audit else {
+            return;
+        };
+        let describe = |usages: &[&crate::compression::CodecUsage]| {
+            usages
+                .iter()
+                .map(|u| format!("{} ({} files, {})", u.codec, u.files, Self::format_bytes(u.bytes)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut found = Vec::new();
+
+        let uncompressed: Vec<_> = audit.uncompressed().collect();
+        if !uncompressed.is_empty() {
+            found.push(localized_insight!(
+                "warning",
+                "cost",
+                "insight-uncompressed-files",
+                files = uncompressed.iter().map(|u| u.files).sum::<usize>(),
+                audited = audit.audited_files,
+                bytes = Self::format_bytes(uncompressed.iter().map(|u| u.bytes).sum()),
+                codecs = describe(&uncompressed),
+            ));
+        }
+
+        let off_target = audit.off_target();
+        if !off_target.is_empty() {
+            let insight = match &audit.expected {
+                Some(expected) => localized_insight!(
+                    "warning",
+                    "cost",
+                    "insight-unexpected-codec",
+                    expected = expected.to_uppercase(),
+                    files = off_target.iter().map(|u| u.files).sum::<usize>(),
+                    audited = audit.audited_files,
+                    codecs = describe(&off_target),
+                ),
+                None => localized_insight!(
+                    "info",
+                    "cost",
+                    "insight-mixed-codecs",
+                    dominant = audit.codecs.first().map(|u| u.codec.clone()).unwrap_or_default(),
+                    files = off_target.iter().map(|u| u.files).sum::<usize>(),
+                    audited = audit.audited_files,
+                    codecs = describe(&off_target),
+                ),
+            };
+            found.push(insight);
+        }
+
+        for insight in found {
+            self.push(insight, &[Basis::FooterSample]);
+        }
+    }
+
+    /// Compare each file's partition date with when it was written. Files
+    /// landing well after their partition's day are late-arriving data.
+    /// Files rewritten by compaction keep old data under a new modification
+    /// time, so only files added with dataChange=true count.
+    fn analyze_late_arriving_data(&mut self) {
+        let Some(scheme) = self.date_partitioning() else {
+            return;
+        };
+
+        let mut delays: Vec<(i64, i64)> = self
+            .stats
+            .files
+            .iter()
+            .filter(|f| !f.rewritten)
+            .filter_map(|f| {
+                let partition_date = scheme.date_of(&f.partition_values)?;
+                let delay = (f.modification_time.date_naive() - partition_date).num_days();
+                Some((delay, f.size_bytes))
+            })
+            .collect();
+        if delays.is_empty() {
+            return;
+        }
+
+        let (late_files, late_bytes) = delays
+            .iter()
+            .filter(|(delay, _)| *delay > Self::LATE_ARRIVAL_GRACE_DAYS)
+            .fold((0usize, 0i64), |(files, bytes), (_, size)| (files + 1, bytes + size));
+        if late_files == 0 {
+            return;
+        }
+
+        let pct_late = late_files as f64 / delays.len() as f64 * 100.0;
+        delays.sort_by_key(|(delay, _)| *delay);
+        let p95 = delays[((delays.len() - 1) as f64 * 0.95) as usize].0;
+        let max = delays.last().map(|(delay, _)| *delay).unwrap_or(0);
+
+        let severity = if pct_late > Self::LATE_ARRIVAL_WARNING_PCT {
+            "warning"
+        } else {
+            "info"
+        };
+
+        self.insights.push(localized_insight!(
+            severity,
+            "data-quality",
+            "insight-late-arriving",
+            pct_late = format!("{:.1}", pct_late),
+            late_files = late_files,
+            late_size = Self::format_bytes(late_bytes),
+            grace_days = Self::LATE_ARRIVAL_GRACE_DAYS,
+            column = scheme.label(),
+            p95 = p95,
+            max = max,
+            window_days = p95.max(1),
+        ));
+    }
+
+    /// Distinct dates covered by the table's partitions, if it is partitioned
+    /// by a single date column or by year/month/day columns.
+    fn partition_dates(&self) -> Option<(String, BTreeSet<NaiveDate>)> {
+        let scheme = self.date_partitioning()?;
+        let dates = self
+            .stats
+            .files
+            .iter()
+            .filter_map(|f| scheme.date_of(&f.partition_values))
+            .collect();
+        Some((scheme.label(), dates))
+    }
+
+    /// Work out how (if at all) the table encodes a date in its partitions.
+    fn date_partitioning(&self) -> Option<DatePartitioning> {
+        let columns = &self.stats.partition_columns;
+        let find = |name: &str| columns.iter().find(|c| c.eq_ignore_ascii_case(name)).cloned();
+
+        if let (Some(year), Some(month), Some(day)) = (find("year"), find("month"), find("day")) {
+            return Some(DatePartitioning::YearMonthDay { year, month, day });
+        }
+
+        columns
+            .iter()
+            .find(|column| {
+                let mut values = self
+                    .stats
+                    .files
+                    .iter()
+                    .filter_map(|f| f.partition_values.get(*column))
+                    .peekable();
+                values.peek().is_some() && values.all(|v| parse_partition_date(v).is_some())
+            })
+            .map(|column| DatePartitioning::Column(column.clone()))
+    }
+
+    /// Sentence pricing `bytes` per month, or nothing when the cost model
+    /// wasn't applied to the statistics.
+    fn monthly_cost(&self, bytes: i64) -> String {
+        match &self.stats.cost {
+            Some(cost) => crate::tr!(
+                "insight-monthly-cost",
+                amount = cost.format(cost.monthly(bytes)),
+                storage_class = cost.storage_class.as_str(),
+            ),
+            None => String::new(),
+        }
+    }
+
+    pub(crate) fn format_bytes(bytes_value: i64) -> String {
         let mut bytes = bytes_value as f64;
         let units = ["B", "KB", "MB", "GB", "TB"];
         for unit in &units {
@@ -349,3 +1325,36 @@ impl DeltaTableAnalyzer {
     }
 }
 
+
+/// How a table's partition values encode a calendar date.
+enum DatePartitioning {
+    Column(String),
+    YearMonthDay { year: String, month: String, day: String },
+}
+
+impl DatePartitioning {
+    fn label(&self) -> String {
+        match self {
+            DatePartitioning::Column(column) => column.clone(),
+            DatePartitioning::YearMonthDay { year, month, day } => format!("{}/{}/{}", year, month, day),
+        }
+    }
+
+    fn date_of(&self, values: &HashMap<String, String>) -> Option<NaiveDate> {
+        match self {
+            DatePartitioning::Column(column) => parse_partition_date(values.get(column)?),
+            DatePartitioning::YearMonthDay { year, month, day } => NaiveDate::from_ymd_opt(
+                values.get(year)?.parse().ok()?,
+                values.get(month)?.parse().ok()?,
+                values.get(day)?.parse().ok()?,
+            ),
+        }
+    }
+}
+
+/// Parse a partition value as a calendar date (`2024-05-17` or `20240517`).
+fn parse_partition_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y%m%d"))
+        .ok()
+}
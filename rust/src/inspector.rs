@@ -1,9 +1,41 @@
-use anyhow::{Context, Result};
+use crate::amplification::WriteAmplification;
+use crate::attribution::Attribution;
+use crate::cadence::MaintenanceCadence;
+use crate::column_stats::{self, flatten_stats, ColumnStats};
+use crate::cdf::{CdfConsumer, ConsumerLag, NextCommit};
+use crate::clustering::ClusteringInfo;
+use crate::column_mapping::{MappedColumn, MappingEnabled};
+use crate::commits::CommitSummary;
+use crate::compression::{self, CodecAudit, CompressionEstimate};
+use crate::config::Config;
+use crate::contention::WriteContention;
+use crate::cost::CostEstimate;
+use crate::data_skipping::{self, ColumnSkipping};
+use crate::deletion_vectors::{DeletionVectorStats, FileDeletionVector};
+use crate::domain_metadata::{read_domain_metadata, DomainMetadata, CLUSTERING_DOMAIN};
+use crate::generated_columns::GeneratedColumn;
+use crate::growth::GrowthTrend;
+use crate::maintenance::{read_last_checkpoint, LastCheckpoint};
+use crate::parquet_footer::{self, FileFooter};
+use crate::partitions::partition_key;
+use crate::requests;
+use crate::row_tracking::{RowTrackingFiles, RowTrackingInfo};
+use crate::schema::TableSchema;
+use crate::schema_evolution::SchemaEvolution;
+use crate::streaming::{hours_since, CheckpointState, StreamLag, StreamRole, StreamingWriter};
+use crate::time_travel::TimeTravelWindow;
+use anyhow::{bail, Context, Result};
 use chrono::{DateTime, Utc};
-use deltalake::DeltaTable;
+use deltalake::kernel::{CommitInfo, Metadata, Protocol, StorageType, StructType, WriterFeatures};
+use deltalake::storage::ObjectStoreRef;
+use deltalake::table::builder::ensure_table_uri;
+use deltalake::{DeltaTable, DeltaTableBuilder};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use object_store::path::Path as ObjectPath;
+use parquet::file::footer::{decode_footer, decode_metadata};
+use parquet::file::FOOTER_SIZE;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileInfo {
@@ -11,18 +43,53 @@ pub struct FileInfo {
     pub size_bytes: i64,
     pub modification_time: DateTime<Utc>,
     pub partition_values: HashMap<String, String>,
+    pub num_records: Option<i64>,
+    /// Per-column stats from the add action, keyed by dotted column name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub min_values: HashMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub max_values: HashMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub null_counts: HashMap<String, i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletion_vector: Option<FileDeletionVector>,
+    /// Added with dataChange=false, i.e. rewritten by OPTIMIZE/ZORDER or a
+    /// similar compaction rather than by new data arriving
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub rewritten: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Subset of the per-file `stats` JSON written on add actions.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileStats {
+    num_records: Option<i64>,
+    #[serde(default)]
+    min_values: serde_json::Value,
+    #[serde(default)]
+    max_values: serde_json::Value,
+    #[serde(default)]
+    null_count: serde_json::Value,
+}
+
+impl FileStats {
+    fn parse(stats: Option<&str>) -> Option<Self> {
+        stats.and_then(|s| serde_json::from_str(s).ok())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TableStatistics {
     pub table_path: String,
     pub version: i64,
     pub num_files: usize,
     pub total_size_bytes: i64,
-    pub schema: HashMap<String, String>,
+    /// Columns in table order, nested types included
+    pub schema: TableSchema,
     pub partition_columns: Vec<String>,
     pub num_rows: Option<i64>,
     pub files: Vec<FileInfo>,
+    pub column_stats: Vec<ColumnStats>,
     pub metadata: TableMetadata,
     pub total_versions: usize,
     pub oldest_version: i64,
@@ -33,9 +100,131 @@ pub struct TableStatistics {
     pub created_time: Option<DateTime<Utc>>,
     pub last_operation: Option<OperationInfo>,
     pub last_vacuum: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_optimize: Option<DateTime<Utc>>,
+    /// How often OPTIMIZE and VACUUM ran over the retained history
+    #[serde(default)]
+    pub maintenance_cadence: MaintenanceCadence,
+    /// Versions time travel can reach; unknown in exports that predate it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_travel: Option<TimeTravelWindow>,
+    /// Table properties (`delta.*` and user-defined)
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    /// How many replaceWhere/dynamic overwrite commits touched each partition
+    pub partition_rewrites: HashMap<String, usize>,
+    /// Projected ZSTD savings; only filled by `estimate_zstd_savings`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression_estimate: Option<CompressionEstimate>,
+    /// Codecs from Parquet footers; only filled by `audit_codecs`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub codec_audit: Option<CodecAudit>,
+    /// Lag of configured CDF consumers; only filled by `get_cdf_consumer_lag`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cdf_consumers: Vec<ConsumerLag>,
+    /// What VACUUM at the table's retention would delete; unknown when the
+    /// tombstones couldn't be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reclaimable: Option<ReclaimableStorage>,
+    /// Monthly storage cost; only filled by `enrich_statistics`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<CostEstimate>,
+    /// Set when a writer was committing while the table was read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_activity: Option<WriteActivity>,
+    /// How far the latest checkpoint trails `version`; unknown when
+    /// `_last_checkpoint` couldn't be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint_lag: Option<CheckpointLag>,
+    /// Size over the most recent commits; unknown when fewer than two of
+    /// them could be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub growth: Option<GrowthTrend>,
+    /// Rows MERGE/UPDATE/DELETE rewrote per row they changed; unknown when
+    /// none of those commits recorded metrics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_amplification: Option<WriteAmplification>,
+    /// Applications recording progress in `txn` actions, most recent first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub streaming_writers: Vec<StreamingWriter>,
+    /// Commits that overlapped other writers; unknown when the history has
+    /// nothing but blind appends
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_contention: Option<WriteContention>,
+    /// Deleted rows and vector storage; `None` when no file has a deletion vector
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletion_vectors: Option<DeletionVectorStats>,
+    /// Generated and identity columns
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generated_columns: Vec<GeneratedColumn>,
+    /// How well min/max statistics skip files, per column with statistics
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data_skipping: Vec<ColumnSkipping>,
+    /// Schema changes among the most recent commits, newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schema_evolution: Vec<SchemaEvolution>,
 }
 
+/// Distance between the latest checkpoint and the loaded version, per
+/// `_last_checkpoint`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointLag {
+    /// `None` when the table has never been checkpointed
+    pub checkpoint_version: Option<i64>,
+    /// JSON commits a reader replays on top of the checkpoint
+    pub commits_since_checkpoint: i64,
+}
+
+impl CheckpointLag {
+    fn new(version: i64, last_checkpoint: Option<&LastCheckpoint>) -> Self {
+        let checkpoint_version = last_checkpoint.map(|last| last.version);
+        Self {
+            checkpoint_version,
+            commits_since_checkpoint: checkpoint_version.map_or(version + 1, |cp| version - cp),
+        }
+    }
+}
+
+/// What VACUUM would free, counted from the snapshot's tombstones and so a
+/// lower bound; see [`DeltaTableInspector::get_reclaimable_storage`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReclaimableStorage {
+    pub retention_hours: i64,
+    pub files: usize,
+    pub bytes: i64,
+    /// Every removed file still in storage, within the retention or not
+    #[serde(default)]
+    pub tombstoned_files: usize,
+    #[serde(default)]
+    pub tombstoned_bytes: i64,
+}
+
+/// Signs of a writer committing to the table while it was being read. Every
+/// statistic still comes from the single snapshot loaded up front, so the
+/// numbers are consistent with each other, just not necessarily current.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteActivity {
+    /// Version the statistics were computed from
+    pub pinned_version: i64,
+    /// Newest commit in the log once the statistics were done
+    pub latest_version: i64,
+    /// Commit time of the pinned version
+    pub last_commit: Option<DateTime<Utc>>,
+    /// Commit files being written but not yet renamed into place
+    pub pending_commits: usize,
+}
+
+impl WriteActivity {
+    /// The marker shown on reports and in the TUI.
+    pub fn notice(&self) -> String {
+        format!(
+            "table is actively being written; snapshot pinned at version {}",
+            self.pinned_version
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TableMetadata {
     pub id: Option<String>,
     pub name: Option<String>,
@@ -57,18 +246,32 @@ pub struct DeltaTableInspector {
 }
 
 impl DeltaTableInspector {
-    pub async fn new(table_path: &str) -> Result<Self> {
-        let storage_options = Self::get_storage_options(table_path)?;
-        
-        let table = if let Some(options) = storage_options {
-            DeltaTable::new_with_options(table_path, options)
-                .await
-                .context("Failed to open Delta table")?
-        } else {
-            DeltaTable::new(table_path)
-                .await
-                .context("Failed to open Delta table")?
-        };
+    const FOOTER_READ_CONCURRENCY: usize = 16;
+    /// Files without a `numRecords` stat whose footer is read for it
+    const ROW_COUNT_SAMPLE_FILES: usize = 1_000;
+    /// Largest footer read for a row count; wide schemas with many row
+    /// groups stay well below it
+    const MAX_FOOTER_BYTES: usize = 16 * 1024 * 1024;
+    /// Most recent commits read to chart growth and find streaming writers
+    const RECENT_COMMITS: i64 = 200;
+    /// A commit this recent suggests a writer is still running
+    const ACTIVE_WRITE_WINDOW_MINUTES: i64 = 5;
+
+    pub async fn new(table_path: &str, storage_options: HashMap<String, String>) -> Result<Self> {
+        let storage_options = Self::get_storage_options(table_path, storage_options)?.unwrap_or_default();
+
+        // Route every request the table makes through the request meter
+        let store = DeltaTableBuilder::from_uri(table_path)
+            .with_storage_options(storage_options.clone())
+            .build_storage()
+            .context("Failed to open Delta table")?
+            .object_store();
+        let table = DeltaTableBuilder::from_uri(table_path)
+            .with_storage_backend(requests::metered(store), ensure_table_uri(table_path)?)
+            .with_storage_options(storage_options)
+            .load()
+            .await
+            .context("Failed to open Delta table")?;
 
         Ok(Self {
             table_path: table_path.to_string(),
@@ -78,76 +281,122 @@ impl DeltaTableInspector {
 
     fn get_storage_options(
         table_path: &str,
+        user_options: HashMap<String, String>,
     ) -> Result<Option<HashMap<String, String>>> {
+        let mut options = HashMap::new();
+
         if table_path.starts_with("abfss://") || table_path.starts_with("az://") {
             // Azure storage support would be implemented here
-            // For now, return None and let deltalake handle it
+            // For now, let deltalake handle it
             // In a full implementation, we'd use azure_identity here
+        }
+
+        // User-supplied options always win over anything derived above
+        options.extend(user_options);
+
+        if options.is_empty() {
             Ok(None)
         } else {
-            Ok(None)
+            Ok(Some(options))
         }
     }
 
+    /// A second inspector over the same table, loaded at an earlier or later version.
+    pub async fn at_version(&self, version: i64) -> Result<Self> {
+        let mut table = self.table.clone();
+        table
+            .load_version(version)
+            .await
+            .with_context(|| format!("Failed to load version {}", version))?;
+
+        Ok(Self {
+            table_path: self.table_path.clone(),
+            table,
+        })
+    }
+
+    pub fn table_path(&self) -> &str {
+        &self.table_path
+    }
+
+    /// The underlying table snapshot, e.g. for registering with DataFusion.
+    pub fn table(&self) -> &DeltaTable {
+        &self.table
+    }
+
     pub async fn get_statistics(&self) -> Result<TableStatistics> {
         let version = self.table.version();
-        let schema = self.get_schema_dict().await?;
-        let metadata = self.table.metadata();
+        let schema = self.get_schema()?;
+        let metadata = self.table.metadata()?;
 
         let partition_columns = metadata.partition_columns.clone();
 
-        // Get file information
-        // Note: The actual deltalake API may differ - check documentation
-        // Common approaches: get_files(), get_add_actions(), or scan_files()
         let mut files_info = Vec::new();
         let mut total_size = 0i64;
+        for action in self.table.snapshot()?.file_actions()? {
+            total_size += action.size;
 
-        // Try to get files using get_add_actions (common deltalake API)
-        // This may need adjustment based on actual crate version
-        match self.table.get_add_actions(true).await {
-            Ok(add_actions) => {
-                for action in add_actions.iter() {
-                    let size = action.size.unwrap_or(0);
-                    total_size += size;
-
-                    let mut partition_values = HashMap::new();
-                    if let Some(partition_values_map) = &action.partition_values {
-                        for (key, value) in partition_values_map {
-                            if let Some(val) = value {
-                                partition_values.insert(key.clone(), val.to_string());
-                            }
-                        }
-                    }
-
-                    let modification_time = action.modification_time
-                        .map(|ts| DateTime::from_timestamp(ts / 1000, 0).unwrap_or_default())
-                        .unwrap_or_else(Utc::now);
-
-                    files_info.push(FileInfo {
-                        path: action.path.clone(),
-                        size_bytes: size,
-                        modification_time,
-                        partition_values,
-                    });
-                }
-            }
-            Err(_) => {
-                // If get_add_actions doesn't work, try alternative API
-                // This is a fallback - adjust based on actual deltalake crate API
-            }
+            let partition_values = action
+                .partition_values
+                .iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.clone()?)))
+                .collect();
+
+            let modification_time = DateTime::from_timestamp(action.modification_time / 1000, 0).unwrap_or_default();
+
+            let file_stats = FileStats::parse(action.stats.as_deref()).unwrap_or_default();
+
+            let mut min_values = HashMap::new();
+            let mut max_values = HashMap::new();
+            let mut null_counts = HashMap::new();
+            flatten_stats(&file_stats.min_values, "", &mut min_values, &|v| Some(v.clone()));
+            flatten_stats(&file_stats.max_values, "", &mut max_values, &|v| Some(v.clone()));
+            flatten_stats(&file_stats.null_count, "", &mut null_counts, &|v| v.as_i64());
+
+            let deletion_vector = action.deletion_vector.as_ref().map(|dv| FileDeletionVector {
+                cardinality: dv.cardinality,
+                size_bytes: dv.size_in_bytes as i64,
+                inline: dv.storage_type == StorageType::Inline,
+            });
+
+            files_info.push(FileInfo {
+                path: action.path.clone(),
+                size_bytes: action.size,
+                modification_time,
+                partition_values,
+                num_records: file_stats.num_records,
+                min_values,
+                max_values,
+                null_counts,
+                deletion_vector,
+                rewritten: !action.data_change,
+            });
         }
 
         let num_files = files_info.len();
 
+        // Files written without stats fall back to reading their parquet footer
+        self.fill_missing_row_counts(&mut files_info).await;
+        let num_rows = if files_info.iter().all(|f| f.num_records.is_some()) {
+            Some(files_info.iter().filter_map(|f| f.num_records).sum())
+        } else {
+            None
+        };
+
+        let column_stats = column_stats::aggregate(&files_info);
+        let properties = table_properties(metadata);
+        let data_skipping = data_skipping::analyze(&files_info, &schema.data_skipping_columns(&properties, &partition_columns));
+        let deletion_vectors = DeletionVectorStats::from_files(&files_info);
+
         // Get Delta-specific information
-        let protocol = self.table.protocol();
-        let history = self.table.history().await?;
+        let protocol = self.table.protocol()?;
+        let history = self.get_history(false).await?;
 
         let total_versions = history.len();
         let min_reader_version = protocol.min_reader_version;
         let min_writer_version = protocol.min_writer_version;
-        let reader_features = protocol.reader_features.unwrap_or_default();
-        let writer_features = protocol.writer_features.unwrap_or_default();
+        let reader_features = feature_names(&protocol.reader_features);
+        let writer_features = feature_names(&protocol.writer_features);
 
         // Get created time from metadata
         let created_time = metadata.created_time
@@ -164,16 +413,14 @@ impl DeltaTableInspector {
                 operation: entry.operation.clone().unwrap_or_default(),
                 timestamp,
                 parameters: entry.operation_parameters.clone().unwrap_or_default(),
-                metrics: HashMap::new(), // operation_metrics doesn't exist in deltalake 0.18
+                metrics: operation_metrics(entry),
             }
         });
 
-        // Check for last vacuum operation
-        let last_vacuum = history.iter()
-            .find(|entry| entry.operation.as_deref() == Some("VACUUM"))
-            .and_then(|entry| {
-                DateTime::from_timestamp(entry.timestamp.unwrap_or(0) / 1000, 0)
-            });
+        let maintenance_cadence = MaintenanceCadence::from_history(&history);
+        let time_travel = TimeTravelWindow::from_history(version, &properties, &history);
+        let last_vacuum = maintenance_cadence.vacuum.last_run;
+        let last_optimize = maintenance_cadence.optimize.last_run;
 
         // Get oldest available version
         let oldest_version = history.iter()
@@ -181,15 +428,40 @@ impl DeltaTableInspector {
             .min()
             .unwrap_or(0);
 
+        let partition_rewrites = if partition_columns.is_empty() {
+            HashMap::new()
+        } else {
+            self.count_partition_rewrites(&history).await
+        };
+
+        let recent_commits = self.read_recent_commits(Self::RECENT_COMMITS).await;
+        let growth = Self::growth_trend(total_size, num_files as i64, &recent_commits);
+        let streaming_writers = self.streaming_writers(&recent_commits);
+        let schema_evolution = self.schema_evolution(&recent_commits).await;
+        let write_amplification = WriteAmplification::from_history(&history);
+        let write_contention = WriteContention::from_history(&history);
+
+        // Tombstone replay can fail on tables with expired logs; report it as unknown
+        let reclaimable = self.get_reclaimable_storage().await.ok();
+
+        // Checked last so commits landing during the reads above are seen
+        let last_commit = last_operation.as_ref().map(|op| op.timestamp);
+        let write_activity = self.get_write_activity(last_commit).await.ok().flatten();
+        let checkpoint_lag = read_last_checkpoint(&self.table)
+            .await
+            .ok()
+            .map(|last| CheckpointLag::new(version, last.as_ref()));
+
         Ok(TableStatistics {
             table_path: self.table_path.clone(),
-            version: version as i64,
+            version,
             num_files,
             total_size_bytes: total_size,
             schema,
             partition_columns,
-            num_rows: None,
+            num_rows,
             files: files_info,
+            column_stats,
             metadata: TableMetadata {
                 id: Some(metadata.id.to_string()),
                 name: metadata.name.clone(),
@@ -200,31 +472,487 @@ impl DeltaTableInspector {
             oldest_version: oldest_version as i64,
             min_reader_version,
             min_writer_version,
-            reader_features: reader_features.into_iter().collect(),
-            writer_features: writer_features.into_iter().collect(),
+            reader_features,
+            writer_features,
             created_time,
             last_operation,
             last_vacuum,
+            last_optimize,
+            maintenance_cadence,
+            time_travel: Some(time_travel),
+            properties,
+            partition_rewrites,
+            compression_estimate: None,
+            codec_audit: None,
+            cdf_consumers: Vec::new(),
+            reclaimable,
+            cost: None,
+            write_activity,
+            checkpoint_lag,
+            growth,
+            write_amplification,
+            streaming_writers,
+            write_contention,
+            deletion_vectors,
+            generated_columns: self.table.get_schema().map(GeneratedColumn::from_schema).unwrap_or_default(),
+            data_skipping,
+            schema_evolution,
         })
     }
 
-    async fn get_schema_dict(&self) -> Result<HashMap<String, String>> {
-        let schema = self.table.schema();
-        let mut result = HashMap::new();
+    /// Correlate a streaming checkpoint with this table: as a source, compare
+    /// the version it has read up to with the table's; as a sink, compare its
+    /// committed batches with the batch IDs recorded in the table's txn actions.
+    pub async fn get_stream_lag(&self, name: &str, checkpoint: CheckpointState) -> Result<StreamLag> {
+        let table_version = self.table.version();
+        let table_id = self.table.metadata()?.id.clone();
+        let in_flight_batches = StreamLag::in_flight(&checkpoint);
+
+        let mut lag = StreamLag {
+            name: name.to_string(),
+            role: StreamRole::Unrelated,
+            checkpoint,
+            table_version,
+            processed: None,
+            behind: None,
+            lag_hours: None,
+            in_flight_batches,
+        };
 
-        // Get Arrow schema from deltalake schema
-        // The exact API may vary - adjust based on deltalake crate version
-        let arrow_schema = schema.to_arrow()?;
-        for field in arrow_schema.fields() {
-            let type_str = format!("{:?}", field.data_type());
-            result.insert(field.name().clone(), type_str);
+        if let Some(source) = lag.checkpoint.delta_sources.iter().find(|s| s.reservoir_id == table_id) {
+            let processed = source.processed_version();
+            lag.role = StreamRole::Source;
+            lag.processed = Some(processed);
+            lag.behind = Some((table_version - processed).max(0));
+            lag.lag_hours = if processed >= table_version {
+                Some(0.0)
+            } else {
+                // Age of the first commit the stream hasn't read
+                self.read_commit_actions(processed + 1)
+                    .await
+                    .ok()
+                    .and_then(|actions| CommitSummary::from_actions(processed + 1, &actions).timestamp)
+                    .map(hours_since)
+            };
+            return Ok(lag);
+        }
+
+        let sink_version = lag.checkpoint.query_id.as_ref().and_then(|query_id| {
+            self.table.get_app_transaction_version().get(query_id).map(|txn| txn.version)
+        });
+        if let Some(batch) = sink_version {
+            lag.role = StreamRole::Sink;
+            lag.processed = Some(batch);
+            lag.behind = lag.checkpoint.last_committed_batch.map(|committed| (committed - batch).max(0));
+            lag.lag_hours = lag.checkpoint.last_batch_timestamp.map(hours_since);
+        }
+        Ok(lag)
+    }
+
+    /// Add the optional, config-driven parts of the statistics: the storage
+    /// cost estimate, the ZSTD savings estimate, the codec audit and CDF
+    /// consumer lag.
+    pub async fn enrich_statistics(&self, stats: &mut TableStatistics, config: &Config) -> Result<()> {
+        stats.cost = Some(CostEstimate::new(stats, &config.cost, &config.storage_class_for(&self.table_path)));
+        let consumers = config.cdf_consumers_for(&self.table_path);
+        if !consumers.is_empty() {
+            stats.cdf_consumers = self.get_cdf_consumer_lag(&consumers).await?;
+        }
+        if let Some(level) = config.zstd_estimate_level {
+            self.estimate_zstd_savings(stats, level).await;
+        }
+        if let Some(files) = config.codec_audit_files {
+            self.audit_codecs(stats, (files > 0).then_some(files), config.expected_codec.clone()).await;
+        }
+        Ok(())
+    }
+
+    /// How far each consumer is behind, and whether its unprocessed changes are
+    /// about to leave (or have left) the retention window.
+    pub async fn get_cdf_consumer_lag(&self, consumers: &[CdfConsumer]) -> Result<Vec<ConsumerLag>> {
+        let config = table_properties(self.table.metadata()?);
+        let hours = |key: &str, default: i64| {
+            config.get(key).and_then(|v| parse_interval_hours(v)).unwrap_or(default)
+        };
+        // Change data files go with VACUUM, commits with log cleanup
+        let retention_hours = hours("delta.deletedFileRetentionDuration", DEFAULT_DELETED_FILE_RETENTION_HOURS)
+            .min(hours("delta.logRetentionDuration", DEFAULT_LOG_RETENTION_HOURS));
+
+        let current_version = self.table.version();
+        let mut lags = Vec::new();
+        for consumer in consumers {
+            let next = consumer.last_processed_version + 1;
+            let next_commit = if next > current_version {
+                NextCommit::Read(None)
+            } else {
+                match self.read_commit_actions_if_present(next).await {
+                    Ok(Some(actions)) => NextCommit::Read(CommitSummary::from_actions(next, &actions).timestamp),
+                    Ok(None) => NextCommit::Cleaned,
+                    Err(e) => NextCommit::Unreadable(format!("{:#}", e)),
+                }
+            };
+            lags.push(ConsumerLag::new(consumer, current_version, next_commit, retention_hours));
+        }
+        Ok(lags)
+    }
+
+    /// Sample files and estimate what rewriting them with ZSTD at `level`
+    /// would save, storing the result on `stats` for the analyzer.
+    pub async fn estimate_zstd_savings(&self, stats: &mut TableStatistics, level: i32) {
+        stats.compression_estimate = compression::estimate_zstd_savings(&self.table, &stats.files, level).await;
+    }
+
+    /// Read the footers of `sample_files` files (all of them when `None`) and
+    /// store their codecs on `stats` for the analyzer.
+    pub async fn audit_codecs(&self, stats: &mut TableStatistics, sample_files: Option<usize>, expected: Option<String>) {
+        stats.codec_audit = Some(compression::audit_codecs(&self.table, &stats.files, sample_files, expected).await);
+    }
+
+    /// Removed files whose tombstones are older than the table's
+    /// `delta.deletedFileRetentionDuration`, i.e. what VACUUM would delete now,
+    /// and all tombstoned files. A lower bound: tombstones dropped when a
+    /// checkpoint was written and files never referenced by the log, both of
+    /// which VACUUM also deletes, aren't counted. Only
+    /// [`crate::maintenance::vacuum_dry_run`] lists storage for the full set.
+    pub async fn get_reclaimable_storage(&self) -> Result<ReclaimableStorage> {
+        let config = table_properties(self.table.metadata()?);
+        let retention_hours = config.get("delta.deletedFileRetentionDuration")
+            .and_then(|v| parse_interval_hours(v))
+            .unwrap_or(DEFAULT_DELETED_FILE_RETENTION_HOURS);
+        let cutoff = Utc::now().timestamp_millis() - retention_hours * 60 * 60 * 1000;
+
+        let tombstones = self.table.snapshot()?
+            .all_tombstones(self.table.object_store())
+            .await?;
+        let mut reclaimable = ReclaimableStorage {
+            retention_hours,
+            files: 0,
+            bytes: 0,
+            tombstoned_files: 0,
+            tombstoned_bytes: 0,
+        };
+        for remove in tombstones {
+            let size = remove.size.unwrap_or(0);
+            reclaimable.tombstoned_files += 1;
+            reclaimable.tombstoned_bytes += size;
+            if remove.deletion_timestamp.unwrap_or(0) < cutoff {
+                reclaimable.files += 1;
+                reclaimable.bytes += size;
+            }
         }
+        Ok(reclaimable)
+    }
+
+    /// Whether a writer is committing to the table: commits newer than the
+    /// loaded snapshot, commit files still being written, or a very recent
+    /// commit. Only the log past the snapshot version is listed.
+    pub async fn get_write_activity(&self, last_commit: Option<DateTime<Utc>>) -> Result<Option<WriteActivity>> {
+        let pinned_version = self.table.version();
+        let offset = ObjectPath::from(format!("_delta_log/{:020}.json", pinned_version));
+        let newer: Vec<String> = self.table.object_store()
+            .list_with_offset(Some(&ObjectPath::from("_delta_log")), &offset)
+            .try_filter_map(|meta| async move { Ok(meta.location.filename().map(str::to_string)) })
+            .try_collect()
+            .await?;
+
+        let latest_version = newer.iter()
+            .filter_map(|name| name.strip_suffix(".json"))
+            .filter_map(|version| version.parse::<i64>().ok())
+            .fold(pinned_version, i64::max);
+        // deltalake and delta-rs write _commit_<uuid>.json.tmp before the
+        // atomic rename; Hadoop-based writers use .<version>.json.<uuid>.tmp,
+        // which sorts before the offset and isn't seen
+        let pending_commits = newer.iter().filter(|name| name.ends_with(".tmp")).count();
+        let recent = last_commit.is_some_and(|t| {
+            Utc::now() - t < chrono::Duration::minutes(Self::ACTIVE_WRITE_WINDOW_MINUTES)
+        });
+
+        if latest_version == pinned_version && pending_commits == 0 && !recent {
+            return Ok(None);
+        }
+        Ok(Some(WriteActivity {
+            pinned_version,
+            latest_version,
+            last_commit,
+            pending_commits,
+        }))
+    }
+
+    /// Actions of up to `count` commits, newest first. Stops at the first
+    /// commit that can't be read, since log cleanup removes the oldest first.
+    async fn read_recent_commits(&self, count: i64) -> Vec<(i64, Vec<serde_json::Value>)> {
+        let current_version = self.table.version();
+        let oldest = (current_version - count + 1).max(0);
+        let results: Vec<Result<(i64, Vec<serde_json::Value>)>> = stream::iter((oldest..=current_version).rev())
+            .map(|version| async move { self.read_commit_actions(version).await.map(|actions| (version, actions)) })
+            .buffered(Self::FOOTER_READ_CONCURRENCY)
+            .collect()
+            .await;
+        results.into_iter().map_while(Result::ok).collect()
+    }
+
+    /// Size and file count after each of `recent_commits`, worked back from
+    /// the current `size_bytes` and `num_files`.
+    fn growth_trend(size_bytes: i64, num_files: i64, recent_commits: &[(i64, Vec<serde_json::Value>)]) -> Option<GrowthTrend> {
+        let commits: Vec<CommitSummary> = recent_commits
+            .iter()
+            .map(|(version, actions)| CommitSummary::from_actions(*version, actions))
+            .collect();
+        let trend = GrowthTrend::from_commits(size_bytes, num_files, &commits);
+        (trend.points.len() >= 2).then_some(trend)
+    }
+
+    /// Applications recording progress in the table's txn actions, with the
+    /// commit each last wrote among the most recent ones.
+    pub async fn get_streaming_writers(&self) -> Result<Vec<StreamingWriter>> {
+        let recent_commits = self.read_recent_commits(Self::RECENT_COMMITS).await;
+        Ok(self.streaming_writers(&recent_commits))
+    }
+
+    /// Every app ID in the snapshot's txn actions, with the latest of
+    /// `recent_commits` that recorded it.
+    fn streaming_writers(&self, recent_commits: &[(i64, Vec<serde_json::Value>)]) -> Vec<StreamingWriter> {
+        let mut writers: Vec<StreamingWriter> = self.table.get_app_transaction_version()
+            .into_values()
+            .map(|txn| StreamingWriter {
+                app_id: txn.app_id,
+                epoch: txn.version,
+                last_updated: txn.last_updated.and_then(DateTime::from_timestamp_millis),
+                last_commit_version: None,
+                last_commit: None,
+            })
+            .collect();
+
+        for (version, actions) in recent_commits {
+            let app_ids: HashSet<&str> = actions.iter()
+                .filter_map(|action| action.get("txn")?.get("appId")?.as_str())
+                .collect();
+            if app_ids.is_empty() {
+                continue;
+            }
+            let timestamp = CommitSummary::from_actions(*version, actions).timestamp;
+            for writer in writers.iter_mut().filter(|w| w.last_commit_version.is_none() && app_ids.contains(w.app_id.as_str())) {
+                writer.last_commit_version = Some(*version);
+                writer.last_commit = timestamp;
+            }
+        }
+
+        writers.sort_by(|a, b| b.last_seen().cmp(&a.last_seen()).then_with(|| a.app_id.cmp(&b.app_id)));
+        writers
+    }
+
+    /// Schema changes made by the `metaData` actions of `recent_commits`,
+    /// newest first. The schema before the oldest of them is read from the
+    /// version preceding it; if that version can't be loaded, the oldest
+    /// change only serves as the baseline for the next.
+    async fn schema_evolution(&self, recent_commits: &[(i64, Vec<serde_json::Value>)]) -> Vec<SchemaEvolution> {
+        let mut previous: Option<TableSchema> = None;
+        let mut changes = Vec::new();
+        for (version, actions) in recent_commits.iter().rev() {
+            let Some(schema) = actions.iter().find_map(|action| {
+                let schema_string = action.get("metaData")?.get("schemaString")?.as_str()?;
+                serde_json::from_str::<StructType>(schema_string).ok()
+            }) else {
+                continue;
+            };
+            let schema = TableSchema::from_struct(&schema);
+            if previous.is_none() && *version > 0 {
+                previous = self.at_version(version - 1).await.ok().and_then(|before| before.get_schema().ok());
+            }
+            if let Some(evolution) = previous.as_ref().and_then(|old| SchemaEvolution::between(*version, old, &schema)) {
+                let summary = CommitSummary::from_actions(*version, actions);
+                changes.push(SchemaEvolution {
+                    timestamp: summary.timestamp,
+                    operation: summary.operation,
+                    ..evolution
+                });
+            }
+            previous = Some(schema);
+        }
+        changes.reverse();
+        changes
+    }
 
-        Ok(result)
+    /// Read the raw actions of a single commit file, one JSON object per line.
+    pub async fn read_commit_actions(&self, version: i64) -> Result<Vec<serde_json::Value>> {
+        self.read_commit_actions_if_present(version)
+            .await?
+            .with_context(|| format!("Commit {} not found in the transaction log", version))
     }
 
-    pub async fn get_history(&self, reverse: bool) -> Result<Vec<deltalake::kernel::CommitInfo>> {
-        let mut history = self.table.history().await?;
+    /// Like [`Self::read_commit_actions`], but `None` when the commit file
+    /// doesn't exist (e.g. cleaned up after the log retention).
+    pub async fn read_commit_actions_if_present(&self, version: i64) -> Result<Option<Vec<serde_json::Value>>> {
+        let Some(bytes) = self.table.log_store().read_commit_entry(version).await? else {
+            return Ok(None);
+        };
+
+        let text = String::from_utf8_lossy(&bytes);
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("Malformed action in commit {}", version))
+            })
+            .collect::<Result<_>>()
+            .map(Some)
+    }
+
+    /// Count, per partition, the partition-scoped overwrite commits whose
+    /// remove actions touched it. Commits that can't be read are skipped.
+    async fn count_partition_rewrites(
+        &self,
+        history: &[CommitInfo],
+    ) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+
+        for entry in history {
+            if !Self::is_partition_overwrite(entry) {
+                continue;
+            }
+            let Some(version) = commit_version(entry) else {
+                continue;
+            };
+            let Ok(actions) = self.read_commit_actions(version).await else {
+                continue;
+            };
+
+            let touched: HashSet<String> = actions.iter()
+                .filter_map(|action| action.get("remove"))
+                .filter_map(|remove| remove.get("partitionValues"))
+                .filter_map(|values| {
+                    serde_json::from_value::<HashMap<String, Option<String>>>(values.clone()).ok()
+                })
+                .map(|values| {
+                    // Null values are left out, as in the files' partition values
+                    let values = values.into_iter()
+                        .filter_map(|(k, v)| Some((k, v?)))
+                        .collect();
+                    partition_key(&values)
+                })
+                .collect();
+
+            for key in touched {
+                *counts.entry(key).or_insert(0) += 1;
+            }
+        }
+
+        counts
+    }
+
+    fn is_partition_overwrite(entry: &CommitInfo) -> bool {
+        if entry.operation.as_deref() != Some("WRITE") {
+            return false;
+        }
+        let Some(params) = &entry.operation_parameters else {
+            return false;
+        };
+        let param = |name: &str| {
+            params.get(name).map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+        };
+
+        let is_overwrite = param("mode").is_some_and(|m| m.trim_matches('"').eq_ignore_ascii_case("overwrite"));
+        let is_scoped = param("predicate").is_some_and(|p| !p.trim_matches('"').is_empty() && p != "null")
+            || param("partitionOverwriteMode").is_some_and(|m| m.trim_matches('"').eq_ignore_ascii_case("dynamic"));
+        is_overwrite && is_scoped
+    }
+
+    /// Read row counts from parquet footers for files whose add action had no
+    /// `numRecords` stat, at most [`Self::ROW_COUNT_SAMPLE_FILES`] of them.
+    /// Files past that, files that can't be read and files whose footer is
+    /// over [`Self::MAX_FOOTER_BYTES`] are left as `None`.
+    async fn fill_missing_row_counts(&self, files: &mut [FileInfo]) {
+        let missing: Vec<usize> = files.iter()
+            .enumerate()
+            .filter(|(_, f)| f.num_records.is_none())
+            .map(|(idx, _)| idx)
+            .take(Self::ROW_COUNT_SAMPLE_FILES)
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+
+        let store = self.table.object_store();
+        let counts: Vec<(usize, Option<i64>)> = stream::iter(missing)
+            .map(|idx| {
+                let store = store.clone();
+                let path = ObjectPath::from(files[idx].path.as_str());
+                async move { (idx, Self::read_footer_row_count(store, path).await.ok()) }
+            })
+            .buffer_unordered(Self::FOOTER_READ_CONCURRENCY)
+            .collect()
+            .await;
+
+        for (idx, count) in counts {
+            files[idx].num_records = count;
+        }
+    }
+
+    /// Row count from the footer of the file at `path`, reading only the
+    /// footer and refusing ones larger than [`Self::MAX_FOOTER_BYTES`].
+    async fn read_footer_row_count(store: ObjectStoreRef, path: ObjectPath) -> Result<i64> {
+        let size = store.head(&path).await?.size;
+        if size < FOOTER_SIZE {
+            bail!("{} is too small to be a Parquet file", path);
+        }
+        let tail = store.get_range(&path, size - FOOTER_SIZE..size).await?;
+        let tail: &[u8; FOOTER_SIZE] = tail.as_ref().try_into()?;
+        let metadata_len = decode_footer(tail)?;
+        if metadata_len > Self::MAX_FOOTER_BYTES {
+            bail!("Footer of {} is {} bytes, over the {} read for row counts", path, metadata_len, Self::MAX_FOOTER_BYTES);
+        }
+        let start = (size - FOOTER_SIZE)
+            .checked_sub(metadata_len)
+            .with_context(|| format!("Footer of {} is larger than the file", path))?;
+        let metadata = store.get_range(&path, start..size - FOOTER_SIZE).await?;
+        Ok(decode_metadata(&metadata)?.file_metadata().num_rows())
+    }
+
+    /// Row groups, codecs, encodings and column chunk sizes of one data
+    /// file, `path` being relative to the table root as in its add action.
+    pub async fn get_file_footer(&self, path: &str) -> Result<FileFooter> {
+        parquet_footer::read_file_footer(self.table.object_store(), path).await
+    }
+
+    pub fn get_schema(&self) -> Result<TableSchema> {
+        Ok(TableSchema::from_struct(self.table.get_schema()?))
+    }
+
+    /// Commit info of the commits in the log up to the loaded version,
+    /// newest first unless `reverse`. Commits without a commitInfo action are
+    /// left out, so each entry's version is recorded in it; read it with
+    /// [`commit_version`].
+    pub async fn get_history(&self, reverse: bool) -> Result<Vec<CommitInfo>> {
+        let listing = self.table.object_store()
+            .list_with_delimiter(Some(&ObjectPath::from("_delta_log")))
+            .await
+            .context("Failed to list _delta_log")?;
+        let current_version = self.table.version();
+        let mut versions: Vec<i64> = listing.objects
+            .iter()
+            .filter_map(|meta| meta.location.filename()?.strip_suffix(".json")?.parse().ok())
+            .filter(|version| *version <= current_version)
+            .collect();
+        versions.sort_unstable_by(|a, b| b.cmp(a));
+
+        let commits: Vec<Option<CommitInfo>> = stream::iter(versions)
+            .map(|version| async move {
+                let actions = self.read_commit_actions_if_present(version).await?;
+                let info = actions
+                    .into_iter()
+                    .flatten()
+                    .find_map(|mut action| action.get_mut("commitInfo").map(serde_json::Value::take))
+                    .and_then(|info| serde_json::from_value::<CommitInfo>(info).ok());
+                Ok::<_, anyhow::Error>(info.map(|mut info| {
+                    info.info.insert("version".to_string(), version.into());
+                    info
+                }))
+            })
+            .buffered(Self::FOOTER_READ_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut history: Vec<CommitInfo> = commits.into_iter().flatten().collect();
         if reverse {
             history.reverse();
         }
@@ -235,17 +963,14 @@ impl DeltaTableInspector {
         let metadata = self.table.metadata()?;
         let protocol = self.table.protocol()?;
 
-        let table_config = metadata.configuration.clone().unwrap_or_default();
+        let table_config = table_properties(metadata);
 
-        // Get checkpoint information
-        let table_path = Path::new(&self.table_path);
-        let delta_log_path = table_path.join("_delta_log");
-
-        let mut checkpoint_info = CheckpointInfo {
-            has_checkpoints: false,
-            latest_checkpoint: None,
-            checkpoint_size_bytes: 0,
-        };
+        // List through the table's store so remote tables work too; direct
+        // children only, leaving out _sidecars/ and _staged_commits/
+        let listing = self.table.object_store()
+            .list_with_delimiter(Some(&ObjectPath::from("_delta_log")))
+            .await
+            .context("Failed to list _delta_log")?;
 
         let mut transaction_log_info = TransactionLogInfo {
             num_json_files: 0,
@@ -253,44 +978,61 @@ impl DeltaTableInspector {
             log_size_bytes: 0,
         };
 
-        if delta_log_path.exists() {
-            let json_files: Vec<_> = std::fs::read_dir(&delta_log_path)?
-                .filter_map(|entry| entry.ok())
-                .filter(|entry| {
-                    entry.path().extension().and_then(|s| s.to_str()) == Some("json")
-                })
-                .collect();
+        // Checkpoint version → name of its first part and size of all parts
+        let mut checkpoints: BTreeMap<i64, (String, i64)> = BTreeMap::new();
+        for meta in &listing.objects {
+            let Some(name) = meta.location.filename() else {
+                continue;
+            };
+            if name.contains(".checkpoint.") {
+                let Some(version) = name.get(..20).and_then(|v| v.parse::<i64>().ok()) else {
+                    continue;
+                };
+                let (first_part, size) = checkpoints.entry(version).or_insert_with(|| (name.to_string(), 0));
+                if name < first_part.as_str() {
+                    *first_part = name.to_string();
+                }
+                *size += meta.size as i64;
+            } else if name.ends_with(".json") {
+                transaction_log_info.num_json_files += 1;
+                transaction_log_info.log_size_bytes += meta.size as u64;
+            }
+        }
+        transaction_log_info.num_checkpoints = checkpoints.len();
 
-            let checkpoint_files: Vec<_> = std::fs::read_dir(&delta_log_path)?
-                .filter_map(|entry| entry.ok())
-                .filter(|entry| {
-                    entry.path().to_string_lossy().contains("checkpoint")
-                })
-                .collect();
+        let latest = checkpoints.into_iter().next_back();
+        let mut checkpoint_info = CheckpointInfo {
+            has_checkpoints: latest.is_some(),
+            latest_checkpoint: latest.as_ref().map(|(_, (name, _))| name.clone()),
+            checkpoint_size_bytes: latest.map_or(0, |(_, (_, size))| size),
+            last_checkpoint: None,
+            lag: None,
+        };
 
-            transaction_log_info.num_json_files = json_files.len();
-            transaction_log_info.num_checkpoints = checkpoint_files.len();
-            transaction_log_info.log_size_bytes = json_files.iter()
-                .filter_map(|entry| entry.metadata().ok())
-                .map(|meta| meta.len())
-                .sum();
-
-            if let Some(latest_checkpoint) = checkpoint_files.iter()
-                .max_by_key(|entry| {
-                    entry.metadata().and_then(|m| m.modified().ok()).unwrap_or_default()
-                }) {
-                checkpoint_info.has_checkpoints = true;
-                checkpoint_info.latest_checkpoint = Some(
-                    latest_checkpoint.path().file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("")
-                        .to_string()
-                );
-                checkpoint_info.checkpoint_size_bytes = latest_checkpoint.metadata()?.len() as i64;
-            }
+        // Readers find the latest checkpoint through _last_checkpoint, which
+        // lags the listing when a writer failed to update it
+        if let Ok(last_checkpoint) = read_last_checkpoint(&self.table).await {
+            checkpoint_info.lag = Some(CheckpointLag::new(self.table.version(), last_checkpoint.as_ref()));
+            checkpoint_info.last_checkpoint = last_checkpoint;
         }
 
-        let advanced_features = Self::detect_advanced_features(&table_config, &protocol);
+        let mut advanced_features = Self::detect_advanced_features(&table_config, protocol);
+        if advanced_features.row_tracking.supported {
+            advanced_features.row_tracking.files = self.get_row_tracking_files().await.ok();
+        }
+        let writer_features = feature_names(&protocol.writer_features);
+        let domains = if writer_features.iter().any(|name| name == "domainMetadata") {
+            read_domain_metadata(&self.table).await.unwrap_or_default()
+        } else {
+            BTreeMap::new()
+        };
+        if advanced_features.column_mapping.enabled {
+            self.fill_column_mapping(&mut advanced_features.column_mapping).await;
+        }
+        if advanced_features.clustering.supported {
+            advanced_features.clustering = self.get_clustering(domains.get(CLUSTERING_DOMAIN)).await;
+            advanced_features.liquid_clustering = advanced_features.clustering.enabled();
+        }
 
         Ok(ConfigurationInfo {
             table_properties: table_config,
@@ -298,28 +1040,61 @@ impl DeltaTableInspector {
             table_name: metadata.name.clone(),
             description: metadata.description.clone(),
             created_time: metadata.created_time,
-            partition_columns: metadata.partition_columns,
+            partition_columns: metadata.partition_columns.clone(),
             protocol: ProtocolInfo {
                 min_reader_version: protocol.min_reader_version,
                 min_writer_version: protocol.min_writer_version,
-                reader_features: protocol.reader_features.unwrap_or_default().into_iter().collect(),
-                writer_features: protocol.writer_features.unwrap_or_default().into_iter().collect(),
+                reader_features: feature_names(&protocol.reader_features),
+                writer_features,
             },
             checkpoint_info,
             transaction_log: transaction_log_info,
             advanced_features,
+            domain_metadata: domains.into_values().collect(),
         })
     }
 
+    /// Row IDs and commit versions the current files hand down to their rows.
+    async fn get_row_tracking_files(&self) -> Result<RowTrackingFiles> {
+        let mut files = RowTrackingFiles::default();
+        for action in self.table.snapshot()?.file_actions()? {
+            let num_records = FileStats::parse(action.stats.as_deref()).and_then(|stats| stats.num_records);
+            files.add(action.base_row_id, action.default_row_commit_version, num_records);
+        }
+        Ok(files)
+    }
+
+    /// Physical names and field IDs from the schema, and the files written
+    /// before mapping was turned on.
+    async fn fill_column_mapping(&self, mapping: &mut ColumnMappingInfo) {
+        if let Ok(schema) = self.table.get_schema() {
+            mapping.columns = MappedColumn::from_schema(schema);
+        }
+        let history = self.get_history(false).await.unwrap_or_default();
+        mapping.enabled_at = MappingEnabled::find(&history);
+        let Some(enabled) = mapping.enabled_at.as_ref().and_then(|enabled| enabled.timestamp) else {
+            return;
+        };
+        if let Ok(actions) = self.table.snapshot().and_then(|snapshot| snapshot.file_actions()) {
+            let enabled = enabled.timestamp_millis();
+            mapping.files_before_mapping = Some(actions.iter().filter(|action| action.modification_time < enabled).count());
+        }
+    }
+
+    /// Clustering columns from the `delta.clustering` domain, falling back to
+    /// the last CLUSTER BY in history when the log can't be replayed.
+    async fn get_clustering(&self, domain: Option<&DomainMetadata>) -> ClusteringInfo {
+        let history = self.get_history(false).await.unwrap_or_default();
+        ClusteringInfo::new(true, domain, self.table.get_schema().ok(), &history)
+    }
+
     fn detect_advanced_features(
         config: &HashMap<String, String>,
-        protocol: &deltalake::kernel::Protocol,
+        protocol: &Protocol,
     ) -> AdvancedFeatures {
-        let writer_features: Vec<String> = protocol.writer_features.clone()
-            .unwrap_or_default()
-            .into_iter()
-            .map(|f| format!("{:?}", f))
-            .collect();
+        let has_writer_feature = |feature: WriterFeatures| {
+            protocol.writer_features.as_ref().is_some_and(|features| features.contains(&feature))
+        };
 
         let column_mapping_mode = config.get("delta.columnMapping.mode")
             .cloned()
@@ -331,13 +1106,23 @@ impl DeltaTableInspector {
             .collect();
 
         AdvancedFeatures {
-            deletion_vectors: writer_features.contains(&"deletionVectors".to_string()),
+            deletion_vectors: has_writer_feature(WriterFeatures::DeletionVectors),
             column_mapping: ColumnMappingInfo {
                 enabled: column_mapping_mode != "none",
                 mode: column_mapping_mode,
+                columns: Vec::new(),
+                max_column_id: config.get("delta.columnMapping.maxColumnId").and_then(|v| v.parse().ok()),
+                enabled_at: None,
+                files_before_mapping: None,
+            },
+            // Set once the clustering columns are known
+            liquid_clustering: false,
+            clustering: ClusteringInfo {
+                supported: feature_names(&protocol.writer_features).iter().any(|name| name == "clustering"),
+                ..ClusteringInfo::default()
             },
-            liquid_clustering: config.contains_key("clustering"),
-            timestamp_ntz: writer_features.contains(&"timestampNtz".to_string()),
+            timestamp_ntz: has_writer_feature(WriterFeatures::TimestampWithoutTimezone),
+            row_tracking: RowTrackingInfo::from_config(config, has_writer_feature(WriterFeatures::RowTracking)),
             check_constraints,
             auto_optimize: AutoOptimizeInfo {
                 enabled: config.get("delta.autoOptimize.autoCompact")
@@ -363,15 +1148,19 @@ impl DeltaTableInspector {
                 .map(|v| v == "true")
                 .unwrap_or(false),
             vacuum_retention_hours: config.get("delta.deletedFileRetentionDuration")
-                .and_then(|v| {
-                    v.replace("hours", "").trim().parse::<i32>().ok()
-                })
-                .unwrap_or(168),
+                .and_then(|v| parse_interval_hours(v))
+                .map(|hours| hours as i32)
+                .unwrap_or(DEFAULT_DELETED_FILE_RETENTION_HOURS as i32),
+            log_retention_hours: config.get("delta.logRetentionDuration")
+                .and_then(|v| parse_interval_hours(v))
+                .map(|hours| hours as i32)
+                .unwrap_or(DEFAULT_LOG_RETENTION_HOURS as i32),
+            checkpoint_interval: checkpoint_interval(config),
         }
     }
 
     pub async fn get_timeline_analysis(&self) -> Result<TimelineAnalysis> {
-        let history = self.table.history().await?;
+        let history = self.get_history(false).await?;
 
         if history.is_empty() {
             return Ok(TimelineAnalysis {
@@ -382,6 +1171,7 @@ impl DeltaTableInspector {
                 write_patterns: Vec::new(),
                 first_operation: None,
                 latest_operation: None,
+                attribution: Attribution::default(),
             });
         }
 
@@ -393,12 +1183,12 @@ impl DeltaTableInspector {
         }
 
         // Group operations by day
-        let mut operations_by_day: HashMap<String, Vec<&deltalake::kernel::CommitInfo>> = HashMap::new();
+        let mut operations_by_day: HashMap<String, Vec<&CommitInfo>> = HashMap::new();
         for entry in &history {
             let timestamp = DateTime::from_timestamp(entry.timestamp.unwrap_or(0) / 1000, 0)
                 .unwrap_or_default();
             let day_key = timestamp.format("%Y-%m-%d").to_string();
-            operations_by_day.entry(day_key).or_insert_with(Vec::new).push(entry);
+            operations_by_day.entry(day_key).or_default().push(entry);
         }
 
         // Calculate version creation rate
@@ -430,10 +1220,11 @@ impl DeltaTableInspector {
             write_patterns,
             first_operation: history.last().cloned(),
             latest_operation: history.first().cloned(),
+            attribution: Attribution::from_history(&history),
         })
     }
 
-    fn analyze_write_patterns(history: &[deltalake::kernel::CommitInfo]) -> Vec<String> {
+    fn analyze_write_patterns(history: &[CommitInfo]) -> Vec<String> {
         let mut patterns = Vec::new();
 
         let writes: Vec<_> = history.iter()
@@ -471,6 +1262,75 @@ impl DeltaTableInspector {
     }
 }
 
+/// Protocol feature names as they appear in the log (`deletionVectors`, not
+/// the enum's Debug form), sorted so output is stable.
+fn feature_names<T: ToString>(features: &Option<HashSet<T>>) -> Vec<String> {
+    let mut names: Vec<String> = features.iter().flatten().map(ToString::to_string).collect();
+    names.sort();
+    names
+}
+
+/// Version of a commit returned by [`DeltaTableInspector::get_history`].
+pub fn commit_version(commit: &CommitInfo) -> Option<i64> {
+    commit.info.get("version").and_then(serde_json::Value::as_i64)
+}
+
+/// Table properties set in the metadata. Keys the log sets to null are
+/// left out, as if they weren't set.
+pub(crate) fn table_properties(metadata: &Metadata) -> HashMap<String, String> {
+    metadata
+        .configuration
+        .iter()
+        .filter_map(|(key, value)| Some((key.clone(), value.clone()?)))
+        .collect()
+}
+
+/// `operationMetrics` of a commit (numFiles, numOutputRows, ...). deltalake
+/// keeps fields it doesn't model in `info`.
+pub(crate) fn operation_metrics(commit: &CommitInfo) -> HashMap<String, serde_json::Value> {
+    commit.info
+        .get("operationMetrics")
+        .and_then(serde_json::Value::as_object)
+        .map(|metrics| metrics.clone().into_iter().collect())
+        .unwrap_or_default()
+}
+
+/// Delta's default for `delta.deletedFileRetentionDuration` (7 days).
+pub const DEFAULT_DELETED_FILE_RETENTION_HOURS: i64 = 168;
+
+/// Delta's default `delta.logRetentionDuration` (30 days).
+pub const DEFAULT_LOG_RETENTION_HOURS: i64 = 30 * 24;
+
+/// Delta's default `delta.checkpointInterval`: a checkpoint every 10 commits.
+pub const DEFAULT_CHECKPOINT_INTERVAL: i64 = 10;
+
+/// Commits between checkpoints, per `delta.checkpointInterval`.
+pub fn checkpoint_interval(properties: &HashMap<String, String>) -> i64 {
+    properties
+        .get("delta.checkpointInterval")
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|interval| *interval > 0)
+        .unwrap_or(DEFAULT_CHECKPOINT_INTERVAL)
+}
+
+/// Parse a Delta interval property such as `interval 7 days` or `168 hours`
+/// into whole hours.
+pub fn parse_interval_hours(value: &str) -> Option<i64> {
+    let value = value.trim().to_lowercase();
+    let value = value.strip_prefix("interval").unwrap_or(&value).trim();
+    let mut parts = value.split_whitespace();
+    let amount: f64 = parts.next()?.parse().ok()?;
+    let hours = match parts.next().unwrap_or("hours").trim_end_matches('s') {
+        "second" => amount / 3600.0,
+        "minute" => amount / 60.0,
+        "hour" => amount,
+        "day" => amount * 24.0,
+        "week" => amount * 24.0 * 7.0,
+        _ => return None,
+    };
+    Some(hours.round() as i64)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConfigurationInfo {
     pub table_properties: HashMap<String, String>,
@@ -483,6 +1343,9 @@ pub struct ConfigurationInfo {
     pub checkpoint_info: CheckpointInfo,
     pub transaction_log: TransactionLogInfo,
     pub advanced_features: AdvancedFeatures,
+    /// Latest `domainMetadata` action per domain, by name
+    #[serde(default)]
+    pub domain_metadata: Vec<DomainMetadata>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -498,6 +1361,11 @@ pub struct CheckpointInfo {
     pub has_checkpoints: bool,
     pub latest_checkpoint: Option<String>,
     pub checkpoint_size_bytes: i64,
+    /// Parsed `_last_checkpoint`
+    #[serde(default)]
+    pub last_checkpoint: Option<LastCheckpoint>,
+    #[serde(default)]
+    pub lag: Option<CheckpointLag>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -511,19 +1379,50 @@ pub struct TransactionLogInfo {
 pub struct AdvancedFeatures {
     pub deletion_vectors: bool,
     pub column_mapping: ColumnMappingInfo,
+    /// Same as `clustering.enabled()`
     pub liquid_clustering: bool,
+    #[serde(default)]
+    pub clustering: ClusteringInfo,
     pub timestamp_ntz: bool,
+    #[serde(default)]
+    pub row_tracking: RowTrackingInfo,
     pub check_constraints: HashMap<String, String>,
     pub auto_optimize: AutoOptimizeInfo,
     pub data_skipping: DataSkippingInfo,
     pub change_data_feed: bool,
     pub vacuum_retention_hours: i32,
+    /// `delta.logRetentionDuration`
+    #[serde(default = "default_log_retention_hours")]
+    pub log_retention_hours: i32,
+    /// `delta.checkpointInterval`
+    #[serde(default = "default_checkpoint_interval")]
+    pub checkpoint_interval: i64,
+}
+
+fn default_log_retention_hours() -> i32 {
+    DEFAULT_LOG_RETENTION_HOURS as i32
+}
+
+fn default_checkpoint_interval() -> i64 {
+    DEFAULT_CHECKPOINT_INTERVAL
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColumnMappingInfo {
     pub enabled: bool,
     pub mode: String,
+    /// Logical to physical names and field IDs; only read when enabled
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<MappedColumn>,
+    /// `delta.columnMapping.maxColumnId`
+    #[serde(default)]
+    pub max_column_id: Option<i64>,
+    /// When mapping was turned on for an existing table
+    #[serde(default)]
+    pub enabled_at: Option<MappingEnabled>,
+    /// Current files written before `enabled_at`, still under logical names
+    #[serde(default)]
+    pub files_before_mapping: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -543,10 +1442,154 @@ pub struct DataSkippingInfo {
 pub struct TimelineAnalysis {
     pub total_operations: usize,
     pub operations_by_type: HashMap<String, i32>,
-    pub operations_by_day: HashMap<String, Vec<deltalake::kernel::CommitInfo>>,
+    pub operations_by_day: HashMap<String, Vec<CommitInfo>>,
     pub version_creation_rate: f64,
     pub write_patterns: Vec<String>,
-    pub first_operation: Option<deltalake::kernel::CommitInfo>,
-    pub latest_operation: Option<deltalake::kernel::CommitInfo>,
+    pub first_operation: Option<CommitInfo>,
+    pub latest_operation: Option<CommitInfo>,
+    /// Engines, users and jobs writing the table
+    #[serde(default)]
+    pub attribution: Attribution,
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use std::fs;
+    use tempfile::TempDir;
+
+    const SCHEMA: &str = r#"{"type":"struct","fields":[{"name":"id","type":"long","nullable":false,"metadata":{}},{"name":"date","type":"string","nullable":true,"metadata":{}}]}"#;
+
+    /// Write each commit's actions as versions 0.. of a `_delta_log`. Version 0
+    /// also gets the protocol and a schema partitioned by `date`; no data files
+    /// are written.
+    fn table(commits: &[Vec<Value>]) -> TempDir {
+        let dir = TempDir::new().expect("create temp dir");
+        let log = dir.path().join("_delta_log");
+        fs::create_dir(&log).expect("create _delta_log");
+        for (version, actions) in commits.iter().enumerate() {
+            let mut lines = Vec::new();
+            if version == 0 {
+                lines.push(json!({ "protocol": { "minReaderVersion": 1, "minWriterVersion": 2 } }));
+                lines.push(json!({ "metaData": {
+                    "id": "00000000-0000-0000-0000-000000000000",
+                    "format": { "provider": "parquet", "options": {} },
+                    "schemaString": SCHEMA,
+                    "partitionColumns": ["date"],
+                    "configuration": {},
+                    "createdTime": 1_700_000_000_000_i64,
+                } }));
+            }
+            lines.extend(actions.iter().cloned());
+            let body: Vec<String> = lines.iter().map(Value::to_string).collect();
+            fs::write(log.join(format!("{:020}.json", version)), body.join("\n")).expect("write commit");
+        }
+        dir
+    }
+
+    fn commit(version: i64, operation: &str, parameters: Value, read_version: Option<i64>) -> Value {
+        json!({ "commitInfo": {
+            "timestamp": 1_700_000_000_000_i64 + version * 60_000,
+            "operation": operation,
+            "operationParameters": parameters,
+            "readVersion": read_version,
+        } })
+    }
+
+    fn add(path: &str, date: &str) -> Value {
+        json!({ "add": {
+            "path": path,
+            "partitionValues": { "date": date },
+            "size": 1_000,
+            "modificationTime": 1_700_000_000_000_i64,
+            "dataChange": true,
+            "stats": json!({ "numRecords": 10 }).to_string(),
+        } })
+    }
+
+    fn remove(path: &str, date: &str) -> Value {
+        json!({ "remove": {
+            "path": path,
+            "partitionValues": { "date": date },
+            "deletionTimestamp": 1_700_000_000_000_i64,
+            "dataChange": true,
+        } })
+    }
+
+    async fn statistics(dir: &TempDir) -> TableStatistics {
+        let inspector = DeltaTableInspector::new(dir.path().to_str().unwrap(), HashMap::new())
+            .await
+            .expect("open table");
+        inspector.get_statistics().await.expect("statistics")
+    }
+
+    #[tokio::test]
+    async fn counts_repeated_partition_overwrites() {
+        let overwrite = json!({ "mode": "Overwrite", "predicate": "date = '2024-01-01'" });
+        let dir = table(&[
+            vec![commit(0, "WRITE", json!({ "mode": "Append" }), None), add("date=2024-01-01/a.parquet", "2024-01-01")],
+            vec![
+                commit(1, "WRITE", overwrite.clone(), Some(0)),
+                remove("date=2024-01-01/a.parquet", "2024-01-01"),
+                add("date=2024-01-01/b.parquet", "2024-01-01"),
+            ],
+            vec![
+                commit(2, "WRITE", overwrite, Some(1)),
+                remove("date=2024-01-01/b.parquet", "2024-01-01"),
+                add("date=2024-01-01/c.parquet", "2024-01-01"),
+            ],
+        ]);
+        let stats = statistics(&dir).await;
+        assert_eq!(stats.partition_rewrites.get("date=2024-01-01"), Some(&2));
+    }
+
+    #[tokio::test]
+    async fn time_travel_window_starts_at_the_first_commit_of_an_unvacuumed_log() {
+        let append = || json!({ "mode": "Append" });
+        let dir = table(&[
+            vec![commit(0, "WRITE", append(), None), add("date=2024-01-01/a.parquet", "2024-01-01")],
+            vec![commit(1, "WRITE", append(), Some(0)), add("date=2024-01-02/b.parquet", "2024-01-02")],
+            vec![commit(2, "WRITE", append(), Some(1)), add("date=2024-01-03/c.parquet", "2024-01-03")],
+        ]);
+        let window = statistics(&dir).await.time_travel.expect("time travel window");
+        assert_eq!(window.oldest_logged_version, 0);
+        assert_eq!(window.oldest_logged_time, DateTime::from_timestamp_millis(1_700_000_000_000));
+        assert_eq!(window.oldest_readable_version, 0);
+        assert_eq!(window.readable_versions(), 3);
+    }
+
+    #[tokio::test]
+    async fn counts_commits_that_read_a_stale_version_as_contention() {
+        let merge = || json!({ "predicate": "target.id = source.id" });
+        let dir = table(&[
+            vec![commit(0, "WRITE", json!({ "mode": "Append" }), None), add("date=2024-01-01/a.parquet", "2024-01-01")],
+            vec![commit(1, "MERGE", merge(), Some(0)), add("date=2024-01-01/b.parquet", "2024-01-01")],
+            vec![commit(2, "MERGE", merge(), Some(0)), add("date=2024-01-01/c.parquet", "2024-01-01")],
+            vec![commit(3, "UPDATE", json!({}), Some(0)), add("date=2024-01-01/d.parquet", "2024-01-01")],
+        ]);
+        let contention = statistics(&dir).await.write_contention.expect("conflict-prone commits");
+        assert_eq!(contention.overlapping_commits, 2);
+        assert_eq!(contention.max_overlap, 2);
+        assert_eq!(contention.by_operation.get("MERGE"), Some(&1));
+        assert_eq!(contention.by_operation.get("UPDATE"), Some(&1));
+    }
+
+    #[test]
+    fn parses_interval_properties_into_hours() {
+        assert_eq!(parse_interval_hours("interval 7 days"), Some(168));
+        assert_eq!(parse_interval_hours("INTERVAL 1 WEEK"), Some(168));
+        assert_eq!(parse_interval_hours("30 days"), Some(720));
+        assert_eq!(parse_interval_hours("168 hours"), Some(168));
+        assert_eq!(parse_interval_hours(" 24 "), Some(24));
+        assert_eq!(parse_interval_hours("interval 90 minutes"), Some(2));
+        assert_eq!(parse_interval_hours("interval 3600 seconds"), Some(1));
+    }
+
+    #[test]
+    fn rejects_unknown_units_and_amounts() {
+        assert_eq!(parse_interval_hours("interval 2 fortnights"), None);
+        assert_eq!(parse_interval_hours("interval seven days"), None);
+        assert_eq!(parse_interval_hours(""), None);
+    }
+}
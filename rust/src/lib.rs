@@ -0,0 +1,100 @@
+//! Inspect and analyze Delta tables.
+//!
+//! The stable entry points are [`DeltaTableInspector`], which opens a table and
+//! gathers [`TableStatistics`], and [`DeltaTableAnalyzer`], which turns those
+//! statistics into [`Insight`]s:
+//!
+//! ```no_run
+//! use deltective::{health_score, DeltaTableAnalyzer, DeltaTableInspector};
+//! use std::collections::HashMap;
+//!
+//! # async fn example() -> anyhow::Result<()> {
+//! let inspector = DeltaTableInspector::new("/data/lake/orders", HashMap::new()).await?;
+//! let stats = inspector.get_statistics().await?;
+//! let insights = DeltaTableAnalyzer::new(stats).analyze();
+//! println!("health {}/100", health_score(&insights));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod amplification;
+pub mod attribution;
+pub mod cadence;
+pub mod cdf;
+pub mod clustering;
+pub mod column_mapping;
+pub mod column_stats;
+pub mod column_storage;
+pub mod compression;
+pub mod constraints;
+pub mod contention;
+pub mod cost;
+pub mod data_skipping;
+pub mod deletion_vectors;
+pub mod diagnosis;
+pub mod diff;
+pub mod domain_metadata;
+pub mod generated_columns;
+pub mod growth;
+pub mod i18n;
+pub mod insights;
+pub mod inspector;
+pub mod parquet_footer;
+pub mod partitions;
+pub mod prescribe;
+pub mod render;
+pub mod report;
+pub mod row_tracking;
+pub mod rules;
+pub mod schema;
+pub mod schema_evolution;
+pub mod streaming;
+pub mod time_travel;
+pub mod upgrade;
+
+// Support modules for the `deltective` binary; not covered by the stable API
+#[doc(hidden)]
+pub mod alerts;
+#[doc(hidden)]
+pub mod catalog;
+#[doc(hidden)]
+pub mod cancel;
+#[doc(hidden)]
+pub mod checkpoint_contents;
+#[doc(hidden)]
+pub mod commits;
+#[doc(hidden)]
+pub mod config;
+#[doc(hidden)]
+pub mod diagnostics;
+#[doc(hidden)]
+pub mod discovery;
+#[doc(hidden)]
+pub mod fleet;
+#[doc(hidden)]
+pub mod fsck;
+#[doc(hidden)]
+pub mod history;
+#[doc(hidden)]
+pub mod log_validation;
+#[doc(hidden)]
+pub mod maintenance;
+#[doc(hidden)]
+pub mod migration;
+#[doc(hidden)]
+pub mod parquet_dataset;
+#[doc(hidden)]
+pub mod preview;
+#[doc(hidden)]
+pub mod requests;
+#[doc(hidden)]
+pub mod run_links;
+#[doc(hidden)]
+pub mod select;
+#[doc(hidden)]
+pub mod server;
+#[doc(hidden)]
+pub mod workspace;
+
+pub use insights::{health_score, DeltaTableAnalyzer, Insight};
+pub use inspector::{DeltaTableInspector, TableStatistics};
@@ -0,0 +1,754 @@
+//! Structural validation of a table's `_delta_log`.
+//!
+//! The log is replayed from its own files rather than through deltalake, so
+//! a log that deltalake refuses to load can still be diagnosed. Replay starts
+//! from version 0 or, when older commits have been cleaned up, from the
+//! earliest checkpoint, and checks each commit against the protocol. Later
+//! checkpoints are compared with the replayed state at their version.
+
+use crate::render::{Document, Item, Renderable, Tone};
+use crate::requests;
+use anyhow::{Context, Result};
+use arrow::array::{Array, AsArray};
+use deltalake::storage::ObjectStoreRef;
+use deltalake::DeltaTableBuilder;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectMeta;
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const LOG_DIR: &str = "_delta_log";
+const COMMIT_READ_CONCURRENCY: usize = 16;
+/// Findings listed individually in the text output; JSON has all of them.
+const LISTED_FINDINGS: usize = 100;
+
+/// Actions defined by the Delta protocol.
+const KNOWN_ACTIONS: &[&str] = &[
+    "add",
+    "remove",
+    "metaData",
+    "protocol",
+    "commitInfo",
+    "txn",
+    "cdc",
+    "domainMetadata",
+    "checkpointMetadata",
+    "sidecar",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// What kind of problem a finding is, for filtering JSON output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FindingKind {
+    VersionGap,
+    MalformedCommit,
+    DuplicateAction,
+    ProtocolViolation,
+    CheckpointMismatch,
+    UnknownAction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogFinding {
+    pub severity: Severity,
+    pub kind: FindingKind,
+    pub version: Option<i64>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogValidationReport {
+    pub table_path: String,
+    /// Earliest and latest commit in the log
+    pub first_version: Option<i64>,
+    pub last_version: Option<i64>,
+    /// Version replay started from, and whether from a checkpoint
+    pub replay_start: Option<i64>,
+    pub replay_from_checkpoint: bool,
+    pub commits_checked: usize,
+    pub checkpoints_checked: usize,
+    /// Files in the table after replaying the last commit
+    pub active_files: usize,
+    pub findings: Vec<LogFinding>,
+}
+
+impl LogValidationReport {
+    /// Whether the log has no errors; warnings don't stop readers.
+    pub fn is_valid(&self) -> bool {
+        !self.findings.iter().any(|f| f.severity == Severity::Error)
+    }
+}
+
+/// A checkpoint, single file or split into parts.
+struct Checkpoint {
+    version: i64,
+    parts: Vec<ObjectPath>,
+    /// Number of parts the file names announce
+    expected_parts: usize,
+    /// V2 checkpoints may keep their add actions in sidecar files
+    v2: bool,
+}
+
+/// Table state as replay reaches each version.
+#[derive(Default)]
+struct State {
+    files: HashSet<String>,
+    protocol: Option<Value>,
+    metadata: Option<Value>,
+}
+
+impl State {
+    fn reader_version(&self) -> i64 {
+        self.protocol.as_ref().and_then(|p| p["minReaderVersion"].as_i64()).unwrap_or(0)
+    }
+
+    fn writer_version(&self) -> i64 {
+        self.protocol.as_ref().and_then(|p| p["minWriterVersion"].as_i64()).unwrap_or(0)
+    }
+
+    fn writer_feature(&self, feature: &str) -> bool {
+        self.protocol.as_ref().is_some_and(|p| {
+            p["writerFeatures"]
+                .as_array()
+                .is_some_and(|features| features.iter().any(|f| f.as_str() == Some(feature)))
+        })
+    }
+
+    fn partition_columns(&self) -> Option<HashSet<String>> {
+        self.metadata.as_ref().map(|m| {
+            m["partitionColumns"]
+                .as_array()
+                .map(|cols| cols.iter().filter_map(|c| c.as_str().map(str::to_string)).collect())
+                .unwrap_or_default()
+        })
+    }
+}
+
+struct Validator {
+    findings: Vec<LogFinding>,
+}
+
+impl Validator {
+    fn add(&mut self, severity: Severity, kind: FindingKind, version: Option<i64>, message: impl Into<String>) {
+        self.findings.push(LogFinding {
+            severity,
+            kind,
+            version,
+            message: message.into(),
+        });
+    }
+
+    fn error(&mut self, kind: FindingKind, version: i64, message: impl Into<String>) {
+        self.add(Severity::Error, kind, Some(version), message);
+    }
+
+    fn warning(&mut self, kind: FindingKind, version: i64, message: impl Into<String>) {
+        self.add(Severity::Warning, kind, Some(version), message);
+    }
+}
+
+/// Validate the log of the table at `path`.
+pub async fn validate(path: &str, storage_options: HashMap<String, String>) -> Result<LogValidationReport> {
+    let store = requests::metered(
+        DeltaTableBuilder::from_uri(path)
+            .with_storage_options(storage_options)
+            .build_storage()
+            .with_context(|| format!("Failed to open {}", path))?
+            .object_store(),
+    );
+    let listing: Vec<ObjectMeta> = store
+        .list(Some(&ObjectPath::from(LOG_DIR)))
+        .try_collect()
+        .await
+        .with_context(|| format!("Failed to list {}/{}", path, LOG_DIR))?;
+
+    let mut commits: BTreeMap<i64, ObjectPath> = BTreeMap::new();
+    let mut checkpoints: BTreeMap<i64, Checkpoint> = BTreeMap::new();
+    for meta in listing {
+        let Some(name) = meta.location.filename().map(str::to_string) else {
+            continue;
+        };
+        let Some((version, rest)) = name.split_once('.') else {
+            continue;
+        };
+        let Ok(version) = version.parse::<i64>() else {
+            continue;
+        };
+        if rest == "json" {
+            commits.insert(version, meta.location);
+        } else if let Some(kind) = rest.strip_prefix("checkpoint.").and_then(|r| r.strip_suffix(".parquet")) {
+            // <v>.checkpoint.parquet, <v>.checkpoint.<i>.<n>.parquet or a V2 <v>.checkpoint.<uuid>.parquet
+            let expected_parts = kind
+                .split_once('.')
+                .and_then(|(_, n)| n.parse().ok())
+                .unwrap_or(1);
+            let checkpoint = checkpoints.entry(version).or_insert(Checkpoint {
+                version,
+                parts: Vec::new(),
+                expected_parts,
+                v2: !kind.contains('.'),
+            });
+            checkpoint.parts.push(meta.location);
+        } else if rest == "checkpoint.parquet" {
+            checkpoints
+                .entry(version)
+                .or_insert(Checkpoint {
+                    version,
+                    parts: Vec::new(),
+                    expected_parts: 1,
+                    v2: false,
+                })
+                .parts
+                .push(meta.location);
+        }
+    }
+
+    let mut validator = Validator { findings: Vec::new() };
+    let mut report = LogValidationReport {
+        table_path: path.to_string(),
+        first_version: commits.keys().next().copied(),
+        last_version: commits.keys().next_back().copied(),
+        replay_start: None,
+        replay_from_checkpoint: false,
+        commits_checked: 0,
+        checkpoints_checked: 0,
+        active_files: 0,
+        findings: Vec::new(),
+    };
+    let (Some(first), Some(last)) = (report.first_version, report.last_version) else {
+        validator.add(Severity::Error, FindingKind::VersionGap, None, "No commit files in _delta_log");
+        report.findings = validator.findings;
+        return Ok(report);
+    };
+
+    // Versions must be contiguous from wherever the retained log starts
+    let missing: Vec<i64> = (first..=last).filter(|v| !commits.contains_key(v)).collect();
+    for range in ranges(&missing) {
+        validator.error(
+            FindingKind::VersionGap,
+            range.0,
+            if range.0 == range.1 {
+                format!("Commit {} is missing", range.0)
+            } else {
+                format!("Commits {} to {} are missing", range.0, range.1)
+            },
+        );
+    }
+    for checkpoint in checkpoints.values().filter(|c| c.parts.len() != c.expected_parts) {
+        validator.error(
+            FindingKind::CheckpointMismatch,
+            checkpoint.version,
+            format!(
+                "Checkpoint {} has {} of its {} parts",
+                checkpoint.version,
+                checkpoint.parts.len(),
+                checkpoint.expected_parts
+            ),
+        );
+    }
+    check_last_checkpoint(&store, &checkpoints, &mut validator).await;
+
+    // Replay from version 0, else from the newest checkpoint at or before the
+    // first retained commit that the following commits continue from
+    let mut state = State::default();
+    let start = if first == 0 {
+        0
+    } else {
+        let base = checkpoints
+            .range(..first)
+            .next_back()
+            .filter(|(v, _)| **v == first - 1)
+            .or_else(|| checkpoints.range(first..).next());
+        match base {
+            Some((&version, checkpoint)) => {
+                match read_checkpoint(&store, checkpoint).await {
+                    Ok(loaded) => state = loaded,
+                    Err(e) => validator.error(
+                        FindingKind::CheckpointMismatch,
+                        version,
+                        format!("Checkpoint {} can't be read: {:#}", version, e),
+                    ),
+                }
+                report.replay_from_checkpoint = true;
+                report.checkpoints_checked += 1;
+                version + 1
+            }
+            None => {
+                validator.error(
+                    FindingKind::VersionGap,
+                    first,
+                    format!(
+                        "The log starts at version {} with no checkpoint to start from; versions before it were removed",
+                        first
+                    ),
+                );
+                first
+            }
+        }
+    };
+    report.replay_start = Some(if report.replay_from_checkpoint { start - 1 } else { start });
+
+    let versions: Vec<(i64, ObjectPath)> = commits.range(start..).map(|(v, p)| (*v, p.clone())).collect();
+    let mut contents = stream::iter(versions)
+        .map(|(version, location)| {
+            let store = store.clone();
+            async move {
+                let bytes = match store.get(&location).await {
+                    Ok(result) => result.bytes().await,
+                    Err(e) => Err(e),
+                };
+                (version, bytes)
+            }
+        })
+        .buffered(COMMIT_READ_CONCURRENCY);
+
+    while let Some((version, bytes)) = contents.next().await {
+        match bytes {
+            Ok(bytes) => replay_commit(version, &String::from_utf8_lossy(&bytes), &mut state, &mut validator),
+            Err(e) => validator.error(
+                FindingKind::MalformedCommit,
+                version,
+                format!("Commit {} can't be read: {}", version, e),
+            ),
+        }
+        report.commits_checked += 1;
+
+        if let Some(checkpoint) = checkpoints.get(&version) {
+            compare_checkpoint(&store, checkpoint, &state, &mut validator).await;
+            report.checkpoints_checked += 1;
+        }
+    }
+
+    report.active_files = state.files.len();
+    validator.findings.sort_by_key(|f| (f.version, std::cmp::Reverse(f.severity)));
+    report.findings = validator.findings;
+    Ok(report)
+}
+
+/// Collapse sorted versions into inclusive ranges.
+fn ranges(versions: &[i64]) -> Vec<(i64, i64)> {
+    let mut ranges: Vec<(i64, i64)> = Vec::new();
+    for &v in versions {
+        match ranges.last_mut() {
+            Some(range) if range.1 + 1 == v => range.1 = v,
+            _ => ranges.push((v, v)),
+        }
+    }
+    ranges
+}
+
+async fn check_last_checkpoint(
+    store: &ObjectStoreRef,
+    checkpoints: &BTreeMap<i64, Checkpoint>,
+    validator: &mut Validator,
+) {
+    let path = ObjectPath::from(format!("{}/_last_checkpoint", LOG_DIR));
+    let bytes = match store.get(&path).await {
+        Ok(result) => result.bytes().await,
+        Err(object_store::Error::NotFound { .. }) => return,
+        Err(e) => Err(e),
+    };
+    let last = bytes
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).map_err(|e| e.to_string()));
+    match last.as_ref().map(|last| last["version"].as_i64()) {
+        Ok(Some(version)) if !checkpoints.contains_key(&version) => validator.add(
+            Severity::Error,
+            FindingKind::CheckpointMismatch,
+            Some(version),
+            format!("_last_checkpoint points to version {}, which has no checkpoint file", version),
+        ),
+        Ok(Some(version)) if checkpoints.keys().next_back().is_some_and(|latest| *latest > version) => {
+            validator.add(
+                Severity::Warning,
+                FindingKind::CheckpointMismatch,
+                Some(version),
+                format!(
+                    "_last_checkpoint points to version {} but a newer checkpoint exists; readers replay more commits than needed",
+                    version
+                ),
+            )
+        }
+        Ok(Some(_)) => {}
+        Ok(None) => validator.add(
+            Severity::Error,
+            FindingKind::CheckpointMismatch,
+            None,
+            "_last_checkpoint has no version",
+        ),
+        Err(e) => validator.add(
+            Severity::Error,
+            FindingKind::CheckpointMismatch,
+            None,
+            format!("_last_checkpoint can't be read: {}", e),
+        ),
+    }
+}
+
+/// Apply one commit to `state`, recording what breaks the protocol.
+fn replay_commit(version: i64, text: &str, state: &mut State, validator: &mut Validator) {
+    use FindingKind::*;
+
+    let mut actions = Vec::new();
+    for (line_number, line) in text.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+        match serde_json::from_str::<Value>(line) {
+            Ok(Value::Object(action)) => actions.push(action),
+            Ok(_) => validator.error(
+                MalformedCommit,
+                version,
+                format!("Line {} of commit {} is not a JSON object", line_number + 1, version),
+            ),
+            Err(e) => validator.error(
+                MalformedCommit,
+                version,
+                format!("Line {} of commit {} is not valid JSON: {}", line_number + 1, version, e),
+            ),
+        }
+    }
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut added: HashSet<(String, Option<String>)> = HashSet::new();
+    let mut removed: HashSet<(String, Option<String>)> = HashSet::new();
+
+    // Protocol and metadata changes apply to the rest of the commit
+    for action in &actions {
+        if let Some(protocol) = action.get("protocol") {
+            check_protocol(version, protocol, validator);
+            state.protocol = Some(protocol.clone());
+        }
+        if let Some(metadata) = action.get("metaData") {
+            state.metadata = Some(metadata.clone());
+        }
+    }
+
+    for action in &actions {
+        if action.len() != 1 {
+            validator.error(
+                MalformedCommit,
+                version,
+                format!(
+                    "An action in commit {} has {} top-level keys; each line must hold exactly one action",
+                    version,
+                    action.len()
+                ),
+            );
+        }
+        for (kind, body) in action {
+            let kind = kind.as_str();
+            if !KNOWN_ACTIONS.contains(&kind) {
+                validator.warning(UnknownAction, version, format!("Unknown action \"{}\" in commit {}", kind, version));
+                continue;
+            }
+            *counts.entry(kind).or_default() += 1;
+
+            match kind {
+                "add" | "remove" => {
+                    let Some(path) = body["path"].as_str() else {
+                        validator.error(MalformedCommit, version, format!("A {} action in commit {} has no path", kind, version));
+                        continue;
+                    };
+                    let dv = &body["deletionVector"];
+                    let dv = dv.is_object().then(|| format!("{}{}", dv["storageType"], dv["pathOrInlineDv"]));
+                    if dv.is_some() && !state.writer_feature("deletionVectors") {
+                        validator.error(
+                            ProtocolViolation,
+                            version,
+                            format!("{} has a deletion vector but the protocol doesn't enable deletionVectors", path),
+                        );
+                    }
+                    let key = (path.to_string(), dv);
+                    if kind == "add" {
+                        for field in ["size", "modificationTime", "dataChange"] {
+                            if body.get(field).is_none() {
+                                validator.error(
+                                    MalformedCommit,
+                                    version,
+                                    format!("add of {} in commit {} has no {}", path, version, field),
+                                );
+                            }
+                        }
+                        check_partition_values(version, path, body, state, validator);
+                        if !added.insert(key) {
+                            validator.error(DuplicateAction, version, format!("{} is added twice in commit {}", path, version));
+                        }
+                    } else if !removed.insert(key) {
+                        validator.error(
+                            DuplicateAction,
+                            version,
+                            format!("{} is removed twice in commit {}", path, version),
+                        );
+                    }
+                }
+                "cdc" if !is_enabled(state, "delta.enableChangeDataFeed") => validator.warning(
+                    ProtocolViolation,
+                    version,
+                    format!("Commit {} writes change data but delta.enableChangeDataFeed isn't set", version),
+                ),
+                "domainMetadata" if !state.writer_feature("domainMetadata") => validator.error(
+                    ProtocolViolation,
+                    version,
+                    format!("Commit {} has domainMetadata but the protocol doesn't enable domainMetadata", version),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    // Actions within a commit are unordered, so removes apply before adds;
+    // a remove paired with an add of the same path is a deletion vector update
+    for (path, _) in &removed {
+        if !state.files.remove(path) && !added.iter().any(|(p, _)| p == path) {
+            validator.warning(
+                DuplicateAction,
+                version,
+                format!("Commit {} removes {}, which isn't in the table", version, path),
+            );
+        }
+    }
+    state.files.extend(added.iter().map(|(path, _)| path.clone()));
+    // The same file both added and removed without a deletion vector is
+    // ambiguous to readers
+    for (path, dv) in added.intersection(&removed) {
+        if dv.is_none() {
+            validator.error(
+                DuplicateAction,
+                version,
+                format!("{} is both added and removed in commit {}", path, version),
+            );
+        }
+    }
+    for kind in ["protocol", "metaData", "commitInfo"] {
+        if counts.get(kind).copied().unwrap_or(0) > 1 {
+            validator.error(
+                ProtocolViolation,
+                version,
+                format!("Commit {} has {} {} actions; at most one is allowed", version, counts[kind], kind),
+            );
+        }
+    }
+    if version == 0 {
+        for kind in ["protocol", "metaData"] {
+            if !counts.contains_key(kind) {
+                validator.error(ProtocolViolation, 0, format!("Commit 0 has no {} action", kind));
+            }
+        }
+    }
+}
+
+fn is_enabled(state: &State, property: &str) -> bool {
+    state
+        .metadata
+        .as_ref()
+        .and_then(|m| m["configuration"][property].as_str())
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+}
+
+fn check_protocol(version: i64, protocol: &Value, validator: &mut Validator) {
+    let reader = protocol["minReaderVersion"].as_i64();
+    let writer = protocol["minWriterVersion"].as_i64();
+    let (Some(reader), Some(writer)) = (reader, writer) else {
+        validator.error(
+            FindingKind::ProtocolViolation,
+            version,
+            format!("The protocol action in commit {} lacks minReaderVersion or minWriterVersion", version),
+        );
+        return;
+    };
+    if reader >= 3 && !protocol["readerFeatures"].is_array() {
+        validator.error(
+            FindingKind::ProtocolViolation,
+            version,
+            format!("Reader version {} in commit {} requires a readerFeatures list", reader, version),
+        );
+    }
+    if writer >= 7 && !protocol["writerFeatures"].is_array() {
+        validator.error(
+            FindingKind::ProtocolViolation,
+            version,
+            format!("Writer version {} in commit {} requires a writerFeatures list", writer, version),
+        );
+    }
+    if reader < 3 && protocol.get("readerFeatures").is_some() {
+        validator.warning(
+            FindingKind::ProtocolViolation,
+            version,
+            format!("Commit {} lists readerFeatures with reader version {}; they are ignored", version, reader),
+        );
+    }
+}
+
+fn check_partition_values(version: i64, path: &str, add: &Value, state: &State, validator: &mut Validator) {
+    let Some(expected) = state.partition_columns() else {
+        return;
+    };
+    let actual: HashSet<String> = add["partitionValues"]
+        .as_object()
+        .map(|values| values.keys().cloned().collect())
+        .unwrap_or_default();
+    if actual != expected {
+        let mut columns: Vec<&String> = actual.iter().collect();
+        columns.sort();
+        validator.error(
+            FindingKind::ProtocolViolation,
+            version,
+            format!(
+                "add of {} in commit {} has partition values for [{}], not the table's partition columns",
+                path,
+                version,
+                columns.iter().map(|c| c.as_str()).collect::<Vec<_>>().join(", ")
+            ),
+        );
+    }
+}
+
+/// Active files, protocol and metadata recorded in a checkpoint.
+async fn read_checkpoint(store: &ObjectStoreRef, checkpoint: &Checkpoint) -> Result<State> {
+    let mut state = State::default();
+    for part in &checkpoint.parts {
+        let meta = store.head(part).await?;
+        let reader = ParquetObjectReader::new(store.clone(), meta);
+        let mut batches = ParquetRecordBatchStreamBuilder::new(reader).await?.build()?;
+        while let Some(batch) = batches.try_next().await? {
+            if let Some(paths) = batch
+                .column_by_name("add")
+                .and_then(|add| add.as_struct_opt())
+                .and_then(|add| add.column_by_name("path"))
+                .and_then(|path| path.as_string_opt::<i32>())
+            {
+                state.files.extend(paths.iter().flatten().map(str::to_string));
+            }
+            if let Some(protocol) = batch.column_by_name("protocol").and_then(|p| p.as_struct_opt()) {
+                let version = |name: &str| {
+                    protocol
+                        .column_by_name(name)
+                        .and_then(|c| c.as_primitive_opt::<arrow::datatypes::Int32Type>())
+                        .and_then(|c| (0..c.len()).find(|i| c.is_valid(*i)).map(|i| c.value(i)))
+                };
+                if let (Some(reader), Some(writer)) = (version("minReaderVersion"), version("minWriterVersion")) {
+                    state.protocol = Some(serde_json::json!({
+                        "minReaderVersion": reader,
+                        "minWriterVersion": writer,
+                    }));
+                }
+            }
+        }
+    }
+    Ok(state)
+}
+
+/// Compare a checkpoint with the state replayed up to its version.
+async fn compare_checkpoint(store: &ObjectStoreRef, checkpoint: &Checkpoint, state: &State, validator: &mut Validator) {
+    let version = checkpoint.version;
+    if checkpoint.parts.len() != checkpoint.expected_parts {
+        // Already reported as incomplete
+        return;
+    }
+    let recorded = match read_checkpoint(store, checkpoint).await {
+        Ok(recorded) => recorded,
+        Err(e) => {
+            validator.error(
+                FindingKind::CheckpointMismatch,
+                version,
+                format!("Checkpoint {} can't be read: {:#}", version, e),
+            );
+            return;
+        }
+    };
+
+    let only_in_checkpoint = recorded.files.difference(&state.files).count();
+    let only_in_log = state.files.difference(&recorded.files).count();
+    // Sidecars aren't read, so an empty V2 checkpoint proves nothing
+    let sidecars = checkpoint.v2 && recorded.files.is_empty();
+    if !sidecars && (only_in_checkpoint > 0 || only_in_log > 0) {
+        validator.error(
+            FindingKind::CheckpointMismatch,
+            version,
+            format!(
+                "Checkpoint {} disagrees with the replayed commits: {} file(s) only in the checkpoint, {} only in the log",
+                version, only_in_checkpoint, only_in_log
+            ),
+        );
+    }
+    if recorded.protocol.is_some()
+        && (recorded.reader_version(), recorded.writer_version()) != (state.reader_version(), state.writer_version())
+    {
+        validator.error(
+            FindingKind::CheckpointMismatch,
+            version,
+            format!(
+                "Checkpoint {} records protocol {}/{} but the commits give {}/{}",
+                version,
+                recorded.reader_version(),
+                recorded.writer_version(),
+                state.reader_version(),
+                state.writer_version()
+            ),
+        );
+    }
+}
+
+impl Renderable for LogValidationReport {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("Transaction log validation: {}", self.table_path));
+        let errors = self.findings.iter().filter(|f| f.severity == Severity::Error).count();
+        let warnings = self.findings.len() - errors;
+        let verdict = if self.is_valid() {
+            Item::new("[VALID]", "The log replays cleanly").tone(Tone::Good)
+        } else {
+            Item::new("[INVALID]", format!("{} error(s): readers may fail or see wrong data", errors))
+                .tone(Tone::Critical)
+        };
+        doc.list(vec![verdict]);
+
+        let version_range = match (self.first_version, self.last_version) {
+            (Some(first), Some(last)) => format!("{} to {}", first, last),
+            _ => "none".to_string(),
+        };
+        doc.fields([
+            ("Commits in log", version_range),
+            (
+                "Replayed from",
+                match self.replay_start {
+                    Some(v) if self.replay_from_checkpoint => format!("checkpoint {}", v),
+                    Some(v) => format!("version {}", v),
+                    None => "-".to_string(),
+                },
+            ),
+            ("Commits checked", self.commits_checked.to_string()),
+            ("Checkpoints checked", self.checkpoints_checked.to_string()),
+            ("Active files", self.active_files.to_string()),
+            ("Errors", errors.to_string()),
+            ("Warnings", warnings.to_string()),
+        ]);
+
+        if !self.findings.is_empty() {
+            let mut items: Vec<Item> = self
+                .findings
+                .iter()
+                .take(LISTED_FINDINGS)
+                .map(|f| {
+                    let (marker, tone) = match f.severity {
+                        Severity::Error => ("[ERROR]", Tone::Critical),
+                        Severity::Warning => ("[WARNING]", Tone::Warning),
+                    };
+                    Item::new(marker, f.message.clone()).tone(tone)
+                })
+                .collect();
+            if self.findings.len() > LISTED_FINDINGS {
+                items.push(Item::new("…", format!("{} more", self.findings.len() - LISTED_FINDINGS)));
+            }
+            doc.heading("Findings").list(items);
+        }
+        doc
+    }
+}
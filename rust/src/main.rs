@@ -1,6 +1,5 @@
 mod cli;
-mod inspector;
-mod insights;
+mod commands;
 mod tui_app;
 
 use anyhow::Result;
@@ -8,4 +7,3 @@ use anyhow::Result;
 fn main() -> Result<()> {
     cli::run()
 }
-
@@ -0,0 +1,662 @@
+use crate::insights::{AnalyzerThresholds, DeltaTableAnalyzer};
+use crate::inspector::{
+    commit_version, parse_interval_hours, table_properties, TableStatistics, DEFAULT_DELETED_FILE_RETENTION_HOURS, DEFAULT_LOG_RETENTION_HOURS,
+};
+use crate::render::{Document, Renderable};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use deltalake::kernel::CommitInfo;
+use deltalake::{DeltaOps, DeltaTable};
+use object_store::path::Path as ObjectPath;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+/// Fewer files than this share going away makes OPTIMIZE a poor trade for
+/// the bytes it rewrites.
+const WORTHWHILE_FILE_REDUCTION_PCT: f64 = 10.0;
+
+/// Files listed individually in a VACUUM dry run; the rest are summarized.
+const LISTED_VACUUM_FILES: usize = 20;
+
+/// What an OPTIMIZE would touch, estimated from the current snapshot before
+/// asking for confirmation.
+#[derive(Debug, Clone, Serialize)]
+pub struct OptimizePlan {
+    pub version: i64,
+    pub target_size_bytes: u64,
+    /// Files below the target size, which are candidates for compaction
+    pub small_files: usize,
+    pub small_files_bytes: i64,
+    pub total_files: usize,
+    pub simulation: OptimizeSimulation,
+}
+
+impl OptimizePlan {
+    pub fn new(stats: &TableStatistics, thresholds: &AnalyzerThresholds) -> Self {
+        let target_size_bytes = (thresholds.target_file_mb * BYTES_PER_MB) as u64;
+        let small: Vec<i64> = stats
+            .files
+            .iter()
+            .map(|f| f.size_bytes)
+            .filter(|size| (*size as u64) < target_size_bytes)
+            .collect();
+
+        Self {
+            version: stats.version,
+            target_size_bytes,
+            small_files: small.len(),
+            small_files_bytes: small.iter().sum(),
+            total_files: stats.num_files,
+            simulation: OptimizeSimulation::new(stats, target_size_bytes),
+        }
+    }
+}
+
+/// Expected outcome of a bin-packing OPTIMIZE, from packing the current
+/// files the way delta-rs does.
+#[derive(Debug, Clone, Serialize)]
+pub struct OptimizeSimulation {
+    pub version: i64,
+    pub target_size_bytes: u64,
+    pub files_before: usize,
+    pub files_after: usize,
+    pub total_bytes: i64,
+    /// Files in bins of two or more, which are read and written again
+    pub files_rewritten: usize,
+    pub bytes_rewritten: i64,
+    /// Output files, one per bin
+    pub bins: usize,
+    pub partitions_compacted: usize,
+}
+
+impl OptimizeSimulation {
+    /// Within each partition, files below the target are taken largest first
+    /// and put in the first bin they fit in without going over the target.
+    /// Bins holding a single file are left alone, as OPTIMIZE skips them.
+    pub fn new(stats: &TableStatistics, target_size_bytes: u64) -> Self {
+        let target = target_size_bytes as i64;
+        let mut partitions: BTreeMap<Vec<(&String, &String)>, Vec<i64>> = BTreeMap::new();
+        for file in stats.files.iter().filter(|f| f.size_bytes < target) {
+            let mut key: Vec<(&String, &String)> = file.partition_values.iter().collect();
+            key.sort();
+            partitions.entry(key).or_default().push(file.size_bytes);
+        }
+
+        let mut simulation = Self {
+            version: stats.version,
+            target_size_bytes,
+            files_before: stats.files.len(),
+            files_after: stats.files.len(),
+            total_bytes: stats.files.iter().map(|f| f.size_bytes).sum(),
+            files_rewritten: 0,
+            bytes_rewritten: 0,
+            bins: 0,
+            partitions_compacted: 0,
+        };
+        for sizes in partitions.values_mut() {
+            sizes.sort_unstable_by(|a, b| b.cmp(a));
+            // (bytes, files) per bin
+            let mut bins: Vec<(i64, usize)> = Vec::new();
+            for &size in sizes.iter() {
+                match bins.iter_mut().find(|(bytes, _)| bytes + size <= target) {
+                    Some(bin) => *bin = (bin.0 + size, bin.1 + 1),
+                    None => bins.push((size, 1)),
+                }
+            }
+            let merged: Vec<&(i64, usize)> = bins.iter().filter(|(_, files)| *files > 1).collect();
+            if merged.is_empty() {
+                continue;
+            }
+            simulation.partitions_compacted += 1;
+            simulation.bins += merged.len();
+            simulation.files_rewritten += merged.iter().map(|(_, files)| files).sum::<usize>();
+            simulation.bytes_rewritten += merged.iter().map(|(bytes, _)| bytes).sum::<i64>();
+        }
+        simulation.files_after = simulation.files_before - simulation.files_rewritten + simulation.bins;
+        simulation
+    }
+
+    pub fn avg_size_before(&self) -> i64 {
+        self.total_bytes / self.files_before.max(1) as i64
+    }
+
+    pub fn avg_size_after(&self) -> i64 {
+        self.total_bytes / self.files_after.max(1) as i64
+    }
+
+    /// Share of the table's files that go away, in percent.
+    pub fn file_reduction_pct(&self) -> f64 {
+        (self.files_before - self.files_after) as f64 / self.files_before.max(1) as f64 * 100.0
+    }
+
+    /// Whether running OPTIMIZE now removes enough files to pay for the
+    /// rewrite; below that, later runs merge the same files anyway.
+    pub fn worthwhile(&self) -> bool {
+        self.file_reduction_pct() >= WORTHWHILE_FILE_REDUCTION_PCT
+    }
+
+    fn describe(&self, doc: &mut Document) {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        doc.fields([
+            (
+                "Files",
+                format!(
+                    "{} → {} ({:.1}% fewer)",
+                    self.files_before,
+                    self.files_after,
+                    self.file_reduction_pct()
+                ),
+            ),
+            (
+                "Average file size",
+                format!("{} → {}", format_bytes(self.avg_size_before()), format_bytes(self.avg_size_after())),
+            ),
+            (
+                "Rewritten",
+                format!(
+                    "{} files ({}, {:.1}% of the table) into {} in {} partitions",
+                    self.files_rewritten,
+                    format_bytes(self.bytes_rewritten),
+                    self.bytes_rewritten as f64 / self.total_bytes.max(1) as f64 * 100.0,
+                    self.bins,
+                    self.partitions_compacted
+                ),
+            ),
+        ]);
+    }
+}
+
+/// Result of a compaction committed to the table.
+#[derive(Debug, Clone, Serialize)]
+pub struct OptimizeOutcome {
+    /// Table version written by the OPTIMIZE commit
+    pub version: i64,
+    pub files_removed: u64,
+    pub files_added: u64,
+    pub bytes_removed: i64,
+    pub bytes_added: i64,
+    pub partitions_optimized: u64,
+    pub files_considered: usize,
+    pub files_skipped: usize,
+}
+
+/// Compact small files of `table` into files of about `target_size_bytes`
+/// (bin-packing, no Z-order) and commit the result as a new version.
+pub async fn optimize(table: &DeltaTable, target_size_bytes: u64) -> Result<OptimizeOutcome> {
+    let (table, metrics) = DeltaOps::from(table.clone())
+        .optimize()
+        .with_target_size(target_size_bytes as i64)
+        .await
+        .context("OPTIMIZE failed")?;
+
+    Ok(OptimizeOutcome {
+        version: table.version(),
+        files_removed: metrics.num_files_removed,
+        files_added: metrics.num_files_added,
+        bytes_removed: metrics.files_removed.total_size,
+        bytes_added: metrics.files_added.total_size,
+        partitions_optimized: metrics.partitions_optimized,
+        files_considered: metrics.total_considered_files,
+        files_skipped: metrics.total_files_skipped,
+    })
+}
+
+/// Retention a VACUUM of this table uses unless told otherwise: its
+/// `delta.deletedFileRetentionDuration`, or the Delta default of 7 days.
+pub fn default_vacuum_retention_hours(stats: &TableStatistics) -> i64 {
+    stats
+        .properties
+        .get("delta.deletedFileRetentionDuration")
+        .and_then(|v| parse_interval_hours(v))
+        .unwrap_or(DEFAULT_DELETED_FILE_RETENTION_HOURS)
+}
+
+/// Files a VACUUM with this retention would delete, from a dry run.
+#[derive(Debug, Clone, Serialize)]
+pub struct VacuumPlan {
+    pub version: i64,
+    pub retention_hours: i64,
+    pub files: Vec<VacuumCandidate>,
+    /// Bytes of the files with a known size
+    pub total_bytes: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VacuumCandidate {
+    pub path: String,
+    /// Size from the file's remove action; unknown for files the log never referenced
+    pub size_bytes: Option<i64>,
+}
+
+/// Result of a VACUUM that deleted files.
+#[derive(Debug, Clone, Serialize)]
+pub struct VacuumOutcome {
+    pub retention_hours: i64,
+    pub files_deleted: usize,
+    pub bytes_deleted: i64,
+}
+
+/// List the files VACUUM would delete with `retention_hours` of retention,
+/// without deleting anything. Fails like VACUUM itself when the retention is
+/// shorter than the table allows.
+pub async fn vacuum_dry_run(table: &DeltaTable, retention_hours: i64) -> Result<VacuumPlan> {
+    let (_, metrics) = DeltaOps::from(table.clone())
+        .vacuum()
+        .with_retention_period(chrono::Duration::hours(retention_hours))
+        .with_dry_run(true)
+        .await
+        .context("VACUUM dry run failed")?;
+
+    let sizes: HashMap<String, i64> = table
+        .snapshot()?
+        .all_tombstones(table.object_store())
+        .await?
+        .filter_map(|remove| Some((remove.path.clone(), remove.size?)))
+        .collect();
+    let files: Vec<VacuumCandidate> = metrics
+        .files_deleted
+        .into_iter()
+        .map(|path| VacuumCandidate {
+            size_bytes: sizes.get(&path).copied(),
+            path,
+        })
+        .collect();
+
+    Ok(VacuumPlan {
+        version: table.version(),
+        retention_hours,
+        total_bytes: files.iter().filter_map(|f| f.size_bytes).sum(),
+        files,
+    })
+}
+
+/// VACUUM was refused because files beyond the confirmed dry run became
+/// eligible; `plan` is the new dry run to confirm instead.
+#[derive(Debug, Error)]
+#[error("{unconfirmed} more file(s) became eligible for VACUUM since the dry run; nothing was deleted. Run the dry run again and confirm the new list")]
+pub struct VacuumPlanChanged {
+    pub unconfirmed: usize,
+    pub plan: VacuumPlan,
+}
+
+/// Delete the files listed by `plan`'s dry run, keeping
+/// `plan.retention_hours` of history. VACUUM can't be limited to a list of
+/// files, so the dry run is repeated first and nothing is deleted when files
+/// that weren't confirmed have become eligible since.
+pub async fn vacuum(table: &DeltaTable, plan: &VacuumPlan) -> Result<VacuumOutcome> {
+    let current = vacuum_dry_run(table, plan.retention_hours).await?;
+    let confirmed: HashSet<&str> = plan.files.iter().map(|f| f.path.as_str()).collect();
+    let unconfirmed = current.files.iter().filter(|f| !confirmed.contains(f.path.as_str())).count();
+    if unconfirmed > 0 {
+        return Err(VacuumPlanChanged { unconfirmed, plan: current }.into());
+    }
+
+    let (_, metrics) = DeltaOps::from(table.clone())
+        .vacuum()
+        .with_retention_period(chrono::Duration::hours(plan.retention_hours))
+        .await
+        .context("VACUUM failed")?;
+
+    let planned: HashMap<&str, i64> = plan
+        .files
+        .iter()
+        .filter_map(|f| Some((f.path.as_str(), f.size_bytes?)))
+        .collect();
+    Ok(VacuumOutcome {
+        retention_hours: plan.retention_hours,
+        files_deleted: metrics.files_deleted.len(),
+        bytes_deleted: metrics.files_deleted.iter().filter_map(|path| planned.get(path.as_str())).sum(),
+    })
+}
+
+/// State of the log before writing a checkpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointPlan {
+    pub version: i64,
+    /// Version of the checkpoint `_last_checkpoint` points at
+    pub last_checkpoint: Option<i64>,
+    /// JSON commits a reader replays on top of that checkpoint
+    pub commits_since_checkpoint: i64,
+}
+
+/// Result of writing a checkpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointOutcome {
+    pub version: i64,
+    /// JSON commits readers no longer replay
+    pub commits_covered: i64,
+}
+
+impl CheckpointPlan {
+    pub async fn new(table: &DeltaTable) -> Result<Self> {
+        let last_checkpoint = last_checkpoint_version(table).await?;
+        let version = table.version();
+        Ok(Self {
+            version,
+            last_checkpoint,
+            commits_since_checkpoint: last_checkpoint.map_or(version + 1, |cp| version - cp),
+        })
+    }
+}
+
+/// Contents of `_delta_log/_last_checkpoint`, which readers use to find the
+/// latest checkpoint without listing the log. Only `version` is required;
+/// writers fill in the rest as they see fit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LastCheckpoint {
+    pub version: i64,
+    /// Number of actions in the checkpoint
+    pub size: Option<i64>,
+    /// Number of parts of a multi-part checkpoint; absent for a single file
+    pub parts: Option<u32>,
+    pub size_in_bytes: Option<i64>,
+    pub num_of_add_files: Option<i64>,
+}
+
+/// Parse `_delta_log/_last_checkpoint`, if the table has one.
+pub async fn read_last_checkpoint(table: &DeltaTable) -> Result<Option<LastCheckpoint>> {
+    let path = ObjectPath::from("_delta_log/_last_checkpoint");
+    let bytes = match table.object_store().get(&path).await {
+        Ok(result) => result.bytes().await?,
+        Err(object_store::Error::NotFound { .. }) => return Ok(None),
+        Err(e) => return Err(e).context("Failed to read _last_checkpoint"),
+    };
+    Ok(Some(serde_json::from_slice(&bytes).context("Invalid _last_checkpoint")?))
+}
+
+/// Version recorded in `_delta_log/_last_checkpoint`, if the table has one.
+pub async fn last_checkpoint_version(table: &DeltaTable) -> Result<Option<i64>> {
+    Ok(read_last_checkpoint(table).await?.map(|last| last.version))
+}
+
+/// Write a checkpoint for the version `plan` was made for, so readers load
+/// one Parquet file instead of replaying every JSON commit.
+pub async fn checkpoint(table: &DeltaTable, plan: &CheckpointPlan) -> Result<CheckpointOutcome> {
+    let mut table = table.clone();
+    if table.version() != plan.version {
+        table.load_version(plan.version).await?;
+    }
+    deltalake::checkpoints::create_checkpoint(&table)
+        .await
+        .with_context(|| format!("Failed to write a checkpoint for version {}", plan.version))?;
+
+    Ok(CheckpointOutcome {
+        version: plan.version,
+        commits_covered: plan.commits_since_checkpoint,
+    })
+}
+
+/// What VACUUM at several retentions would free and which versions it would
+/// leave unreadable.
+#[derive(Debug, Clone, Serialize)]
+pub struct VacuumSimulation {
+    pub version: i64,
+    /// `delta.deletedFileRetentionDuration`; VACUUM refuses shorter retentions
+    /// unless its safety check is disabled
+    pub deleted_file_retention_hours: i64,
+    /// `delta.logRetentionDuration`
+    pub log_retention_hours: i64,
+    /// Latest version whose commit is past the log retention; log cleanup at
+    /// the next checkpoint removes it and everything older, whatever VACUUM does
+    pub log_expired_through: Option<i64>,
+    pub scenarios: Vec<RetentionScenario>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RetentionScenario {
+    pub retention_hours: i64,
+    /// Shorter than `delta.deletedFileRetentionDuration`
+    pub below_table_retention: bool,
+    pub files_freed: usize,
+    pub bytes_freed: i64,
+    /// Latest version that would reference a deleted file; it and every
+    /// older version could no longer be read
+    pub unreadable_through: Option<i64>,
+}
+
+/// Simulate VACUUM at each of `retentions_hours`, and at the table's own
+/// retention, from the table's tombstones and its newest-first `history`. A
+/// version counts as unreadable when it was committed before the removal of
+/// a file VACUUM would delete, since reading it needs that file.
+pub async fn simulate_vacuum(
+    table: &DeltaTable,
+    history: &[CommitInfo],
+    retentions_hours: &[i64],
+) -> Result<VacuumSimulation> {
+    let properties = table_properties(table.metadata()?);
+    let hours = |key: &str, default: i64| {
+        properties.get(key).and_then(|v| parse_interval_hours(v)).unwrap_or(default)
+    };
+    let deleted_file_retention_hours =
+        hours("delta.deletedFileRetentionDuration", DEFAULT_DELETED_FILE_RETENTION_HOURS);
+    let log_retention_hours = hours("delta.logRetentionDuration", DEFAULT_LOG_RETENTION_HOURS);
+
+    let tombstones: Vec<(i64, i64)> = table
+        .snapshot()?
+        .all_tombstones(table.object_store())
+        .await?
+        .map(|remove| (remove.deletion_timestamp.unwrap_or(0), remove.size.unwrap_or(0)))
+        .collect();
+    let version = table.version();
+    let commits: Vec<(i64, i64)> = history
+        .iter()
+        .filter_map(|commit| Some((commit_version(commit)?, commit.timestamp.unwrap_or(0))))
+        .collect();
+    let committed_before = |millis: i64| commits.iter().filter(|(_, ts)| *ts < millis).map(|(v, _)| *v).max();
+
+    let mut retentions = retentions_hours.to_vec();
+    retentions.push(deleted_file_retention_hours);
+    retentions.sort_unstable();
+    retentions.dedup();
+
+    let now = Utc::now().timestamp_millis();
+    let cutoff = |hours: i64| now - hours * 60 * 60 * 1000;
+    let scenarios = retentions
+        .iter()
+        .map(|&retention_hours| {
+            let expired: Vec<&(i64, i64)> =
+                tombstones.iter().filter(|(ts, _)| *ts < cutoff(retention_hours)).collect();
+            RetentionScenario {
+                retention_hours,
+                below_table_retention: retention_hours < deleted_file_retention_hours,
+                files_freed: expired.len(),
+                bytes_freed: expired.iter().map(|(_, size)| size).sum(),
+                unreadable_through: expired.iter().map(|(ts, _)| *ts).max().and_then(committed_before),
+            }
+        })
+        .collect();
+
+    Ok(VacuumSimulation {
+        version,
+        deleted_file_retention_hours,
+        log_retention_hours,
+        log_expired_through: committed_before(cutoff(log_retention_hours)),
+        scenarios,
+    })
+}
+
+impl Renderable for OptimizePlan {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new("Run OPTIMIZE?");
+        doc.fields([
+            ("Version", self.version.to_string()),
+            ("Target file size", format_bytes(self.target_size_bytes as i64)),
+            (
+                "Candidates",
+                format!(
+                    "{} of {} files below the target ({})",
+                    self.small_files,
+                    self.total_files,
+                    format_bytes(self.small_files_bytes)
+                ),
+            ),
+        ]);
+        doc.heading("Expected result");
+        self.simulation.describe(&mut doc);
+        doc.text("Small files are rewritten into larger ones and committed as a new version. Removed files stay on storage until VACUUM.");
+        doc
+    }
+}
+
+impl Renderable for OptimizeSimulation {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("OPTIMIZE simulation at version {}", self.version));
+        doc.fields([("Target file size", DeltaTableAnalyzer::format_bytes(self.target_size_bytes as i64))]);
+        self.describe(&mut doc);
+        if self.bins == 0 {
+            doc.text("Nothing to compact: no partition has two files below the target that fit in one.");
+        } else if self.worthwhile() {
+            doc.text("Worth running now: the table ends up with noticeably fewer, larger files.");
+        } else {
+            doc.text(format!(
+                "Can wait: fewer than {:.0}% of the files would go away for the bytes rewritten.",
+                WORTHWHILE_FILE_REDUCTION_PCT
+            ));
+        }
+        doc.text("Sizes assume output files as large as the files they replace; recompression usually shrinks them a little.");
+        doc
+    }
+}
+
+impl Renderable for OptimizeOutcome {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new("OPTIMIZE finished");
+        doc.fields([
+            ("New version", self.version.to_string()),
+            (
+                "Files removed",
+                format!("{} ({})", self.files_removed, format_bytes(self.bytes_removed)),
+            ),
+            ("Files added", format!("{} ({})", self.files_added, format_bytes(self.bytes_added))),
+            ("Partitions optimized", self.partitions_optimized.to_string()),
+            (
+                "Files considered",
+                format!("{} ({} skipped)", self.files_considered, self.files_skipped),
+            ),
+        ]);
+        doc
+    }
+}
+
+impl Renderable for VacuumPlan {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new("Run VACUUM?");
+        doc.fields([
+            ("Version", self.version.to_string()),
+            ("Retention", format!("{} hours", self.retention_hours)),
+            (
+                "Would delete",
+                format!("{} files ({})", self.files.len(), format_bytes(self.total_bytes)),
+            ),
+        ]);
+        if self.files.is_empty() {
+            doc.text("Nothing to delete: every unreferenced file is still within the retention period.");
+            return doc;
+        }
+
+        let mut rows: Vec<Vec<String>> = self
+            .files
+            .iter()
+            .take(LISTED_VACUUM_FILES)
+            .map(|f| vec![f.path.clone(), f.size_bytes.map_or("unknown".to_string(), format_bytes)])
+            .collect();
+        if self.files.len() > LISTED_VACUUM_FILES {
+            rows.push(vec![format!("… {} more", self.files.len() - LISTED_VACUUM_FILES), String::new()]);
+        }
+        doc.table(&["File", "Size"], rows);
+        doc.text("Deleted files are gone for good: time travel to versions older than the retention stops working.");
+        doc
+    }
+}
+
+impl Renderable for VacuumOutcome {
+    fn document(&self) -> Document {
+        let mut doc = Document::new("VACUUM finished");
+        doc.fields([
+            ("Retention", format!("{} hours", self.retention_hours)),
+            (
+                "Deleted",
+                format!(
+                    "{} files ({})",
+                    self.files_deleted,
+                    DeltaTableAnalyzer::format_bytes(self.bytes_deleted)
+                ),
+            ),
+        ]);
+        doc
+    }
+}
+
+impl Renderable for CheckpointPlan {
+    fn document(&self) -> Document {
+        let mut doc = Document::new("Write a checkpoint?");
+        doc.fields([
+            ("Version", self.version.to_string()),
+            (
+                "Last checkpoint",
+                self.last_checkpoint.map_or("none".to_string(), |v| format!("version {}", v)),
+            ),
+            ("Commits since", self.commits_since_checkpoint.to_string()),
+        ]);
+        if self.commits_since_checkpoint == 0 {
+            doc.text("The current version already has a checkpoint; writing it again only rewrites the same file.");
+        } else {
+            doc.text("The table state is written to one Parquet file in _delta_log. No data files change and no commit is added.");
+        }
+        doc
+    }
+}
+
+impl Renderable for CheckpointOutcome {
+    fn document(&self) -> Document {
+        let mut doc = Document::new("Checkpoint written");
+        doc.fields([
+            ("Version", self.version.to_string()),
+            ("Commits no longer replayed", self.commits_covered.to_string()),
+        ]);
+        doc
+    }
+}
+
+impl Renderable for VacuumSimulation {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("VACUUM simulation at version {}", self.version));
+        doc.fields([
+            ("deletedFileRetentionDuration", format!("{} hours", self.deleted_file_retention_hours)),
+            ("logRetentionDuration", format!("{} hours", self.log_retention_hours)),
+            (
+                "Expired from the log",
+                self.log_expired_through
+                    .map_or("none".to_string(), |v| format!("versions ≤ {} (removed at the next checkpoint)", v)),
+            ),
+        ]);
+        doc.table(
+            &["Retention", "Files freed", "Space freed", "Unreadable versions"],
+            self.scenarios
+                .iter()
+                .map(|s| {
+                    let retention = if s.below_table_retention {
+                        format!("{}h (below table setting)", s.retention_hours)
+                    } else {
+                        format!("{}h", s.retention_hours)
+                    };
+                    vec![
+                        retention,
+                        s.files_freed.to_string(),
+                        DeltaTableAnalyzer::format_bytes(s.bytes_freed),
+                        s.unreadable_through.map_or("none".to_string(), |v| format!("≤ {}", v)),
+                    ]
+                })
+                .collect(),
+        );
+        doc.text("Retentions below the table setting need spark.databricks.delta.retentionDurationCheck.enabled=false (or enforce_retention_duration=false in delta-rs) and can break concurrent readers and writers.");
+        doc
+    }
+}
@@ -0,0 +1,852 @@
+//! Readiness of an Iceberg or Hudi table for migration to Delta.
+//!
+//! Neither format is read beyond its metadata: Iceberg's latest
+//! `metadata/*.metadata.json` and Hudi's `.hoodie/hoodie.properties` plus
+//! the timeline file names. That is enough for the table facts and to map
+//! each column type, partition transform and format feature to its Delta
+//! equivalent, flagging what converts as is, what needs changes and what
+//! blocks a migration.
+
+use crate::insights::DeltaTableAnalyzer;
+use crate::render::{Document, Item, Renderable, Tone};
+use crate::requests;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use deltalake::storage::ObjectStoreRef;
+use deltalake::DeltaTableBuilder;
+use futures::TryStreamExt;
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectMeta;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+const ICEBERG_METADATA_DIR: &str = "metadata";
+const HUDI_METADATA_DIR: &str = ".hoodie";
+
+/// Open table formats other than Delta that can be detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableFormat {
+    Iceberg,
+    Hudi,
+}
+
+impl TableFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            TableFormat::Iceberg => "Iceberg",
+            TableFormat::Hudi => "Hudi",
+        }
+    }
+}
+
+/// Which format's metadata a local directory holds, if any. Remote paths
+/// aren't probed.
+pub fn detect_local(path: &str) -> Option<TableFormat> {
+    let path = Path::new(path);
+    if path.join(HUDI_METADATA_DIR).join("hoodie.properties").is_file() {
+        return Some(TableFormat::Hudi);
+    }
+    let has_iceberg_metadata = std::fs::read_dir(path.join(ICEBERG_METADATA_DIR)).is_ok_and(|entries| {
+        entries
+            .filter_map(|e| e.ok())
+            .any(|e| e.file_name().to_string_lossy().ends_with(".metadata.json"))
+    });
+    has_iceberg_metadata.then_some(TableFormat::Iceberg)
+}
+
+/// How well something carries over to Delta. Ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Compatibility {
+    /// Converts as is
+    Direct,
+    /// Converts with a change in type, layout or behavior
+    NeedsChanges,
+    /// No Delta equivalent; the table can't be migrated as it is
+    Blocker,
+}
+
+impl Compatibility {
+    fn label(self) -> &'static str {
+        match self {
+            Compatibility::Direct => "OK",
+            Compatibility::NeedsChanges => "CHANGES",
+            Compatibility::Blocker => "BLOCKER",
+        }
+    }
+
+    fn tone(self) -> Tone {
+        match self {
+            Compatibility::Direct => Tone::Good,
+            Compatibility::NeedsChanges => Tone::Warning,
+            Compatibility::Blocker => Tone::Critical,
+        }
+    }
+}
+
+/// Basic facts read from the table's own metadata.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TableFacts {
+    pub name: Option<String>,
+    /// Iceberg `format-version` or Hudi `hoodie.table.version`
+    pub format_version: Option<String>,
+    /// Hudi `COPY_ON_WRITE` or `MERGE_ON_READ`
+    pub table_type: Option<String>,
+    pub last_updated: Option<DateTime<Utc>>,
+    /// Iceberg snapshots or completed Hudi commits still in the metadata
+    pub commits: usize,
+    pub records: Option<i64>,
+    pub data_files: Option<i64>,
+    pub total_size_bytes: Option<i64>,
+    /// Hudi record key fields
+    pub record_key: Vec<String>,
+    pub properties: BTreeMap<String, String>,
+}
+
+/// A source element (column, partition transform or feature) and its Delta
+/// equivalent.
+#[derive(Debug, Clone, Serialize)]
+pub struct Mapping {
+    pub source: String,
+    pub delta: String,
+    pub compatibility: Compatibility,
+    pub note: Option<String>,
+}
+
+impl Mapping {
+    fn new(source: impl Into<String>, delta: impl Into<String>, compatibility: Compatibility) -> Self {
+        Self {
+            source: source.into(),
+            delta: delta.into(),
+            compatibility,
+            note: None,
+        }
+    }
+
+    fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+}
+
+/// Column, partitioning and feature mappings and the overall verdict.
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationReport {
+    pub path: String,
+    pub format: TableFormat,
+    pub facts: TableFacts,
+    /// One entry per top-level column; nested types take their worst field
+    pub columns: Vec<Mapping>,
+    pub partitioning: Vec<Mapping>,
+    pub features: Vec<Mapping>,
+    /// Worst compatibility across all mappings
+    pub readiness: Compatibility,
+}
+
+/// Detect Iceberg or Hudi metadata under `path` and build the readiness
+/// report. Fails when neither is found.
+pub async fn report(path: &str, storage_options: HashMap<String, String>) -> Result<MigrationReport> {
+    let store = requests::metered(
+        DeltaTableBuilder::from_uri(path)
+            .with_storage_options(storage_options)
+            .build_storage()
+            .with_context(|| format!("Failed to open {}", path))?
+            .object_store(),
+    );
+
+    let hudi_properties = ObjectPath::from(format!("{}/hoodie.properties", HUDI_METADATA_DIR));
+    let (format, facts, columns, partitioning, features) = if store.head(&hudi_properties).await.is_ok() {
+        let (facts, columns, partitioning, features) = hudi(&store).await?;
+        (TableFormat::Hudi, facts, columns, partitioning, features)
+    } else if let Some(metadata) = latest_iceberg_metadata(&store).await? {
+        let (facts, columns, partitioning, features) = iceberg(&store, &metadata).await?;
+        (TableFormat::Iceberg, facts, columns, partitioning, features)
+    } else {
+        bail!(
+            "No Iceberg ({}/*.metadata.json) or Hudi ({}/hoodie.properties) metadata found at {}",
+            ICEBERG_METADATA_DIR,
+            HUDI_METADATA_DIR,
+            path
+        );
+    };
+
+    let readiness = columns
+        .iter()
+        .chain(&partitioning)
+        .chain(&features)
+        .map(|m| m.compatibility)
+        .max()
+        .unwrap_or(Compatibility::Direct);
+    Ok(MigrationReport {
+        path: path.to_string(),
+        format,
+        facts,
+        columns,
+        partitioning,
+        features,
+        readiness,
+    })
+}
+
+type Mappings = (TableFacts, Vec<Mapping>, Vec<Mapping>, Vec<Mapping>);
+
+async fn read_string(store: &ObjectStoreRef, location: &ObjectPath) -> Result<String> {
+    let bytes = store
+        .get(location)
+        .await
+        .with_context(|| format!("Failed to read {}", location))?
+        .bytes()
+        .await?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+async fn list(store: &ObjectStoreRef, dir: &str) -> Result<Vec<ObjectMeta>> {
+    Ok(store.list(Some(&ObjectPath::from(dir))).try_collect().await?)
+}
+
+// Iceberg
+
+/// The current metadata file: the one `version-hint.text` names, else the
+/// highest numbered `*.metadata.json`.
+async fn latest_iceberg_metadata(store: &ObjectStoreRef) -> Result<Option<ObjectPath>> {
+    let hint = ObjectPath::from(format!("{}/version-hint.text", ICEBERG_METADATA_DIR));
+    if let Ok(version) = read_string(store, &hint).await {
+        let candidate = ObjectPath::from(format!("{}/v{}.metadata.json", ICEBERG_METADATA_DIR, version.trim()));
+        if store.head(&candidate).await.is_ok() {
+            return Ok(Some(candidate));
+        }
+    }
+    // Named v<N>.metadata.json (Hadoop catalog) or <N>-<uuid>.metadata.json
+    let sequence = |meta: &ObjectMeta| -> i64 {
+        let name = meta.location.filename().unwrap_or_default();
+        name.trim_start_matches('v')
+            .split(['-', '.'])
+            .next()
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(-1)
+    };
+    Ok(list(store, ICEBERG_METADATA_DIR)
+        .await?
+        .into_iter()
+        .filter(|meta| meta.location.as_ref().ends_with(".metadata.json"))
+        .max_by_key(|meta| (sequence(meta), meta.last_modified))
+        .map(|meta| meta.location))
+}
+
+async fn iceberg(store: &ObjectStoreRef, metadata_path: &ObjectPath) -> Result<Mappings> {
+    let metadata: Value = serde_json::from_str(&read_string(store, metadata_path).await?)
+        .with_context(|| format!("Failed to parse {}", metadata_path))?;
+    let format_version = metadata["format-version"].as_i64().unwrap_or(1);
+
+    let properties: BTreeMap<String, String> = metadata["properties"]
+        .as_object()
+        .map(|props| {
+            props
+                .iter()
+                .map(|(k, v)| (k.clone(), v.as_str().map_or_else(|| v.to_string(), str::to_string)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let snapshots = metadata["snapshots"].as_array().cloned().unwrap_or_default();
+    let current = metadata["current-snapshot-id"]
+        .as_i64()
+        .and_then(|id| snapshots.iter().find(|s| s["snapshot-id"].as_i64() == Some(id)));
+    let summary =
+        |key: &str| -> Option<i64> { current.and_then(|s| s["summary"][key].as_str()).and_then(|v| v.parse().ok()) };
+
+    let facts = TableFacts {
+        name: metadata["location"]
+            .as_str()
+            .and_then(|l| l.trim_end_matches('/').rsplit('/').next())
+            .map(str::to_string),
+        format_version: Some(format_version.to_string()),
+        table_type: None,
+        last_updated: metadata["last-updated-ms"]
+            .as_i64()
+            .and_then(|ms| Utc.timestamp_millis_opt(ms).single()),
+        commits: snapshots.len(),
+        records: summary("total-records"),
+        data_files: summary("total-data-files"),
+        total_size_bytes: summary("total-files-size"),
+        record_key: Vec::new(),
+        properties: properties.clone(),
+    };
+
+    // v2 keeps every schema and points at the current one; v1 has just `schema`
+    let schema = metadata["schemas"]
+        .as_array()
+        .and_then(|schemas| {
+            let id = metadata["current-schema-id"].as_i64();
+            schemas.iter().find(|s| s["schema-id"].as_i64() == id)
+        })
+        .unwrap_or(&metadata["schema"]);
+    let fields = schema["fields"].as_array().cloned().unwrap_or_default();
+    let names: HashMap<i64, String> = fields
+        .iter()
+        .filter_map(|f| Some((f["id"].as_i64()?, f["name"].as_str()?.to_string())))
+        .collect();
+    let columns = fields
+        .iter()
+        .map(|field| {
+            let name = field["name"].as_str().unwrap_or("?");
+            let mapping = iceberg_type(&field["type"]);
+            Mapping { source: format!("{} {}", name, mapping.source), ..mapping }
+        })
+        .collect();
+
+    // v1 has a single `partition-spec` list of fields
+    let spec_fields = metadata["partition-specs"]
+        .as_array()
+        .and_then(|specs| {
+            let id = metadata["default-spec-id"].as_i64();
+            specs.iter().find(|s| s["spec-id"].as_i64() == id)
+        })
+        .map(|spec| spec["fields"].clone())
+        .unwrap_or_else(|| metadata["partition-spec"].clone());
+    let partitioning = spec_fields
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .iter()
+        .map(|field| {
+            let column = field["source-id"]
+                .as_i64()
+                .and_then(|id| names.get(&id).cloned())
+                .unwrap_or_else(|| field["name"].as_str().unwrap_or("?").to_string());
+            iceberg_transform(&column, field["transform"].as_str().unwrap_or("identity"))
+        })
+        .collect();
+
+    let mut features = Vec::new();
+    let spec_count = metadata["partition-specs"].as_array().map_or(1, |specs| specs.len());
+    if spec_count > 1 {
+        features.push(
+            Mapping::new(
+                format!("Partition evolution ({} specs)", spec_count),
+                "Fixed partitioning",
+                Compatibility::NeedsChanges,
+            )
+            .note("Delta partitions every file the same way; files written under older specs must be rewritten to the current one."),
+        );
+    }
+    let schema_count = metadata["schemas"].as_array().map_or(1, |schemas| schemas.len());
+    features.push(
+        Mapping::new(
+            "Field IDs / schema evolution",
+            "Column mapping (delta.columnMapping.mode = id)",
+            Compatibility::NeedsChanges,
+        )
+        .note(if schema_count > 1 {
+            format!(
+                "{} schema versions: renamed or dropped columns resolve by field ID, so enable column mapping to keep Parquet files readable without a rewrite.",
+                schema_count
+            )
+        } else {
+            "Iceberg resolves columns by field ID; enable column mapping to keep renames and drops cheap after migrating.".to_string()
+        }),
+    );
+    let deletes = summary("total-delete-files").unwrap_or(0);
+    let equality_deletes = summary("total-equality-deletes").unwrap_or(0);
+    if equality_deletes > 0 {
+        features.push(
+            Mapping::new(
+                format!("Equality deletes ({} rows)", equality_deletes),
+                "None",
+                Compatibility::Blocker,
+            )
+            .note("Delta has no equality deletes; rewrite the affected data files (e.g. compaction with delete application) before converting."),
+        );
+    } else if deletes > 0 {
+        features.push(
+            Mapping::new(format!("Position delete files ({})", deletes), "Deletion vectors", Compatibility::NeedsChanges)
+                .note("Converters don't carry delete files over; compact them into the data files first, or rewrite the table."),
+        );
+    }
+    if metadata["sort-orders"]
+        .as_array()
+        .is_some_and(|orders| orders.iter().any(|o| o["fields"].as_array().is_some_and(|f| !f.is_empty())))
+    {
+        features.push(
+            Mapping::new("Sort order", "Z-order or liquid clustering", Compatibility::NeedsChanges)
+                .note("Delta doesn't sort on write; cluster the sort columns with OPTIMIZE ZORDER BY or liquid clustering."),
+        );
+    }
+    if metadata["refs"]
+        .as_object()
+        .is_some_and(|refs| refs.keys().any(|name| name != "main"))
+    {
+        features.push(
+            Mapping::new("Branches / tags", "None", Compatibility::NeedsChanges)
+                .note("Only the main branch is migrated; tags can become Delta version numbers or shallow clones."),
+        );
+    }
+    let file_format = properties
+        .get("write.format.default")
+        .map_or("parquet".to_string(), |f| f.to_lowercase());
+    if file_format != "parquet" {
+        features.push(
+            Mapping::new(format!("{} data files", file_format.to_uppercase()), "Parquet only", Compatibility::Blocker)
+                .note("Delta only reads Parquet; the data has to be rewritten."),
+        );
+    }
+    if format_version >= 3 {
+        features.push(
+            Mapping::new(format!("Format version {}", format_version), "Unknown", Compatibility::NeedsChanges)
+                .note("v3 features (row lineage, default values, deletion vectors) aren't checked; review them by hand."),
+        );
+    }
+    features.push(
+        Mapping::new(
+            format!("Snapshot history ({})", snapshots.len()),
+            "Delta history from version 0",
+            Compatibility::NeedsChanges,
+        )
+        .note("Only the current snapshot converts; time travel to older snapshots is lost."),
+    );
+
+    Ok((facts, columns, partitioning, features))
+}
+
+/// Map an Iceberg type to its Delta type. `source` is the Iceberg type as
+/// text; nested types carry their worst field.
+fn iceberg_type(ty: &Value) -> Mapping {
+    if let Some(primitive) = ty.as_str() {
+        return iceberg_primitive(primitive);
+    }
+    let nested = |source: String, delta: String, parts: Vec<Mapping>| {
+        let worst = parts.iter().max_by_key(|m| m.compatibility);
+        Mapping {
+            source,
+            delta,
+            compatibility: worst.map_or(Compatibility::Direct, |m| m.compatibility),
+            note: worst.and_then(|m| m.note.clone()),
+        }
+    };
+    match ty["type"].as_str() {
+        Some("struct") => {
+            let fields: Vec<(String, Mapping)> = ty["fields"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+                .iter()
+                .map(|f| (f["name"].as_str().unwrap_or("?").to_string(), iceberg_type(&f["type"])))
+                .collect();
+            let join = |side: fn(&Mapping) -> &str| {
+                fields
+                    .iter()
+                    .map(|(name, m)| format!("{}: {}", name, side(m)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            nested(
+                format!("struct<{}>", join(|m| &m.source)),
+                format!("struct<{}>", join(|m| &m.delta)),
+                fields.into_iter().map(|(_, m)| m).collect(),
+            )
+        }
+        Some("list") => {
+            let element = iceberg_type(&ty["element"]);
+            nested(
+                format!("list<{}>", element.source),
+                format!("array<{}>", element.delta),
+                vec![element],
+            )
+        }
+        Some("map") => {
+            let (key, value) = (iceberg_type(&ty["key"]), iceberg_type(&ty["value"]));
+            nested(
+                format!("map<{}, {}>", key.source, value.source),
+                format!("map<{}, {}>", key.delta, value.delta),
+                vec![key, value],
+            )
+        }
+        _ => Mapping::new(ty.to_string(), "?", Compatibility::Blocker).note("Unrecognized Iceberg type."),
+    }
+}
+
+fn iceberg_primitive(ty: &str) -> Mapping {
+    use Compatibility::*;
+    let direct = |delta: &str| Mapping::new(ty, delta, Direct);
+    match ty {
+        "boolean" | "float" | "double" | "string" | "binary" | "date" | "long" => direct(ty),
+        "int" => direct("integer"),
+        "timestamptz" => direct("timestamp"),
+        "timestamp" => Mapping::new(ty, "timestamp_ntz", NeedsChanges)
+            .note("Needs the timestampNtz table feature (reader 3 / writer 7)."),
+        "timestamp_ns" => Mapping::new(ty, "timestamp_ntz", NeedsChanges)
+            .note("Delta timestamps have microsecond precision; nanoseconds are truncated."),
+        "timestamptz_ns" => Mapping::new(ty, "timestamp", NeedsChanges)
+            .note("Delta timestamps have microsecond precision; nanoseconds are truncated."),
+        "uuid" => Mapping::new(ty, "string", NeedsChanges).note("Delta has no UUID type; stored as a 36-character string."),
+        "time" => Mapping::new(ty, "long", Blocker)
+            .note("Delta has no TIME type; the column must be rewritten, e.g. as microseconds since midnight."),
+        "variant" => Mapping::new(ty, "variant", NeedsChanges).note("Needs the variantType table feature."),
+        _ if ty.starts_with("decimal") => direct(ty),
+        _ if ty.starts_with("fixed") => {
+            Mapping::new(ty, "binary", NeedsChanges).note("Delta binary doesn't enforce the fixed length.")
+        }
+        _ => Mapping::new(ty, "?", Blocker).note("Unrecognized Iceberg type."),
+    }
+}
+
+/// Map an Iceberg partition transform to Delta partitioning.
+fn iceberg_transform(column: &str, transform: &str) -> Mapping {
+    use Compatibility::*;
+    let source = format!("{}({})", transform, column);
+    let generated = |expression: String| {
+        Mapping::new(source.clone(), format!("PARTITIONED BY generated column {}", expression), NeedsChanges)
+            .note("No hidden partitioning in Delta: queries must filter on the source column for the generated column to prune.")
+    };
+    match transform {
+        "identity" => Mapping::new(source.clone(), format!("PARTITIONED BY ({})", column), Direct),
+        "year" | "month" | "day" | "hour" => {
+            let expression = match transform {
+                "year" => format!("YEAR({})", column),
+                "month" => format!("DATE_FORMAT({}, 'yyyy-MM')", column),
+                "day" => format!("CAST({} AS DATE)", column),
+                _ => format!("DATE_FORMAT({}, 'yyyy-MM-dd-HH')", column),
+            };
+            generated(expression)
+        }
+        "void" => Mapping::new(source, "Not partitioned", Direct).note("Dropped partition field."),
+        _ if transform.starts_with("truncate") => {
+            let width = transform.trim_start_matches("truncate[").trim_end_matches(']');
+            generated(format!("SUBSTRING({}, 1, {})", column, width))
+        }
+        _ if transform.starts_with("bucket") => Mapping::new(source, "Liquid clustering or Z-order", NeedsChanges)
+            .note("Delta has no bucket partitioning; cluster on the column instead."),
+        _ => Mapping::new(source, "?", Blocker).note("Unrecognized partition transform."),
+    }
+}
+
+// Hudi
+
+/// Parse `hoodie.properties`, a Java properties file.
+fn parse_properties(text: &str) -> BTreeMap<String, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('!'))
+        .filter_map(|line| {
+            let (key, value) = line.split_once(['=', ':'])?;
+            Some((key.trim().to_string(), value.trim().replace("\\:", ":").replace("\\=", "=")))
+        })
+        .collect()
+}
+
+async fn hudi(store: &ObjectStoreRef) -> Result<Mappings> {
+    use Compatibility::NeedsChanges;
+    let properties = parse_properties(
+        &read_string(store, &ObjectPath::from(format!("{}/hoodie.properties", HUDI_METADATA_DIR))).await?,
+    );
+    let property = |key: &str| properties.get(key).cloned();
+    let fields = |key: &str| -> Vec<String> {
+        property(key)
+            .map(|v| v.split(',').map(|f| f.trim().to_string()).filter(|f| !f.is_empty()).collect())
+            .unwrap_or_default()
+    };
+
+    // Instants are <timestamp>.<action>[.<state>] files, completed ones with no
+    // state; the timeline moved under .hoodie/timeline in table version 8.
+    // Deeper paths belong to the archive or the metadata table's own timeline.
+    let mut completed: BTreeMap<String, String> = BTreeMap::new();
+    let mut requested_compactions = Vec::new();
+    for meta in list(store, HUDI_METADATA_DIR).await? {
+        let location = meta.location.as_ref();
+        let relative = location.strip_prefix(&format!("{}/", HUDI_METADATA_DIR)).unwrap_or(location);
+        let name = relative.strip_prefix("timeline/").unwrap_or(relative);
+        if name.contains('/') {
+            continue;
+        }
+        let Some((instant, action)) = name.split_once('.') else {
+            continue;
+        };
+        // v8 names completed instants <requested>_<completed>
+        let instant = instant.split('_').next().unwrap_or(instant);
+        if instant.len() < 14 || !instant.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        match action {
+            "compaction.requested" => requested_compactions.push(instant.to_string()),
+            _ if !action.contains('.') => {
+                completed.insert(instant.to_string(), action.to_string());
+            }
+            _ => {}
+        }
+    }
+    let commits = completed
+        .values()
+        .filter(|action| matches!(action.as_str(), "commit" | "deltacommit" | "replacecommit"))
+        .count();
+    let pending_compactions = requested_compactions
+        .iter()
+        .filter(|instant| !completed.contains_key(*instant))
+        .count();
+    let latest = completed.keys().next_back().cloned();
+
+    let table_type = property("hoodie.table.type").unwrap_or_else(|| "COPY_ON_WRITE".to_string());
+    let record_key = fields("hoodie.table.recordkey.fields");
+    let facts = TableFacts {
+        name: property("hoodie.table.name"),
+        format_version: property("hoodie.table.version"),
+        table_type: Some(table_type.clone()),
+        // Instants are yyyyMMddHHmmss[SSS] in the writer's timezone, usually UTC
+        last_updated: latest.as_deref().and_then(|instant| {
+            chrono::NaiveDateTime::parse_from_str(&instant[..14], "%Y%m%d%H%M%S")
+                .ok()
+                .map(|t| t.and_utc())
+        }),
+        commits,
+        records: None,
+        data_files: None,
+        total_size_bytes: None,
+        record_key: record_key.clone(),
+        properties: properties.clone(),
+    };
+
+    let columns = match property("hoodie.table.create.schema") {
+        Some(schema) => {
+            let schema: Value = serde_json::from_str(&schema).context("Failed to parse hoodie.table.create.schema")?;
+            schema["fields"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+                .iter()
+                .map(|field| {
+                    let name = field["name"].as_str().unwrap_or("?");
+                    let mapping = avro_type(&field["type"]);
+                    Mapping { source: format!("{} {}", name, mapping.source), ..mapping }
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    // Partition fields may carry a key generator type, as in `ts:TIMESTAMP`
+    let hive_style = property("hoodie.datasource.write.hive_style_partitioning").is_some_and(|v| v == "true");
+    let partitioning = fields("hoodie.table.partition.fields")
+        .iter()
+        .map(|field| {
+            let (column, kind) = field.split_once(':').unwrap_or((field.as_str(), "SIMPLE"));
+            let mapping = if kind.eq_ignore_ascii_case("TIMESTAMP") {
+                Mapping::new(field.clone(), format!("PARTITIONED BY generated column from {}", column), NeedsChanges)
+                    .note("Timestamp-based keys format the partition value; recreate the format as a generated column.")
+            } else {
+                Mapping::new(field.clone(), format!("PARTITIONED BY ({})", column), Compatibility::Direct)
+            };
+            if hive_style || mapping.compatibility != Compatibility::Direct {
+                mapping
+            } else {
+                Mapping { compatibility: NeedsChanges, ..mapping }
+                    .note("Partition directories aren't key=value, so files have to be rewritten rather than converted in place.")
+            }
+        })
+        .collect();
+
+    let mut features = Vec::new();
+    if table_type.eq_ignore_ascii_case("MERGE_ON_READ") {
+        features.push(
+            Mapping::new("MERGE_ON_READ table", "Copy-on-write files (+ deletion vectors)", NeedsChanges).note(format!(
+                "Log files must be compacted into base files before converting{}.",
+                if pending_compactions > 0 {
+                    format!("; {} compactions are pending", pending_compactions)
+                } else {
+                    String::new()
+                }
+            )),
+        );
+    } else {
+        features.push(Mapping::new("COPY_ON_WRITE table", "Delta table", Compatibility::Direct));
+    }
+    if !record_key.is_empty() {
+        let precombine = property("hoodie.table.precombine.field");
+        features.push(
+            Mapping::new(format!("Record key ({})", record_key.join(", ")), "MERGE INTO on the key", NeedsChanges).note(format!(
+                "Delta doesn't enforce keys; upserts become MERGE INTO ... ON {}{}.",
+                record_key.join(" AND "),
+                precombine.map_or(String::new(), |f| format!(", keeping the row with the highest {}", f))
+            )),
+        );
+    }
+    if property("hoodie.populate.meta.fields").is_none_or(|v| v != "false") {
+        features.push(
+            Mapping::new("_hoodie_* meta columns", "Regular columns", NeedsChanges)
+                .note("The five _hoodie_ columns are in every data file; drop them when rewriting, or keep them as plain columns."),
+        );
+    }
+    if let Some(format) = property("hoodie.table.base.file.format").filter(|f| !f.eq_ignore_ascii_case("PARQUET")) {
+        features.push(
+            Mapping::new(format!("{} base files", format), "Parquet only", Compatibility::Blocker)
+                .note("Delta only reads Parquet; the data has to be rewritten."),
+        );
+    }
+    if properties.keys().any(|k| k.starts_with("hoodie.table.metadata.partitions")) {
+        features.push(
+            Mapping::new("Metadata table indexes", "File statistics / bloom filters", NeedsChanges)
+                .note("Hudi's record and bloom indexes aren't migrated; Delta relies on per-file min/max statistics."),
+        );
+    }
+    features.push(
+        Mapping::new(format!("Timeline ({} commits)", commits), "Delta history from version 0", NeedsChanges)
+            .note("Only the latest state converts; incremental queries and time travel to older instants are lost."),
+    );
+
+    Ok((facts, columns, partitioning, features))
+}
+
+/// Map an Avro type from Hudi's table schema to its Delta type.
+fn avro_type(ty: &Value) -> Mapping {
+    use Compatibility::*;
+    // Nullable fields are a union with "null"
+    if let Some(union) = ty.as_array() {
+        let types: Vec<&Value> = union.iter().filter(|t| t.as_str() != Some("null")).collect();
+        return match types.as_slice() {
+            [single] => avro_type(single),
+            _ => Mapping::new(ty.to_string(), "?", Blocker).note("Delta has no union types."),
+        };
+    }
+    if let Some(primitive) = ty.as_str() {
+        return match primitive {
+            "boolean" | "long" | "float" | "double" | "string" | "binary" => Mapping::new(primitive, primitive, Direct),
+            "int" => Mapping::new(primitive, "integer", Direct),
+            "bytes" => Mapping::new(primitive, "binary", Direct),
+            _ => Mapping::new(primitive, "?", Blocker).note("Named type references aren't resolved."),
+        };
+    }
+    let kind = ty["type"].as_str().unwrap_or("?");
+    match (kind, ty["logicalType"].as_str()) {
+        (_, Some("date")) => Mapping::new("date", "date", Direct),
+        (_, Some("timestamp-millis")) | (_, Some("timestamp-micros")) => {
+            Mapping::new(ty["logicalType"].as_str().unwrap_or(kind), "timestamp", Direct)
+        }
+        (_, Some(local)) if local.starts_with("local-timestamp") => Mapping::new(local, "timestamp_ntz", NeedsChanges)
+            .note("Needs the timestampNtz table feature (reader 3 / writer 7)."),
+        (_, Some("decimal")) => {
+            let (precision, scale) = (ty["precision"].as_i64().unwrap_or(0), ty["scale"].as_i64().unwrap_or(0));
+            let decimal = format!("decimal({}, {})", precision, scale);
+            if precision > 38 {
+                Mapping::new(decimal.clone(), decimal, Blocker).note("Delta decimals hold at most 38 digits.")
+            } else {
+                Mapping::new(decimal.clone(), decimal, Direct)
+            }
+        }
+        (_, Some("uuid")) => Mapping::new("uuid", "string", Direct),
+        (_, Some(time)) if time.starts_with("time-") => Mapping::new(time, "long", Blocker)
+            .note("Delta has no TIME type; the column must be rewritten, e.g. as microseconds since midnight."),
+        ("record", _) => {
+            let fields: Vec<(String, Mapping)> = ty["fields"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+                .iter()
+                .map(|f| (f["name"].as_str().unwrap_or("?").to_string(), avro_type(&f["type"])))
+                .collect();
+            let worst = fields.iter().map(|(_, m)| m).max_by_key(|m| m.compatibility);
+            Mapping {
+                source: "record".to_string(),
+                delta: format!(
+                    "struct<{}>",
+                    fields.iter().map(|(n, m)| format!("{}: {}", n, m.delta)).collect::<Vec<_>>().join(", ")
+                ),
+                compatibility: worst.map_or(Direct, |m| m.compatibility),
+                note: worst.and_then(|m| m.note.clone()),
+            }
+        }
+        ("array", _) => {
+            let items = avro_type(&ty["items"]);
+            Mapping { source: format!("array<{}>", items.source), delta: format!("array<{}>", items.delta), ..items }
+        }
+        ("map", _) => {
+            let values = avro_type(&ty["values"]);
+            Mapping {
+                source: format!("map<{}>", values.source),
+                delta: format!("map<string, {}>", values.delta),
+                ..values
+            }
+        }
+        ("enum", _) => Mapping::new("enum", "string", NeedsChanges).note("Enum symbols aren't enforced in Delta."),
+        ("fixed", _) => Mapping::new("fixed", "binary", NeedsChanges).note("Delta binary doesn't enforce the fixed length."),
+        (primitive, None) => avro_type(&Value::String(primitive.to_string())),
+        _ => Mapping::new(ty.to_string(), "?", Blocker).note("Unrecognized Avro type."),
+    }
+}
+
+impl Renderable for MigrationReport {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new(format!("Migration readiness: {} table at {}", self.format.name(), self.path));
+        let verdict = match self.readiness {
+            Compatibility::Direct => "Ready: everything maps directly to Delta.",
+            Compatibility::NeedsChanges => "Ready with changes: some columns, partitioning or features need adapting.",
+            Compatibility::Blocker => "Blocked: resolve the blockers below before migrating.",
+        };
+        doc.list(vec![Item::new(format!("[{}]", self.readiness.label()), verdict).tone(self.readiness.tone())]);
+
+        let facts = &self.facts;
+        let unknown = || "unknown".to_string();
+        let mut fields = vec![
+            ("Name", facts.name.clone().unwrap_or_else(unknown)),
+            ("Format version", facts.format_version.clone().unwrap_or_else(unknown)),
+        ];
+        if let Some(table_type) = &facts.table_type {
+            fields.push(("Table type", table_type.clone()));
+        }
+        if !facts.record_key.is_empty() {
+            fields.push(("Record key", facts.record_key.join(", ")));
+        }
+        fields.extend([
+            (
+                "Last updated",
+                facts.last_updated.map_or_else(unknown, |t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+            ),
+            ("Commits in metadata", facts.commits.to_string()),
+            ("Records", facts.records.map_or_else(unknown, |r| r.to_string())),
+            ("Data files", facts.data_files.map_or_else(unknown, |f| f.to_string())),
+            ("Total size", facts.total_size_bytes.map_or_else(unknown, format_bytes)),
+        ]);
+        doc.fields(fields);
+
+        let rows = |mappings: &[Mapping]| -> Vec<Vec<String>> {
+            mappings
+                .iter()
+                .map(|m| {
+                    vec![
+                        m.source.clone(),
+                        m.delta.clone(),
+                        m.compatibility.label().to_string(),
+                        m.note.clone().unwrap_or_default(),
+                    ]
+                })
+                .collect()
+        };
+        doc.heading("Columns");
+        if self.columns.is_empty() {
+            doc.text("No schema in the table metadata.");
+        } else {
+            doc.table(&["Column", "Delta type", "Status", "Note"], rows(&self.columns));
+        }
+        doc.heading("Partitioning");
+        if self.partitioning.is_empty() {
+            doc.text("Not partitioned.");
+        } else {
+            doc.table(&["Partition field", "Delta", "Status", "Note"], rows(&self.partitioning));
+        }
+        doc.heading("Features").table(&["Feature", "Delta", "Status", "Note"], rows(&self.features));
+
+        doc.heading("Next steps");
+        doc.text(match self.format {
+            TableFormat::Iceberg => {
+                "Convert in place with CONVERT TO DELTA iceberg.`<path>` (Databricks) or translate the metadata with \
+                 Apache XTable; otherwise rewrite with CREATE TABLE ... USING DELTA AS SELECT."
+            }
+            TableFormat::Hudi => {
+                "Translate the metadata with Apache XTable (copy-on-write tables), or rewrite with \
+                 CREATE TABLE ... USING DELTA AS SELECT, dropping the _hoodie_ columns."
+            }
+        });
+        doc
+    }
+}
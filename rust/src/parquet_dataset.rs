@@ -0,0 +1,379 @@
+//! Degraded inspection of a plain Parquet dataset, i.e. a directory of
+//! Parquet files without a `_delta_log`.
+//!
+//! With no transaction log there are no versions, history, tombstones or
+//! per-file statistics. What's left is the file listing, hive-style partition
+//! directories and a sample of Parquet footers, which is enough to assess the
+//! layout before migrating the dataset to Delta.
+
+use crate::insights::{AnalyzerThresholds, DeltaTableAnalyzer, Insight};
+use crate::inspector::{FileInfo, TableStatistics};
+use crate::partitions::partition_key;
+use crate::render::{Document, Item, Renderable, Tone};
+use crate::report::{size_histogram, SizeBucket};
+use crate::requests;
+use anyhow::{bail, Context, Result};
+use deltalake::storage::ObjectStoreRef;
+use deltalake::DeltaTableBuilder;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectMeta;
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// Footers read to sample row counts, row groups, codecs and schema.
+const FOOTER_SAMPLE_FILES: usize = 20;
+const FOOTER_READ_CONCURRENCY: usize = 8;
+/// Partitions listed individually in the report, largest file count first.
+const LISTED_PARTITIONS: usize = 20;
+
+/// Directory levels searched for a Parquet file, deep enough for a few
+/// levels of partition directories.
+const PROBE_DEPTH: usize = 5;
+
+/// Whether `path`, a local directory or an object store URL, holds Parquet
+/// files but no `_delta_log`. Paths that can't be listed count as not.
+pub async fn is_parquet_dataset(path: &str, storage_options: HashMap<String, String>) -> bool {
+    // The builder creates missing local directories, so check first
+    if !path.contains("://") && !Path::new(path).is_dir() {
+        return false;
+    }
+    let Ok(storage) = DeltaTableBuilder::from_uri(path).with_storage_options(storage_options).build_storage() else {
+        return false;
+    };
+    let store = requests::metered(storage.object_store());
+
+    let mut level: Vec<Option<ObjectPath>> = vec![None];
+    for depth in 0..PROBE_DEPTH {
+        let mut next = Vec::new();
+        for dir in level {
+            let Ok(listing) = store.list_with_delimiter(dir.as_ref()).await else {
+                continue;
+            };
+            if depth == 0 && listing.common_prefixes.iter().any(|p| p.filename() == Some("_delta_log")) {
+                return false;
+            }
+            if listing.objects.iter().any(|meta| meta.location.extension() == Some("parquet")) {
+                return true;
+            }
+            next.extend(listing.common_prefixes.into_iter().map(Some));
+        }
+        level = next;
+    }
+    false
+}
+
+/// What a degraded inspection found.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParquetDatasetReport {
+    pub path: String,
+    /// Always "parquet", so JSON consumers can tell this apart from a Delta table
+    pub format: &'static str,
+    pub num_files: usize,
+    pub total_size_bytes: i64,
+    /// Files that aren't Parquet data, such as `_SUCCESS` markers and `.crc` files
+    pub other_files: usize,
+    pub file_sizes: Vec<SizeBucket>,
+    /// Hive-style `key=value` directory names, outermost first
+    pub partition_columns: Vec<String>,
+    pub partitions: Vec<PartitionDirectory>,
+    pub footer_sample: FooterSample,
+    pub insights: Vec<Insight>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PartitionDirectory {
+    pub key: String,
+    pub files: usize,
+    pub bytes: i64,
+}
+
+/// Figures read from the footers of a sample of files.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FooterSample {
+    pub files: usize,
+    pub rows: i64,
+    pub row_groups: usize,
+    pub codecs: Vec<String>,
+    /// Column name and Arrow type from the first sampled file, in file order
+    pub schema: Vec<(String, String)>,
+    /// Sampled files whose schema differs from the first one
+    pub schema_mismatches: usize,
+    /// Rows in the whole dataset, extrapolated from the sample's rows per
+    /// byte; exact when every file was sampled
+    pub estimated_rows: Option<i64>,
+}
+
+/// List `path`, read a sample of footers and run the layout checks.
+pub async fn inspect(
+    path: &str,
+    storage_options: HashMap<String, String>,
+    thresholds: &AnalyzerThresholds,
+) -> Result<ParquetDatasetReport> {
+    let store = requests::metered(
+        DeltaTableBuilder::from_uri(path)
+            .with_storage_options(storage_options)
+            .build_storage()
+            .with_context(|| format!("Failed to open {}", path))?
+            .object_store(),
+    );
+
+    let root = store
+        .list_with_delimiter(None)
+        .await
+        .with_context(|| format!("Failed to list {}", path))?;
+    if root.common_prefixes.iter().any(|p| p.filename() == Some("_delta_log")) {
+        bail!(
+            "{} is a Delta table (it has a _delta_log); inspect it with the Delta commands instead, e.g. `deltective report {}`",
+            path,
+            path
+        );
+    }
+
+    let listing: Vec<ObjectMeta> = store
+        .list(None)
+        .try_collect()
+        .await
+        .with_context(|| format!("Failed to list {}", path))?;
+    // Tables nested in the dataset keep their logs out of the file counts
+    let listing = listing
+        .into_iter()
+        .filter(|meta| !meta.location.as_ref().split('/').any(|part| part == "_delta_log"));
+    let (data, other): (Vec<_>, Vec<_>) = listing.partition(|meta| {
+        let location = meta.location.as_ref();
+        location.ends_with(".parquet")
+            && !location.split('/').any(|part| part.starts_with('_') || part.starts_with('.'))
+    });
+
+    let mut partition_columns: Vec<String> = Vec::new();
+    let files: Vec<FileInfo> = data
+        .iter()
+        .map(|meta| {
+            let mut partition_values = HashMap::new();
+            for part in meta.location.as_ref().split('/') {
+                if let Some((key, value)) = part.split_once('=') {
+                    if !partition_columns.iter().any(|c| c == key) {
+                        partition_columns.push(key.to_string());
+                    }
+                    partition_values.insert(key.to_string(), value.to_string());
+                }
+            }
+            FileInfo {
+                path: meta.location.to_string(),
+                size_bytes: meta.size as i64,
+                modification_time: meta.last_modified,
+                partition_values,
+                num_records: None,
+                min_values: HashMap::new(),
+                max_values: HashMap::new(),
+                null_counts: HashMap::new(),
+                deletion_vector: None,
+                rewritten: false,
+            }
+        })
+        .collect();
+
+    let mut partitions: HashMap<String, PartitionDirectory> = HashMap::new();
+    if !partition_columns.is_empty() {
+        for file in &files {
+            let key = partition_key(&file.partition_values);
+            let entry = partitions.entry(key.clone()).or_insert(PartitionDirectory { key, files: 0, bytes: 0 });
+            entry.files += 1;
+            entry.bytes += file.size_bytes;
+        }
+    }
+    let mut partitions: Vec<PartitionDirectory> = partitions.into_values().collect();
+    partitions.sort_by(|a, b| b.files.cmp(&a.files).then_with(|| a.key.cmp(&b.key)));
+
+    let total_size_bytes = files.iter().map(|f| f.size_bytes).sum();
+    let footer_sample = sample_footers(&store, &files, total_size_bytes).await;
+
+    let stats = TableStatistics {
+        table_path: path.to_string(),
+        num_files: files.len(),
+        total_size_bytes,
+        partition_columns: partition_columns.clone(),
+        files,
+        ..TableStatistics::default()
+    };
+    let insights = DeltaTableAnalyzer::with_thresholds(stats.clone(), thresholds.clone()).analyze_layout();
+
+    Ok(ParquetDatasetReport {
+        path: path.to_string(),
+        format: "parquet",
+        num_files: stats.num_files,
+        total_size_bytes,
+        other_files: other.len(),
+        file_sizes: size_histogram(&stats.files),
+        partition_columns,
+        partitions,
+        footer_sample,
+        insights,
+    })
+}
+
+/// Read footers of files spread evenly across the size distribution. Files
+/// whose footer can't be read are left out of the sample.
+async fn sample_footers(store: &ObjectStoreRef, files: &[FileInfo], total_size_bytes: i64) -> FooterSample {
+    let mut by_size: Vec<&FileInfo> = files.iter().collect();
+    by_size.sort_by_key(|f| f.size_bytes);
+    let step = (by_size.len() / FOOTER_SAMPLE_FILES).max(1);
+    let sample: Vec<&FileInfo> = by_size.into_iter().step_by(step).take(FOOTER_SAMPLE_FILES).collect();
+
+    let footers: Vec<(i64, Footer)> = stream::iter(sample)
+        .map(|file| {
+            let store = store.clone();
+            let path = ObjectPath::from(file.path.as_str());
+            let size = file.size_bytes;
+            async move { read_footer(store, path).await.ok().map(|footer| (size, footer)) }
+        })
+        .buffer_unordered(FOOTER_READ_CONCURRENCY)
+        .filter_map(|footer| async move { footer })
+        .collect()
+        .await;
+
+    let mut result = FooterSample::default();
+    let mut codecs = BTreeSet::new();
+    let mut sampled_bytes = 0;
+    for (size, footer) in footers {
+        if result.files == 0 {
+            result.schema = footer.schema.clone();
+        } else if !same_columns(&footer.schema, &result.schema) {
+            result.schema_mismatches += 1;
+        }
+        result.files += 1;
+        result.rows += footer.rows;
+        result.row_groups += footer.row_groups;
+        codecs.extend(footer.codecs);
+        sampled_bytes += size;
+    }
+    result.codecs = codecs.into_iter().collect();
+    if result.files == files.len() {
+        result.estimated_rows = Some(result.rows);
+    } else if sampled_bytes > 0 {
+        result.estimated_rows = Some((result.rows as f64 / sampled_bytes as f64 * total_size_bytes as f64) as i64);
+    }
+    result
+}
+
+struct Footer {
+    rows: i64,
+    row_groups: usize,
+    codecs: BTreeSet<String>,
+    schema: Vec<(String, String)>,
+}
+
+/// Same names and types; order doesn't matter since readers match columns by name.
+fn same_columns(a: &[(String, String)], b: &[(String, String)]) -> bool {
+    a.len() == b.len() && a.iter().all(|column| b.contains(column))
+}
+
+async fn read_footer(store: ObjectStoreRef, path: ObjectPath) -> Result<Footer> {
+    let meta = store.head(&path).await?;
+    let reader = ParquetObjectReader::new(store, meta);
+    let builder = ParquetRecordBatchStreamBuilder::new(reader).await?;
+    let metadata = builder.metadata();
+    Ok(Footer {
+        rows: metadata.file_metadata().num_rows(),
+        row_groups: metadata.num_row_groups(),
+        codecs: metadata
+            .row_groups()
+            .iter()
+            .flat_map(|rg| rg.columns().iter().map(|c| c.compression().to_string()))
+            .collect(),
+        schema: builder
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| (field.name().clone(), format!("{:?}", field.data_type())))
+            .collect(),
+    })
+}
+
+impl Renderable for ParquetDatasetReport {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new(format!("Parquet dataset (not a Delta table): {}", self.path));
+        doc.text(
+            "No _delta_log found, so this is a degraded inspection: versions, history, VACUUM state and \
+             per-file statistics aren't available. Figures come from the file listing and a sample of Parquet footers.",
+        );
+        doc.fields([
+            ("Data files", self.num_files.to_string()),
+            ("Total size", format_bytes(self.total_size_bytes)),
+            (
+                "Average file size",
+                format_bytes(self.total_size_bytes / self.num_files.max(1) as i64),
+            ),
+            ("Other files", self.other_files.to_string()),
+        ]);
+
+        doc.heading("File size distribution").table(
+            &["Size", "Files", "Bytes"],
+            self.file_sizes
+                .iter()
+                .map(|b| vec![b.label.clone(), b.files.to_string(), format_bytes(b.bytes)])
+                .collect(),
+        );
+
+        doc.heading("Partition directories");
+        if self.partition_columns.is_empty() {
+            doc.text("None: files aren't in key=value directories.");
+        } else {
+            doc.fields([
+                ("Columns", self.partition_columns.join(", ")),
+                ("Partitions", self.partitions.len().to_string()),
+                (
+                    "Files per partition",
+                    format!("{:.1}", self.num_files as f64 / self.partitions.len().max(1) as f64),
+                ),
+            ]);
+            let mut rows: Vec<Vec<String>> = self
+                .partitions
+                .iter()
+                .take(LISTED_PARTITIONS)
+                .map(|p| vec![p.key.clone(), p.files.to_string(), format_bytes(p.bytes)])
+                .collect();
+            if self.partitions.len() > LISTED_PARTITIONS {
+                rows.push(vec![
+                    format!("… {} more", self.partitions.len() - LISTED_PARTITIONS),
+                    String::new(),
+                    String::new(),
+                ]);
+            }
+            doc.table(&["Partition", "Files", "Size"], rows);
+        }
+
+        let sample = &self.footer_sample;
+        doc.heading(format!("Footer sample ({} of {} files)", sample.files, self.num_files));
+        doc.fields([
+            ("Rows in sample", sample.rows.to_string()),
+            match sample.estimated_rows {
+                Some(rows) if sample.files == self.num_files => ("Total rows", rows.to_string()),
+                Some(rows) => ("Estimated rows", format!("~{}", rows)),
+                None => ("Estimated rows", "unknown".to_string()),
+            },
+            (
+                "Row groups per file",
+                format!("{:.1}", sample.row_groups as f64 / sample.files.max(1) as f64),
+            ),
+            ("Codecs", sample.codecs.join(", ")),
+            ("Columns", sample.schema.len().to_string()),
+            ("Files with a different schema", sample.schema_mismatches.to_string()),
+        ]);
+
+        doc.heading("Insights").list(
+            self.insights
+                .iter()
+                .map(|i| {
+                    Item::new(format!("[{}]", i.severity.to_uppercase()), i.title.clone())
+                        .detail(i.description.clone())
+                        .tone(Tone::from_severity(&i.severity))
+                })
+                .collect(),
+        );
+        doc
+    }
+}
@@ -0,0 +1,200 @@
+use crate::insights::DeltaTableAnalyzer;
+use crate::inspector::FileInfo;
+use crate::render::{Document, Renderable};
+use anyhow::{Context, Result};
+use deltalake::storage::ObjectStoreRef;
+use futures::stream::{self, StreamExt};
+use object_store::path::Path as ObjectPath;
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+const FOOTER_READ_CONCURRENCY: usize = 8;
+
+/// The layout of one data file as its Parquet footer describes it.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileFooter {
+    pub path: String,
+    pub size_bytes: i64,
+    pub num_rows: i64,
+    /// Writer that produced the file, e.g. `parquet-mr version 1.12.3`
+    pub created_by: Option<String>,
+    pub row_groups: Vec<RowGroupSummary>,
+    /// Column chunks summed over the row groups, in file schema order
+    pub columns: Vec<ColumnChunkSummary>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RowGroupSummary {
+    pub num_rows: i64,
+    pub compressed_bytes: i64,
+    pub uncompressed_bytes: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnChunkSummary {
+    /// Dotted leaf path, which for nested types includes Parquet's
+    /// `list.element` and `key_value` levels
+    pub path: String,
+    pub physical_type: String,
+    pub codecs: Vec<String>,
+    pub encodings: Vec<String>,
+    pub compressed_bytes: i64,
+    pub uncompressed_bytes: i64,
+}
+
+impl ColumnChunkSummary {
+    /// Uncompressed over compressed size; `None` for empty chunks.
+    pub fn compression_ratio(&self) -> Option<f64> {
+        (self.compressed_bytes > 0).then(|| self.uncompressed_bytes as f64 / self.compressed_bytes as f64)
+    }
+}
+
+/// Read the footer of the file at `path`, relative to the root of `store`
+/// as in add actions.
+pub async fn read_file_footer(store: ObjectStoreRef, path: &str) -> Result<FileFooter> {
+    let location = ObjectPath::from(path);
+    let meta = store.head(&location).await.with_context(|| format!("File {} not found", path))?;
+    let size_bytes = meta.size as i64;
+    let builder = ParquetRecordBatchStreamBuilder::new(ParquetObjectReader::new(store, meta))
+        .await
+        .with_context(|| format!("Failed to read the Parquet footer of {}", path))?;
+    let metadata = builder.metadata();
+
+    let mut columns: Vec<(ColumnChunkSummary, BTreeSet<String>, BTreeSet<String>)> = Vec::new();
+    for row_group in metadata.row_groups() {
+        for (idx, chunk) in row_group.columns().iter().enumerate() {
+            if columns.len() <= idx {
+                columns.push((
+                    ColumnChunkSummary {
+                        path: chunk.column_path().string(),
+                        physical_type: chunk.column_type().to_string(),
+                        codecs: Vec::new(),
+                        encodings: Vec::new(),
+                        compressed_bytes: 0,
+                        uncompressed_bytes: 0,
+                    },
+                    BTreeSet::new(),
+                    BTreeSet::new(),
+                ));
+            }
+            let (summary, codecs, encodings) = &mut columns[idx];
+            summary.compressed_bytes += chunk.compressed_size();
+            summary.uncompressed_bytes += chunk.uncompressed_size();
+            // The level isn't stored in the file, so ZSTD(ZstdLevel(1)) would mislead
+            let codec = chunk.compression().to_string();
+            codecs.insert(codec.split('(').next().unwrap_or(&codec).to_string());
+            encodings.extend(chunk.encodings().iter().map(|encoding| encoding.to_string()));
+        }
+    }
+
+    Ok(FileFooter {
+        path: path.to_string(),
+        size_bytes,
+        num_rows: metadata.file_metadata().num_rows(),
+        created_by: metadata.file_metadata().created_by().map(str::to_string),
+        row_groups: metadata
+            .row_groups()
+            .iter()
+            .map(|row_group| RowGroupSummary {
+                num_rows: row_group.num_rows(),
+                compressed_bytes: row_group.compressed_size(),
+                uncompressed_bytes: row_group.total_byte_size(),
+            })
+            .collect(),
+        columns: columns
+            .into_iter()
+            .map(|(summary, codecs, encodings)| ColumnChunkSummary {
+                codecs: codecs.into_iter().collect(),
+                encodings: encodings.into_iter().collect(),
+                ..summary
+            })
+            .collect(),
+    })
+}
+
+/// Footers of `sample_files` files spread evenly over the size range, or of
+/// every file when `None`. Footers that can't be read are `None`.
+pub async fn read_footers(
+    store: ObjectStoreRef,
+    files: &[FileInfo],
+    sample_files: Option<usize>,
+) -> Vec<(&FileInfo, Option<FileFooter>)> {
+    let mut by_size: Vec<&FileInfo> = files.iter().collect();
+    by_size.sort_by_key(|f| f.size_bytes);
+    let selected: Vec<&FileInfo> = match sample_files {
+        Some(sample) if sample < by_size.len() => {
+            let step = (by_size.len() / sample.max(1)).max(1);
+            by_size.into_iter().step_by(step).take(sample).collect()
+        }
+        _ => by_size,
+    };
+
+    // The reads own their paths so the returned future stays Send for any
+    // lifetime of `files`, which axum handlers need
+    let paths: Vec<String> = selected.iter().map(|file| file.path.clone()).collect();
+    let footers: Vec<(usize, Option<FileFooter>)> = stream::iter(paths.into_iter().enumerate())
+        .map(|(i, path)| {
+            let store = store.clone();
+            async move { (i, read_file_footer(store, &path).await.ok()) }
+        })
+        .buffer_unordered(FOOTER_READ_CONCURRENCY)
+        .collect()
+        .await;
+    footers.into_iter().map(|(i, footer)| (selected[i], footer)).collect()
+}
+
+impl Renderable for FileFooter {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new(format!("Parquet footer of {}", self.path));
+        let uncompressed: i64 = self.columns.iter().map(|c| c.uncompressed_bytes).sum();
+        let compressed: i64 = self.columns.iter().map(|c| c.compressed_bytes).sum();
+        doc.fields([
+            ("File size", format_bytes(self.size_bytes)),
+            ("Rows", self.num_rows.to_string()),
+            ("Row groups", self.row_groups.len().to_string()),
+            ("Columns", self.columns.len().to_string()),
+            (
+                "Data",
+                format!("{} compressed, {} uncompressed", format_bytes(compressed), format_bytes(uncompressed)),
+            ),
+            ("Created by", self.created_by.clone().unwrap_or_else(|| "-".to_string())),
+        ]);
+
+        doc.heading("Row groups").table(
+            &["#", "Rows", "Compressed", "Uncompressed"],
+            self.row_groups
+                .iter()
+                .enumerate()
+                .map(|(idx, rg)| {
+                    vec![
+                        idx.to_string(),
+                        rg.num_rows.to_string(),
+                        format_bytes(rg.compressed_bytes),
+                        format_bytes(rg.uncompressed_bytes),
+                    ]
+                })
+                .collect(),
+        );
+
+        doc.heading("Column chunks").table(
+            &["Column", "Type", "Codec", "Encodings", "Compressed", "Uncompressed", "Ratio"],
+            self.columns
+                .iter()
+                .map(|c| {
+                    vec![
+                        c.path.clone(),
+                        c.physical_type.clone(),
+                        c.codecs.join(", "),
+                        c.encodings.join(", "),
+                        format_bytes(c.compressed_bytes),
+                        format_bytes(c.uncompressed_bytes),
+                        c.compression_ratio().map(|r| format!("{:.1}×", r)).unwrap_or_else(|| "-".to_string()),
+                    ]
+                })
+                .collect(),
+        );
+        doc
+    }
+}
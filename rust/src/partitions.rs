@@ -0,0 +1,163 @@
+use crate::inspector::{FileInfo, TableStatistics};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A node in the partition hierarchy, one level per partition column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionNode {
+    pub column: String,
+    pub value: String,
+    /// `col1=v1/col2=v2/...` path from the root, used as a stable key
+    pub path: String,
+    pub depth: usize,
+    pub file_count: usize,
+    pub total_size_bytes: i64,
+    pub children: Vec<PartitionNode>,
+}
+
+/// Aggregate of all files sharing one full set of partition values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionSummary {
+    pub key: String,
+    pub values: HashMap<String, String>,
+    pub file_count: usize,
+    pub total_size_bytes: i64,
+    /// Sum of `numRecords`, if every file in the partition reports it
+    pub num_rows: Option<i64>,
+    pub last_modified: DateTime<Utc>,
+    /// Indices into `TableStatistics::files`, newest first
+    pub file_indices: Vec<usize>,
+}
+
+/// Rollup of all partitions at a single hierarchy level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionLevelSummary {
+    pub depth: usize,
+    pub column: String,
+    pub num_partitions: usize,
+    pub avg_files_per_partition: f64,
+    pub avg_size_per_partition: i64,
+}
+
+/// Canonical `col=value,col=value` key for a set of partition values.
+pub fn partition_key(values: &HashMap<String, String>) -> String {
+    let mut parts: Vec<String> = values
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    parts.sort();
+    parts.join(",")
+}
+
+/// Group the table's files by their full partition key.
+pub fn summarize_partitions(stats: &TableStatistics) -> Vec<PartitionSummary> {
+    let mut groups: HashMap<String, PartitionSummary> = HashMap::new();
+
+    for (idx, file) in stats.files.iter().enumerate() {
+        let key = partition_key(&file.partition_values);
+        let entry = groups.entry(key.clone()).or_insert_with(|| PartitionSummary {
+            key,
+            values: file.partition_values.clone(),
+            file_count: 0,
+            total_size_bytes: 0,
+            num_rows: Some(0),
+            last_modified: file.modification_time,
+            file_indices: Vec::new(),
+        });
+        entry.file_count += 1;
+        entry.total_size_bytes += file.size_bytes;
+        entry.num_rows = match (entry.num_rows, file.num_records) {
+            (Some(total), Some(rows)) => Some(total + rows),
+            _ => None,
+        };
+        entry.last_modified = entry.last_modified.max(file.modification_time);
+        entry.file_indices.push(idx);
+    }
+
+    let mut partitions: Vec<PartitionSummary> = groups.into_values().collect();
+    for partition in &mut partitions {
+        // Newest files first
+        partition
+            .file_indices
+            .sort_by(|&a, &b| stats.files[b].modification_time.cmp(&stats.files[a].modification_time));
+    }
+    partitions
+}
+
+/// Build the partition hierarchy following the table's partition column order.
+/// Returns the top-level nodes sorted by value.
+pub fn build_partition_tree(stats: &TableStatistics) -> Vec<PartitionNode> {
+    let files: Vec<&FileInfo> = stats.files.iter().collect();
+    build_level(&files, &stats.partition_columns, 0, "")
+}
+
+fn build_level(
+    files: &[&FileInfo],
+    columns: &[String],
+    depth: usize,
+    parent_path: &str,
+) -> Vec<PartitionNode> {
+    let Some(column) = columns.get(depth) else {
+        return Vec::new();
+    };
+
+    let mut groups: std::collections::BTreeMap<String, Vec<&FileInfo>> =
+        std::collections::BTreeMap::new();
+    for file in files {
+        let value = file
+            .partition_values
+            .get(column)
+            .cloned()
+            .unwrap_or_else(|| "__HIVE_DEFAULT_PARTITION__".to_string());
+        groups.entry(value).or_default().push(file);
+    }
+
+    groups
+        .into_iter()
+        .map(|(value, group)| {
+            let path = if parent_path.is_empty() {
+                format!("{}={}", column, value)
+            } else {
+                format!("{}/{}={}", parent_path, column, value)
+            };
+            let children = build_level(&group, columns, depth + 1, &path);
+            PartitionNode {
+                column: column.clone(),
+                value,
+                depth,
+                file_count: group.len(),
+                total_size_bytes: group.iter().map(|f| f.size_bytes).sum(),
+                children,
+                path,
+            }
+        })
+        .collect()
+}
+
+/// Summarize each level of the hierarchy (e.g. year, year/month, year/month/day).
+pub fn summarize_levels(stats: &TableStatistics, tree: &[PartitionNode]) -> Vec<PartitionLevelSummary> {
+    let mut summaries = Vec::new();
+    let mut level: Vec<&PartitionNode> = tree.iter().collect();
+
+    for (depth, column) in stats.partition_columns.iter().enumerate() {
+        if level.is_empty() {
+            break;
+        }
+        let num_partitions = level.len();
+        let total_files: usize = level.iter().map(|n| n.file_count).sum();
+        let total_size: i64 = level.iter().map(|n| n.total_size_bytes).sum();
+
+        summaries.push(PartitionLevelSummary {
+            depth,
+            column: column.clone(),
+            num_partitions,
+            avg_files_per_partition: total_files as f64 / num_partitions as f64,
+            avg_size_per_partition: total_size / num_partitions as i64,
+        });
+
+        level = level.iter().flat_map(|n| n.children.iter()).collect();
+    }
+
+    summaries
+}
@@ -0,0 +1,327 @@
+use crate::data_skipping;
+use crate::insights::{AnalyzerThresholds, Insight};
+use crate::inspector::TableStatistics;
+use crate::maintenance::default_vacuum_retention_hours;
+use crate::render::{Document, Item, Renderable};
+use crate::upgrade::{quote_identifier, table_reference};
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+/// Insights a bin-packing compaction addresses.
+const COMPACTION_INSIGHTS: [&str; 6] = [
+    "insight-small-files-critical",
+    "insight-small-files-warning",
+    "insight-suboptimal-file-size",
+    "insight-high-file-count",
+    "insight-regular-optimization",
+    "insight-small-writes",
+];
+const CODEC_INSIGHTS: [&str; 3] = ["insight-uncompressed-files", "insight-unexpected-codec", "insight-zstd-savings"];
+const VACUUM_INSIGHTS: [&str; 4] = [
+    "insight-never-vacuumed",
+    "insight-not-vacuumed-in-log",
+    "insight-vacuum-overdue",
+    "insight-reclaimable-storage",
+];
+const CHECKPOINT_INSIGHTS: [&str; 2] = ["insight-checkpoint-stale", "insight-no-checkpoint"];
+
+/// Language of the generated script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Dialect {
+    /// Spark SQL, e.g. in a Databricks notebook or `spark-sql`
+    Sql,
+    /// Python with the `deltalake` (delta-rs) package
+    Python,
+}
+
+impl Dialect {
+    pub const NAMES: [&'static str; 2] = ["sql", "python"];
+
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "sql" => Ok(Dialect::Sql),
+            "python" => Ok(Dialect::Python),
+            other => bail!("Unknown script dialect '{}'; expected one of {}", other, Self::NAMES.join(", ")),
+        }
+    }
+
+    fn comment(self) -> &'static str {
+        match self {
+            Dialect::Sql => "--",
+            Dialect::Python => "#",
+        }
+    }
+}
+
+/// One maintenance action, with the statements performing it.
+#[derive(Debug, Clone, Serialize)]
+pub struct PrescribedStep {
+    pub description: String,
+    /// Titles of the insights the step addresses
+    pub addresses: Vec<String>,
+    /// Empty when the dialect has no way to do it
+    pub statements: Vec<String>,
+}
+
+/// Maintenance script for a table, derived from its insights.
+#[derive(Debug, Clone, Serialize)]
+pub struct Prescription {
+    pub table_path: String,
+    pub version: i64,
+    pub dialect: Dialect,
+    /// In the order to run them: properties first so the rewrite honours
+    /// them, VACUUM after OPTIMIZE so it can't delete what OPTIMIZE removed
+    pub steps: Vec<PrescribedStep>,
+}
+
+/// Turn the `insights` raised for `stats` into maintenance steps. Insights
+/// no statement can fix (schema changes, partitioning, CDF lag) are left out.
+pub fn prescribe(
+    stats: &TableStatistics,
+    insights: &[Insight],
+    thresholds: &AnalyzerThresholds,
+    dialect: Dialect,
+) -> Prescription {
+    let raised = |ids: &[&str]| -> Vec<String> {
+        insights
+            .iter()
+            .filter(|insight| ids.contains(&insight.id.as_str()))
+            .map(|insight| insight.title.clone())
+            .collect()
+    };
+    let enabled = |key: &str| stats.properties.get(key).is_some_and(|v| v.eq_ignore_ascii_case("true"));
+    let table = table_reference(&stats.table_path);
+    let mut steps = Vec::new();
+
+    let small_writes = raised(&["insight-small-writes"]);
+    let properties: Vec<&str> = ["delta.autoOptimize.optimizeWrite", "delta.autoOptimize.autoCompact"]
+        .into_iter()
+        .filter(|key| !enabled(key))
+        .collect();
+    if !small_writes.is_empty() && !properties.is_empty() {
+        let statement = match dialect {
+            Dialect::Sql => format!(
+                "ALTER TABLE {} SET TBLPROPERTIES ({});",
+                table,
+                properties.iter().map(|key| format!("'{}' = 'true'", key)).collect::<Vec<_>>().join(", ")
+            ),
+            Dialect::Python => format!(
+                "dt.alter.set_table_properties({{{}}})",
+                properties.iter().map(|key| format!("{}: \"true\"", python_string(key))).collect::<Vec<_>>().join(", ")
+            ),
+        };
+        steps.push(PrescribedStep {
+            description: "Have writers coalesce small writes (honoured by Spark and Databricks writers)".to_string(),
+            addresses: small_writes,
+            statements: vec![statement],
+        });
+    }
+
+    let codec_findings = raised(&CODEC_INSIGHTS);
+    let codec = (!codec_findings.is_empty()).then(|| {
+        stats
+            .codec_audit
+            .as_ref()
+            .and_then(|audit| audit.expected.clone())
+            .unwrap_or_else(|| "zstd".to_string())
+    });
+    if let (Some(codec), Dialect::Sql) = (&codec, dialect) {
+        steps.push(PrescribedStep {
+            description: format!("Write {} from here on; OPTIMIZE below recompresses only the files it rewrites", codec),
+            addresses: codec_findings.clone(),
+            statements: vec![format!("SET spark.sql.parquet.compression.codec = {};", codec.to_lowercase())],
+        });
+    }
+
+    let clustered = stats.writer_features.iter().any(|feature| feature == "clustering");
+    let candidates = data_skipping::zorder_candidates(&stats.data_skipping, &stats.schema);
+    let skipping = raised(&["insight-data-skipping"]);
+    // Files only change codec when OPTIMIZE rewrites them
+    let compaction: Vec<String> = raised(&COMPACTION_INSIGHTS).into_iter().chain(codec_findings).collect();
+    let target_size = (thresholds.target_file_mb * 1024.0 * 1024.0) as u64;
+    if !skipping.is_empty() && clustered {
+        let statements = match dialect {
+            Dialect::Sql => vec![
+                format!("ALTER TABLE {} CLUSTER BY ({});", table, sql_columns(&candidates)),
+                format!("OPTIMIZE {};", table),
+            ],
+            Dialect::Python => Vec::new(),
+        };
+        steps.push(PrescribedStep {
+            description: format!("Cluster by {} and recluster the table", candidates.join(", ")),
+            addresses: skipping.into_iter().chain(compaction).collect(),
+            statements,
+        });
+    } else if !skipping.is_empty() || !compaction.is_empty() {
+        let zorder = if skipping.is_empty() { Vec::new() } else { candidates };
+        let description = if zorder.is_empty() {
+            format!("Compact small files into files of about {} MB", thresholds.target_file_mb)
+        } else {
+            format!(
+                "Compact into files of about {} MB, Z-ordered by {}",
+                thresholds.target_file_mb,
+                zorder.join(", ")
+            )
+        };
+        let statements = match dialect {
+            Dialect::Sql => {
+                let mut optimize = format!("OPTIMIZE {}", table);
+                if !zorder.is_empty() {
+                    optimize.push_str(&format!(" ZORDER BY ({})", sql_columns(&zorder)));
+                }
+                vec![
+                    format!("SET spark.databricks.delta.optimize.maxFileSize = {};", target_size),
+                    format!("{};", optimize),
+                ]
+            }
+            Dialect::Python => {
+                let mut args = vec![format!("target_size={}", target_size)];
+                if let Some(codec) = &codec {
+                    args.push(format!("writer_properties=WriterProperties(compression={})", python_string(&codec.to_uppercase())));
+                }
+                if zorder.is_empty() {
+                    vec![format!("dt.optimize.compact({})", args.join(", "))]
+                } else {
+                    let columns = zorder.iter().map(|c| python_string(c)).collect::<Vec<_>>().join(", ");
+                    vec![format!("dt.optimize.z_order([{}], {})", columns, args.join(", "))]
+                }
+            }
+        };
+        steps.push(PrescribedStep {
+            description,
+            addresses: skipping.into_iter().chain(compaction).collect(),
+            statements,
+        });
+    }
+
+    let deletion_vectors = raised(&["insight-deletion-vector-density"]);
+    if !deletion_vectors.is_empty() {
+        steps.push(PrescribedStep {
+            description: "Rewrite files with deletion vectors so deleted rows stop being read and filtered".to_string(),
+            addresses: deletion_vectors,
+            statements: match dialect {
+                Dialect::Sql => vec![format!("REORG TABLE {} APPLY (PURGE);", table)],
+                Dialect::Python => Vec::new(),
+            },
+        });
+    }
+
+    let vacuum = raised(&VACUUM_INSIGHTS);
+    if !vacuum.is_empty() {
+        let hours = default_vacuum_retention_hours(stats);
+        steps.push(PrescribedStep {
+            description: format!("Delete files removed more than {} hours ago (the table's retention)", hours),
+            addresses: vacuum,
+            statements: vec![match dialect {
+                Dialect::Sql => format!("VACUUM {} RETAIN {} HOURS;", table, hours),
+                Dialect::Python => format!("dt.vacuum(retention_hours={}, dry_run=False)", hours),
+            }],
+        });
+    }
+
+    let checkpoint = raised(&CHECKPOINT_INSIGHTS);
+    if !checkpoint.is_empty() {
+        steps.push(PrescribedStep {
+            description: "Write a checkpoint so readers stop replaying JSON commits".to_string(),
+            addresses: checkpoint,
+            statements: match dialect {
+                // Spark only checkpoints on commit, every delta.checkpointInterval
+                Dialect::Sql => Vec::new(),
+                Dialect::Python => vec!["dt.create_checkpoint()".to_string()],
+            },
+        });
+    }
+
+    Prescription {
+        table_path: stats.table_path.clone(),
+        version: stats.version,
+        dialect,
+        steps,
+    }
+}
+
+/// `columns` as a Spark SQL column list, each name backtick-quoted.
+fn sql_columns(columns: &[String]) -> String {
+    columns.iter().map(|c| quote_identifier(c)).collect::<Vec<_>>().join(", ")
+}
+
+/// `value` as a double-quoted Python string literal.
+fn python_string(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => literal.push_str(&format!("\\u{:04x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+impl Prescription {
+    /// The steps as a script that can be run as is; steps the dialect can't
+    /// express are kept as comments.
+    pub fn script(&self) -> String {
+        let comment = self.dialect.comment();
+        let mut lines = vec![format!(
+            "{} Maintenance for {} at version {}, generated by deltective",
+            comment, self.table_path, self.version
+        )];
+        if self.dialect == Dialect::Python {
+            let writer_properties = self.steps.iter().flat_map(|s| &s.statements).any(|s| s.contains("WriterProperties"));
+            lines.push(if writer_properties {
+                "from deltalake import DeltaTable, WriterProperties".to_string()
+            } else {
+                "from deltalake import DeltaTable".to_string()
+            });
+            lines.push(String::new());
+            lines.push(format!("dt = DeltaTable({})", python_string(&self.table_path)));
+        }
+        if self.steps.is_empty() {
+            lines.push(format!("{} Nothing to do: no insight calls for maintenance", comment));
+        }
+
+        for (idx, step) in self.steps.iter().enumerate() {
+            lines.push(String::new());
+            lines.push(format!("{} {}. {}", comment, idx + 1, step.description));
+            lines.push(format!("{}    Addresses: {}", comment, step.addresses.join("; ")));
+            if step.statements.is_empty() {
+                lines.push(format!("{}    No equivalent in this dialect; run it from another engine", comment));
+            }
+            lines.extend(step.statements.iter().cloned());
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+impl Renderable for Prescription {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("Maintenance for {} at version {}", self.table_path, self.version));
+        if self.steps.is_empty() {
+            doc.text("Nothing to do: no insight calls for maintenance.");
+            return doc;
+        }
+        let steps = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| {
+                let statements = if step.statements.is_empty() {
+                    "No equivalent in this dialect; run it from another engine".to_string()
+                } else {
+                    step.statements.join("\n")
+                };
+                Item::new(format!("{:>2}.", idx + 1), format!("{} (addresses: {})", step.description, step.addresses.join("; ")))
+                    .detail(statements)
+            })
+            .collect();
+        doc.list(steps);
+        doc
+    }
+}
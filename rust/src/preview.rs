@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use datafusion::prelude::{ParquetReadOptions, SessionContext};
+use deltalake::arrow::record_batch::RecordBatch;
+use deltalake::arrow::util::display::{ArrayFormatter, FormatOptions};
+use deltalake::DeltaTable;
+use std::collections::HashMap;
+use std::sync::Arc;
+use url::Url;
+
+/// Default number of rows fetched for a preview.
+pub const PREVIEW_ROWS: usize = 50;
+
+/// What to read rows from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewSource {
+    Table,
+    Partition(HashMap<String, String>),
+    /// Data file path relative to the table root
+    File(String),
+}
+
+impl PreviewSource {
+    pub fn describe(&self) -> String {
+        match self {
+            PreviewSource::Table => "entire table".to_string(),
+            PreviewSource::Partition(values) => {
+                format!("partition {}", crate::partitions::partition_key(values))
+            }
+            PreviewSource::File(path) => format!("file {}", path),
+        }
+    }
+}
+
+/// A page of rows rendered to strings for display.
+#[derive(Debug, Clone)]
+pub struct DataPreview {
+    pub source: PreviewSource,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Read up to `limit` rows from the table snapshot (or one partition/file of it).
+pub async fn preview(table: &DeltaTable, source: PreviewSource, limit: usize) -> Result<DataPreview> {
+    let ctx = SessionContext::new();
+
+    let df = match &source {
+        PreviewSource::Table | PreviewSource::Partition(_) => {
+            ctx.register_table("delta_table", Arc::new(table.clone()))?;
+            let mut sql = "SELECT * FROM delta_table".to_string();
+            if let PreviewSource::Partition(values) = &source {
+                let mut predicates: Vec<String> = values
+                    .iter()
+                    .map(|(column, value)| {
+                        format!(
+                            "CAST(\"{}\" AS VARCHAR) = '{}'",
+                            column.replace('"', "\"\""),
+                            value.replace('\'', "''")
+                        )
+                    })
+                    .collect();
+                predicates.sort();
+                if !predicates.is_empty() {
+                    sql.push_str(" WHERE ");
+                    sql.push_str(&predicates.join(" AND "));
+                }
+            }
+            sql.push_str(&format!(" LIMIT {}", limit));
+            ctx.sql(&sql).await?
+        }
+        PreviewSource::File(path) => {
+            // Make the table's object store available for its own URL scheme
+            let table_url = Url::parse(&table.table_uri()).context("Invalid table URI")?;
+            ctx.runtime_env()
+                .register_object_store(&table_url, table.object_store());
+            let file_url = format!("{}/{}", table.table_uri().trim_end_matches('/'), path);
+            ctx.read_parquet(file_url, ParquetReadOptions::default())
+                .await?
+                .limit(0, Some(limit))?
+        }
+    };
+
+    let batches = df.collect().await?;
+    let columns = batches
+        .first()
+        .map(|b| b.schema().fields().iter().map(|f| f.name().clone()).collect())
+        .unwrap_or_default();
+
+    Ok(DataPreview {
+        source,
+        columns,
+        rows: batches_to_rows(&batches)?,
+    })
+}
+
+fn batches_to_rows(batches: &[RecordBatch]) -> Result<Vec<Vec<String>>> {
+    let options = FormatOptions::default().with_null("NULL");
+    let mut rows = Vec::new();
+
+    for batch in batches {
+        let formatters = batch
+            .columns()
+            .iter()
+            .map(|array| ArrayFormatter::try_new(array.as_ref(), &options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for row in 0..batch.num_rows() {
+            rows.push(formatters.iter().map(|f| f.value(row).to_string()).collect());
+        }
+    }
+
+    Ok(rows)
+}
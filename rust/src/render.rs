@@ -0,0 +1,148 @@
+pub mod html;
+pub mod markdown;
+pub mod text;
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+/// How an item should stand out, mapped to colours or markers by each renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tone {
+    Plain,
+    Good,
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Tone {
+    /// Tone for an insight severity string.
+    pub fn from_severity(severity: &str) -> Self {
+        match severity {
+            "critical" => Tone::Critical,
+            "warning" => Tone::Warning,
+            "info" => Tone::Info,
+            "good" => Tone::Good,
+            _ => Tone::Plain,
+        }
+    }
+}
+
+/// One entry of a list block: a short marker (e.g. `+`, `WARNING`, `3.`),
+/// the text, and optional detail shown beneath it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Item {
+    pub marker: String,
+    pub text: String,
+    pub detail: Option<String>,
+    pub tone: Tone,
+}
+
+impl Item {
+    pub fn new(marker: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            marker: marker.into(),
+            text: text.into(),
+            detail: None,
+            tone: Tone::Plain,
+        }
+    }
+
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn tone(mut self, tone: Tone) -> Self {
+        self.tone = tone;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum Block {
+    Heading(String),
+    Text(String),
+    /// Label/value pairs, e.g. a summary
+    Fields(Vec<(String, String)>),
+    Table { headers: Vec<String>, rows: Vec<Vec<String>> },
+    List(Vec<Item>),
+}
+
+/// Format-neutral description of an analysis result. Analyses describe their
+/// output once as a document and every [`Renderer`] lays it out. JSON output
+/// serializes the result itself instead (see [`render`]).
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub title: String,
+    pub blocks: Vec<Block>,
+}
+
+impl Document {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            blocks: Vec::new(),
+        }
+    }
+
+    pub fn heading(&mut self, text: impl Into<String>) -> &mut Self {
+        self.blocks.push(Block::Heading(text.into()));
+        self
+    }
+
+    pub fn text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.blocks.push(Block::Text(text.into()));
+        self
+    }
+
+    pub fn fields<K: Into<String>, V: Into<String>>(&mut self, fields: impl IntoIterator<Item = (K, V)>) -> &mut Self {
+        let fields = fields.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self.blocks.push(Block::Fields(fields));
+        self
+    }
+
+    pub fn table(&mut self, headers: &[&str], rows: Vec<Vec<String>>) -> &mut Self {
+        self.blocks.push(Block::Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows,
+        });
+        self
+    }
+
+    pub fn list(&mut self, items: Vec<Item>) -> &mut Self {
+        self.blocks.push(Block::List(items));
+        self
+    }
+}
+
+/// An analysis result that can be shown in any output format.
+pub trait Renderable: Serialize {
+    fn document(&self) -> Document;
+}
+
+/// Lays out a [`Document`] in one output format.
+pub trait Renderer {
+    type Output;
+
+    fn render(&self, document: &Document) -> Self::Output;
+}
+
+/// Output formats every [`Renderable`] supports on the command line.
+pub const FORMATS: [&str; 4] = ["text", "json", "markdown", "html"];
+
+/// Render `value` in one of [`FORMATS`]. JSON is `value` serialized as is;
+/// the other formats lay out its [`Document`].
+pub fn render<T: Renderable + ?Sized>(value: &T, format: &str) -> Result<String> {
+    if format == "json" {
+        return Ok(serde_json::to_string_pretty(value)? + "\n");
+    }
+    let document = value.document();
+    Ok(match format {
+        "text" => text::TextRenderer.render(&document),
+        "markdown" => markdown::MarkdownRenderer.render(&document),
+        "html" => html::HtmlRenderer.render(&document),
+        other => bail!("Unknown output format '{}'; expected one of {}", other, FORMATS.join(", ")),
+    })
+}
@@ -0,0 +1,118 @@
+use crate::render::{Block, Document, Renderer, Tone};
+use std::fmt::Write;
+
+/// Stylesheet of every HTML page deltective writes: generic documents and
+/// the richer `report` page.
+pub(crate) const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Roboto, sans-serif; margin: 0; background: #f5f6f8; color: #1f2933; }
+main { max-width: 1100px; margin: 0 auto; padding: 24px; }
+h1 { margin-bottom: 4px; }
+h2 { margin-top: 32px; border-bottom: 2px solid #d9dde3; padding-bottom: 4px; }
+.subtitle { color: #616e7c; margin-top: 0; }
+.cards { display: grid; grid-template-columns: repeat(auto-fit, minmax(160px, 1fr)); gap: 12px; }
+.card { background: #fff; border-radius: 6px; padding: 12px 16px; box-shadow: 0 1px 2px rgba(0,0,0,.08); }
+.card .label { color: #616e7c; font-size: 0.85em; }
+.card .value { font-size: 1.4em; font-weight: 600; }
+table { border-collapse: collapse; width: 100%; background: #fff; }
+th, td { text-align: left; padding: 6px 10px; border-bottom: 1px solid #e4e7eb; }
+th { background: #eef0f3; }
+ul { list-style: none; padding-left: 0; }
+li { background: #fff; border-left: 5px solid #d9dde3; padding: 6px 12px; margin-bottom: 6px; }
+.marker { font-weight: 600; margin-right: 6px; }
+.insight { background: #fff; border-left: 5px solid; border-radius: 4px; padding: 8px 14px; margin-bottom: 10px; }
+.insight h3 { margin: 4px 0; font-size: 1em; }
+.insight p { margin: 4px 0; }
+.critical { border-color: #d64545; }
+.warning { border-color: #e6a23c; }
+.info { border-color: #3b82c4; }
+.good { border-color: #3f9142; }
+.tag { font-size: 0.75em; text-transform: uppercase; color: #616e7c; }
+svg text { font-size: 12px; fill: #1f2933; }
+footer { color: #9aa5b1; font-size: 0.85em; margin-top: 32px; }
+"#;
+
+/// A standalone HTML page.
+pub struct HtmlRenderer;
+
+impl Renderer for HtmlRenderer {
+    type Output = String;
+
+    fn render(&self, document: &Document) -> String {
+        let title = escape(&document.title);
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<main>\n<h1>{}</h1>\n",
+            title, STYLE, title
+        );
+        for block in &document.blocks {
+            match block {
+                Block::Heading(text) => {
+                    let _ = writeln!(out, "<h2>{}</h2>", escape(text));
+                }
+                Block::Text(text) => {
+                    let _ = writeln!(out, "<p>{}</p>", escape(text));
+                }
+                Block::Fields(fields) => {
+                    out.push_str("<table>\n");
+                    for (label, value) in fields {
+                        let _ = writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", escape(label), escape(value));
+                    }
+                    out.push_str("</table>\n");
+                }
+                Block::Table { headers, rows } => {
+                    out.push_str("<table>\n<tr>");
+                    for header in headers {
+                        let _ = write!(out, "<th>{}</th>", escape(header));
+                    }
+                    out.push_str("</tr>\n");
+                    for row in rows {
+                        out.push_str("<tr>");
+                        for cell in row {
+                            let _ = write!(out, "<td>{}</td>", escape(cell));
+                        }
+                        out.push_str("</tr>\n");
+                    }
+                    out.push_str("</table>\n");
+                }
+                Block::List(items) => {
+                    out.push_str("<ul>\n");
+                    for item in items {
+                        let class = match item.tone {
+                            Tone::Plain => "",
+                            Tone::Good => "good",
+                            Tone::Info => "info",
+                            Tone::Warning => "warning",
+                            Tone::Critical => "critical",
+                        };
+                        let _ = write!(out, "<li class=\"{}\">", class);
+                        if !item.marker.is_empty() {
+                            let _ = write!(out, "<span class=\"marker\">{}</span>", escape(&item.marker));
+                        }
+                        out.push_str(&escape(&item.text));
+                        if let Some(detail) = &item.detail {
+                            let _ = write!(out, "<br><code>{}</code>", escape(detail));
+                        }
+                        out.push_str("</li>\n");
+                    }
+                    out.push_str("</ul>\n");
+                }
+            }
+        }
+        out.push_str("</main>\n</body>\n</html>\n");
+        out
+    }
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
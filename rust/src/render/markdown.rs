@@ -0,0 +1,55 @@
+use crate::render::{Block, Document, Renderer};
+use std::fmt::Write;
+
+/// GitHub-flavoured Markdown, for PRs, wikis and incident docs.
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    type Output = String;
+
+    fn render(&self, document: &Document) -> String {
+        let mut out = format!("# {}\n", document.title);
+        for block in &document.blocks {
+            out.push('\n');
+            match block {
+                Block::Heading(text) => {
+                    let _ = writeln!(out, "## {}", text);
+                }
+                Block::Text(text) => {
+                    let _ = writeln!(out, "{}", text);
+                }
+                Block::Fields(fields) => {
+                    out.push_str("| | |\n|---|---|\n");
+                    for (label, value) in fields {
+                        let _ = writeln!(out, "| {} | {} |", cell(label), cell(value));
+                    }
+                }
+                Block::Table { headers, rows } => {
+                    let _ = writeln!(out, "| {} |", headers.iter().map(|h| cell(h)).collect::<Vec<_>>().join(" | "));
+                    let _ = writeln!(out, "|{}", "---|".repeat(headers.len()));
+                    for row in rows {
+                        let _ = writeln!(out, "| {} |", row.iter().map(|c| cell(c)).collect::<Vec<_>>().join(" | "));
+                    }
+                }
+                Block::List(items) => {
+                    for item in items {
+                        if item.marker.is_empty() {
+                            let _ = writeln!(out, "- {}", item.text);
+                        } else {
+                            let _ = writeln!(out, "- **{}** {}", item.marker, item.text);
+                        }
+                        if let Some(detail) = &item.detail {
+                            let _ = writeln!(out, "  - `{}`", detail.replace('`', "'"));
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Keep a value on one table row: escape pipes and flatten newlines.
+pub(crate) fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
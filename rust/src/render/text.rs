@@ -0,0 +1,67 @@
+use crate::render::{Block, Document, Renderer};
+use std::fmt::Write;
+
+/// Plain terminal text, in the `═══ HEADING ═══` style of the TUI.
+pub struct TextRenderer;
+
+impl Renderer for TextRenderer {
+    type Output = String;
+
+    fn render(&self, document: &Document) -> String {
+        let mut out = format!("═══ {} ═══\n", document.title.to_uppercase());
+        for block in &document.blocks {
+            out.push('\n');
+            match block {
+                Block::Heading(text) => {
+                    let _ = writeln!(out, "═══ {} ═══", text.to_uppercase());
+                }
+                Block::Text(text) => {
+                    let _ = writeln!(out, "  {}", text);
+                }
+                Block::Fields(fields) => {
+                    let width = fields.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0) + 1;
+                    for (label, value) in fields {
+                        let _ = writeln!(out, "{:width$} {}", format!("{}:", label), value, width = width);
+                    }
+                }
+                Block::Table { headers, rows } => {
+                    let widths: Vec<usize> = (0..headers.len())
+                        .map(|col| {
+                            rows.iter()
+                                .filter_map(|row| row.get(col))
+                                .chain(std::iter::once(&headers[col]))
+                                .map(|cell| cell.chars().count())
+                                .max()
+                                .unwrap_or(0)
+                        })
+                        .collect();
+                    let line = |cells: &[String]| {
+                        let padded: Vec<String> = cells
+                            .iter()
+                            .zip(&widths)
+                            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                            .collect();
+                        format!("  {}", padded.join("  ").trim_end())
+                    };
+                    let _ = writeln!(out, "{}", line(headers));
+                    for row in rows {
+                        let _ = writeln!(out, "{}", line(row));
+                    }
+                }
+                Block::List(items) => {
+                    for item in items {
+                        if item.marker.is_empty() {
+                            let _ = writeln!(out, "  {}", item.text);
+                        } else {
+                            let _ = writeln!(out, "  {} {}", item.marker, item.text);
+                        }
+                        if let Some(detail) = &item.detail {
+                            let _ = writeln!(out, "      {}", detail);
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,462 @@
+pub mod html;
+pub mod markdown;
+
+use crate::attribution::Attribution;
+use crate::cost::CostEstimate;
+use crate::diagnosis::Diagnosis;
+use crate::insights::{health_score, DeltaTableAnalyzer, Insight};
+use crate::inspector::{commit_version, FileInfo, TableStatistics, TimelineAnalysis, WriteActivity};
+use crate::render::{Document, Item, Renderable, Tone};
+use crate::run_links::{self, RunLink, RunLinkTemplate};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use deltalake::kernel::CommitInfo;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Days of commit activity included in the report's timeline chart.
+const TIMELINE_DAYS: usize = 30;
+
+/// Most recent commits listed in the report.
+const RECENT_COMMITS: usize = 10;
+
+const MB: i64 = 1024 * 1024;
+
+/// Upper bounds (exclusive) of the file size buckets, with their labels. The
+/// last bucket is open-ended.
+const SIZE_BUCKETS: &[(i64, &str)] = &[
+    (MB, "< 1 MB"),
+    (10 * MB, "1–10 MB"),
+    (64 * MB, "10–64 MB"),
+    (128 * MB, "64–128 MB"),
+    (256 * MB, "128–256 MB"),
+    (1024 * MB, "256 MB–1 GB"),
+    (i64::MAX, "≥ 1 GB"),
+];
+
+/// Exactly what an analysis ran against, so it can be reproduced later with
+/// `--as-of-report`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provenance {
+    pub table_path: String,
+    /// Snapshot version analyzed
+    pub version: i64,
+    /// Commit time of that version
+    pub version_timestamp: Option<DateTime<Utc>>,
+    pub generated_at: DateTime<Utc>,
+    pub deltective_version: String,
+    pub deltalake_version: String,
+    /// Set when a writer was committing during the analysis
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_activity: Option<WriteActivity>,
+}
+
+impl Provenance {
+    pub fn new(stats: &TableStatistics) -> Self {
+        Self {
+            table_path: stats.table_path.clone(),
+            version: stats.version,
+            version_timestamp: stats.last_operation.as_ref().map(|op| op.timestamp),
+            generated_at: Utc::now(),
+            deltective_version: env!("CARGO_PKG_VERSION").to_string(),
+            deltalake_version: env!("DELTALAKE_VERSION").to_string(),
+            write_activity: stats.write_activity.clone(),
+        }
+    }
+
+    /// Read the provenance from a JSON export (`report`, `check` or `info`
+    /// with `--format json`). Exports from before provenance was recorded
+    /// still carry the table path and version at the top level.
+    pub fn from_export(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut value: serde_json::Value = serde_json::from_str(&text)
+            .with_context(|| format!("{} is not a JSON export", path.display()))?;
+        if let Some(provenance) = value.get_mut("provenance").map(serde_json::Value::take) {
+            return serde_json::from_value(provenance)
+                .with_context(|| format!("Invalid provenance in {}", path.display()));
+        }
+        let version = value["version"]
+            .as_i64()
+            .with_context(|| format!("{} records no table version", path.display()))?;
+        Ok(Self {
+            table_path: value["table_path"].as_str().unwrap_or_default().to_string(),
+            version,
+            version_timestamp: None,
+            generated_at: value["generated_at"]
+                .as_str()
+                .and_then(|t| t.parse().ok())
+                .unwrap_or_default(),
+            deltective_version: "unknown".to_string(),
+            deltalake_version: "unknown".to_string(),
+            write_activity: None,
+        })
+    }
+
+    /// One line for report footers.
+    pub fn summary(&self) -> String {
+        format!(
+            "Version {}{} · deltective {} · deltalake {}{}",
+            self.version,
+            self.version_timestamp
+                .map_or(String::new(), |t| format!(" (committed {})", format_time(Some(t)))),
+            self.deltective_version,
+            self.deltalake_version,
+            self.write_activity
+                .as_ref()
+                .map_or(String::new(), |activity| format!(" · {}", activity.notice()))
+        )
+    }
+}
+
+/// Everything a shareable table report shows, gathered up front so each
+/// output format only has to lay it out.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub generated_at: DateTime<Utc>,
+    pub provenance: Provenance,
+    pub table_path: String,
+    pub version: i64,
+    pub num_files: usize,
+    pub total_size_bytes: i64,
+    pub num_rows: Option<i64>,
+    pub total_versions: usize,
+    pub created_time: Option<DateTime<Utc>>,
+    pub last_write: Option<DateTime<Utc>>,
+    pub last_operation: Option<String>,
+    pub last_vacuum: Option<DateTime<Utc>>,
+    /// Monthly storage cost; missing when the statistics weren't enriched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<CostEstimate>,
+    pub min_reader_version: i32,
+    pub min_writer_version: i32,
+    pub table_features: Vec<String>,
+    pub schema: Vec<SchemaColumn>,
+    pub health_score: u8,
+    pub insights: Vec<Insight>,
+    /// Root-cause chains linking the insights; see [`crate::diagnosis`]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub diagnoses: Vec<Diagnosis>,
+    pub file_sizes: Vec<SizeBucket>,
+    pub timeline: TimelineSummary,
+    /// Latest commits, newest first
+    pub recent_history: Vec<HistoryEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryEntry {
+    pub version: i64,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub operation: String,
+    pub user_metadata: Option<String>,
+    /// Orchestrator runs that wrote the commit, from the config's `run_links`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub run_links: Vec<RunLink>,
+}
+
+impl HistoryEntry {
+    /// The commit message followed by its run links, for plain text.
+    fn message_with_links(&self) -> String {
+        let mut message = self.user_metadata.clone().unwrap_or_default();
+        for link in &self.run_links {
+            if !message.is_empty() {
+                message.push(' ');
+            }
+            message.push_str(&format!("↗ {}: {}", link.name, link.url));
+        }
+        message
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaColumn {
+    pub name: String,
+    pub data_type: String,
+    pub partition_column: bool,
+    /// Generation expression or identity definition, as DDL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeBucket {
+    pub label: String,
+    pub files: usize,
+    pub bytes: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineSummary {
+    pub total_operations: usize,
+    pub version_creation_rate: f64,
+    /// Operation → count, most frequent first
+    pub operations_by_type: Vec<(String, i32)>,
+    /// Commits per day over the most recent active days, oldest first
+    pub commits_per_day: Vec<(String, usize)>,
+    pub write_patterns: Vec<String>,
+    /// Engines, users and jobs writing the table
+    pub attribution: Attribution,
+}
+
+impl Report {
+    /// Build a report; `history` is newest first, as returned by
+    /// `DeltaTableInspector::get_history(false)`. Recent commits link to the
+    /// runs that wrote them through `run_link_templates`.
+    pub fn new(
+        stats: &TableStatistics,
+        timeline: &TimelineAnalysis,
+        history: &[CommitInfo],
+        insights: Vec<Insight>,
+        run_link_templates: &[RunLinkTemplate],
+    ) -> Self {
+        // Table order, as in the DDL; partition columns are flagged, not moved
+        let schema: Vec<SchemaColumn> = stats
+            .schema
+            .fields
+            .iter()
+            .map(|field| SchemaColumn {
+                name: field.name.clone(),
+                data_type: field.data_type.display(),
+                partition_column: stats.partition_columns.contains(&field.name),
+                generated: stats.generated_columns.iter().find(|c| c.name == field.name).map(|c| c.describe()),
+            })
+            .collect();
+
+        let mut table_features: Vec<String> = stats
+            .reader_features
+            .iter()
+            .chain(&stats.writer_features)
+            .cloned()
+            .collect();
+        table_features.sort();
+        table_features.dedup();
+
+        let provenance = Provenance::new(stats);
+        Self {
+            generated_at: provenance.generated_at,
+            provenance,
+            table_path: stats.table_path.clone(),
+            version: stats.version,
+            num_files: stats.num_files,
+            total_size_bytes: stats.total_size_bytes,
+            num_rows: stats.num_rows,
+            total_versions: stats.total_versions,
+            created_time: stats.created_time,
+            last_write: stats.last_operation.as_ref().map(|op| op.timestamp),
+            last_operation: stats.last_operation.as_ref().map(|op| op.operation.clone()),
+            last_vacuum: stats.last_vacuum,
+            cost: stats.cost.clone(),
+            min_reader_version: stats.min_reader_version,
+            min_writer_version: stats.min_writer_version,
+            table_features,
+            schema,
+            health_score: health_score(&insights),
+            insights,
+            diagnoses: Vec::new(),
+            file_sizes: size_histogram(&stats.files),
+            timeline: TimelineSummary::new(timeline),
+            recent_history: history
+                .iter()
+                .take(RECENT_COMMITS)
+                .filter_map(|commit| Some(HistoryEntry {
+                    version: commit_version(commit)?,
+                    timestamp: commit.timestamp.and_then(DateTime::from_timestamp_millis),
+                    operation: commit.operation.clone().unwrap_or_else(|| "UNKNOWN".to_string()),
+                    user_metadata: commit.user_metadata.clone().filter(|m| !m.is_empty()),
+                    run_links: serde_json::to_value(commit)
+                        .map(|info| run_links::resolve(run_link_templates, &info))
+                        .unwrap_or_default(),
+                }))
+                .collect(),
+        }
+    }
+}
+
+impl Report {
+    /// Include a Diagnosis section built by [`crate::diagnosis::diagnose`].
+    pub fn with_diagnoses(mut self, diagnoses: Vec<Diagnosis>) -> Self {
+        self.diagnoses = diagnoses;
+        self
+    }
+}
+
+/// The report's sections for the generic formats (text, JSON); `html` and
+/// `markdown` lay it out with charts and details of their own.
+impl Renderable for Report {
+    fn document(&self) -> Document {
+        let format_bytes = DeltaTableAnalyzer::format_bytes;
+        let mut doc = Document::new(format!("Delta table report: {}", self.table_path));
+        doc.text(format!(
+            "Version {} · health {}/100 · generated {}",
+            self.version,
+            self.health_score,
+            format_time(Some(self.generated_at))
+        ));
+        if let Some(activity) = &self.provenance.write_activity {
+            doc.text(format!("Note: {}.", activity.notice()));
+        }
+
+        let last_write = match (&self.last_write, &self.last_operation) {
+            (Some(time), Some(op)) => format!("{} ({})", format_time(Some(*time)), op),
+            _ => "none recorded".to_string(),
+        };
+        doc.heading("Summary").fields([
+            ("Total size", format_bytes(self.total_size_bytes)),
+            ("Files", self.num_files.to_string()),
+            ("Rows", self.num_rows.map_or("unknown".to_string(), |rows| rows.to_string())),
+            ("Versions", self.total_versions.to_string()),
+            ("Created", format_time(self.created_time)),
+            ("Last write", last_write),
+            ("Last VACUUM", format_time(self.last_vacuum)),
+        ]);
+
+        if let Some(cost) = &self.cost {
+            let rows = cost
+                .breakdown()
+                .into_iter()
+                .map(|(label, bytes, monthly)| vec![label, format_bytes(bytes), cost.format(monthly)])
+                .collect();
+            doc.heading("Storage cost")
+                .text(format!(
+                    "At {}{} per GB-month ({}).",
+                    cost.currency, cost.price_per_gb_month, cost.storage_class
+                ))
+                .table(&["", "Size", "Monthly"], rows);
+        }
+
+        doc.heading("Protocol").fields([
+            ("Reader version", self.min_reader_version.to_string()),
+            ("Writer version", self.min_writer_version.to_string()),
+            (
+                "Table features",
+                if self.table_features.is_empty() {
+                    "none".to_string()
+                } else {
+                    self.table_features.join(", ")
+                },
+            ),
+        ]);
+
+        if !self.diagnoses.is_empty() {
+            doc.heading("Diagnosis").list(
+                self.diagnoses
+                    .iter()
+                    .map(|d| Item::new("→", format!("{}: {}", d.title, d.narrative)).detail(d.recommendation.clone()))
+                    .collect(),
+            );
+        }
+
+        doc.heading("Insights").list(
+            self.insights
+                .iter()
+                .map(|insight| {
+                    let item = Item::new(
+                        insight.severity.to_uppercase(),
+                        format!("{} [{}]: {}", insight.title, insight.category, insight.description),
+                    )
+                    .tone(Tone::from_severity(&insight.severity));
+                    if insight.recommendation.is_empty() {
+                        item
+                    } else {
+                        item.detail(insight.recommendation.clone())
+                    }
+                })
+                .collect(),
+        );
+
+        doc.heading("File sizes").table(
+            &["Size", "Files", "Bytes"],
+            self.file_sizes
+                .iter()
+                .map(|b| vec![b.label.clone(), b.files.to_string(), format_bytes(b.bytes)])
+                .collect(),
+        );
+
+        if !self.recent_history.is_empty() {
+            doc.heading("Recent history").table(
+                &["Version", "Time", "Operation", "Message"],
+                self.recent_history
+                    .iter()
+                    .map(|entry| {
+                        vec![
+                            entry.version.to_string(),
+                            format_time(entry.timestamp),
+                            entry.operation.clone(),
+                            entry.message_with_links(),
+                        ]
+                    })
+                    .collect(),
+            );
+        }
+
+        doc.heading("Schema").table(
+            &["Column", "Type", "Partition"],
+            self.schema
+                .iter()
+                .map(|column| {
+                    let data_type = match &column.generated {
+                        Some(generated) => format!("{} {}", column.data_type, generated),
+                        None => column.data_type.clone(),
+                    };
+                    vec![column.name.clone(), data_type, if column.partition_column { "✓" } else { "" }.to_string()]
+                })
+                .collect(),
+        );
+        doc.text(self.provenance.summary());
+        doc
+    }
+}
+
+impl TimelineSummary {
+    fn new(timeline: &TimelineAnalysis) -> Self {
+        let mut operations_by_type: Vec<(String, i32)> = timeline
+            .operations_by_type
+            .iter()
+            .map(|(op, count)| (op.clone(), *count))
+            .collect();
+        operations_by_type.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let mut commits_per_day: Vec<(String, usize)> = timeline
+            .operations_by_day
+            .iter()
+            .map(|(day, commits)| (day.clone(), commits.len()))
+            .collect();
+        commits_per_day.sort();
+        let skip = commits_per_day.len().saturating_sub(TIMELINE_DAYS);
+        commits_per_day.drain(..skip);
+
+        Self {
+            total_operations: timeline.total_operations,
+            version_creation_rate: timeline.version_creation_rate,
+            operations_by_type,
+            commits_per_day,
+            write_patterns: timeline.write_patterns.clone(),
+            attribution: timeline.attribution.clone(),
+        }
+    }
+}
+
+/// Count files and bytes per size bucket. Every bucket is returned, including
+/// empty ones, so charts keep a stable axis.
+pub fn size_histogram(files: &[FileInfo]) -> Vec<SizeBucket> {
+    let mut buckets: Vec<SizeBucket> = SIZE_BUCKETS
+        .iter()
+        .map(|(_, label)| SizeBucket {
+            label: label.to_string(),
+            files: 0,
+            bytes: 0,
+        })
+        .collect();
+    for file in files {
+        let idx = SIZE_BUCKETS
+            .iter()
+            .position(|(upper, _)| file.size_bytes < *upper)
+            .unwrap_or(SIZE_BUCKETS.len() - 1);
+        buckets[idx].files += 1;
+        buckets[idx].bytes += file.size_bytes;
+    }
+    buckets
+}
+
+pub(crate) fn format_time(time: Option<DateTime<Utc>>) -> String {
+    time.map_or("never".to_string(), |t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+}
@@ -0,0 +1,323 @@
+use crate::insights::DeltaTableAnalyzer;
+use crate::render::html::{escape, STYLE};
+use crate::report::{format_time, HistoryEntry, Report};
+use std::fmt::Write;
+
+const BAR_COLOR: &str = "#3b82c4";
+
+/// Render `report` as a standalone HTML page. Charts are inline SVG, so the
+/// page has no external dependencies and can be attached or emailed as is.
+pub fn render(report: &Report) -> String {
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Deltective report: {}</title>\n<style>{}</style>\n</head>\n<body>\n<main>\n",
+        escape(&report.table_path),
+        STYLE
+    );
+    let _ = write!(
+        html,
+        "<h1>{}</h1>\n<p class=\"subtitle\">Version {} · generated {}</p>\n",
+        escape(&report.table_path),
+        report.version,
+        format_time(Some(report.generated_at))
+    );
+    if let Some(activity) = &report.provenance.write_activity {
+        let _ = writeln!(
+            html,
+            "<div class=\"insight warning\"><p>Note: {}.</p></div>",
+            escape(&activity.notice())
+        );
+    }
+
+    render_overview(&mut html, report);
+    render_cost(&mut html, report);
+    render_diagnoses(&mut html, report);
+    render_insights(&mut html, report);
+    render_file_sizes(&mut html, report);
+    render_timeline(&mut html, report);
+    render_history(&mut html, report);
+    render_schema(&mut html, report);
+
+    let _ = write!(
+        html,
+        "<footer>Generated by deltective · {}</footer>\n</main>\n</body>\n</html>\n",
+        escape(&report.provenance.summary())
+    );
+    html
+}
+
+fn render_overview(html: &mut String, report: &Report) {
+    html.push_str("<h2>Overview</h2>\n<div class=\"cards\">\n");
+    let cards = [
+        ("Health score", format!("{}/100", report.health_score)),
+        ("Total size", DeltaTableAnalyzer::format_bytes(report.total_size_bytes)),
+        ("Files", report.num_files.to_string()),
+        ("Rows", report.num_rows.map_or("unknown".to_string(), |rows| rows.to_string())),
+        ("Versions", report.total_versions.to_string()),
+        ("Protocol", format!("reader {} / writer {}", report.min_reader_version, report.min_writer_version)),
+    ];
+    for (label, value) in cards {
+        let _ = writeln!(
+            html,
+            "<div class=\"card\"><div class=\"label\">{}</div><div class=\"value\">{}</div></div>",
+            label,
+            escape(&value)
+        );
+    }
+    html.push_str("</div>\n<table>\n");
+
+    let last_write = match (&report.last_write, &report.last_operation) {
+        (Some(time), Some(op)) => format!("{} ({})", format_time(Some(*time)), op),
+        _ => "none recorded".to_string(),
+    };
+    let features = if report.table_features.is_empty() {
+        "none".to_string()
+    } else {
+        report.table_features.join(", ")
+    };
+    let rows = [
+        ("Created", format_time(report.created_time)),
+        ("Last write", last_write),
+        ("Last VACUUM", format_time(report.last_vacuum)),
+        ("Table features", features),
+    ];
+    for (label, value) in rows {
+        let _ = writeln!(html, "<tr><th>{}</th><td>{}</td></tr>", label, escape(&value));
+    }
+    html.push_str("</table>\n");
+}
+
+fn render_cost(html: &mut String, report: &Report) {
+    let Some(cost) = &report.cost else {
+        return;
+    };
+    let _ = write!(
+        html,
+        "<h2>Storage Cost</h2>\n<p>At {}{} per GB-month ({}).</p>\n<table>\n<tr><th></th><th>Size</th><th>Monthly</th></tr>\n",
+        escape(&cost.currency),
+        cost.price_per_gb_month,
+        escape(&cost.storage_class)
+    );
+    for (label, bytes, monthly) in cost.breakdown() {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+            label,
+            DeltaTableAnalyzer::format_bytes(bytes),
+            escape(&cost.format(monthly))
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn render_diagnoses(html: &mut String, report: &Report) {
+    if report.diagnoses.is_empty() {
+        return;
+    }
+    html.push_str("<h2>Diagnosis</h2>\n");
+    for diagnosis in &report.diagnoses {
+        let _ = writeln!(
+            html,
+            "<div class=\"insight warning\"><h3>{}</h3><p>{}</p><p><strong>Recommendation:</strong> {}</p></div>",
+            escape(&diagnosis.title),
+            escape(&diagnosis.narrative),
+            escape(&diagnosis.recommendation)
+        );
+    }
+}
+
+fn render_insights(html: &mut String, report: &Report) {
+    html.push_str("<h2>Insights</h2>\n");
+    for insight in &report.insights {
+        let _ = write!(
+            html,
+            "<div class=\"insight {}\"><span class=\"tag\">{} · {}</span><h3>{}</h3><p>{}</p>",
+            escape(&insight.severity),
+            escape(&insight.severity),
+            escape(&insight.category),
+            escape(&insight.title),
+            escape(&insight.description)
+        );
+        if !insight.recommendation.is_empty() {
+            let _ = write!(html, "<p><strong>Recommendation:</strong> {}</p>", escape(&insight.recommendation));
+        }
+        html.push_str("</div>\n");
+    }
+}
+
+fn render_file_sizes(html: &mut String, report: &Report) {
+    html.push_str("<h2>File Size Distribution</h2>\n");
+    let bars: Vec<(String, f64, String)> = report
+        .file_sizes
+        .iter()
+        .map(|bucket| {
+            let detail = format!("{} files, {}", bucket.files, DeltaTableAnalyzer::format_bytes(bucket.bytes));
+            (bucket.label.clone(), bucket.files as f64, detail)
+        })
+        .collect();
+    html.push_str(&horizontal_bars(&bars));
+}
+
+fn render_timeline(html: &mut String, report: &Report) {
+    let timeline = &report.timeline;
+    let _ = writeln!(
+        html,
+        "<h2>Timeline</h2>\n<p>{} operations, {:.2} versions/day.</p>",
+        timeline.total_operations, timeline.version_creation_rate
+    );
+    for pattern in &timeline.write_patterns {
+        let _ = writeln!(html, "<p>{}</p>", escape(pattern));
+    }
+
+    if !timeline.commits_per_day.is_empty() {
+        html.push_str("<h3>Commits per day</h3>\n");
+        html.push_str(&column_chart(&timeline.commits_per_day));
+    }
+    if !timeline.operations_by_type.is_empty() {
+        html.push_str("<h3>Operations by type</h3>\n");
+        let bars: Vec<(String, f64, String)> = timeline
+            .operations_by_type
+            .iter()
+            .map(|(op, count)| (op.clone(), *count as f64, count.to_string()))
+            .collect();
+        html.push_str(&horizontal_bars(&bars));
+    }
+    let attribution = &timeline.attribution;
+    for (title, contributors) in [("Engines", &attribution.engines), ("Users", &attribution.users), ("Jobs", &attribution.jobs)] {
+        // A single "unknown" group says nothing about who writes
+        if contributors.iter().all(|c| c.name == "unknown") {
+            continue;
+        }
+        let _ = writeln!(html, "<h3>{}</h3>", title);
+        let bars: Vec<(String, f64, String)> = contributors
+            .iter()
+            .map(|c| {
+                let detail = format!("{} commits, {}", c.commits, DeltaTableAnalyzer::format_bytes(c.bytes_written));
+                (c.name.clone(), c.commits as f64, detail)
+            })
+            .collect();
+        html.push_str(&horizontal_bars(&bars));
+    }
+}
+
+fn render_history(html: &mut String, report: &Report) {
+    if report.recent_history.is_empty() {
+        return;
+    }
+    html.push_str("<h2>Recent History</h2>\n<table>\n<tr><th>Version</th><th>Time</th><th>Operation</th><th>Message</th></tr>\n");
+    for entry in &report.recent_history {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            entry.version,
+            format_time(entry.timestamp),
+            escape(&entry.operation),
+            message_cell(entry)
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+/// The commit message and links to the runs that wrote the commit.
+fn message_cell(entry: &HistoryEntry) -> String {
+    let mut cell = escape(entry.user_metadata.as_deref().unwrap_or(""));
+    for link in &entry.run_links {
+        let _ = write!(cell, " <a href=\"{}\">{}</a>", escape(&link.url), escape(&link.name));
+    }
+    cell
+}
+
+fn render_schema(html: &mut String, report: &Report) {
+    html.push_str("<h2>Schema</h2>\n<table>\n<tr><th>Column</th><th>Type</th><th>Partition</th></tr>\n");
+    for column in &report.schema {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td><code>{}</code>{}</td><td>{}</td></tr>",
+            escape(&column.name),
+            escape(&column.data_type),
+            column.generated.as_deref().map(|g| format!(" {}", escape(g))).unwrap_or_default(),
+            if column.partition_column { "✓" } else { "" }
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+/// SVG bar chart with one labelled row per `(label, value, detail)`.
+fn horizontal_bars(bars: &[(String, f64, String)]) -> String {
+    const ROW: usize = 24;
+    const LABEL_WIDTH: f64 = 140.0;
+    const BAR_WIDTH: f64 = 520.0;
+
+    let max = bars.iter().map(|(_, value, _)| *value).fold(0.0, f64::max).max(1.0);
+    let height = bars.len() * ROW;
+    let mut svg = format!(
+        "<svg width=\"800\" height=\"{}\" viewBox=\"0 0 800 {}\" role=\"img\">\n",
+        height, height
+    );
+    for (idx, (label, value, detail)) in bars.iter().enumerate() {
+        let y = idx * ROW;
+        let width = value / max * BAR_WIDTH;
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{}\">{}</text><rect x=\"{}\" y=\"{}\" width=\"{:.1}\" height=\"{}\" fill=\"{}\"/><text x=\"{:.1}\" y=\"{}\">{}</text>",
+            y + 16,
+            escape(label),
+            LABEL_WIDTH,
+            y + 4,
+            width,
+            ROW - 8,
+            BAR_COLOR,
+            LABEL_WIDTH + width + 6.0,
+            y + 16,
+            escape(detail)
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// SVG column chart of counts over consecutive labels (e.g. days).
+fn column_chart(columns: &[(String, usize)]) -> String {
+    const HEIGHT: f64 = 160.0;
+    const WIDTH: f64 = 800.0;
+
+    let max = columns.iter().map(|(_, count)| *count).max().unwrap_or(1).max(1) as f64;
+    let slot = WIDTH / columns.len() as f64;
+    let mut svg = format!(
+        "<svg width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" role=\"img\">\n",
+        WIDTH,
+        HEIGHT + 20.0,
+        WIDTH,
+        HEIGHT + 20.0
+    );
+    for (idx, (label, count)) in columns.iter().enumerate() {
+        let height = *count as f64 / max * (HEIGHT - 16.0);
+        let x = idx as f64 * slot;
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"><title>{}: {} commit(s)</title></rect>",
+            x + 1.0,
+            HEIGHT - height,
+            (slot - 2.0).max(1.0),
+            height,
+            BAR_COLOR,
+            escape(label),
+            count
+        );
+    }
+    // Label the first and last day; the rest are available as tooltips
+    if let (Some((first, _)), Some((last, _))) = (columns.first(), columns.last()) {
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{}\">{}</text><text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>",
+            HEIGHT + 16.0,
+            escape(first),
+            WIDTH,
+            HEIGHT + 16.0,
+            escape(last)
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
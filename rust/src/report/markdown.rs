@@ -0,0 +1,143 @@
+use crate::insights::DeltaTableAnalyzer;
+use crate::render::markdown::cell;
+use crate::report::{format_time, HistoryEntry, Report};
+use std::fmt::Write;
+
+/// Render `report` as GitHub-flavoured Markdown, for pasting into PRs, wikis
+/// and incident docs.
+pub fn render(report: &Report) -> String {
+    let mut md = String::new();
+    let _ = writeln!(md, "# Delta table report: `{}`\n", report.table_path);
+    let _ = writeln!(
+        md,
+        "Version {} · health **{}/100** · generated {}\n",
+        report.version,
+        report.health_score,
+        format_time(Some(report.generated_at))
+    );
+    if let Some(activity) = &report.provenance.write_activity {
+        let _ = writeln!(md, "> **Note:** {}.\n", activity.notice());
+    }
+
+    md.push_str("## Summary\n\n| | |\n|---|---|\n");
+    let last_write = match (&report.last_write, &report.last_operation) {
+        (Some(time), Some(op)) => format!("{} ({})", format_time(Some(*time)), op),
+        _ => "none recorded".to_string(),
+    };
+    let rows = [
+        ("Total size", DeltaTableAnalyzer::format_bytes(report.total_size_bytes)),
+        ("Files", report.num_files.to_string()),
+        ("Rows", report.num_rows.map_or("unknown".to_string(), |rows| rows.to_string())),
+        ("Versions", report.total_versions.to_string()),
+        ("Created", format_time(report.created_time)),
+        ("Last write", last_write),
+        ("Last VACUUM", format_time(report.last_vacuum)),
+    ];
+    for (label, value) in rows {
+        let _ = writeln!(md, "| {} | {} |", label, cell(&value));
+    }
+
+    if let Some(cost) = &report.cost {
+        let _ = writeln!(
+            md,
+            "\n## Storage Cost\n\nAt {}{} per GB-month ({}).\n\n| | Size | Monthly |\n|---|---:|---:|",
+            cost.currency,
+            cost.price_per_gb_month,
+            cell(&cost.storage_class)
+        );
+        for (label, bytes, monthly) in cost.breakdown() {
+            let _ = writeln!(
+                md,
+                "| {} | {} | {} |",
+                label,
+                DeltaTableAnalyzer::format_bytes(bytes),
+                cost.format(monthly)
+            );
+        }
+    }
+
+    let _ = writeln!(
+        md,
+        "\n## Protocol\n\n- Reader version: {}\n- Writer version: {}\n- Table features: {}\n",
+        report.min_reader_version,
+        report.min_writer_version,
+        if report.table_features.is_empty() {
+            "none".to_string()
+        } else {
+            report.table_features.iter().map(|f| format!("`{}`", f)).collect::<Vec<_>>().join(", ")
+        }
+    );
+
+    if !report.diagnoses.is_empty() {
+        md.push_str("## Diagnosis\n\n");
+        for diagnosis in &report.diagnoses {
+            let _ = writeln!(md, "- **{}**: {}", diagnosis.title, diagnosis.narrative);
+            let _ = writeln!(md, "  - Recommendation: {}", diagnosis.recommendation);
+        }
+        md.push('\n');
+    }
+
+    md.push_str("## Insights\n\n");
+    for insight in &report.insights {
+        let _ = writeln!(
+            md,
+            "- **{}** [{}/{}]: {}",
+            insight.title,
+            insight.severity,
+            insight.category,
+            insight.description
+        );
+        if !insight.recommendation.is_empty() {
+            let _ = writeln!(md, "  - Recommendation: {}", insight.recommendation);
+        }
+    }
+
+    md.push_str("\n## File Sizes\n\n| Size | Files | Bytes |\n|---|---:|---:|\n");
+    for bucket in &report.file_sizes {
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} |",
+            bucket.label,
+            bucket.files,
+            DeltaTableAnalyzer::format_bytes(bucket.bytes)
+        );
+    }
+
+    if !report.recent_history.is_empty() {
+        md.push_str("\n## Recent History\n\n| Version | Time | Operation | Message |\n|---:|---|---|---|\n");
+        for entry in &report.recent_history {
+            let _ = writeln!(
+                md,
+                "| {} | {} | {} | {} |",
+                entry.version,
+                format_time(entry.timestamp),
+                cell(&entry.operation),
+                message_cell(entry)
+            );
+        }
+    }
+
+    md.push_str("\n<details>\n<summary>Schema</summary>\n\n| Column | Type | Partition |\n|---|---|---|\n");
+    for column in &report.schema {
+        let _ = writeln!(
+            md,
+            "| {} | `{}`{} | {} |",
+            cell(&column.name),
+            cell(&column.data_type),
+            column.generated.as_deref().map(|g| format!(" {}", cell(g))).unwrap_or_default(),
+            if column.partition_column { "✓" } else { "" }
+        );
+    }
+    md.push_str("\n</details>\n");
+    let _ = writeln!(md, "\n---\n\n_{}_", report.provenance.summary());
+    md
+}
+
+/// The commit message and links to the runs that wrote the commit.
+fn message_cell(entry: &HistoryEntry) -> String {
+    let mut message = cell(entry.user_metadata.as_deref().unwrap_or(""));
+    for link in &entry.run_links {
+        let _ = write!(message, " [{}]({})", cell(&link.name), link.url);
+    }
+    message
+}
@@ -0,0 +1,233 @@
+//! Counting, budgeting and throttling of object storage requests.
+//!
+//! Every store deltective opens is wrapped in a [`MeteredStore`], which counts
+//! requests process-wide and enforces the limits set with [`configure`]: a
+//! total budget after which requests fail instead of being sent, and a cap on
+//! requests in flight at once. Fleet scans of thousands of tables otherwise
+//! fan out enough LIST and GET requests to trip S3 throttling or run up a
+//! surprise bill.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use deltalake::storage::ObjectStoreRef;
+use futures::stream::{BoxStream, StreamExt};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions,
+    PutPayload, PutResult,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Objects returned per LIST page by S3, GCS and Azure.
+const LIST_PAGE_SIZE: u64 = 1000;
+
+/// Limits on storage requests, from `requests:` in the config file or the
+/// `--max-requests` / `--max-concurrent-requests` flags.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequestLimits {
+    /// Requests allowed per run; later requests fail
+    pub budget: Option<u64>,
+    /// Requests allowed in flight at once
+    pub concurrency: Option<usize>,
+}
+
+struct Limits {
+    budget: Option<u64>,
+    concurrency: Option<Semaphore>,
+}
+
+static LIMITS: OnceLock<Limits> = OnceLock::new();
+static REQUESTS: AtomicU64 = AtomicU64::new(0);
+
+/// Apply `limits` for the rest of the process. Only the first call counts,
+/// so set them before opening any table.
+pub fn configure(limits: &RequestLimits) {
+    let _ = LIMITS.set(Limits {
+        budget: limits.budget,
+        concurrency: limits.concurrency.map(|n| Semaphore::new(n.max(1))),
+    });
+}
+
+/// Requests made so far, including those refused for exceeding the budget.
+pub fn count() -> u64 {
+    REQUESTS.load(Ordering::Relaxed)
+}
+
+/// Requests consumed by a run, for reports.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RequestUsage {
+    pub requests: u64,
+    pub budget: Option<u64>,
+}
+
+impl RequestUsage {
+    /// Usage since `start`, a previous [`count`].
+    pub fn since(start: u64) -> Self {
+        Self {
+            requests: count().saturating_sub(start),
+            budget: LIMITS.get().and_then(|limits| limits.budget),
+        }
+    }
+}
+
+impl fmt::Display for RequestUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.budget {
+            Some(budget) => write!(f, "{} storage request(s) of a budget of {}", self.requests, budget),
+            None => write!(f, "{} storage request(s)", self.requests),
+        }
+    }
+}
+
+/// Count `n` requests, failing once the budget is spent.
+fn charge(n: u64) -> object_store::Result<()> {
+    let total = REQUESTS.fetch_add(n, Ordering::Relaxed) + n;
+    match LIMITS.get().and_then(|limits| limits.budget) {
+        Some(budget) if total > budget => Err(object_store::Error::Generic {
+            store: "request budget",
+            source: format!("storage request budget of {} exhausted", budget).into(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Count one request and wait for a concurrency slot, held until the
+/// returned permit is dropped.
+async fn start() -> object_store::Result<Option<SemaphorePermit<'static>>> {
+    charge(1)?;
+    match LIMITS.get().and_then(|limits| limits.concurrency.as_ref()) {
+        Some(semaphore) => Ok(semaphore.acquire().await.ok()),
+        None => Ok(None),
+    }
+}
+
+/// Wrap `store` so its requests are counted and limited.
+pub fn metered(store: ObjectStoreRef) -> ObjectStoreRef {
+    Arc::new(MeteredStore { inner: store })
+}
+
+/// Object store that counts and limits the requests made through it.
+#[derive(Debug)]
+pub struct MeteredStore {
+    inner: ObjectStoreRef,
+}
+
+impl fmt::Display for MeteredStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Metered({})", self.inner)
+    }
+}
+
+/// Charge LIST requests by page as entries stream in.
+fn metered_list(
+    stream: BoxStream<'_, object_store::Result<ObjectMeta>>,
+) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+    stream
+        .enumerate()
+        .map(|(i, entry)| {
+            // The first page is charged up front
+            if i > 0 && (i as u64).is_multiple_of(LIST_PAGE_SIZE) {
+                charge(1)?;
+            }
+            entry
+        })
+        .boxed()
+}
+
+#[async_trait]
+impl ObjectStore for MeteredStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        let _permit = start().await?;
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        let _permit = start().await?;
+        self.inner.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> object_store::Result<GetResult> {
+        let _permit = start().await?;
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn get_range(&self, location: &Path, range: Range<usize>) -> object_store::Result<Bytes> {
+        let _permit = start().await?;
+        self.inner.get_range(location, range).await
+    }
+
+    async fn get_ranges(&self, location: &Path, ranges: &[Range<usize>]) -> object_store::Result<Vec<Bytes>> {
+        // Stores coalesce nearby ranges, so this is at least one request
+        let _permit = start().await?;
+        self.inner.get_ranges(location, ranges).await
+    }
+
+    async fn head(&self, location: &Path) -> object_store::Result<ObjectMeta> {
+        let _permit = start().await?;
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        let _permit = start().await?;
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+        if let Err(e) = charge(1) {
+            return futures::stream::once(async move { Err(e) }).boxed();
+        }
+        metered_list(self.inner.list(prefix))
+    }
+
+    fn list_with_offset(
+        &self,
+        prefix: Option<&Path>,
+        offset: &Path,
+    ) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+        if let Err(e) = charge(1) {
+            return futures::stream::once(async move { Err(e) }).boxed();
+        }
+        metered_list(self.inner.list_with_offset(prefix, offset))
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        let _permit = start().await?;
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        let _permit = start().await?;
+        self.inner.copy(from, to).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        let _permit = start().await?;
+        self.inner.rename(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        let _permit = start().await?;
+        self.inner.copy_if_not_exists(from, to).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        // Commits go through here; delegating keeps the store's atomic rename
+        let _permit = start().await?;
+        self.inner.rename_if_not_exists(from, to).await
+    }
+}
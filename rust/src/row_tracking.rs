@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Bytes per row of each materialized row tracking column (a LONG), before
+/// compression.
+pub const MATERIALIZED_COLUMN_BYTES: i64 = 8;
+
+/// Row tracking (the `rowTracking` writer feature): a stable ID and last
+/// commit version for every row. Add actions carry a `baseRowId` and
+/// `defaultRowCommitVersion` that rows inherit from their file until a rewrite
+/// has to keep them, at which point the values are written into hidden
+/// columns of the data files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RowTrackingInfo {
+    /// The protocol lists the feature
+    pub supported: bool,
+    /// `delta.enableRowTracking`; writers only assign IDs once it is set
+    pub enabled: bool,
+    /// `delta.rowTrackingSuspended`, set while IDs are backfilled
+    pub suspended: bool,
+    /// Physical names of the hidden columns rewrites materialize into
+    pub materialized_row_id_column: Option<String>,
+    pub materialized_row_commit_version_column: Option<String>,
+    /// Row IDs in the current files; only read when the feature is supported
+    pub files: Option<RowTrackingFiles>,
+}
+
+impl RowTrackingInfo {
+    pub fn from_config(config: &HashMap<String, String>, supported: bool) -> Self {
+        let flag = |key: &str| config.get(key).is_some_and(|v| v.eq_ignore_ascii_case("true"));
+        Self {
+            supported,
+            enabled: flag("delta.enableRowTracking"),
+            suspended: flag("delta.rowTrackingSuspended"),
+            materialized_row_id_column: config.get("delta.rowTracking.materializedRowIdColumnName").cloned(),
+            materialized_row_commit_version_column: config
+                .get("delta.rowTracking.materializedRowCommitVersionColumnName")
+                .cloned(),
+            files: None,
+        }
+    }
+
+    /// Uncompressed bytes the two materialized columns take if every row in
+    /// the files with row IDs has them. Rows only get them once rewritten
+    /// (UPDATE, MERGE, OPTIMIZE), so this is an upper bound.
+    pub fn materialized_overhead_bytes(&self) -> Option<i64> {
+        let files = self.files.as_ref()?;
+        Some(files.rows * MATERIALIZED_COLUMN_BYTES * 2)
+    }
+}
+
+/// `baseRowId` and `defaultRowCommitVersion` across the add actions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RowTrackingFiles {
+    pub files: usize,
+    /// Files with a `baseRowId`; the rest predate row tracking or were
+    /// written by an engine that doesn't assign IDs
+    pub files_with_row_ids: usize,
+    pub min_base_row_id: Option<i64>,
+    /// Highest row ID assigned in the current files, from `baseRowId` plus `numRecords`
+    pub max_row_id: Option<i64>,
+    pub min_row_commit_version: Option<i64>,
+    pub max_row_commit_version: Option<i64>,
+    /// Rows in the files with a `baseRowId` and a row count
+    pub rows: i64,
+}
+
+impl RowTrackingFiles {
+    /// Track one add action.
+    pub fn add(&mut self, base_row_id: Option<i64>, row_commit_version: Option<i64>, num_records: Option<i64>) {
+        self.files += 1;
+        if let Some(base) = base_row_id {
+            self.files_with_row_ids += 1;
+            self.min_base_row_id = Some(self.min_base_row_id.map_or(base, |min| min.min(base)));
+            let last = base + num_records.unwrap_or(1).max(1) - 1;
+            self.max_row_id = Some(self.max_row_id.map_or(last, |max| max.max(last)));
+            self.rows += num_records.unwrap_or(0);
+        }
+        if let Some(version) = row_commit_version {
+            self.min_row_commit_version = Some(self.min_row_commit_version.map_or(version, |min| min.min(version)));
+            self.max_row_commit_version = Some(self.max_row_commit_version.map_or(version, |max| max.max(version)));
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::insights::{AnalyzerThresholds, Confidence, Insight};
+use crate::inspector::TableStatistics;
+use crate::partitions::partition_key;
+use anyhow::{bail, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// Metrics a rule can test, with a short description for error messages and docs.
+pub const METRICS: &[(&str, &str)] = &[
+    ("num_files", "number of active data files"),
+    ("total_size_gb", "total size of active files in GB"),
+    ("num_rows", "total row count, where file statistics provide it"),
+    ("avg_file_size_mb", "average file size in MB"),
+    ("small_file_pct", "percentage of files below the small-file threshold"),
+    ("num_partitions", "number of distinct partitions"),
+    ("total_versions", "number of commits in the history"),
+    ("hours_since_last_write", "hours since the latest commit"),
+    ("days_since_vacuum", "days since the last VACUUM, or since creation if never vacuumed"),
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Comparison {
+    #[serde(rename = ">")]
+    Greater,
+    #[serde(rename = ">=")]
+    GreaterOrEqual,
+    #[serde(rename = "<")]
+    Less,
+    #[serde(rename = "<=")]
+    LessOrEqual,
+    #[serde(rename = "==")]
+    Equal,
+    #[serde(rename = "!=")]
+    NotEqual,
+}
+
+impl Comparison {
+    fn holds(self, value: f64, threshold: f64) -> bool {
+        match self {
+            Comparison::Greater => value > threshold,
+            Comparison::GreaterOrEqual => value >= threshold,
+            Comparison::Less => value < threshold,
+            Comparison::LessOrEqual => value <= threshold,
+            Comparison::Equal => value == threshold,
+            Comparison::NotEqual => value != threshold,
+        }
+    }
+}
+
+fn default_category() -> String {
+    "custom".to_string()
+}
+
+/// A team-defined check from the `rules` section of the config file. The rule
+/// fires, producing an insight, when `metric <comparison> threshold` holds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    pub name: String,
+    pub metric: String,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    pub severity: String,
+    #[serde(default = "default_category")]
+    pub category: String,
+    /// Description of the finding; `{value}` and `{threshold}` are substituted
+    pub message: String,
+    #[serde(default)]
+    pub recommendation: String,
+    /// Only apply to tables carrying all of these config tags
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+}
+
+impl Rule {
+    pub fn validate(&self) -> Result<()> {
+        if !METRICS.iter().any(|(name, _)| *name == self.metric) {
+            let known: Vec<&str> = METRICS.iter().map(|(name, _)| *name).collect();
+            bail!("Rule '{}' uses unknown metric '{}'; expected one of {}", self.name, self.metric, known.join(", "));
+        }
+        if !["critical", "warning", "info"].contains(&self.severity.as_str()) {
+            bail!("Rule '{}' has severity '{}'; expected critical, warning or info", self.name, self.severity);
+        }
+        Ok(())
+    }
+
+    pub fn applies_to(&self, tags: &BTreeMap<String, String>) -> bool {
+        self.tags.iter().all(|(key, value)| tags.get(key) == Some(value))
+    }
+
+    /// The insight this rule raises for `stats`, if it fires. Rules whose
+    /// metric isn't available for the table (e.g. no row counts) don't fire.
+    pub fn evaluate(&self, stats: &TableStatistics, thresholds: &AnalyzerThresholds) -> Option<Insight> {
+        let value = metric_value(&self.metric, stats, thresholds)?;
+        if !self.comparison.holds(value, self.threshold) {
+            return None;
+        }
+
+        let fill = |text: &str| {
+            text.replace("{value}", &format_number(value))
+                .replace("{threshold}", &format_number(self.threshold))
+        };
+        Some(Insight {
+            id: self.name.clone(),
+            severity: self.severity.clone(),
+            category: self.category.clone(),
+            title: self.name.clone(),
+            description: fill(&self.message),
+            recommendation: fill(&self.recommendation),
+            confidence: Confidence::High,
+            assumptions: Vec::new(),
+        })
+    }
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+pub fn metric_value(metric: &str, stats: &TableStatistics, thresholds: &AnalyzerThresholds) -> Option<f64> {
+    const MB: f64 = 1024.0 * 1024.0;
+    let value = match metric {
+        "num_files" => stats.num_files as f64,
+        "total_size_gb" => stats.total_size_bytes as f64 / (MB * 1024.0),
+        "num_rows" => stats.num_rows? as f64,
+        "avg_file_size_mb" => {
+            if stats.num_files == 0 {
+                return None;
+            }
+            stats.total_size_bytes as f64 / stats.num_files as f64 / MB
+        }
+        "small_file_pct" => {
+            if stats.files.is_empty() {
+                return None;
+            }
+            let small = stats
+                .files
+                .iter()
+                .filter(|f| (f.size_bytes as f64 / MB) < thresholds.small_file_mb)
+                .count();
+            small as f64 / stats.files.len() as f64 * 100.0
+        }
+        "num_partitions" => {
+            let partitions: HashSet<String> =
+                stats.files.iter().map(|f| partition_key(&f.partition_values)).collect();
+            if stats.partition_columns.is_empty() { 0.0 } else { partitions.len() as f64 }
+        }
+        "total_versions" => stats.total_versions as f64,
+        "hours_since_last_write" => {
+            let last = stats.last_operation.as_ref()?.timestamp;
+            (Utc::now() - last).num_minutes() as f64 / 60.0
+        }
+        "days_since_vacuum" => {
+            let since = stats.last_vacuum.or(stats.created_time)?;
+            (Utc::now() - since).num_hours() as f64 / 24.0
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(metric: &str, comparison: Comparison, threshold: f64) -> Rule {
+        Rule {
+            name: "file-budget".to_string(),
+            metric: metric.to_string(),
+            comparison,
+            threshold,
+            severity: "warning".to_string(),
+            category: default_category(),
+            message: "{value} files, budget {threshold}".to_string(),
+            recommendation: "Stay under {threshold}".to_string(),
+            tags: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn fires_when_the_comparison_holds() {
+        let stats = TableStatistics { num_files: 1500, ..Default::default() };
+        let insight = rule("num_files", Comparison::Greater, 1000.0)
+            .evaluate(&stats, &AnalyzerThresholds::default())
+            .expect("rule fires");
+        assert_eq!(insight.id, "file-budget");
+        assert_eq!(insight.category, "custom");
+        assert_eq!(insight.description, "1500 files, budget 1000");
+        assert_eq!(insight.recommendation, "Stay under 1000");
+    }
+
+    #[test]
+    fn stays_silent_when_the_comparison_fails() {
+        let stats = TableStatistics { num_files: 1000, ..Default::default() };
+        assert!(rule("num_files", Comparison::Greater, 1000.0).evaluate(&stats, &AnalyzerThresholds::default()).is_none());
+        assert!(rule("num_files", Comparison::GreaterOrEqual, 1000.0).evaluate(&stats, &AnalyzerThresholds::default()).is_some());
+    }
+
+    #[test]
+    fn unavailable_metrics_never_fire() {
+        // No row counts, and no files to average over
+        let stats = TableStatistics::default();
+        assert!(rule("num_rows", Comparison::GreaterOrEqual, 0.0).evaluate(&stats, &AnalyzerThresholds::default()).is_none());
+        assert!(rule("avg_file_size_mb", Comparison::Less, 1.0).evaluate(&stats, &AnalyzerThresholds::default()).is_none());
+    }
+
+    #[test]
+    fn applies_only_to_tables_with_every_tag() {
+        let mut rule = rule("num_files", Comparison::Greater, 0.0);
+        rule.tags = BTreeMap::from([("team".to_string(), "ads".to_string())]);
+        let tags = |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        assert!(rule.applies_to(&tags(&[("team", "ads"), ("tier", "gold")])));
+        assert!(!rule.applies_to(&tags(&[("team", "search")])));
+        assert!(!rule.applies_to(&tags(&[])));
+    }
+
+    #[test]
+    fn validate_rejects_unknown_metrics_and_severities() {
+        assert!(rule("num_files", Comparison::Greater, 0.0).validate().is_ok());
+        assert!(rule("num_widgets", Comparison::Greater, 0.0).validate().is_err());
+        let mut rule = rule("num_files", Comparison::Greater, 0.0);
+        rule.severity = "good".to_string();
+        assert!(rule.validate().is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Characters a substituted value keeps as they are: RFC 3986 unreserved.
+/// Everything else is percent-encoded, so a value can't add path segments,
+/// query parameters or a fragment to the link.
+const VALUE: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+/// A URL pattern from the config file, e.g.
+/// `https://airflow.example.com/dags/{dag_id}/grid?dag_run_id={run_id}`.
+/// Placeholders name commitInfo fields: top-level fields (`engineInfo`),
+/// fields of the Databricks `job` object (`jobId`, `runId`), or keys of a
+/// JSON object stored in `userMetadata`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RunLinkTemplate {
+    pub name: String,
+    pub url: String,
+}
+
+/// A resolved link from a commit to the orchestrator run that wrote it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunLink {
+    pub name: String,
+    pub url: String,
+}
+
+/// Links for every template whose placeholders can all be filled from the
+/// commit's commitInfo.
+pub fn resolve(templates: &[RunLinkTemplate], commit_info: &Value) -> Vec<RunLink> {
+    if templates.is_empty() {
+        return Vec::new();
+    }
+    let fields = commit_fields(commit_info);
+
+    templates
+        .iter()
+        .filter_map(|template| {
+            Some(RunLink {
+                name: template.name.clone(),
+                url: fill(&template.url, &fields)?,
+            })
+        })
+        .collect()
+}
+
+/// Flatten the places pipelines record run identifiers into one lookup.
+fn commit_fields(commit_info: &Value) -> HashMap<String, String> {
+    let mut fields = HashMap::new();
+    let mut add_scalars = |object: &Value| {
+        if let Some(map) = object.as_object() {
+            for (key, value) in map {
+                let text = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Number(n) => n.to_string(),
+                    Value::Bool(b) => b.to_string(),
+                    _ => continue,
+                };
+                fields.entry(key.clone()).or_insert(text);
+            }
+        }
+    };
+
+    add_scalars(commit_info);
+    if let Some(job) = commit_info.get("job") {
+        add_scalars(job);
+    }
+    if let Some(metadata) = commit_info.get("userMetadata").and_then(Value::as_str) {
+        if let Ok(parsed) = serde_json::from_str::<Value>(metadata) {
+            add_scalars(&parsed);
+        }
+    }
+    fields
+}
+
+/// `template` with each `{field}` replaced by the percent-encoded value, or
+/// `None` if a field is missing.
+fn fill(template: &str, fields: &HashMap<String, String>) -> Option<String> {
+    let mut url = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = start + rest[start..].find('}')?;
+        url.push_str(&rest[..start]);
+        url.extend(utf8_percent_encode(fields.get(&rest[start + 1..end])?, VALUE));
+        rest = &rest[end + 1..];
+    }
+    url.push_str(rest);
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fields(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn fills_every_placeholder() {
+        let url = fill("https://airflow/dags/{dag_id}/grid?dag_run_id={run_id}", &fields(&[("dag_id", "orders"), ("run_id", "42")]));
+        assert_eq!(url.as_deref(), Some("https://airflow/dags/orders/grid?dag_run_id=42"));
+        assert_eq!(fill("https://airflow/home", &fields(&[])).as_deref(), Some("https://airflow/home"));
+    }
+
+    #[test]
+    fn percent_encodes_values() {
+        let url = fill("https://ci/{run}", &fields(&[("run", "manual__2024-01-01T00:00:00+00:00/../x?y#z")]));
+        assert_eq!(url.as_deref(), Some("https://ci/manual__2024-01-01T00%3A00%3A00%2B00%3A00%2F..%2Fx%3Fy%23z"));
+    }
+
+    #[test]
+    fn missing_fields_and_unclosed_placeholders_give_no_link() {
+        assert_eq!(fill("https://ci/{run}", &fields(&[])), None);
+        assert_eq!(fill("https://ci/{run", &fields(&[("run", "1")])), None);
+    }
+
+    #[test]
+    fn resolves_fields_from_job_and_user_metadata() {
+        let templates = [
+            RunLinkTemplate { name: "databricks".to_string(), url: "https://dbx/#job/{jobId}/run/{runId}".to_string() },
+            RunLinkTemplate { name: "airflow".to_string(), url: "https://airflow/{dag_id}".to_string() },
+            RunLinkTemplate { name: "missing".to_string(), url: "https://ci/{build}".to_string() },
+        ];
+        let commit_info = json!({
+            "job": { "jobId": "7", "runId": 99 },
+            "userMetadata": "{\"dag_id\": \"orders\"}",
+        });
+        let links: Vec<(String, String)> =
+            resolve(&templates, &commit_info).into_iter().map(|link| (link.name, link.url)).collect();
+        assert_eq!(
+            links,
+            vec![
+                ("databricks".to_string(), "https://dbx/#job/7/run/99".to_string()),
+                ("airflow".to_string(), "https://airflow/orders".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use deltalake::kernel::{DataType, MetadataValue, StructField, StructType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Leaf columns Delta collects min/max/null statistics for when
+/// `delta.dataSkippingNumIndexedCols` isn't set.
+pub const DEFAULT_NUM_INDEXED_COLS: usize = 32;
+
+/// The table schema in column order, nested types included, with the type
+/// names Delta uses in its log (`long`, `decimal(10,2)`, `array<string>`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TableSchema {
+    pub fields: Vec<SchemaField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaField {
+    pub name: String,
+    pub data_type: SchemaType,
+    pub nullable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SchemaType {
+    Primitive {
+        name: String,
+    },
+    Struct {
+        fields: Vec<SchemaField>,
+    },
+    Array {
+        element: Box<SchemaType>,
+        contains_null: bool,
+    },
+    Map {
+        key: Box<SchemaType>,
+        value: Box<SchemaType>,
+        value_contains_null: bool,
+    },
+}
+
+impl TableSchema {
+    pub fn from_struct(schema: &StructType) -> Self {
+        Self {
+            fields: schema.fields().map(SchemaField::from_field).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Top-level field by name.
+    pub fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Every field in tree order with its dotted path and depth, including
+    /// the struct fields nested under arrays and maps.
+    pub fn columns(&self) -> Vec<(String, usize, &SchemaField)> {
+        let mut columns = Vec::new();
+        collect_columns(&self.fields, "", 0, &mut columns);
+        columns
+    }
+
+    /// Dotted paths of the columns writers collect file statistics for: the
+    /// ones listed in `delta.dataSkippingStatsColumns`, otherwise the first
+    /// `delta.dataSkippingNumIndexedCols` leaf columns (-1 for all) outside
+    /// the partition columns. Struct fields count as separate leaves; arrays
+    /// and maps take up a slot but get no statistics.
+    pub fn data_skipping_columns(&self, properties: &HashMap<String, String>, partition_columns: &[String]) -> Vec<String> {
+        if let Some(columns) = properties.get("delta.dataSkippingStatsColumns") {
+            return columns
+                .split(',')
+                .map(|column| column.trim().replace('`', ""))
+                .filter(|column| !column.is_empty())
+                .collect();
+        }
+        let limit = match properties.get("delta.dataSkippingNumIndexedCols").and_then(|v| v.trim().parse::<i64>().ok()) {
+            Some(n) if n < 0 => usize::MAX,
+            Some(n) => n as usize,
+            None => DEFAULT_NUM_INDEXED_COLS,
+        };
+        let mut leaves = Vec::new();
+        let data_fields: Vec<&SchemaField> = self.fields.iter().filter(|f| !partition_columns.contains(&f.name)).collect();
+        collect_leaves(&data_fields, "", &mut leaves);
+        leaves
+            .into_iter()
+            .take(limit)
+            .filter_map(|(path, primitive)| primitive.then_some(path))
+            .collect()
+    }
+}
+
+fn collect_columns<'a>(fields: &'a [SchemaField], prefix: &str, depth: usize, columns: &mut Vec<(String, usize, &'a SchemaField)>) {
+    for field in fields {
+        let path = format!("{}{}", prefix, field.name);
+        columns.push((path.clone(), depth, field));
+        collect_columns(field.data_type.children(), &format!("{}.", path), depth + 1, columns);
+    }
+}
+
+/// Leaf paths in schema order, flagged when the leaf is a primitive.
+fn collect_leaves(fields: &[&SchemaField], prefix: &str, leaves: &mut Vec<(String, bool)>) {
+    for field in fields {
+        let path = format!("{}{}", prefix, field.name);
+        match &field.data_type {
+            SchemaType::Struct { fields } => {
+                let fields: Vec<&SchemaField> = fields.iter().collect();
+                collect_leaves(&fields, &format!("{}.", path), leaves);
+            }
+            SchemaType::Primitive { .. } => leaves.push((path, true)),
+            SchemaType::Array { .. } | SchemaType::Map { .. } => leaves.push((path, false)),
+        }
+    }
+}
+
+impl SchemaField {
+    fn from_field(field: &StructField) -> Self {
+        Self {
+            name: field.name().clone(),
+            data_type: SchemaType::from_data_type(field.data_type()),
+            nullable: field.is_nullable(),
+            comment: match field.metadata().get("comment") {
+                Some(MetadataValue::String(comment)) => Some(comment.clone()),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl SchemaType {
+    fn from_data_type(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Primitive(primitive) => SchemaType::Primitive { name: primitive.to_string() },
+            DataType::Struct(inner) => SchemaType::Struct {
+                fields: inner.fields().map(SchemaField::from_field).collect(),
+            },
+            DataType::Array(array) => SchemaType::Array {
+                element: Box::new(Self::from_data_type(array.element_type())),
+                contains_null: array.contains_null(),
+            },
+            DataType::Map(map) => SchemaType::Map {
+                key: Box::new(Self::from_data_type(map.key_type())),
+                value: Box::new(Self::from_data_type(map.value_type())),
+                value_contains_null: map.value_contains_null(),
+            },
+        }
+    }
+
+    /// Full type in Delta's DDL notation, e.g. `map<string,struct<id:long>>`.
+    pub fn display(&self) -> String {
+        match self {
+            SchemaType::Primitive { name } => name.clone(),
+            SchemaType::Struct { fields } => format!(
+                "struct<{}>",
+                fields
+                    .iter()
+                    .map(|f| format!("{}:{}", f.name, f.data_type.display()))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            SchemaType::Array { element, .. } => format!("array<{}>", element.display()),
+            SchemaType::Map { key, value, .. } => format!("map<{},{}>", key.display(), value.display()),
+        }
+    }
+
+    /// Type with struct fields left out, for tree rows whose children are
+    /// listed below them, e.g. `array<struct>`.
+    pub fn summary(&self) -> String {
+        match self {
+            SchemaType::Primitive { name } => name.clone(),
+            SchemaType::Struct { .. } => "struct".to_string(),
+            SchemaType::Array { element, .. } => format!("array<{}>", element.summary()),
+            SchemaType::Map { key, value, .. } => format!("map<{},{}>", key.summary(), value.summary()),
+        }
+    }
+
+    /// Struct fields directly under this type, looking through arrays and
+    /// map values, which is where a tree shows them.
+    pub fn children(&self) -> &[SchemaField] {
+        match self {
+            SchemaType::Primitive { .. } => &[],
+            SchemaType::Struct { fields } => fields,
+            SchemaType::Array { element, .. } => element.children(),
+            SchemaType::Map { value, .. } => value.children(),
+        }
+    }
+
+    /// Name of the outermost type: `long`, `struct`, `array` or `map`.
+    pub fn kind(&self) -> &str {
+        match self {
+            SchemaType::Primitive { name } => name.split('(').next().unwrap_or(name),
+            SchemaType::Struct { .. } => "struct",
+            SchemaType::Array { .. } => "array",
+            SchemaType::Map { .. } => "map",
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::schema::{SchemaType, TableSchema};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A commit whose `metaData` action changed the schema, and how.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaEvolution {
+    pub version: i64,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub operation: Option<String>,
+    /// Dotted paths; a dropped struct is listed without its fields
+    pub dropped: Vec<String>,
+    pub added: Vec<String>,
+    pub type_changes: Vec<TypeChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeChange {
+    pub column: String,
+    pub old_type: String,
+    pub new_type: String,
+    /// Delta type widening (e.g. `integer` → `long`), which readers of the old
+    /// type can still consume
+    pub widening: bool,
+}
+
+impl SchemaEvolution {
+    /// What changed from `old` to `new` in commit `version`, or `None` when
+    /// the columns and their types are the same (e.g. only a comment or
+    /// nullability changed).
+    pub fn between(version: i64, old: &TableSchema, new: &TableSchema) -> Option<Self> {
+        let old_columns = old.columns();
+        let new_list = new.columns();
+        let new_columns: HashMap<&str, &SchemaType> =
+            new_list.iter().map(|(path, _, field)| (path.as_str(), &field.data_type)).collect();
+        let old_paths: Vec<&str> = old_columns.iter().map(|(path, _, _)| path.as_str()).collect();
+
+        let mut dropped: Vec<String> = Vec::new();
+        let mut type_changes = Vec::new();
+        for (path, _, field) in &old_columns {
+            match new_columns.get(path.as_str()) {
+                None => {
+                    if !dropped.iter().any(|parent| path.starts_with(&format!("{}.", parent))) {
+                        dropped.push(path.clone());
+                    }
+                }
+                Some(new_type) => {
+                    // Struct fields are compared one by one as their own paths
+                    let (old_type, new_type) = (field.data_type.summary(), new_type.summary());
+                    if old_type != new_type {
+                        type_changes.push(TypeChange {
+                            column: path.clone(),
+                            widening: is_widening(&old_type, &new_type),
+                            old_type,
+                            new_type,
+                        });
+                    }
+                }
+            }
+        }
+        let mut added: Vec<String> = Vec::new();
+        for (path, _, _) in &new_list {
+            if !old_paths.contains(&path.as_str()) && !added.iter().any(|parent| path.starts_with(&format!("{}.", parent))) {
+                added.push(path.clone());
+            }
+        }
+
+        (!dropped.is_empty() || !added.is_empty() || !type_changes.is_empty()).then_some(Self {
+            version,
+            timestamp: None,
+            operation: None,
+            dropped,
+            added,
+            type_changes,
+        })
+    }
+
+    /// Type changes readers of the previous schema can't consume.
+    pub fn incompatible_changes(&self) -> impl Iterator<Item = &TypeChange> {
+        self.type_changes.iter().filter(|change| !change.widening)
+    }
+
+    /// Whether the change can break downstream consumers: a column went away
+    /// or changed to a type that isn't a widening of the old one.
+    pub fn is_breaking(&self) -> bool {
+        !self.dropped.is_empty() || self.incompatible_changes().next().is_some()
+    }
+}
+
+/// Type changes the Delta type widening feature allows, which keep old
+/// values readable as the new type.
+pub fn is_widening(old: &str, new: &str) -> bool {
+    const INTEGERS: [&str; 4] = ["byte", "short", "integer", "long"];
+    let rank = |name: &str| INTEGERS.iter().position(|t| *t == name);
+    if let (Some(a), Some(b)) = (rank(old), rank(new)) {
+        return a < b;
+    }
+    if matches!(
+        (old, new),
+        ("float", "double") | ("byte" | "short" | "integer", "double") | ("date", "timestamp_ntz")
+    ) {
+        return true;
+    }
+    match (decimal(old), decimal(new)) {
+        // More digits on both sides of the point
+        (Some((p1, s1)), Some((p2, s2))) => s2 >= s1 && p2 - s2 >= p1 - s1,
+        _ => false,
+    }
+}
+
+/// Precision and scale of a `decimal(p,s)` type name.
+fn decimal(name: &str) -> Option<(u32, u32)> {
+    let (precision, scale) = name.strip_prefix("decimal(")?.strip_suffix(')')?.split_once(',')?;
+    Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+}
@@ -0,0 +1,146 @@
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+/// A comma-separated list of jq-style paths such as
+/// `.num_files,.tables[].table_path,.tables[0].tags.team`.
+pub struct Selector {
+    paths: Vec<Vec<Segment>>,
+}
+
+enum Segment {
+    Field(String),
+    Index(usize),
+    Each,
+}
+
+impl Selector {
+    pub fn parse(expr: &str) -> Result<Self> {
+        let paths = expr
+            .split(',')
+            .map(|path| parse_path(path.trim()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { paths })
+    }
+
+    /// Every value matched by each path, in order. Missing fields select `null`.
+    pub fn apply(&self, value: &Value) -> Vec<Value> {
+        let mut out = Vec::new();
+        for path in &self.paths {
+            let mut current = vec![value.clone()];
+            for segment in path {
+                current = current
+                    .into_iter()
+                    .flat_map(|v| match segment {
+                        Segment::Field(name) => vec![v.get(name).cloned().unwrap_or(Value::Null)],
+                        Segment::Index(idx) => vec![v.get(idx).cloned().unwrap_or(Value::Null)],
+                        Segment::Each => match v {
+                            Value::Array(items) => items,
+                            Value::Object(map) => map.into_iter().map(|(_, v)| v).collect(),
+                            _ => Vec::new(),
+                        },
+                    })
+                    .collect();
+            }
+            out.extend(current);
+        }
+        out
+    }
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment>> {
+    let Some(rest) = path.strip_prefix('.') else {
+        bail!("Invalid selector '{}': paths start with '.'", path);
+    };
+
+    let mut segments = Vec::new();
+    let mut chars = rest.chars().peekable();
+    let mut field = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' | '[' => {
+                if !field.is_empty() {
+                    segments.push(Segment::Field(std::mem::take(&mut field)));
+                }
+                if c == '[' {
+                    let mut index = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) => index.push(c),
+                            None => bail!("Invalid selector '{}': unterminated '['", path),
+                        }
+                    }
+                    if index.is_empty() {
+                        segments.push(Segment::Each);
+                    } else {
+                        let index = index
+                            .parse()
+                            .with_context(|| format!("Invalid index '[{}]' in selector '{}'", index, path))?;
+                        segments.push(Segment::Index(index));
+                    }
+                }
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() {
+        segments.push(Segment::Field(field));
+    }
+    Ok(segments)
+}
+
+/// Render a selected value for scripts: strings print without quotes, anything
+/// else as compact JSON.
+pub fn format_selected(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn select(expr: &str, value: &Value) -> Vec<Value> {
+        Selector::parse(expr).expect("valid selector").apply(value)
+    }
+
+    #[test]
+    fn selects_fields_indexes_and_every_element() {
+        let value = json!({
+            "num_files": 3,
+            "tables": [
+                { "table_path": "/a", "tags": { "team": "ads" } },
+                { "table_path": "/b", "tags": {} },
+            ],
+        });
+        assert_eq!(select(".num_files", &value), vec![json!(3)]);
+        assert_eq!(select(".tables[].table_path", &value), vec![json!("/a"), json!("/b")]);
+        assert_eq!(select(".tables[0].tags.team", &value), vec![json!("ads")]);
+        assert_eq!(select(".num_files, .tables[1].table_path", &value), vec![json!(3), json!("/b")]);
+    }
+
+    #[test]
+    fn missing_fields_select_null() {
+        let value = json!({ "tables": [] });
+        assert_eq!(select(".nope", &value), vec![Value::Null]);
+        assert_eq!(select(".tables[5]", &value), vec![Value::Null]);
+        assert!(select(".tables[]", &value).is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        assert!(Selector::parse("num_files").is_err());
+        assert!(Selector::parse(".tables[x]").is_err());
+        assert!(Selector::parse(".tables[0").is_err());
+        assert!(Selector::parse(".tables[").is_err());
+    }
+
+    #[test]
+    fn formats_strings_without_quotes() {
+        assert_eq!(format_selected(&json!("/a")), "/a");
+        assert_eq!(format_selected(&json!({ "a": 1 })), r#"{"a":1}"#);
+    }
+}
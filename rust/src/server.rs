@@ -0,0 +1,201 @@
+use crate::cancel;
+use crate::config::Config;
+use crate::insights::{health_score, Insight};
+use crate::inspector::{ConfigurationInfo, DeltaTableInspector, TableStatistics};
+use anyhow::Result;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use deltalake::kernel::CommitInfo;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// Requests loading a table at the same time; the rest wait their turn
+const MAX_CONCURRENT_LOADS: usize = 4;
+
+/// Characters left as they are in a path segment: RFC 3986 unreserved
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+/// Tables served and how to open them. Each request opens the table afresh,
+/// so responses always reflect the latest version; statistics are only
+/// recomputed when the version changed.
+pub struct ServerState {
+    /// Table ID → table path
+    tables: BTreeMap<String, String>,
+    storage_options: HashMap<String, String>,
+    config: Config,
+    timeout: Option<Duration>,
+    loads: Semaphore,
+    /// Table ID → enriched statistics of the version last loaded
+    statistics: Mutex<HashMap<String, TableStatistics>>,
+}
+
+impl ServerState {
+    pub fn new(
+        tables: BTreeMap<String, String>,
+        storage_options: HashMap<String, String>,
+        config: Config,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            tables,
+            storage_options,
+            config,
+            timeout,
+            loads: Semaphore::new(MAX_CONCURRENT_LOADS),
+            statistics: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct TableListing {
+    id: String,
+    table_path: String,
+    tags: BTreeMap<String, String>,
+    /// Endpoint URLs with the ID percent-encoded
+    links: BTreeMap<&'static str, String>,
+}
+
+#[derive(Serialize)]
+struct InsightsResponse {
+    table_path: String,
+    version: i64,
+    health_score: u8,
+    insights: Vec<Insight>,
+}
+
+/// Failure of a single request, returned as `{"error": "..."}`.
+enum ApiError {
+    UnknownTable(String),
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        ApiError::Internal(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::UnknownTable(id) => (StatusCode::NOT_FOUND, format!("Unknown table '{}'", id)),
+            ApiError::Internal(e) => {
+                // The chain can name storage paths and credentials sources; keep it in the log
+                eprintln!("Request failed: {:#}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+            }
+        };
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
+
+type ApiResult<T> = std::result::Result<Json<T>, ApiError>;
+
+pub fn router(state: Arc<ServerState>) -> Router {
+    Router::new()
+        .route("/tables", get(list_tables))
+        .route("/tables/:id/stats", get(stats))
+        .route("/tables/:id/history", get(history))
+        .route("/tables/:id/insights", get(insights))
+        .route("/tables/:id/config", get(configuration))
+        .with_state(state)
+}
+
+async fn list_tables(State(state): State<Arc<ServerState>>) -> Json<Vec<TableListing>> {
+    let listings = state
+        .tables
+        .iter()
+        .map(|(id, path)| {
+            let encoded = utf8_percent_encode(id, PATH_SEGMENT).to_string();
+            let links = ["stats", "history", "insights", "config"]
+                .into_iter()
+                .map(|endpoint| (endpoint, format!("/tables/{}/{}", encoded, endpoint)))
+                .collect();
+            TableListing {
+                id: id.clone(),
+                table_path: path.clone(),
+                tags: state.config.tags_for(path),
+                links,
+            }
+        })
+        .collect();
+    Json(listings)
+}
+
+async fn open(state: &ServerState, id: &str) -> Result<DeltaTableInspector, ApiError> {
+    let path = state.tables.get(id).ok_or_else(|| ApiError::UnknownTable(id.to_string()))?;
+    Ok(cancel::run(DeltaTableInspector::new(path, state.config.storage_options_for(path, &state.storage_options)), state.timeout).await?)
+}
+
+/// Statistics of the table's latest version, enriched per the config. Only
+/// loaded when the version moved since the last request, since enrichment
+/// downloads sampled data files.
+async fn enriched_statistics(state: &ServerState, id: &str) -> Result<TableStatistics, ApiError> {
+    let _permit = state.loads.acquire().await.map_err(anyhow::Error::from)?;
+    let inspector = open(state, id).await?;
+    let version = inspector.table().version();
+    let cached = state.statistics.lock().unwrap_or_else(|e| e.into_inner()).get(id).cloned();
+    if let Some(stats) = cached.filter(|stats| stats.version == version) {
+        return Ok(stats);
+    }
+
+    let load = async {
+        let mut stats = inspector.get_statistics().await?;
+        inspector.enrich_statistics(&mut stats, &state.config).await?;
+        Ok(stats)
+    };
+    let stats = cancel::run(load, state.timeout).await?;
+    state.statistics.lock().unwrap_or_else(|e| e.into_inner()).insert(id.to_string(), stats.clone());
+    Ok(stats)
+}
+
+async fn stats(State(state): State<Arc<ServerState>>, Path(id): Path<String>) -> ApiResult<TableStatistics> {
+    Ok(Json(enriched_statistics(&state, &id).await?))
+}
+
+async fn history(State(state): State<Arc<ServerState>>, Path(id): Path<String>) -> ApiResult<Vec<CommitInfo>> {
+    let _permit = state.loads.acquire().await.map_err(anyhow::Error::from)?;
+    let inspector = open(&state, &id).await?;
+    Ok(Json(cancel::run(inspector.get_history(false), state.timeout).await?))
+}
+
+async fn insights(State(state): State<Arc<ServerState>>, Path(id): Path<String>) -> ApiResult<InsightsResponse> {
+    let stats = enriched_statistics(&state, &id).await?;
+    let (table_path, version) = (stats.table_path.clone(), stats.version);
+    let insights = state.config.analyzer(stats).analyze();
+    Ok(Json(InsightsResponse {
+        table_path,
+        version,
+        health_score: health_score(&insights),
+        insights,
+    }))
+}
+
+async fn configuration(
+    State(state): State<Arc<ServerState>>,
+    Path(id): Path<String>,
+) -> ApiResult<ConfigurationInfo> {
+    let _permit = state.loads.acquire().await.map_err(anyhow::Error::from)?;
+    let inspector = open(&state, &id).await?;
+    Ok(Json(cancel::run(inspector.get_configuration(), state.timeout).await?))
+}
+
+/// Serve until Ctrl-C.
+pub async fn serve(addr: &str, state: ServerState) -> Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, router(Arc::new(state)))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,312 @@
+use crate::render::{Document, Item, Renderable, Tone};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectStore;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use url::Url;
+
+/// A Spark structured streaming checkpoint declared in the config file, for a
+/// query that reads from or writes to `table`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StreamingCheckpointConfig {
+    pub name: String,
+    /// Checkpoint location (the directory holding `offsets/` and `commits/`)
+    pub path: String,
+    pub table: String,
+}
+
+/// Offset a Delta source recorded for one micro-batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeltaSourceOffset {
+    pub reservoir_id: String,
+    pub reservoir_version: i64,
+    /// Position within `reservoir_version`; -1 means none of its files were read yet
+    pub index: i64,
+    pub is_starting_version: bool,
+}
+
+impl DeltaSourceOffset {
+    fn parse(line: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(line).ok()?;
+        Some(Self {
+            reservoir_id: value.get("reservoirId")?.as_str()?.to_string(),
+            reservoir_version: value.get("reservoirVersion")?.as_i64()?,
+            index: value.get("index").and_then(Value::as_i64).unwrap_or(-1),
+            is_starting_version: value.get("isStartingVersion").and_then(Value::as_bool).unwrap_or(false),
+        })
+    }
+
+    /// Last table version the stream has fully processed. Delta marks the end
+    /// of a version with index `i64::MAX`; any other index means the stream is
+    /// before or part-way through `reservoir_version`.
+    pub fn processed_version(&self) -> i64 {
+        if self.index == i64::MAX {
+            self.reservoir_version
+        } else {
+            self.reservoir_version - 1
+        }
+    }
+}
+
+/// What a checkpoint directory says about its query's progress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointState {
+    pub path: String,
+    /// Query ID from the checkpoint's `metadata` file; Delta sinks record it as the txn app ID
+    pub query_id: Option<String>,
+    /// Latest batch that finished (has a `commits/` entry)
+    pub last_committed_batch: Option<i64>,
+    /// Latest batch that was planned (has an `offsets/` entry)
+    pub last_planned_batch: Option<i64>,
+    pub last_batch_timestamp: Option<DateTime<Utc>>,
+    /// Delta sources in the last committed batch's offsets
+    pub delta_sources: Vec<DeltaSourceOffset>,
+}
+
+impl CheckpointState {
+    /// Read the `metadata`, `offsets/` and `commits/` entries of a checkpoint.
+    pub async fn read(path: &str, storage_options: &HashMap<String, String>) -> Result<Self> {
+        let (store, root) = open_store(path, storage_options)?;
+
+        let query_id = match read_text(store.as_ref(), &root.child("metadata")).await {
+            Ok(text) => serde_json::from_str::<Value>(&text)
+                .ok()
+                .and_then(|v| v.get("id").and_then(Value::as_str).map(String::from)),
+            Err(_) => None,
+        };
+        let last_committed_batch = latest_batch(store.as_ref(), &root.child("commits")).await?;
+        let last_planned_batch = latest_batch(store.as_ref(), &root.child("offsets")).await?;
+
+        let mut state = Self {
+            path: path.to_string(),
+            query_id,
+            last_committed_batch,
+            last_planned_batch,
+            last_batch_timestamp: None,
+            delta_sources: Vec::new(),
+        };
+
+        if let Some(batch) = last_committed_batch {
+            let offsets_file = root.child("offsets").child(batch.to_string());
+            let text = read_text(store.as_ref(), &offsets_file)
+                .await
+                .with_context(|| format!("Failed to read offsets for batch {} in {}", batch, path))?;
+            // Line 1 is the format version, line 2 the batch metadata, then one line per source
+            let mut lines = text.lines().skip(1);
+            state.last_batch_timestamp = lines
+                .next()
+                .and_then(|line| serde_json::from_str::<Value>(line).ok())
+                .and_then(|meta| meta.get("batchTimestampMs").and_then(Value::as_i64))
+                .and_then(DateTime::from_timestamp_millis);
+            state.delta_sources = lines.filter_map(DeltaSourceOffset::parse).collect();
+        }
+
+        Ok(state)
+    }
+}
+
+/// Checkpoints kept next to the data under `<table>/_checkpoints/`, a common
+/// layout for streams writing to the table. Only local tables are searched.
+pub fn discover_checkpoints(table_path: &str) -> Vec<String> {
+    let root = Path::new(table_path).join("_checkpoints");
+    if root.join("offsets").is_dir() {
+        return vec![root.to_string_lossy().to_string()];
+    }
+    let Ok(entries) = std::fs::read_dir(&root) else {
+        return Vec::new();
+    };
+    let mut found: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.join("offsets").is_dir())
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    found.sort();
+    found
+}
+
+fn open_store(path: &str, storage_options: &HashMap<String, String>) -> Result<(Box<dyn ObjectStore>, ObjectPath)> {
+    let url = if path.contains("://") {
+        Url::parse(path).with_context(|| format!("Invalid checkpoint location {}", path))?
+    } else {
+        let absolute = std::fs::canonicalize(path)
+            .with_context(|| format!("Checkpoint location does not exist: {}", path))?;
+        Url::from_directory_path(&absolute)
+            .map_err(|_| anyhow::anyhow!("Invalid checkpoint location {}", path))?
+    };
+    let (store, root) = object_store::parse_url_opts(&url, storage_options)
+        .with_context(|| format!("Failed to open checkpoint location {}", path))?;
+    Ok((store, root))
+}
+
+async fn read_text(store: &dyn ObjectStore, path: &ObjectPath) -> Result<String> {
+    let bytes = store.get(path).await?.bytes().await?;
+    Ok(String::from_utf8_lossy(&bytes).to_string())
+}
+
+/// Highest numbered batch file directly under `dir` (ignoring `.crc` and temp files).
+async fn latest_batch(store: &dyn ObjectStore, dir: &ObjectPath) -> Result<Option<i64>> {
+    let objects: Vec<_> = store.list(Some(dir)).try_collect().await?;
+    Ok(objects
+        .iter()
+        .filter_map(|meta| meta.location.filename()?.parse::<i64>().ok())
+        .max())
+}
+
+/// How the stream relates to the inspected table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamRole {
+    /// The stream reads the table (a Delta source offset matches its table ID)
+    Source,
+    /// The stream writes the table (its query ID appears as a txn app ID)
+    Sink,
+    /// Neither; the checkpoint belongs to some other pipeline
+    Unrelated,
+}
+
+/// End-to-end progress of one streaming query against the table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamLag {
+    pub name: String,
+    pub role: StreamRole,
+    pub checkpoint: CheckpointState,
+    pub table_version: i64,
+    /// Source: last table version fully read. Sink: last batch ID committed to the table.
+    pub processed: Option<i64>,
+    /// Source: table versions not yet read. Sink: committed batches missing from the table.
+    pub behind: Option<i64>,
+    /// Source: age of the oldest unread commit. Sink: time since the last batch ran.
+    pub lag_hours: Option<f64>,
+    /// Batches planned in `offsets/` but not yet committed
+    pub in_flight_batches: i64,
+}
+
+impl StreamLag {
+    pub fn in_flight(checkpoint: &CheckpointState) -> i64 {
+        match (checkpoint.last_planned_batch, checkpoint.last_committed_batch) {
+            (Some(planned), Some(committed)) => (planned - committed).max(0),
+            (Some(planned), None) => planned + 1,
+            _ => 0,
+        }
+    }
+}
+
+impl Renderable for [StreamLag] {
+    fn document(&self) -> Document {
+        let mut doc = Document::new("Streaming queries");
+        for stream in self {
+            let checkpoint = &stream.checkpoint;
+            doc.heading(&stream.name);
+            let mut fields = vec![("Checkpoint", checkpoint.path.clone())];
+            if let Some(query_id) = &checkpoint.query_id {
+                fields.push(("Query ID", query_id.clone()));
+            }
+            fields.push((
+                "Last batch",
+                format!(
+                    "{}{}",
+                    checkpoint.last_committed_batch.map_or("none".to_string(), |b| b.to_string()),
+                    checkpoint
+                        .last_batch_timestamp
+                        .map(|t| format!(" at {}", t.format("%Y-%m-%d %H:%M:%S UTC")))
+                        .unwrap_or_default()
+                ),
+            ));
+            if stream.in_flight_batches > 0 {
+                fields.push((
+                    "In flight",
+                    format!("{} batch(es) planned but not committed", stream.in_flight_batches),
+                ));
+            }
+
+            let lag = stream.lag_hours.map_or("unknown".to_string(), |h| format!("{:.1}h", h));
+            match stream.role {
+                StreamRole::Source => fields.push((
+                    "Reads table",
+                    format!(
+                        "processed through v{} of v{} ({} version(s) behind, lag {})",
+                        stream.processed.unwrap_or_default(),
+                        stream.table_version,
+                        stream.behind.unwrap_or_default(),
+                        lag
+                    ),
+                )),
+                StreamRole::Sink => fields.push((
+                    "Writes table",
+                    format!(
+                        "batch {} is the latest in the table ({} committed batch(es) missing, last batch {} ago)",
+                        stream.processed.unwrap_or_default(),
+                        stream.behind.map_or("?".to_string(), |b| b.to_string()),
+                        lag
+                    ),
+                )),
+                StreamRole::Unrelated => {
+                    doc.fields(fields);
+                    doc.list(vec![
+                        Item::new("?", "No Delta source offset or txn for this table").tone(Tone::Warning)
+                    ]);
+                    continue;
+                }
+            }
+            doc.fields(fields);
+        }
+        doc
+    }
+}
+
+/// An application recording its progress in the table's `txn` actions, as
+/// structured streaming sinks do with their query ID and batch number.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamingWriter {
+    pub app_id: String,
+    /// Latest `version` the application recorded, i.e. its epoch or batch ID
+    pub epoch: i64,
+    /// `lastUpdated` of the txn action, when the writer sets it
+    pub last_updated: Option<DateTime<Utc>>,
+    /// Latest commit found carrying the txn; older commits aren't searched
+    pub last_commit_version: Option<i64>,
+    pub last_commit: Option<DateTime<Utc>>,
+}
+
+impl StreamingWriter {
+    pub fn last_seen(&self) -> Option<DateTime<Utc>> {
+        self.last_commit.or(self.last_updated)
+    }
+}
+
+impl Renderable for [StreamingWriter] {
+    fn document(&self) -> Document {
+        let mut doc = Document::new("Streaming writers");
+        if self.is_empty() {
+            doc.text("No txn actions in the log; no streaming or idempotent writer records its progress in this table");
+            return doc;
+        }
+        let rows = self
+            .iter()
+            .map(|writer| {
+                vec![
+                    writer.app_id.clone(),
+                    writer.epoch.to_string(),
+                    writer.last_commit_version.map(|v| v.to_string()).unwrap_or_else(|| "?".to_string()),
+                    writer
+                        .last_seen()
+                        .map(|t| format!("{} ({:.1}h ago)", t.format("%Y-%m-%d %H:%M:%S UTC"), hours_since(t)))
+                        .unwrap_or_else(|| "unknown".to_string()),
+                ]
+            })
+            .collect();
+        doc.table(&["App ID", "Epoch", "Last commit", "Last committed"], rows);
+        doc
+    }
+}
+
+pub fn hours_since(time: DateTime<Utc>) -> f64 {
+    (Utc::now() - time).num_minutes() as f64 / 60.0
+}
@@ -0,0 +1,98 @@
+use crate::inspector::{commit_version, parse_interval_hours, DEFAULT_DELETED_FILE_RETENTION_HOURS, DEFAULT_LOG_RETENTION_HOURS};
+use chrono::{DateTime, Duration, Utc};
+use deltalake::kernel::CommitInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Versions time travel can still reach: their commit is in the log and
+/// none of their files has been vacuumed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeTravelWindow {
+    pub version: i64,
+    /// `delta.logRetentionDuration`
+    pub log_retention_hours: i64,
+    /// `delta.deletedFileRetentionDuration`
+    pub deleted_file_retention_hours: i64,
+    /// Oldest commit still in the log
+    pub oldest_logged_version: i64,
+    pub oldest_logged_time: Option<DateTime<Utc>>,
+    /// Oldest version whose files all still exist. The version current at
+    /// the last VACUUM's cutoff kept all of its files; older ones may not have
+    pub oldest_readable_version: i64,
+    pub oldest_readable_time: Option<DateTime<Utc>>,
+    pub last_vacuum: Option<DateTime<Utc>>,
+    /// Retention the last VACUUM ran with
+    pub last_vacuum_retention_hours: Option<i64>,
+}
+
+impl TimeTravelWindow {
+    /// Window of a table at `version` with these `properties`, from its
+    /// newest-first `history`, as from
+    /// [`crate::DeltaTableInspector::get_history`].
+    pub fn from_history(version: i64, properties: &HashMap<String, String>, history: &[CommitInfo]) -> Self {
+        let hours = |key: &str, default: i64| properties.get(key).and_then(|v| parse_interval_hours(v)).unwrap_or(default);
+        let deleted_file_retention_hours =
+            hours("delta.deletedFileRetentionDuration", DEFAULT_DELETED_FILE_RETENTION_HOURS);
+        let commits: Vec<(i64, Option<DateTime<Utc>>)> = history
+            .iter()
+            .filter_map(|entry| Some((commit_version(entry)?, entry.timestamp.and_then(DateTime::from_timestamp_millis))))
+            .collect();
+        let (oldest_logged_version, oldest_logged_time) = commits.last().copied().unwrap_or((version, None));
+
+        // VACUUM START records the retention; END only that it finished
+        let vacuum = history.iter().find(|entry| {
+            entry.operation.as_deref().is_some_and(|op| op == "VACUUM START" || op == "VACUUM")
+        });
+        let last_vacuum = vacuum.and_then(|entry| entry.timestamp).and_then(DateTime::from_timestamp_millis);
+        let last_vacuum_retention_hours = vacuum.map(|entry| {
+            vacuum_retention_millis(entry).map_or(deleted_file_retention_hours, |millis| millis / 3_600_000)
+        });
+
+        let (oldest_readable_version, oldest_readable_time) = match (last_vacuum, last_vacuum_retention_hours) {
+            (Some(at), Some(retention)) => {
+                let cutoff = at - Duration::hours(retention);
+                commits
+                    .iter()
+                    .find(|(_, time)| time.is_some_and(|t| t <= cutoff))
+                    .copied()
+                    .unwrap_or((oldest_logged_version, oldest_logged_time))
+            }
+            _ => (oldest_logged_version, oldest_logged_time),
+        };
+
+        Self {
+            version,
+            log_retention_hours: hours("delta.logRetentionDuration", DEFAULT_LOG_RETENTION_HOURS),
+            deleted_file_retention_hours,
+            oldest_logged_version,
+            oldest_logged_time,
+            oldest_readable_version: oldest_readable_version.max(oldest_logged_version),
+            oldest_readable_time,
+            last_vacuum,
+            last_vacuum_retention_hours,
+        }
+    }
+
+    /// Versions that can be loaded today.
+    pub fn readable_versions(&self) -> i64 {
+        self.version - self.oldest_readable_version + 1
+    }
+
+    /// Logged versions whose files VACUUM already deleted: they show up in
+    /// the history but fail to load.
+    pub fn unreadable_logged_versions(&self) -> i64 {
+        self.oldest_readable_version - self.oldest_logged_version
+    }
+}
+
+/// `specifiedRetentionMillis`, else `defaultRetentionMillis`, of a VACUUM
+/// commit; numbers are serialized as strings by Spark.
+fn vacuum_retention_millis(entry: &CommitInfo) -> Option<i64> {
+    let parameters = entry.operation_parameters.as_ref()?;
+    ["specifiedRetentionMillis", "defaultRetentionMillis"].iter().find_map(|key| match parameters.get(*key)? {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.trim_matches('"').parse().ok(),
+        _ => None,
+    })
+}
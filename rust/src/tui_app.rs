@@ -3,23 +3,233 @@ pub mod history;
 pub mod insights;
 pub mod configuration;
 pub mod timeline;
+pub mod partitions;
+pub mod column_stats;
+pub mod data;
+pub mod changes;
+pub mod document;
+pub mod maintenance;
+pub mod picker;
+pub mod replay;
+pub mod search;
+pub mod theme;
+pub mod compare;
+pub mod what_if;
+pub mod commit_detail;
+pub mod commit_explorer;
+pub mod schema;
 
-use crate::inspector::{DeltaTableInspector, TableStatistics};
-use crate::insights::DeltaTableAnalyzer;
-use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crate::tui_app::changes::VersionChanges;
+use crate::tui_app::commit_detail::CommitDetail;
+use crate::tui_app::commit_explorer::CommitExplorerState;
+use crate::tui_app::history::HistoryState;
+use crate::tui_app::maintenance::{DialogResponse, MaintenanceAction, MaintenanceDialog, RETENTION_CHOICES};
+use crate::tui_app::partitions::PartitionsState;
+use crate::tui_app::picker::PickerState;
+use crate::tui_app::replay::ReplayState;
+use crate::tui_app::schema::SchemaState;
+use crate::tui_app::search::SearchState;
+use crate::tui_app::theme::palette;
+use crate::tui_app::what_if::WhatIfState;
+use deltective::cancel;
+use deltective::checkpoint_contents::{self, CheckpointContents};
+use deltective::diagnostics::Diagnostics;
+use deltective::config::Config;
+use deltective::discovery;
+use deltective::inspector::{ConfigurationInfo, DeltaTableInspector, TableStatistics, TimelineAnalysis};
+use deltective::maintenance::{self as table_maintenance, CheckpointPlan, OptimizePlan, VacuumPlanChanged, VacuumSimulation};
+use deltective::migration;
+use deltective::parquet_dataset;
+use deltective::parquet_footer::FileFooter;
+use deltective::preview::{self, DataPreview, PreviewSource, PREVIEW_ROWS};
+use deltective::render::{Document, Renderable};
+use deltective::tr;
+use anyhow::{bail, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use deltalake::kernel::CommitInfo;
 use ratatui::{
-    backend::CrosstermBackend,
+    backend::{CrosstermBackend, TestBackend},
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Tabs},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs},
     Frame, Terminal,
 };
+use serde_json::json;
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Options controlling how the TUI loads and monitors a table.
+pub struct TuiOptions {
+    pub storage_options: HashMap<String, String>,
+    pub timeout: Option<Duration>,
+    pub diagnostics: bool,
+    /// Poll for new commits at this interval and reload when the version changes.
+    pub refresh_interval: Option<Duration>,
+    /// Step through the table's versions, advancing at this interval
+    pub replay: Option<Duration>,
+    /// Version the replay starts from; defaults to the oldest available
+    pub replay_from: Option<i64>,
+    /// Run links, analyzer thresholds and rules, and ZSTD estimation settings
+    pub config: Config,
+}
+
+/// Data that is fetched on a background task.
+pub enum Loadable<T> {
+    Loading,
+    Ready(T),
+    Failed(String),
+}
+
+impl<T> Loadable<T> {
+    fn from_result(result: Result<T>) -> Self {
+        match result {
+            Ok(value) => Loadable::Ready(value),
+            Err(e) => Loadable::Failed(e.to_string()),
+        }
+    }
+
+    fn ready(&self) -> Option<&T> {
+        match self {
+            Loadable::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn is_loading(&self) -> bool {
+        matches!(self, Loadable::Loading)
+    }
+}
+
+/// Results sent from background load tasks to the UI thread.
+enum LoadEvent {
+    Opened(Arc<DeltaTableInspector>),
+    OpenFailed(anyhow::Error),
+    NewVersion(i64),
+    Statistics(Result<Box<TableStatistics>>),
+    History(Result<Vec<CommitInfo>>),
+    Configuration(Result<ConfigurationInfo>),
+    Timeline(Result<TimelineAnalysis>),
+    Preview(Result<DataPreview>),
+    Maintenance(Result<Document>),
+    /// A plan computed in the background, to confirm before running
+    MaintenancePlan(Result<MaintenanceAction>),
+    VacuumSimulation(Result<VacuumSimulation>),
+    /// Latest checkpoint summarized for the Configuration tab
+    CheckpointContents(Result<CheckpointContents>),
+    /// Parquet footer of a file opened from the Partitions tab
+    FileFooter(Result<FileFooter>),
+    /// Raw actions of one commit, for the Commits tab
+    CommitActions(i64, Result<Vec<serde_json::Value>>),
+    /// Actions of the commit opened from the History tab
+    CommitDetail(i64, Result<Vec<serde_json::Value>>),
+}
+
+/// Open the TUI on a table, or on a table picker when `table_path` is a
+/// directory, local or on an object store, that holds Delta tables rather
+/// than being one.
+pub fn run_tui(table_path: &str, options: TuiOptions) -> Result<()> {
+    let TuiOptions {
+        storage_options,
+        timeout,
+        diagnostics,
+        refresh_interval,
+        replay,
+        replay_from,
+        config,
+    } = options;
+
+    theme::set_palette(config.palette.as_deref().unwrap_or("default"))?;
+
+    // Walk the root through its object store like `scan` does. A root that
+    // can't be listed is opened as a table, which shows why it failed
+    let runtime = tokio::runtime::Runtime::new()?;
+    let root_options = config.storage_options_for(table_path, &storage_options);
+    let picker = match runtime.block_on(discovery::discover_tables(table_path, root_options.clone())) {
+        Ok(discovery) if !discovery.is_table(table_path) => {
+            // The terminal is still in normal mode here
+            for dir in &discovery.unreadable {
+                eprintln!("Warning: couldn't list {}: {}", dir.path, dir.error);
+            }
+            let tables = discovery.tables;
+            if tables.is_empty() {
+                if let Some(format) = migration::detect_local(table_path) {
+                    bail!(
+                        "{} is not a Delta table but has {} metadata. \
+                         Run `deltective migration-report {}` to check its readiness for migrating to Delta.",
+                        table_path,
+                        format.name(),
+                        table_path
+                    );
+                }
+                if runtime.block_on(parquet_dataset::is_parquet_dataset(table_path, root_options)) {
+                    bail!(
+                        "{} is a plain Parquet dataset, not a Delta table (no _delta_log). \
+                         Run `deltective parquet {}` for a degraded inspection.",
+                        table_path,
+                        table_path
+                    );
+                }
+                bail!("No Delta tables found under {}", table_path);
+            }
+            Some(PickerState::new(table_path, tables))
+        }
+        Ok(_) | Err(_) => None,
+    };
+
+    let (events_tx, events_rx) = mpsc::channel();
+    let mut app = App {
+        runtime,
+        events_tx,
+        events_rx,
+        table_path: table_path.to_string(),
+        storage_options,
+        inspector: None,
+        pending_loads: 0,
+        refresh_interval,
+        last_refresh_check: Instant::now(),
+        stats: Loadable::Loading,
+        history: Loadable::Loading,
+        timeout,
+        diagnostics: Diagnostics::new(diagnostics),
+        current_tab: 0,
+        should_quit: false,
+        tick: 0,
+        scroll_positions: [0; TAB_TITLES.len()],
+        search: SearchState::default(),
+        tab_area: Rect::default(),
+        history_state: HistoryState::new(config.run_links.clone()),
+        config,
+        partitions: None,
+        configuration: Loadable::Loading,
+        timeline: Loadable::Loading,
+        data_preview: None,
+        changes: None,
+        picking: picker.is_some(),
+        picker,
+        replay: replay.map(|interval| ReplayState::new(interval, replay_from)),
+        maintenance: None,
+        what_if: None,
+        schema: SchemaState::default(),
+        commit_explorer: CommitExplorerState::default(),
+        checkpoint_contents: None,
+        file_footer: None,
+    };
+
+    // Start loading right away; the UI comes up immediately and fills in
+    // each tab as its data arrives
+    if !app.picking {
+        app.diagnostics.emit("load_started", json!({ "table_path": table_path }));
+        app.spawn_load();
+    }
 
-pub fn run_tui(table_path: &str) -> Result<()> {
     // Setup terminal
+    install_panic_hook();
     let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
     crossterm::terminal::enable_raw_mode()?;
     crossterm::execute!(
@@ -28,187 +238,1093 @@ pub fn run_tui(table_path: &str) -> Result<()> {
         crossterm::event::EnableMouseCapture
     )?;
 
-    // Initialize inspector
-    let rt = tokio::runtime::Runtime::new()?;
-    let inspector = rt.block_on(DeltaTableInspector::new(table_path))?;
-    let stats = rt.block_on(inspector.get_statistics())?;
-    let history = rt.block_on(inspector.get_history(false))?;
+    let result = app.run(&mut terminal);
+    app.diagnostics.emit("exited", json!({ "ok": result.is_ok() }));
 
-    let mut app = App {
-        table_path: table_path.to_string(),
-        inspector,
-        stats: stats.clone(),
-        history: history.clone(),
-        current_tab: 0,
-        should_quit: false,
-        scroll_positions: [0; 5],
-        history_page: 0,
-        history_reversed: false,
-    };
+    // Restore terminal, even if the event loop failed
+    restore_terminal()?;
 
-    // Main event loop
-    loop {
-        terminal.draw(|f| app.ui(f))?;
-
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
-                match key.code {
-                    KeyCode::Char('q') => break,
-                    KeyCode::Tab => {
-                        app.current_tab = (app.current_tab + 1) % 5;
-                        app.scroll_positions[app.current_tab] = 0;
-                    }
-                    KeyCode::Right => {
-                        app.current_tab = (app.current_tab + 1) % 5;
-                        app.scroll_positions[app.current_tab] = 0;
-                    }
-                    KeyCode::Left => {
-                        app.current_tab = if app.current_tab == 0 {
-                            4
-                        } else {
-                            app.current_tab - 1
-                        };
-                        // Reset scroll when switching tabs
-                        app.scroll_positions[app.current_tab] = 0;
-                    }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        let pos = &mut app.scroll_positions[app.current_tab];
-                        *pos = pos.saturating_sub(1);
-                    }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        let pos = &mut app.scroll_positions[app.current_tab];
-                        *pos = pos.saturating_add(1);
-                    }
-                    KeyCode::PageUp => {
-                        let pos = &mut app.scroll_positions[app.current_tab];
-                        *pos = pos.saturating_sub(10);
-                    }
-                    KeyCode::PageDown => {
-                        let pos = &mut app.scroll_positions[app.current_tab];
-                        *pos = pos.saturating_add(10);
-                    }
-                    KeyCode::Home => {
-                        app.scroll_positions[app.current_tab] = 0;
-                    }
-                    _ => {
-                        // Handle tab-specific keys
-                        app.handle_key(key.code);
-                    }
-                }
-            }
-        }
+    // Don't wait for in-flight loads (e.g. slow remote reads) on quit
+    app.runtime.shutdown_background();
 
-        if app.should_quit {
-            break;
-        }
-    }
+    result
+}
 
-    // Restore terminal
+fn restore_terminal() -> Result<()> {
     crossterm::execute!(
         io::stdout(),
         crossterm::event::DisableMouseCapture,
         crossterm::terminal::LeaveAlternateScreen
     )?;
     crossterm::terminal::disable_raw_mode()?;
-
     Ok(())
 }
 
+thread_local! {
+    // Set while a tab is rendering so the panic hook leaves the terminal alone
+    // and the panic can be shown inside the tab instead
+    static RENDERING_TAB: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restore the terminal before the default hook prints the panic message, so
+/// the message and backtrace are readable and the shell is left usable.
+fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if RENDERING_TAB.with(Cell::get) {
+            return;
+        }
+        let _ = restore_terminal();
+        default_hook(info);
+    }));
+}
+
+/// Run a tab's render function, turning a panic into an error message in the
+/// tab area rather than tearing down the whole application.
+fn render_tab_guarded(
+    f: &mut Frame,
+    area: Rect,
+    diagnostics: &Diagnostics,
+    render: impl FnOnce(&mut Frame),
+) {
+    RENDERING_TAB.with(|flag| flag.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| render(f)));
+    RENDERING_TAB.with(|flag| flag.set(false));
+
+    if let Err(payload) = result {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown error".to_string());
+        diagnostics.emit("tab_panic", json!({ "message": message }));
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(tr!("tui-tab-panic"), Style::default().fg(palette().critical).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(""),
+            Line::from(message),
+        ];
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(tr!("tui-error"))),
+            area,
+        );
+    }
+}
+
 struct App {
+    // Runtime that background load tasks run on
+    runtime: tokio::runtime::Runtime,
+    events_tx: Sender<LoadEvent>,
+    events_rx: Receiver<LoadEvent>,
     table_path: String,
-    inspector: DeltaTableInspector,
-    stats: TableStatistics,
-    history: Vec<deltalake::kernel::CommitInfo>,
+    storage_options: HashMap<String, String>,
+    inspector: Option<Arc<DeltaTableInspector>>,
+    // Background loads that haven't reported back yet
+    pending_loads: usize,
+    refresh_interval: Option<Duration>,
+    last_refresh_check: Instant,
+    stats: Loadable<TableStatistics>,
+    history: Loadable<Vec<CommitInfo>>,
+    timeout: Option<Duration>,
+    diagnostics: Diagnostics,
     current_tab: usize,
     should_quit: bool,
+    // Event loop iterations, drives the loading spinner
+    tick: usize,
     // Scroll position for each tab (vertical offset)
-    scroll_positions: [u16; 5],
-    // History tab pagination
-    history_page: usize,
-    history_reversed: bool,
+    scroll_positions: [u16; TAB_TITLES.len()],
+    // `/` search over the current tab's text
+    search: SearchState,
+    // Where the current tab was last drawn; search renders it off-screen at this width
+    tab_area: Rect,
+    // History tab pagination and search
+    history_state: HistoryState,
+    config: Config,
+    // Partitions tab explorer state, built once statistics arrive
+    partitions: Option<PartitionsState>,
+    configuration: Loadable<ConfigurationInfo>,
+    timeline: Loadable<TimelineAnalysis>,
+    // Data tab rows, requested on first visit
+    data_preview: Option<Loadable<DataPreview>>,
+    // What the last reload brought in, shown briefly after a new version arrives
+    changes: Option<VersionChanges>,
+    // Tables found when started on a directory; Esc returns to the picker
+    picker: Option<PickerState>,
+    picking: bool,
+    // Set with --replay: the tabs show one version at a time instead of the latest
+    replay: Option<ReplayState>,
+    // OPTIMIZE and other maintenance: confirmation, progress and outcome
+    maintenance: Option<MaintenanceDialog>,
+    // Insights tab threshold panel, open while calibrating
+    what_if: Option<WhatIfState>,
+    // Schema tab search, type filter and tree expansion
+    schema: SchemaState,
+    // Commits tab version and its raw actions, read on demand
+    commit_explorer: CommitExplorerState,
+    // Configuration tab checkpoint summary, read when asked for
+    checkpoint_contents: Option<Loadable<CheckpointContents>>,
+    // Partitions tab footer of the selected file, shown over the file list
+    file_footer: Option<Loadable<FileFooter>>,
 }
 
-const HISTORY_PAGE_SIZE: usize = 10;
+// English tab names, also used to identify the tab in diagnostics events
+const TAB_TITLES: [&str; 10] = [
+    "Overview",
+    "History",
+    "Insights",
+    "Configuration",
+    "Timeline",
+    "Partitions",
+    "Column Stats",
+    "Data",
+    "Commits",
+    "Schema",
+];
+// Localized message for each entry of TAB_TITLES
+const TAB_MESSAGES: [&str; TAB_TITLES.len()] = [
+    "tab-overview",
+    "tab-history",
+    "tab-insights",
+    "tab-configuration",
+    "tab-timeline",
+    "tab-partitions",
+    "tab-column-stats",
+    "tab-data",
+    "tab-commits",
+    "tab-schema",
+];
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+// Height of the off-screen buffer a tab is searched in; text below it is not found
+const SEARCH_RENDER_ROWS: u16 = 4000;
 
 impl App {
+    fn run(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+        // Main event loop
+        loop {
+            self.apply_load_events();
+
+            if !self.picking && self.current_tab == 7 && self.data_preview.is_none() {
+                self.request_preview(PreviewSource::Table);
+            }
+
+            if let Some(version) = self.commit_explorer.pending().filter(|_| !self.picking && self.current_tab == 8) {
+                self.request_commit_actions(version);
+            }
+
+            let detail = self.history_state.detail.as_ref().and_then(CommitDetail::pending);
+            if let Some(version) = detail.filter(|_| !self.picking && self.current_tab == 1) {
+                self.request_commit_detail(version);
+            }
+
+            if !self.picking {
+                self.poll_for_new_version();
+                self.advance_replay();
+            }
+
+            terminal.draw(|f| self.ui(f))?;
+            self.tick = self.tick.wrapping_add(1);
+
+            self.diagnostics.heartbeat(json!({
+                "tab": if self.picking { "Picker" } else { TAB_TITLES[self.current_tab] },
+                "version": self.stats.ready().map(|s| s.version),
+            }));
+
+            // Poll rather than block so loads, the spinner and heartbeats keep updating
+            if !event::poll(EVENT_POLL_INTERVAL)? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match key.code {
+                        // Raw mode swallows SIGINT, so treat Ctrl-C as quit
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+                        // A search prompt captures every other key, including 'q'
+                        code if self.is_editing() && self.handle_key(code) => {}
+                        KeyCode::Char('q') => break,
+                        // Tab-specific keys take precedence over global scrolling
+                        code if self.handle_key(code) => {}
+                        // Tab switching and scrolling don't apply to the picker
+                        _ if self.picking => {}
+                        KeyCode::Char('r') | KeyCode::F(5) => self.refresh(),
+                        KeyCode::Char('O') => self.confirm_optimize(),
+                        KeyCode::Char('V') => self.choose_vacuum_retention(),
+                        KeyCode::Char('C') => self.plan_checkpoint(),
+                        KeyCode::Tab => {
+                            self.current_tab = (self.current_tab + 1) % TAB_TITLES.len();
+                            self.scroll_positions[self.current_tab] = 0;
+                        }
+                        KeyCode::Right => {
+                            self.current_tab = (self.current_tab + 1) % TAB_TITLES.len();
+                            self.scroll_positions[self.current_tab] = 0;
+                        }
+                        KeyCode::Left => {
+                            self.current_tab = if self.current_tab == 0 {
+                                TAB_TITLES.len() - 1
+                            } else {
+                                self.current_tab - 1
+                            };
+                            // Reset scroll when switching tabs
+                            self.scroll_positions[self.current_tab] = 0;
+                        }
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            let pos = &mut self.scroll_positions[self.current_tab];
+                            *pos = pos.saturating_sub(1);
+                        }
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            let pos = &mut self.scroll_positions[self.current_tab];
+                            *pos = pos.saturating_add(1);
+                        }
+                        KeyCode::PageUp => {
+                            let pos = &mut self.scroll_positions[self.current_tab];
+                            *pos = pos.saturating_sub(10);
+                        }
+                        KeyCode::PageDown => {
+                            let pos = &mut self.scroll_positions[self.current_tab];
+                            *pos = pos.saturating_add(10);
+                        }
+                        KeyCode::Home => {
+                            self.scroll_positions[self.current_tab] = 0;
+                        }
+                        KeyCode::Esc if self.picker.is_some() => self.picking = true,
+                        _ => {}
+                    }
+                }
+            }
+
+            if let Some(table_path) = self.picker.as_mut().and_then(PickerState::take_choice) {
+                self.open_table(table_path);
+            }
+
+            if let Some(source) = self.partitions.as_mut().and_then(|p| p.take_preview_request()) {
+                self.request_preview(source);
+                self.current_tab = 7;
+                self.scroll_positions[7] = 0;
+            }
+
+            if let Some(path) = self.partitions.as_mut().and_then(|p| p.take_footer_request()) {
+                self.request_file_footer(path);
+            }
+
+            if self.should_quit {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Open the table, then fetch statistics, history, configuration and
+    /// timeline concurrently, reporting each as it completes.
+    fn spawn_load(&mut self) {
+        // Opening the table, then the four loads below
+        self.pending_loads = 5;
+
+        let tx = self.events_tx.clone();
+        let table_path = self.table_path.clone();
+        let storage_options = self.config.storage_options_for(&table_path, &self.storage_options);
+        let timeout = self.timeout;
+        let config = self.config.clone();
+
+        self.runtime.spawn(async move {
+            let inspector = match cancel::run(DeltaTableInspector::new(&table_path, storage_options), timeout).await {
+                Ok(inspector) => Arc::new(inspector),
+                Err(e) => {
+                    let _ = tx.send(LoadEvent::OpenFailed(e));
+                    return;
+                }
+            };
+            let _ = tx.send(LoadEvent::Opened(inspector.clone()));
+            load_tabs(&inspector, &tx, timeout, &config).await;
+        });
+    }
+
+    /// Load `version` of the already opened table in place of the current
+    /// one, e.g. for the next replay step.
+    fn spawn_version_load(&mut self, version: i64) {
+        let Some(inspector) = self.inspector.clone() else {
+            return;
+        };
+        self.pending_loads = 5;
+
+        // Drop results still in flight for the version shown before
+        let (events_tx, events_rx) = mpsc::channel();
+        self.events_tx = events_tx;
+        self.events_rx = events_rx;
+
+        let tx = self.events_tx.clone();
+        let timeout = self.timeout;
+        let config = self.config.clone();
+
+        self.runtime.spawn(async move {
+            let inspector = match cancel::run(inspector.at_version(version), timeout).await {
+                Ok(inspector) => Arc::new(inspector),
+                Err(e) => {
+                    let _ = tx.send(LoadEvent::OpenFailed(e));
+                    return;
+                }
+            };
+            let _ = tx.send(LoadEvent::Opened(inspector.clone()));
+            load_tabs(&inspector, &tx, timeout, &config).await;
+        });
+    }
+
+    /// Switch to another table chosen in the picker, dropping everything
+    /// loaded for the previous one.
+    fn open_table(&mut self, table_path: String) {
+        self.picking = false;
+        if self.table_path == table_path && self.inspector.is_some() {
+            return;
+        }
+
+        // Results still in flight for the previous table go to the old channel
+        let (events_tx, events_rx) = mpsc::channel();
+        self.events_tx = events_tx;
+        self.events_rx = events_rx;
+
+        self.table_path = table_path;
+        self.inspector = None;
+        self.stats = Loadable::Loading;
+        self.history = Loadable::Loading;
+        self.configuration = Loadable::Loading;
+        self.timeline = Loadable::Loading;
+        self.partitions = None;
+        self.data_preview = None;
+        self.search.clear();
+        self.changes = None;
+        self.history_state = HistoryState::new(self.config.run_links.clone());
+        self.commit_explorer = CommitExplorerState::default();
+        self.checkpoint_contents = None;
+        self.file_footer = None;
+        self.current_tab = 0;
+        self.scroll_positions = [0; TAB_TITLES.len()];
+        self.last_refresh_check = Instant::now();
+
+        self.diagnostics.emit("load_started", json!({ "table_path": self.table_path }));
+        self.spawn_load();
+    }
+
+    /// Reload everything from the latest table version. Tabs keep showing the
+    /// previous data until the new results arrive.
+    fn refresh(&mut self) {
+        if self.pending_loads > 0 {
+            return;
+        }
+        self.diagnostics.emit("refresh_started", json!({}));
+        match self.replay.as_ref().and_then(ReplayState::current) {
+            Some(version) => self.spawn_version_load(version),
+            None => self.spawn_load(),
+        }
+    }
+
+    /// With `--replay`, load the next version once the current one is shown
+    /// and the step interval has passed.
+    fn advance_replay(&mut self) {
+        if self.pending_loads > 0 {
+            return;
+        }
+        if let Some(version) = self.replay.as_mut().and_then(ReplayState::due) {
+            self.diagnostics.emit("replay_step", json!({ "version": version }));
+            self.spawn_version_load(version);
+        }
+    }
+
+    /// With `--refresh-interval`, check whether new commits have landed and
+    /// reload if so.
+    fn poll_for_new_version(&mut self) {
+        let Some(interval) = self.refresh_interval.filter(|_| self.replay.is_none()) else {
+            return;
+        };
+        if self.last_refresh_check.elapsed() < interval || self.pending_loads > 0 {
+            return;
+        }
+        self.last_refresh_check = Instant::now();
+
+        let Some(inspector) = self.inspector.clone() else {
+            return;
+        };
+        let tx = self.events_tx.clone();
+        let timeout = self.timeout;
+        self.runtime.spawn(async move {
+            let table = inspector.table();
+            let latest = cancel::run(
+                async { Ok(table.log_store().get_latest_version(table.version()).await?) },
+                timeout,
+            )
+            .await;
+            // A failed check is retried at the next interval
+            if let Ok(latest) = latest {
+                if latest > table.version() {
+                    let _ = tx.send(LoadEvent::NewVersion(latest));
+                }
+            }
+        });
+    }
+
+    /// Ask for confirmation before running OPTIMIZE on the open table.
+    fn confirm_optimize(&mut self) {
+        // Replay shows an old snapshot; compacting it would conflict with newer commits
+        if self.replay.is_some() || self.inspector.is_none() {
+            return;
+        }
+        if let Some(stats) = self.stats.ready() {
+            let plan = OptimizePlan::new(stats, &self.config.thresholds);
+            self.maintenance = Some(MaintenanceDialog::Confirm(MaintenanceAction::Optimize(plan)));
+        }
+    }
+
+    /// Run the confirmed maintenance action in the background; the table is
+    /// reloaded once it has committed.
+    fn start_maintenance(&mut self) {
+        let (Some(MaintenanceDialog::Confirm(action)), Some(inspector)) = (self.maintenance.take(), self.inspector.clone())
+        else {
+            return;
+        };
+
+        let tx = self.events_tx.clone();
+        match &action {
+            MaintenanceAction::Optimize(plan) => {
+                self.diagnostics.emit("optimize_started", json!({ "version": plan.version }));
+                let target_size = plan.target_size_bytes;
+                self.runtime.spawn(async move {
+                    let result = table_maintenance::optimize(inspector.table(), target_size)
+                        .await
+                        .map(|outcome| outcome.document());
+                    let _ = tx.send(LoadEvent::Maintenance(result));
+                });
+            }
+            MaintenanceAction::Checkpoint(plan) => {
+                self.diagnostics.emit("checkpoint_started", json!({ "version": plan.version }));
+                let plan = plan.clone();
+                self.runtime.spawn(async move {
+                    let result = table_maintenance::checkpoint(inspector.table(), &plan)
+                        .await
+                        .map(|outcome| outcome.document());
+                    let _ = tx.send(LoadEvent::Maintenance(result));
+                });
+            }
+            MaintenanceAction::Vacuum(plan) => {
+                self.diagnostics.emit("vacuum_started", json!({ "retention_hours": plan.retention_hours }));
+                let plan = plan.clone();
+                self.runtime.spawn(async move {
+                    let result = table_maintenance::vacuum(inspector.table(), &plan).await;
+                    let event = match result {
+                        Ok(outcome) => LoadEvent::Maintenance(Ok(outcome.document())),
+                        // Ask again with the files that became eligible since
+                        Err(e) => match e.downcast::<VacuumPlanChanged>() {
+                            Ok(changed) => LoadEvent::MaintenancePlan(Ok(MaintenanceAction::Vacuum(changed.plan))),
+                            Err(e) => LoadEvent::Maintenance(Err(e)),
+                        },
+                    };
+                    let _ = tx.send(event);
+                });
+            }
+        }
+        self.maintenance = Some(MaintenanceDialog::Running {
+            name: action.name(),
+            plan: Some(action.plan()),
+            started: Instant::now(),
+        });
+    }
+
+    /// Ask for the VACUUM retention, simulating each offered one in the
+    /// background; the dry run comes next.
+    fn choose_vacuum_retention(&mut self) {
+        if self.replay.is_some() {
+            return;
+        }
+        let (Some(inspector), Some(stats)) = (self.inspector.clone(), self.stats.ready()) else {
+            return;
+        };
+        let hours = table_maintenance::default_vacuum_retention_hours(stats);
+        self.maintenance = Some(MaintenanceDialog::VacuumRetention {
+            hours,
+            table_default: hours,
+            simulation: None,
+        });
+
+        let tx = self.events_tx.clone();
+        self.runtime.spawn(async move {
+            let result = match inspector.get_history(false).await {
+                Ok(history) => table_maintenance::simulate_vacuum(inspector.table(), &history, &RETENTION_CHOICES).await,
+                Err(e) => Err(e),
+            };
+            let _ = tx.send(LoadEvent::VacuumSimulation(result));
+        });
+    }
+
+    /// List the files VACUUM would delete with `retention_hours`, then ask
+    /// for confirmation.
+    fn start_vacuum_dry_run(&mut self, retention_hours: i64) {
+        let Some(inspector) = self.inspector.clone() else {
+            return;
+        };
+        let tx = self.events_tx.clone();
+        self.runtime.spawn(async move {
+            let result = table_maintenance::vacuum_dry_run(inspector.table(), retention_hours).await;
+            let _ = tx.send(LoadEvent::MaintenancePlan(result.map(MaintenanceAction::Vacuum)));
+        });
+        self.maintenance = Some(MaintenanceDialog::Running {
+            name: "VACUUM DRY RUN",
+            plan: None,
+            started: Instant::now(),
+        });
+    }
+
+    /// Look up the last checkpoint, then ask for confirmation before writing
+    /// one for the current version.
+    fn plan_checkpoint(&mut self) {
+        if self.replay.is_some() {
+            return;
+        }
+        let Some(inspector) = self.inspector.clone() else {
+            return;
+        };
+        let tx = self.events_tx.clone();
+        self.runtime.spawn(async move {
+            let result = CheckpointPlan::new(inspector.table()).await;
+            let _ = tx.send(LoadEvent::MaintenancePlan(result.map(MaintenanceAction::Checkpoint)));
+        });
+    }
+
+    fn request_preview(&mut self, source: PreviewSource) {
+        let Some(inspector) = self.inspector.clone() else {
+            // Retried once the table has been opened
+            return;
+        };
+        self.data_preview = Some(Loadable::Loading);
+
+        let tx = self.events_tx.clone();
+        let timeout = self.timeout;
+        self.runtime.spawn(async move {
+            let result = cancel::run(preview::preview(inspector.table(), source, PREVIEW_ROWS), timeout).await;
+            let _ = tx.send(LoadEvent::Preview(result));
+        });
+    }
+
+    /// Summarize the latest checkpoint for the Configuration tab.
+    fn request_checkpoint_contents(&mut self) {
+        let Some(inspector) = self.inspector.clone() else {
+            return;
+        };
+        self.checkpoint_contents = Some(Loadable::Loading);
+
+        let tx = self.events_tx.clone();
+        let timeout = self.timeout;
+        self.runtime.spawn(async move {
+            let result = cancel::run(checkpoint_contents::inspect(inspector.table(), None), timeout).await;
+            let _ = tx.send(LoadEvent::CheckpointContents(result));
+        });
+    }
+
+    /// Read the footer of one data file for the Partitions tab.
+    fn request_file_footer(&mut self, path: String) {
+        let Some(inspector) = self.inspector.clone() else {
+            return;
+        };
+        self.file_footer = Some(Loadable::Loading);
+        self.scroll_positions[5] = 0;
+
+        let tx = self.events_tx.clone();
+        let timeout = self.timeout;
+        self.runtime.spawn(async move {
+            let result = cancel::run(inspector.get_file_footer(&path), timeout).await;
+            let _ = tx.send(LoadEvent::FileFooter(result));
+        });
+    }
+
+    /// Read the raw actions of `version` for the Commits tab.
+    fn request_commit_actions(&mut self, version: i64) {
+        let Some(inspector) = self.inspector.clone() else {
+            // Retried once the table has been opened
+            return;
+        };
+        self.commit_explorer.loading();
+
+        let tx = self.events_tx.clone();
+        let timeout = self.timeout;
+        self.runtime.spawn(async move {
+            let result = cancel::run(inspector.read_commit_actions(version), timeout).await;
+            let _ = tx.send(LoadEvent::CommitActions(version, result));
+        });
+    }
+
+    /// Read the actions of the commit opened from the History tab, for the
+    /// files it touched.
+    fn request_commit_detail(&mut self, version: i64) {
+        let (Some(inspector), Some(detail)) = (self.inspector.clone(), self.history_state.detail.as_mut()) else {
+            // Retried once the table has been opened
+            return;
+        };
+        detail.loading();
+
+        let tx = self.events_tx.clone();
+        let timeout = self.timeout;
+        self.runtime.spawn(async move {
+            let result = cancel::run(inspector.read_commit_actions(version), timeout).await;
+            let _ = tx.send(LoadEvent::CommitDetail(version, result));
+        });
+    }
+
+    /// Apply any results that background tasks have finished since the last frame.
+    fn apply_load_events(&mut self) {
+        while let Ok(event) = self.events_rx.try_recv() {
+            match event {
+                LoadEvent::Opened(inspector) => {
+                    self.diagnostics.emit("table_opened", json!({}));
+                    self.pending_loads = self.pending_loads.saturating_sub(1);
+                    // Previews were read from the previous version; re-read on next visit
+                    if self.inspector.replace(inspector).is_some() {
+                        self.data_preview = None;
+                        self.checkpoint_contents = None;
+                    }
+                    self.commit_explorer.retry_loading();
+                    if let Some(detail) = &mut self.history_state.detail {
+                        detail.retry_loading();
+                    }
+                }
+                LoadEvent::NewVersion(version) => {
+                    self.diagnostics.emit("new_version", json!({ "version": version }));
+                    self.refresh();
+                }
+                LoadEvent::OpenFailed(e) => {
+                    self.pending_loads = 0;
+                    let message = tr!("tui-open-failed", error = e.to_string());
+                    self.diagnostics.emit("load_failed", json!({ "error": message }));
+                    self.stats = Loadable::Failed(message.clone());
+                    if self.history.ready().is_some() {
+                        self.history_state.refresh_error = Some(message.clone());
+                    } else {
+                        self.history = Loadable::Failed(message.clone());
+                    }
+                    self.configuration = Loadable::Failed(message.clone());
+                    self.timeline = Loadable::Failed(message.clone());
+                    self.data_preview = Some(Loadable::Failed(message));
+                }
+                LoadEvent::Statistics(result) => {
+                    self.pending_loads = self.pending_loads.saturating_sub(1);
+                    if let Ok(stats) = &result {
+                        self.diagnostics.emit(
+                            "statistics_loaded",
+                            json!({ "version": stats.version, "num_files": stats.num_files }),
+                        );
+                        self.partitions = Some(PartitionsState::new(stats));
+                        self.commit_explorer.set_range(stats.oldest_version, stats.version);
+                        // The first replay step replaces the latest snapshot with the
+                        // starting version; don't show that as a change
+                        if let Some(replay) = self.replay.as_mut().filter(|r| !r.is_started()) {
+                            let first = replay.begin(stats.oldest_version, stats.version);
+                            self.stats = Loadable::Loading;
+                            self.spawn_version_load(first);
+                            continue;
+                        }
+                        if let Some(changes) =
+                            self.stats.ready().and_then(|old| VersionChanges::between(old, stats, &self.config))
+                        {
+                            self.changes = Some(changes);
+                        }
+                    }
+                    self.stats = Loadable::from_result(result.map(|stats| *stats));
+                }
+                LoadEvent::History(result) => {
+                    self.pending_loads = self.pending_loads.saturating_sub(1);
+                    match result {
+                        Ok(history) => {
+                            self.diagnostics.emit("history_loaded", json!({ "entries": history.len() }));
+                            self.history = Loadable::Ready(history);
+                            self.history_state.refresh_error = None;
+                            // Fresh history is newest first; keep any search
+                            self.history_state.reset();
+                        }
+                        // Keep what a reload failed to replace, and say why it's stale
+                        Err(e) if self.history.ready().is_some() => {
+                            self.history_state.refresh_error = Some(e.to_string());
+                        }
+                        Err(e) => self.history = Loadable::Failed(e.to_string()),
+                    }
+                }
+                LoadEvent::Configuration(result) => {
+                    self.pending_loads = self.pending_loads.saturating_sub(1);
+                    self.diagnostics.emit("configuration_loaded", json!({ "ok": result.is_ok() }));
+                    self.configuration = Loadable::from_result(result);
+                }
+                LoadEvent::Timeline(result) => {
+                    self.pending_loads = self.pending_loads.saturating_sub(1);
+                    self.diagnostics.emit("timeline_loaded", json!({ "ok": result.is_ok() }));
+                    self.timeline = Loadable::from_result(result);
+                }
+                LoadEvent::Preview(result) => {
+                    self.data_preview = Some(Loadable::from_result(result));
+                }
+                LoadEvent::Maintenance(result) => {
+                    self.diagnostics.emit("maintenance_finished", json!({ "ok": result.is_ok() }));
+                    let committed = result.is_ok();
+                    self.maintenance = Some(MaintenanceDialog::Finished(result.map_err(|e| format!("{:#}", e))));
+                    if committed {
+                        self.refresh();
+                    }
+                }
+                LoadEvent::MaintenancePlan(result) => {
+                    self.maintenance = Some(match result {
+                        Ok(action) => MaintenanceDialog::Confirm(action),
+                        Err(e) => MaintenanceDialog::Finished(Err(format!("{:#}", e))),
+                    });
+                }
+                LoadEvent::VacuumSimulation(result) => {
+                    // Dropped if the dialog moved on before it finished
+                    if let Some(MaintenanceDialog::VacuumRetention { simulation, .. }) = &mut self.maintenance {
+                        *simulation = Some(result.map_err(|e| format!("{:#}", e)));
+                    }
+                }
+                LoadEvent::CheckpointContents(result) => {
+                    self.checkpoint_contents = Some(Loadable::from_result(result));
+                }
+                LoadEvent::FileFooter(result) => {
+                    // Dropped when the view was closed while the footer loaded
+                    if self.file_footer.is_some() {
+                        self.file_footer = Some(Loadable::from_result(result));
+                    }
+                }
+                LoadEvent::CommitActions(version, result) => {
+                    self.commit_explorer.loaded(version, result);
+                }
+                LoadEvent::CommitDetail(version, result) => {
+                    if let Some(detail) = &mut self.history_state.detail {
+                        detail.loaded(version, result);
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_editing(&self) -> bool {
+        if self.maintenance.is_some() {
+            return true;
+        }
+        if self.picking {
+            return self.picker.as_ref().is_some_and(PickerState::is_editing);
+        }
+        self.search.is_editing() || self.tab_is_editing()
+    }
+
+    /// Whether the current tab has a prompt or panel of its own taking keys.
+    fn tab_is_editing(&self) -> bool {
+        match self.current_tab {
+            1 => self.history_state.is_editing(),
+            2 => self.what_if.is_some(),
+            8 => self.commit_explorer.is_editing(),
+            9 => self.schema.is_editing(),
+            _ => false,
+        }
+    }
+
+    fn is_loading(&self) -> bool {
+        self.pending_loads > 0 || self.data_preview.as_ref().is_some_and(Loadable::is_loading)
+    }
+
     fn ui(&mut self, f: &mut Frame) {
+        if let Some(picker) = self.picker.as_ref().filter(|_| self.picking) {
+            picker::render(f, f.size(), picker);
+            return;
+        }
+
         let chunks = Layout::default()
             .constraints([Constraint::Length(3), Constraint::Min(0)])
             .split(f.size());
 
         // Tabs
-        let tabs = Tabs::new(vec!["Overview", "History", "Insights", "Configuration", "Timeline"])
-            .block(Block::default().borders(Borders::ALL).title("Deltective"))
+        let spinner = SPINNER_FRAMES[self.tick % SPINNER_FRAMES.len()];
+        let title = if self.is_loading() {
+            tr!("tui-title-loading", path = self.table_path.as_str(), spinner = spinner)
+        } else {
+            tr!("tui-title", path = self.table_path.as_str())
+        };
+        let mut title = vec![Span::raw(title)];
+        if let Some(replay) = &self.replay {
+            title.push(replay.status());
+        }
+        let tabs = Tabs::new(TAB_MESSAGES.iter().map(|id| tr!(id)).collect::<Vec<_>>())
+            .block(Block::default().borders(Borders::ALL).title(Line::from(title)))
             .select(self.current_tab)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(palette().text))
             .highlight_style(
                 Style::default()
                     .add_modifier(Modifier::BOLD)
-                    .bg(Color::Blue),
+                    .bg(palette().selection),
             );
 
         f.render_widget(tabs, chunks[0]);
 
         // Tab content
-        let content_chunk = chunks[1];
+        let area = chunks[1];
+        self.tab_area = area;
         let scroll = self.scroll_positions[self.current_tab];
+        render_tab_guarded(f, area, &self.diagnostics, |f| self.render_tab(f, area, scroll, spinner));
+        if self.search.is_active(self.current_tab) || self.search.is_editing() {
+            search::highlight(f.buffer_mut(), area, &self.search, scroll);
+            search::render_status(f, area, &self.search);
+        }
+
+        if let Some(changes) = self.changes.as_ref().filter(|c| c.is_visible()) {
+            changes::render(f, area, changes);
+        }
+        if let Some(dialog) = &self.maintenance {
+            maintenance::render(f, area, dialog, spinner);
+        }
+    }
+
+    /// Draw the current tab's content into `area`.
+    fn render_tab(&self, f: &mut Frame, area: Rect, scroll: u16, spinner: &str) {
+        let title = tr!(TAB_MESSAGES[self.current_tab]);
+        let title = title.as_str();
         match self.current_tab {
-            0 => overview::render(f, content_chunk, &self.stats, scroll),
-            1 => history::render(
-                f,
-                content_chunk,
-                &self.history,
-                scroll,
-                self.history_page,
-                self.total_history_pages(),
-                self.history_reversed,
-            ),
-            2 => insights::render(f, content_chunk, &self.stats, scroll),
-            3 => configuration::render(f, content_chunk, &self.table_path, &self.inspector, scroll),
-            4 => timeline::render(f, content_chunk, &self.table_path, &self.inspector, scroll),
+            0 => match &self.stats {
+                Loadable::Ready(stats) => overview::render(f, area, stats, scroll),
+                other => render_pending(f, area, title, &tr!("tui-what-statistics"), other, spinner),
+            },
+            1 => match (&self.history, &self.history_state.detail) {
+                (_, Some(detail)) => commit_detail::render(f, area, detail, &self.config.run_links, scroll, spinner),
+                (Loadable::Ready(history), None) => history::render(f, area, history, &self.history_state, scroll),
+                (other, None) => render_pending(f, area, title, &tr!("tui-what-history"), other, spinner),
+            },
+            2 => match &self.stats {
+                Loadable::Ready(stats) => match &self.what_if {
+                    Some(state) => what_if::render(f, area, state, stats, &self.config, scroll),
+                    None => insights::render(f, area, stats, &self.config, scroll),
+                },
+                other => render_pending(f, area, title, &tr!("tui-what-statistics"), other, spinner),
+            },
+            3 => match &self.configuration {
+                Loadable::Ready(config) => {
+                    configuration::render(f, area, config, self.checkpoint_contents.as_ref(), scroll, spinner)
+                }
+                other => render_pending(f, area, title, &tr!("tui-what-configuration"), other, spinner),
+            },
+            4 => match &self.timeline {
+                Loadable::Ready(timeline) => timeline::render(f, area, timeline, self.stats.ready(), &self.config.thresholds.size_forecast_gb, scroll),
+                other => render_pending(f, area, title, &tr!("tui-what-timeline"), other, spinner),
+            },
+            5 => match (&self.stats, &self.partitions, &self.file_footer) {
+                (_, _, Some(footer)) => partitions::render_footer(f, area, footer, scroll, spinner),
+                (Loadable::Ready(stats), Some(state), None) => partitions::render(f, area, stats, state),
+                (other, _, None) => render_pending(f, area, title, &tr!("tui-what-statistics"), other, spinner),
+            },
+            6 => match &self.stats {
+                Loadable::Ready(stats) => column_stats::render(f, area, stats, scroll),
+                other => render_pending(f, area, title, &tr!("tui-what-statistics"), other, spinner),
+            },
+            7 => match &self.data_preview {
+                Some(Loadable::Ready(preview)) => data::render(f, area, preview, scroll),
+                Some(other) => render_pending(f, area, title, &tr!("tui-what-rows"), other, spinner),
+                None => render_pending::<()>(f, area, title, &tr!("tui-what-rows"), &Loadable::Loading, spinner),
+            },
+            8 => commit_explorer::render(f, area, &self.commit_explorer, scroll, spinner),
+            9 => match &self.stats {
+                Loadable::Ready(stats) => schema::render(f, area, stats, &self.schema, scroll),
+                other => render_pending(f, area, title, &tr!("tui-what-statistics"), other, spinner),
+            },
             _ => {}
         }
     }
 
-    fn handle_key(&mut self, key: KeyCode) {
-        match self.current_tab {
-            1 => {
-                // History tab specific keys
-                let total_pages = (self.history.len() + HISTORY_PAGE_SIZE - 1) / HISTORY_PAGE_SIZE;
-                match key {
-                    KeyCode::Char('n') => {
-                        // Next page
-                        if self.history_page + 1 < total_pages {
-                            self.history_page += 1;
-                            self.scroll_positions[1] = 0; // Reset scroll on page change
-                        }
-                    }
-                    KeyCode::Char('p') => {
-                        // Previous page
-                        if self.history_page > 0 {
-                            self.history_page -= 1;
-                            self.scroll_positions[1] = 0;
-                        }
-                    }
-                    KeyCode::Char('r') => {
-                        // Reverse sort
-                        self.history_reversed = !self.history_reversed;
-                        self.history.reverse();
-                        self.history_page = 0;
+    /// Content lines of the current tab matching the search, found by drawing
+    /// the whole tab, unscrolled, into an off-screen buffer.
+    fn search_matches(&self) -> Vec<usize> {
+        let area = Rect::new(0, 0, self.tab_area.width.max(1), SEARCH_RENDER_ROWS);
+        let Ok(mut terminal) = Terminal::new(TestBackend::new(area.width, area.height)) else {
+            return Vec::new();
+        };
+        let drawn = terminal.draw(|f| {
+            render_tab_guarded(f, area, &self.diagnostics, |f| self.render_tab(f, area, 0, SPINNER_FRAMES[0]));
+        });
+        if drawn.is_err() {
+            return Vec::new();
+        }
+        search::matching_lines(terminal.backend().buffer(), area, self.search.query())
+    }
+
+    /// Scroll to the next or previous match of the search.
+    fn jump_to_match(&mut self, forward: bool) {
+        let matches = self.search_matches();
+        let scroll = self.scroll_positions[self.current_tab];
+        if let Some(position) = self.search.advance(matches, forward, scroll) {
+            self.scroll_positions[self.current_tab] = position;
+        }
+    }
+
+    /// Handle keys specific to the current tab, or the picker while it is
+    /// shown. Returns true if the key was consumed.
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if let Some(dialog) = &mut self.maintenance {
+            match dialog.handle_key(key) {
+                DialogResponse::Stay => {}
+                DialogResponse::Close => self.maintenance = None,
+                DialogResponse::Start => self.start_maintenance(),
+                DialogResponse::DryRun(hours) => self.start_vacuum_dry_run(hours),
+            }
+            return true;
+        }
+        if self.picking {
+            return self.picker.as_mut().is_some_and(|picker| picker.handle_key(key));
+        }
+        if self.search.is_editing() {
+            if self.search.handle_edit_key(key) {
+                self.jump_to_match(true);
+            }
+            return true;
+        }
+        if !self.tab_is_editing() {
+            let searching = self.search.is_active(self.current_tab);
+            match key {
+                KeyCode::Char('/') => {
+                    self.search.start(self.current_tab);
+                    return true;
+                }
+                KeyCode::Char(c @ ('n' | 'N')) if searching => {
+                    self.jump_to_match(c == 'n');
+                    return true;
+                }
+                KeyCode::Esc if searching => {
+                    self.search.clear();
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        let consumed = match self.current_tab {
+            1 => match &mut self.history {
+                Loadable::Ready(history) => {
+                    let consumed = self.history_state.handle_key(key, history);
+                    // Moving the selection keeps the page where it is
+                    if consumed && !matches!(key, KeyCode::Up | KeyCode::Down) {
                         self.scroll_positions[1] = 0;
                     }
-                    _ => {}
+                    consumed
+                }
+                _ => false,
+            },
+            2 => match (key, &mut self.what_if) {
+                (KeyCode::Char('t') | KeyCode::Esc, Some(_)) => {
+                    self.what_if = None;
+                    true
+                }
+                (KeyCode::Char('t'), None) => {
+                    self.what_if = Some(WhatIfState::new(&self.config.thresholds));
+                    true
+                }
+                (key, Some(state)) => state.handle_key(key, &self.config.thresholds),
+                _ => false,
+            },
+            3 => match key {
+                KeyCode::Char('i') if !self.checkpoint_contents.as_ref().is_some_and(Loadable::is_loading) => {
+                    self.request_checkpoint_contents();
+                    true
+                }
+                _ => false,
+            },
+            5 => match (&self.stats, &mut self.partitions, &self.file_footer) {
+                // Arrow keys scroll the footer
+                (_, _, Some(_)) => match key {
+                    KeyCode::Esc | KeyCode::Backspace => {
+                        self.file_footer = None;
+                        true
+                    }
+                    _ => false,
+                },
+                (Loadable::Ready(stats), Some(state), None) => state.handle_key(key, stats),
+                _ => false,
+            },
+            7 => match key {
+                KeyCode::Char('t') => {
+                    self.request_preview(PreviewSource::Table);
+                    self.scroll_positions[7] = 0;
+                    true
                 }
+                _ => false,
+            },
+            8 => {
+                let consumed = self.commit_explorer.handle_key(key);
+                if consumed {
+                    self.scroll_positions[8] = 0;
+                }
+                consumed
             }
-            _ => {}
+            9 => match &self.stats {
+                Loadable::Ready(stats) => {
+                    let consumed = self.schema.handle_key(key, stats);
+                    if consumed {
+                        self.scroll_positions[9] = 0;
+                    }
+                    consumed
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+        if consumed {
+            return true;
         }
-    }
 
-    fn total_history_pages(&self) -> usize {
-        (self.history.len() + HISTORY_PAGE_SIZE - 1) / HISTORY_PAGE_SIZE
+        let Some(replay) = self.replay.as_mut() else {
+            return false;
+        };
+        let (consumed, version) = replay.handle_key(key);
+        if let Some(version) = version {
+            self.spawn_version_load(version);
+        }
+        consumed
     }
 }
 
+/// Fetch statistics, history, configuration and timeline concurrently,
+/// reporting each as it completes.
+async fn load_tabs(inspector: &DeltaTableInspector, tx: &Sender<LoadEvent>, timeout: Option<Duration>, config: &Config) {
+    tokio::join!(
+        async {
+            let load = async {
+                let mut stats = inspector.get_statistics().await?;
+                inspector.enrich_statistics(&mut stats, config).await?;
+                Ok(stats)
+            };
+            let result = cancel::run(load, timeout).await;
+            let _ = tx.send(LoadEvent::Statistics(result.map(Box::new)));
+        },
+        async {
+            let result = cancel::run(inspector.get_history(false), timeout).await;
+            let _ = tx.send(LoadEvent::History(result));
+        },
+        async {
+            let result = cancel::run(inspector.get_configuration(), timeout).await;
+            let _ = tx.send(LoadEvent::Configuration(result));
+        },
+        async {
+            let result = cancel::run(inspector.get_timeline_analysis(), timeout).await;
+            let _ = tx.send(LoadEvent::Timeline(result));
+        },
+    );
+}
+
+/// Placeholder for a tab whose data is still loading or failed to load.
+fn render_pending<T>(f: &mut Frame, area: Rect, title: &str, what: &str, state: &Loadable<T>, spinner: &str) {
+    let line = match state {
+        Loadable::Failed(e) => Line::from(vec![
+            Span::styled(tr!("tui-load-failed", what = what, error = e.as_str()), Style::default().fg(palette().critical)),
+        ]),
+        _ => Line::from(vec![
+            Span::styled(format!("{} ", spinner), Style::default().fg(palette().accent)),
+            Span::styled(tr!("tui-loading", what = what), Style::default().fg(palette().muted)),
+        ]),
+    };
+
+    let paragraph = Paragraph::new(vec![line])
+        .block(Block::default().borders(Borders::ALL).title(title.to_string()));
+    f.render_widget(paragraph, area);
+}
+
 // Helper function to format bytes
 pub fn format_bytes(bytes: i64) -> String {
     let mut bytes = bytes as f64;
@@ -0,0 +1,139 @@
+use crate::tui_app::format_bytes;
+use crate::tui_app::theme::{palette, severity_marker};
+use deltective::config::Config;
+use deltective::inspector::TableStatistics;
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// How long the change banner stays up after a reload.
+const HIGHLIGHT_DURATION: Duration = Duration::from_secs(10);
+
+/// What changed between two loads of the table, shown briefly when a new
+/// version arrives so live monitoring shows progress rather than a silent redraw.
+pub struct VersionChanges {
+    from_version: i64,
+    to_version: i64,
+    files_added: usize,
+    files_removed: usize,
+    bytes_delta: i64,
+    rows_delta: Option<i64>,
+    new_insights: Vec<(String, String)>,
+    resolved_insights: Vec<String>,
+    arrived: Instant,
+}
+
+impl VersionChanges {
+    /// Compare two statistics snapshots; `None` when the version didn't change.
+    pub fn between(old: &TableStatistics, new: &TableStatistics, config: &Config) -> Option<Self> {
+        if old.version == new.version {
+            return None;
+        }
+
+        let old_files: HashSet<&str> = old.files.iter().map(|f| f.path.as_str()).collect();
+        let new_files: HashSet<&str> = new.files.iter().map(|f| f.path.as_str()).collect();
+
+        let findings = |stats: &TableStatistics| {
+            config
+                .analyzer(stats.clone())
+                .analyze()
+                .into_iter()
+                .filter(|i| i.severity != "good")
+                .map(|i| (i.title, i.severity))
+                .collect::<Vec<_>>()
+        };
+        let old_insights = findings(old);
+        let new_insights = findings(new);
+        let old_titles: HashSet<&str> = old_insights.iter().map(|(title, _)| title.as_str()).collect();
+        let new_titles: HashSet<&str> = new_insights.iter().map(|(title, _)| title.as_str()).collect();
+
+        Some(Self {
+            from_version: old.version,
+            to_version: new.version,
+            files_added: new_files.difference(&old_files).count(),
+            files_removed: old_files.difference(&new_files).count(),
+            bytes_delta: new.total_size_bytes - old.total_size_bytes,
+            rows_delta: old.num_rows.zip(new.num_rows).map(|(old, new)| new - old),
+            resolved_insights: old_insights
+                .iter()
+                .filter(|(title, _)| !new_titles.contains(title.as_str()))
+                .map(|(title, _)| title.clone())
+                .collect(),
+            new_insights: new_insights
+                .into_iter()
+                .filter(|(title, _)| !old_titles.contains(title.as_str()))
+                .collect(),
+            arrived: Instant::now(),
+        })
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.arrived.elapsed() < HIGHLIGHT_DURATION
+    }
+}
+
+/// Draw the change banner over the bottom of `area`.
+pub fn render(f: &mut Frame, area: Rect, changes: &VersionChanges) {
+    let signed = |value: i64| if value < 0 { format!("-{}", value.abs()) } else { format!("+{}", value) };
+
+    let mut summary = vec![
+        Span::styled(tr!("changes-files-added", count = changes.files_added), Style::default().fg(palette().good)),
+        Span::raw(" / "),
+        Span::styled(tr!("changes-files-removed", count = changes.files_removed), Style::default().fg(palette().critical)),
+        Span::raw(format!(
+            "   {}",
+            tr!(
+                "changes-size",
+                delta = format!(
+                    "{}{}",
+                    if changes.bytes_delta < 0 { "-" } else { "+" },
+                    format_bytes(changes.bytes_delta.abs())
+                ),
+            )
+        )),
+    ];
+    if let Some(rows) = changes.rows_delta {
+        summary.push(Span::raw(format!("   {}", tr!("changes-rows", delta = signed(rows)))));
+    }
+
+    let mut lines = vec![Line::from(summary)];
+    for (title, severity) in &changes.new_insights {
+        let color = palette().severity(severity);
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("changes-new")), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}  {}", severity_marker(severity), title), Style::default().fg(color)),
+        ]));
+    }
+    for title in &changes.resolved_insights {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("changes-resolved")), Style::default().fg(palette().good).add_modifier(Modifier::BOLD)),
+            Span::raw(title.clone()),
+        ]));
+    }
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let banner = Rect {
+        x: area.x,
+        y: area.y + area.height - height,
+        width: area.width,
+        height,
+    };
+    let title = format!(" {} ", tr!("changes-title", from = changes.from_version, to = changes.to_version));
+    f.render_widget(Clear, banner);
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(palette().warning))
+                .title(title),
+        ),
+        banner,
+    );
+}
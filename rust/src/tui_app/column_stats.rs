@@ -0,0 +1,116 @@
+use crate::tui_app::theme::palette;
+use deltective::column_stats::format_value;
+use deltective::data_skipping::{ColumnSkipping, SkippingRating};
+use deltective::inspector::TableStatistics;
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
+    let mut lines = Vec::new();
+
+    lines.push(Line::from(vec![
+        Span::styled(tr!("column-stats-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+
+    if stats.column_stats.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                tr!("column-stats-none"),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled(
+                tr!("column-stats-none-hint"),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "{:30} {:33} {:33} {:>12} {:>7} {:>12} {:>9}",
+                    tr!("column-stats-column"),
+                    tr!("column-stats-min"),
+                    tr!("column-stats-max"),
+                    tr!("column-stats-nulls"),
+                    tr!("column-stats-null-pct"),
+                    tr!("column-stats-files"),
+                    tr!("column-stats-skipped")
+                ),
+                Style::default().fg(palette().section).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+
+        for col in &stats.column_stats {
+            let min = col.min.as_ref().map(format_value).unwrap_or_else(|| "-".to_string());
+            let max = col.max.as_ref().map(format_value).unwrap_or_else(|| "-".to_string());
+            let null_pct = col
+                .null_fraction()
+                .map(|f| format!("{:.1}%", f * 100.0))
+                .unwrap_or_else(|| "-".to_string());
+            let coverage_color = if col.files_with_stats < stats.num_files {
+                palette().warning
+            } else {
+                palette().good
+            };
+
+            let name_color = if stats.partition_columns.contains(&col.name) {
+                palette().warning
+            } else {
+                palette().accent
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:30} ", col.name), Style::default().fg(name_color)),
+                Span::styled(format!("{:33} ", min), Style::default().fg(palette().good)),
+                Span::styled(format!("{:33} ", max), Style::default().fg(palette().good)),
+                Span::raw(format!("{:>12} {:>7} ", col.null_count, null_pct)),
+                Span::styled(
+                    format!("{:>12}", format!("{}/{}", col.files_with_stats, stats.num_files)),
+                    Style::default().fg(coverage_color),
+                ),
+                skipping_span(stats.data_skipping.iter().find(|s| s.column == col.name)),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(
+                tr!("column-stats-files-hint"),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled(
+                tr!("column-stats-skipped-hint"),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("column-stats-block-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+fn skipping_span(skipping: Option<&ColumnSkipping>) -> Span<'static> {
+    let Some(skipping) = skipping else {
+        return Span::styled(format!(" {:>9}", "-"), Style::default().fg(palette().muted));
+    };
+    let color = match skipping.rating() {
+        SkippingRating::Effective => palette().good,
+        SkippingRating::Partial => palette().warning,
+        SkippingRating::Poor => palette().critical,
+    };
+    Span::styled(format!(" {:>9}", format!("{:.0}%", skipping.skip_pct)), Style::default().fg(color))
+}
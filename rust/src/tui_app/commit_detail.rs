@@ -0,0 +1,269 @@
+use crate::tui_app::theme::palette;
+use crate::tui_app::{format_bytes, Loadable};
+use anyhow::Result;
+use chrono::DateTime;
+use deltalake::kernel::CommitInfo;
+use deltective::run_links::{self, RunLinkTemplate};
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use serde_json::{Map, Value};
+
+/// Files listed in full; rewrites of large tables touch thousands.
+const MAX_FILES_SHOWN: usize = 200;
+
+/// commitInfo fields shown in their own sections; everything else the writer
+/// recorded is listed under "Other fields".
+const SHOWN_FIELDS: [&str; 13] = [
+    "timestamp",
+    "operation",
+    "operationParameters",
+    "operationMetrics",
+    "userId",
+    "userName",
+    "engineInfo",
+    "isolationLevel",
+    "isBlindAppend",
+    "readVersion",
+    "userMetadata",
+    "clientVersion",
+    "txnId",
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum FileChange {
+    Added,
+    Removed,
+    ChangeData,
+}
+
+/// A data file added, removed or written as change data by the commit.
+struct TouchedFile {
+    change: FileChange,
+    path: String,
+    size: Option<i64>,
+}
+
+impl TouchedFile {
+    fn from_action(action: &Value) -> Option<Self> {
+        let (change, body) = [("add", FileChange::Added), ("remove", FileChange::Removed), ("cdc", FileChange::ChangeData)]
+            .into_iter()
+            .find_map(|(key, change)| action.get(key).map(|body| (change, body)))?;
+        Some(Self {
+            change,
+            path: body["path"].as_str()?.to_string(),
+            size: body["size"].as_i64(),
+        })
+    }
+}
+
+/// A commit opened from the History tab: everything its commitInfo records,
+/// and the files it touched once its actions have been read.
+pub struct CommitDetail {
+    version: i64,
+    info: Map<String, Value>,
+    /// `None` until requested
+    files: Option<Loadable<Vec<TouchedFile>>>,
+}
+
+impl CommitDetail {
+    pub fn new(version: i64, info: &CommitInfo) -> Self {
+        let info = match serde_json::to_value(info) {
+            Ok(Value::Object(info)) => info,
+            _ => Map::new(),
+        };
+        Self {
+            version,
+            info,
+            files: None,
+        }
+    }
+
+    /// Version whose actions still need to be read.
+    pub fn pending(&self) -> Option<i64> {
+        self.files.is_none().then_some(self.version)
+    }
+
+    pub fn loading(&mut self) {
+        self.files = Some(Loadable::Loading);
+    }
+
+    /// Store the actions read for `version`, unless another commit has been
+    /// opened in the meantime.
+    pub fn loaded(&mut self, version: i64, result: Result<Vec<Value>>) {
+        if self.version == version {
+            self.files = Some(Loadable::from_result(
+                result.map(|actions| actions.iter().filter_map(TouchedFile::from_action).collect()),
+            ));
+        }
+    }
+
+    /// Re-request a read that was in flight when the table was reloaded.
+    pub fn retry_loading(&mut self) {
+        if self.files.as_ref().is_some_and(Loadable::is_loading) {
+            self.files = None;
+        }
+    }
+
+    fn field(&self, key: &str) -> Option<String> {
+        self.info.get(key).filter(|v| !v.is_null()).map(display_value)
+    }
+}
+
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    detail: &CommitDetail,
+    run_links: &[RunLinkTemplate],
+    scroll: u16,
+    spinner: &str,
+) {
+    let mut lines = Vec::new();
+
+    lines.push(Line::from(vec![
+        Span::styled(
+            tr!("commit-detail-heading", version = detail.version),
+            Style::default().fg(palette().accent).add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    let timestamp = detail.info.get("timestamp").and_then(Value::as_i64).map(|ts| {
+        DateTime::from_timestamp(ts / 1000, 0)
+            .unwrap_or_default()
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    });
+    let user = match (detail.field("userName"), detail.field("userId")) {
+        (Some(name), Some(id)) => Some(format!("{} ({})", name, id)),
+        (name, id) => name.or(id),
+    };
+    let summary = [
+        ("commit-detail-operation", detail.field("operation")),
+        ("commit-detail-time", timestamp),
+        ("commit-detail-user", user),
+        ("commit-detail-engine", detail.field("engineInfo").or_else(|| detail.field("clientVersion"))),
+        ("commit-detail-isolation", detail.field("isolationLevel")),
+        ("commit-detail-blind-append", detail.field("isBlindAppend")),
+        ("commit-detail-read-version", detail.field("readVersion")),
+        ("commit-detail-txn-id", detail.field("txnId")),
+        ("commit-detail-message", detail.field("userMetadata")),
+    ];
+    for (label, value) in summary {
+        if let Some(value) = value {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", tr!(label)), Style::default().fg(palette().accent)),
+                Span::styled(value, Style::default().fg(palette().good)),
+            ]));
+        }
+    }
+    for link in run_links::resolve(run_links, &Value::Object(detail.info.clone())) {
+        lines.push(Line::from(vec![
+            Span::styled(format!("↗ {}: ", link.name), Style::default().fg(palette().link)),
+            Span::styled(link.url, Style::default().fg(palette().link).add_modifier(Modifier::UNDERLINED)),
+        ]));
+    }
+
+    push_section(&mut lines, &tr!("commit-detail-parameters"), detail.info.get("operationParameters"));
+    push_section(&mut lines, &tr!("commit-detail-metrics"), detail.info.get("operationMetrics"));
+    let other: Map<String, Value> = detail
+        .info
+        .iter()
+        .filter(|(key, value)| !SHOWN_FIELDS.contains(&key.as_str()) && !value.is_null())
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    push_section(&mut lines, &tr!("commit-detail-other"), Some(&Value::Object(other)));
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("commit-detail-files"), Style::default().fg(palette().section).add_modifier(Modifier::BOLD)),
+    ]));
+    match &detail.files {
+        Some(Loadable::Ready(files)) => push_files(&mut lines, files),
+        Some(Loadable::Failed(e)) => lines.push(Line::from(Span::styled(
+            format!("  {}", tr!("commit-detail-read-failed", error = e.as_str())),
+            Style::default().fg(palette().critical),
+        ))),
+        _ => lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", spinner), Style::default().fg(palette().accent)),
+            Span::styled(tr!("commit-detail-reading"), Style::default().fg(palette().muted)),
+        ])),
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("commit-detail-block-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+/// A heading and one line per key of `fields`; nothing when it is empty.
+fn push_section(lines: &mut Vec<Line<'_>>, title: &str, fields: Option<&Value>) {
+    let Some(fields) = fields.and_then(Value::as_object).filter(|f| !f.is_empty()) else {
+        return;
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(title.to_string(), Style::default().fg(palette().section).add_modifier(Modifier::BOLD)),
+    ]));
+    for (key, value) in fields {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}: ", key), Style::default().fg(palette().accent)),
+            Span::raw(display_value(value)),
+        ]));
+    }
+}
+
+fn push_files(lines: &mut Vec<Line<'_>>, files: &[TouchedFile]) {
+    if files.is_empty() {
+        lines.push(Line::from(Span::styled(format!("  {}", tr!("commit-detail-no-files")), Style::default().fg(palette().muted))));
+        return;
+    }
+
+    let mut totals = Vec::new();
+    for (change, id) in [
+        (FileChange::Added, "commit-detail-added"),
+        (FileChange::Removed, "commit-detail-removed"),
+        (FileChange::ChangeData, "commit-detail-change-data"),
+    ] {
+        let matching: Vec<&TouchedFile> = files.iter().filter(|f| f.change == change).collect();
+        if !matching.is_empty() {
+            let bytes: i64 = matching.iter().filter_map(|f| f.size).sum();
+            totals.push(tr!(id, count = matching.len(), size = format_bytes(bytes)));
+        }
+    }
+    lines.push(Line::from(Span::styled(format!("  {}", totals.join(" · ")), Style::default().fg(palette().muted))));
+
+    for file in files.iter().take(MAX_FILES_SHOWN) {
+        let (marker, color): (&str, Color) = match file.change {
+            FileChange::Added => ("+", palette().good),
+            FileChange::Removed => ("-", palette().critical),
+            FileChange::ChangeData => ("~", palette().section),
+        };
+        let size = file.size.map(|s| format!(" ({})", format_bytes(s))).unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", marker), Style::default().fg(color)),
+            Span::raw(file.path.clone()),
+            Span::styled(size, Style::default().fg(palette().muted)),
+        ]));
+    }
+    if files.len() > MAX_FILES_SHOWN {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", tr!("commit-detail-more-files", count = files.len() - MAX_FILES_SHOWN)),
+            Style::default().fg(palette().muted),
+        )));
+    }
+}
+
+/// Strings without their JSON quotes; everything else as compact JSON.
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,241 @@
+use crate::tui_app::theme::palette;
+use crate::tui_app::Loadable;
+use anyhow::Result;
+use crossterm::event::KeyCode;
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use serde_json::Value;
+
+/// Actions shown in full; commits rewriting a large table can add thousands.
+const MAX_ACTIONS_SHOWN: usize = 500;
+
+/// Order of the per-action counts; other action types follow alphabetically.
+const ACTION_ORDER: [&str; 8] = ["commitInfo", "protocol", "metaData", "txn", "add", "remove", "cdc", "domainMetadata"];
+
+/// One action of a commit, pretty-printed once when the commit is read.
+pub struct RawAction {
+    kind: String,
+    json: String,
+}
+
+impl RawAction {
+    fn new(action: &Value) -> Self {
+        // Each line of a commit holds a single action keyed by its type
+        match action.as_object().filter(|o| o.len() == 1).and_then(|o| o.iter().next()) {
+            Some((kind, body)) => Self {
+                kind: kind.clone(),
+                json: serde_json::to_string_pretty(body).unwrap_or_default(),
+            },
+            None => Self {
+                kind: "?".to_string(),
+                json: serde_json::to_string_pretty(action).unwrap_or_default(),
+            },
+        }
+    }
+}
+
+/// Commits tab: the raw actions of one version, stepped through with n/p or
+/// jumped to by number.
+#[derive(Default)]
+pub struct CommitExplorerState {
+    /// Version shown; the table's current version until another is chosen
+    version: Option<i64>,
+    /// Oldest and latest version, known once statistics arrive
+    range: Option<(i64, i64)>,
+    /// Actions of `version`; `None` until requested
+    actions: Option<Loadable<Vec<RawAction>>>,
+    /// Version number being typed after `g`
+    input: String,
+    editing: bool,
+}
+
+impl CommitExplorerState {
+    /// Record the versions available, showing the latest if nothing has been
+    /// chosen yet.
+    pub fn set_range(&mut self, oldest: i64, latest: i64) {
+        self.range = Some((oldest, latest));
+        if self.version.is_none() {
+            self.version = Some(latest);
+        }
+    }
+
+    /// Version whose actions still need to be read.
+    pub fn pending(&self) -> Option<i64> {
+        self.version.filter(|_| self.actions.is_none())
+    }
+
+    pub fn loading(&mut self) {
+        self.actions = Some(Loadable::Loading);
+    }
+
+    /// Store the actions read for `version`, unless another version has been
+    /// chosen in the meantime.
+    pub fn loaded(&mut self, version: i64, result: Result<Vec<Value>>) {
+        if self.version == Some(version) {
+            self.actions = Some(Loadable::from_result(result.map(|actions| actions.iter().map(RawAction::new).collect())));
+        }
+    }
+
+    /// Re-request a read that was in flight when the table was reloaded; its
+    /// result went to the previous event channel.
+    pub fn retry_loading(&mut self) {
+        if self.actions.as_ref().is_some_and(Loadable::is_loading) {
+            self.actions = None;
+        }
+    }
+
+    /// Whether the version prompt is capturing keystrokes.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Handle a key on the Commits tab. Returns true if the key was consumed.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        if self.editing {
+            match key {
+                KeyCode::Enter => {
+                    self.editing = false;
+                    if let Ok(version) = self.input.parse() {
+                        self.show(version);
+                    }
+                }
+                KeyCode::Esc => self.editing = false,
+                KeyCode::Backspace => {
+                    self.input.pop();
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() => self.input.push(c),
+                _ => {}
+            }
+            return true;
+        }
+
+        let Some(version) = self.version else {
+            return false;
+        };
+        match key {
+            KeyCode::Char('n') => self.show(version + 1),
+            KeyCode::Char('p') => self.show(version - 1),
+            KeyCode::Char('g') => {
+                self.editing = true;
+                self.input.clear();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn show(&mut self, version: i64) {
+        let version = match self.range {
+            Some((oldest, latest)) => version.clamp(oldest, latest),
+            None => version.max(0),
+        };
+        if self.version != Some(version) {
+            self.version = Some(version);
+            self.actions = None;
+        }
+    }
+}
+
+pub fn render(f: &mut Frame, area: Rect, state: &CommitExplorerState, scroll: u16, spinner: &str) {
+    let mut lines = Vec::new();
+
+    lines.push(Line::from(vec![
+        Span::styled(tr!("commits-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+
+    if let Some(version) = state.version {
+        let mut heading = vec![Span::styled(
+            tr!("commits-version", version = version),
+            Style::default().fg(palette().warning).add_modifier(Modifier::BOLD),
+        )];
+        if let Some((oldest, latest)) = state.range {
+            heading.push(Span::styled(
+                format!(" {}", tr!("commits-range", oldest = oldest, latest = latest)),
+                Style::default().fg(palette().muted),
+            ));
+        }
+        lines.push(Line::from(heading));
+    }
+    if state.editing {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", tr!("commits-goto")), Style::default().fg(palette().accent)),
+            Span::styled(format!("{}▏", state.input), Style::default().fg(palette().warning)),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    match &state.actions {
+        Some(Loadable::Ready(actions)) => render_actions(&mut lines, actions),
+        Some(Loadable::Failed(e)) => lines.push(Line::from(Span::styled(
+            tr!("tui-load-failed", what = tr!("tui-what-commit"), error = e.as_str()),
+            Style::default().fg(palette().critical),
+        ))),
+        _ => lines.push(Line::from(vec![
+            Span::styled(format!("{} ", spinner), Style::default().fg(palette().accent)),
+            Span::styled(tr!("tui-loading", what = tr!("tui-what-commit")), Style::default().fg(palette().muted)),
+        ])),
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("commits-block-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_actions(lines: &mut Vec<Line<'_>>, actions: &[RawAction]) {
+    if actions.is_empty() {
+        lines.push(Line::from(Span::styled(tr!("commits-empty"), Style::default().fg(palette().muted))));
+        return;
+    }
+
+    // Per-action counts, in log order of importance
+    let mut kinds: Vec<&str> = actions.iter().map(|a| a.kind.as_str()).collect();
+    kinds.sort_by_key(|kind| (ACTION_ORDER.iter().position(|k| k == kind).unwrap_or(ACTION_ORDER.len()), *kind));
+    kinds.dedup();
+    let mut counts = vec![Span::styled(format!("{} ", tr!("commits-counts")), Style::default().fg(palette().accent))];
+    for (i, kind) in kinds.iter().enumerate() {
+        if i > 0 {
+            counts.push(Span::styled(" · ", Style::default().fg(palette().muted)));
+        }
+        let count = actions.iter().filter(|a| a.kind == *kind).count();
+        counts.push(Span::styled(format!("{} {}", kind, count), Style::default().fg(kind_color(kind))));
+    }
+    lines.push(Line::from(counts));
+    lines.push(Line::from(""));
+
+    for action in actions.iter().take(MAX_ACTIONS_SHOWN) {
+        lines.push(Line::from(Span::styled(
+            action.kind.clone(),
+            Style::default().fg(kind_color(&action.kind)).add_modifier(Modifier::BOLD),
+        )));
+        for json_line in action.json.lines() {
+            lines.push(Line::from(format!("  {}", json_line)));
+        }
+        lines.push(Line::from(""));
+    }
+    if actions.len() > MAX_ACTIONS_SHOWN {
+        lines.push(Line::from(Span::styled(
+            tr!("commits-more", count = actions.len() - MAX_ACTIONS_SHOWN),
+            Style::default().fg(palette().muted),
+        )));
+    }
+}
+
+fn kind_color(kind: &str) -> Color {
+    match kind {
+        "add" | "cdc" => palette().good,
+        "remove" => palette().critical,
+        "protocol" | "metaData" => palette().warning,
+        "commitInfo" => palette().accent,
+        "txn" | "domainMetadata" => palette().section,
+        _ => palette().muted,
+    }
+}
@@ -0,0 +1,118 @@
+use crate::tui_app::document::TuiRenderer;
+use crate::tui_app::theme::palette;
+use crate::tui_app::{install_panic_hook, restore_terminal};
+use deltective::diff::{ComparedTable, TableComparison};
+use deltective::render::Renderer;
+use deltective::tr;
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame, Terminal,
+};
+use std::io;
+
+const BAR_WIDTH: usize = 20;
+
+/// Split-screen view of two tables: each side's figures and file size
+/// distribution, with the schema, property and protocol differences below.
+pub fn run(comparison: &TableComparison) -> Result<()> {
+    install_panic_hook();
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    crossterm::terminal::enable_raw_mode()?;
+    crossterm::execute!(io::stdout(), crossterm::terminal::EnterAlternateScreen)?;
+
+    let result = event_loop(&mut terminal, comparison);
+    restore_terminal()?;
+    result
+}
+
+fn event_loop(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, comparison: &TableComparison) -> Result<()> {
+    let mut scroll: u16 = 0;
+    loop {
+        terminal.draw(|f| render(f, comparison, scroll))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Up | KeyCode::Char('k') => scroll = scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => scroll = scroll.saturating_add(1),
+            KeyCode::PageUp => scroll = scroll.saturating_sub(10),
+            KeyCode::PageDown => scroll = scroll.saturating_add(10),
+            KeyCode::Home => scroll = 0,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn render(f: &mut Frame, comparison: &TableComparison, scroll: u16) {
+    let side_height = (comparison.a.fields().len() + comparison.a.file_sizes.len() + 5) as u16;
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(side_height), Constraint::Min(0)])
+        .split(f.size());
+    let sides = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[0]);
+
+    let max_files = comparison
+        .a
+        .file_sizes
+        .iter()
+        .chain(&comparison.b.file_sizes)
+        .map(|bucket| bucket.files)
+        .max()
+        .unwrap_or(0);
+    render_side(f, sides[0], "A", &comparison.a, &comparison.b, max_files);
+    render_side(f, sides[1], "B", &comparison.b, &comparison.a, max_files);
+
+    let paragraph = Paragraph::new(TuiRenderer.render(&comparison.changes_document()))
+        .block(Block::default().borders(Borders::ALL).title(tr!("compare-block-title")))
+        .scroll((scroll, 0));
+    f.render_widget(paragraph, rows[1]);
+}
+
+/// One table's figures; values that differ from `other` are marked with ≠.
+fn render_side(f: &mut Frame, area: Rect, name: &str, table: &ComparedTable, other: &ComparedTable, max_files: usize) {
+    let mut lines = Vec::new();
+    let width = table.fields().iter().map(|(label, _)| label.len()).max().unwrap_or(0) + 1;
+
+    for ((label, value), (_, other_value)) in table.fields().into_iter().zip(other.fields()) {
+        let differs = value != other_value;
+        let (marker, value_color) = if differs { ("≠ ", palette().warning) } else { ("  ", palette().good) };
+        lines.push(Line::from(vec![
+            Span::styled(marker, Style::default().fg(palette().warning).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:width$} ", format!("{}:", label), width = width), Style::default().fg(palette().accent)),
+            Span::styled(value, Style::default().fg(value_color)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        tr!("compare-file-sizes"),
+        Style::default().fg(palette().section).add_modifier(Modifier::BOLD),
+    )));
+    for bucket in &table.file_sizes {
+        let bar = (bucket.files * BAR_WIDTH).checked_div(max_files).unwrap_or(0);
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:>12} ", bucket.label), Style::default().fg(palette().accent)),
+            Span::styled(format!("{:BAR_WIDTH$}", "█".repeat(bar)), Style::default().fg(palette().good)),
+            Span::raw(format!(" {}", bucket.files)),
+        ]));
+    }
+
+    let title = tr!("compare-side-title", side = name, path = table.table_path.as_str(), version = table.version);
+    f.render_widget(Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title)), area);
+}
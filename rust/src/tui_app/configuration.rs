@@ -1,200 +1,461 @@
-use crate::inspector::DeltaTableInspector;
-use crate::tui_app::format_bytes;
-use anyhow::Result;
+use crate::tui_app::document::TuiRenderer;
+use crate::tui_app::theme::palette;
+use crate::tui_app::{format_bytes, Loadable};
+use deltective::checkpoint_contents::CheckpointContents;
+use deltective::clustering::ClusteringInfo;
+use deltective::constraints::CheckConstraint;
+use deltective::inspector::{ColumnMappingInfo, ConfigurationInfo};
+use deltective::render::{Renderable, Renderer};
+use deltective::row_tracking::{RowTrackingInfo, MATERIALIZED_COLUMN_BYTES};
+use deltective::tr;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
-pub fn render(f: &mut Frame, area: Rect, table_path: &str, inspector: &DeltaTableInspector, scroll: u16) {
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    let config_result = rt.block_on(inspector.get_configuration());
-
-    let mut lines = Vec::new();
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    config: &ConfigurationInfo,
+    checkpoint: Option<&Loadable<CheckpointContents>>,
+    scroll: u16,
+    spinner: &str,
+) {
+    let mut lines = vec![Line::from(vec![
+        Span::styled(tr!("configuration-heading"), Style::default().fg(palette().accent).add_modifier(ratatui::style::Modifier::BOLD)),
+    ])];
+    lines.push(Line::from(""));
 
+    // Table Properties
     lines.push(Line::from(vec![
-        Span::styled("═══ TABLE CONFIGURATION ═══", Style::default().fg(Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(tr!("configuration-properties"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
     ]));
     lines.push(Line::from(""));
-
-    match config_result {
-        Ok(config) => {
-            // Table Properties
+    if config.table_properties.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default().fg(palette().muted)),
+            Span::raw(tr!("configuration-no-properties")),
+        ]));
+    } else {
+        let mut props: Vec<_> = config.table_properties.iter().collect();
+        props.sort_by_key(|(k, _)| *k);
+        for (key, value) in props {
             lines.push(Line::from(vec![
-                Span::styled("📋 Table Properties", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
+                Span::styled(format!("  {}: ", key), Style::default().fg(palette().accent)),
+                Span::styled(value.clone(), Style::default().fg(palette().good)),
             ]));
-            lines.push(Line::from(""));
-            if config.table_properties.is_empty() {
-                lines.push(Line::from(vec![
-                    Span::styled("  ", Style::default().fg(Color::DarkGray)),
-                    Span::raw("No custom properties configured"),
-                ]));
-            } else {
-                let mut props: Vec<_> = config.table_properties.iter().collect();
-                props.sort_by_key(|(k, _)| *k);
-                for (key, value) in props {
-                    lines.push(Line::from(vec![
-                        Span::styled(format!("  {}: ", key), Style::default().fg(Color::Cyan)),
-                        Span::styled(value.clone(), Style::default().fg(Color::Green)),
-                    ]));
-                }
-            }
+        }
+    }
 
-            // Table Metadata
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![
-                Span::styled("🏷️  Table Metadata", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
-            ]));
-            lines.push(Line::from(""));
-            if let Some(id) = &config.table_id {
-                lines.push(Line::from(vec![
-                    Span::styled("  Table ID: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(id.clone(), Style::default().fg(Color::Green)),
-                ]));
-            }
-            if let Some(name) = &config.table_name {
-                lines.push(Line::from(vec![
-                    Span::styled("  Table Name: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(name.clone(), Style::default().fg(Color::Green)),
-                ]));
-            }
-            if let Some(desc) = &config.description {
-                lines.push(Line::from(vec![
-                    Span::styled("  Description: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(desc.clone(), Style::default().fg(Color::Green)),
-                ]));
-            }
-            if !config.partition_columns.is_empty() {
-                lines.push(Line::from(vec![
-                    Span::styled("  Partition Columns: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(config.partition_columns.join(", "), Style::default().fg(Color::Green)),
-                ]));
-            }
+    // Table Metadata
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("configuration-metadata"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    if let Some(id) = &config.table_id {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("configuration-table-id")), Style::default().fg(palette().accent)),
+            Span::styled(id.clone(), Style::default().fg(palette().good)),
+        ]));
+    }
+    if let Some(name) = &config.table_name {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("configuration-table-name")), Style::default().fg(palette().accent)),
+            Span::styled(name.clone(), Style::default().fg(palette().good)),
+        ]));
+    }
+    if let Some(desc) = &config.description {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("configuration-description")), Style::default().fg(palette().accent)),
+            Span::styled(desc.clone(), Style::default().fg(palette().good)),
+        ]));
+    }
+    if !config.partition_columns.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("configuration-partition-columns")), Style::default().fg(palette().accent)),
+            Span::styled(config.partition_columns.join(", "), Style::default().fg(palette().good)),
+        ]));
+    }
 
-            // Protocol Information
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![
-                Span::styled("⚙️  Protocol Versions", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
-            ]));
-            lines.push(Line::from(""));
+    // Protocol Information
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("configuration-protocol"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {} ", tr!("configuration-min-reader")), Style::default().fg(palette().accent)),
+        Span::styled(format!("{}", config.protocol.min_reader_version), Style::default().fg(palette().good)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {} ", tr!("configuration-min-writer")), Style::default().fg(palette().accent)),
+        Span::styled(format!("{}", config.protocol.min_writer_version), Style::default().fg(palette().good)),
+    ]));
+
+    if !config.protocol.reader_features.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("configuration-reader-features")), Style::default().fg(palette().accent)),
+        ]));
+        for feature in &config.protocol.reader_features {
             lines.push(Line::from(vec![
-                Span::styled("  Min Reader Version: ", Style::default().fg(Color::Cyan)),
-                Span::styled(format!("{}", config.protocol.min_reader_version), Style::default().fg(Color::Green)),
+                Span::raw("    • "),
+                Span::raw(feature.clone()),
             ]));
+        }
+    }
+
+    if !config.protocol.writer_features.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("configuration-writer-features")), Style::default().fg(palette().accent)),
+        ]));
+        for feature in &config.protocol.writer_features {
             lines.push(Line::from(vec![
-                Span::styled("  Min Writer Version: ", Style::default().fg(Color::Cyan)),
-                Span::styled(format!("{}", config.protocol.min_writer_version), Style::default().fg(Color::Green)),
+                Span::raw("    • "),
+                Span::raw(feature.clone()),
             ]));
+        }
+    }
 
-            if !config.protocol.reader_features.is_empty() {
-                lines.push(Line::from(""));
-                lines.push(Line::from(vec![
-                    Span::styled("  Reader Features: ", Style::default().fg(Color::Cyan)),
-                ]));
-                for feature in &config.protocol.reader_features {
-                    lines.push(Line::from(vec![
-                        Span::raw("    • "),
-                        Span::raw(feature.clone()),
-                    ]));
-                }
-            }
+    // Advanced Features
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("configuration-advanced"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
 
-            if !config.protocol.writer_features.is_empty() {
-                lines.push(Line::from(""));
-                lines.push(Line::from(vec![
-                    Span::styled("  Writer Features: ", Style::default().fg(Color::Cyan)),
-                ]));
-                for feature in &config.protocol.writer_features {
-                    lines.push(Line::from(vec![
-                        Span::raw("    • "),
-                        Span::raw(feature.clone()),
-                    ]));
-                }
-            }
+    let features = &config.advanced_features;
+    if features.deletion_vectors {
+        lines.push(Line::from(vec![
+            Span::styled("  ✓", Style::default().fg(palette().good)),
+            Span::styled(format!(" {} ", tr!("configuration-deletion-vectors")), Style::default().fg(palette().accent)),
+            Span::styled(tr!("configuration-enabled"), Style::default().fg(palette().good)),
+        ]));
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  ✗ {} {}", tr!("configuration-deletion-vectors"), tr!("configuration-disabled")), Style::default().fg(palette().muted)),
+        ]));
+    }
 
-            // Advanced Features
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![
-                Span::styled("🚀 Advanced Features", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
-            ]));
-            lines.push(Line::from(""));
-
-            let features = &config.advanced_features;
-            if features.deletion_vectors {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✓", Style::default().fg(Color::Green)),
-                    Span::styled(" Deletion Vectors: ", Style::default().fg(Color::Cyan)),
-                    Span::styled("Enabled", Style::default().fg(Color::Green)),
-                ]));
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✗ Deletion Vectors: Disabled", Style::default().fg(Color::DarkGray)),
-                ]));
-            }
+    lines.extend(column_mapping_lines(&features.column_mapping));
 
-            if features.column_mapping.enabled {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✓", Style::default().fg(Color::Green)),
-                    Span::styled(" Column Mapping: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(features.column_mapping.mode.clone(), Style::default().fg(Color::Green)),
-                ]));
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✗ Column Mapping: Disabled", Style::default().fg(Color::DarkGray)),
-                ]));
-            }
+    lines.extend(clustering_lines(&features.clustering));
 
-            if features.liquid_clustering {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✓", Style::default().fg(Color::Green)),
-                    Span::styled(" Liquid Clustering: ", Style::default().fg(Color::Cyan)),
-                    Span::styled("Enabled", Style::default().fg(Color::Green)),
-                ]));
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✗ Liquid Clustering: Disabled", Style::default().fg(Color::DarkGray)),
-                ]));
-            }
+    lines.extend(row_tracking_lines(&features.row_tracking));
 
-            if features.auto_optimize.enabled {
-                let mut opts = Vec::new();
-                if features.auto_optimize.auto_compact {
-                    opts.push("auto compact");
-                }
-                if features.auto_optimize.optimize_write {
-                    opts.push("optimize write");
-                }
-                lines.push(Line::from(vec![
-                    Span::styled("  ✓", Style::default().fg(Color::Green)),
-                    Span::styled(" Auto Optimize: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(opts.join(", "), Style::default().fg(Color::Green)),
-                ]));
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✗ Auto Optimize: Disabled", Style::default().fg(Color::DarkGray)),
-                ]));
-            }
+    if features.auto_optimize.enabled {
+        let mut opts = Vec::new();
+        if features.auto_optimize.auto_compact {
+            opts.push(tr!("configuration-auto-compact"));
+        }
+        if features.auto_optimize.optimize_write {
+            opts.push(tr!("configuration-optimize-write"));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("  ✓", Style::default().fg(palette().good)),
+            Span::styled(format!(" {} ", tr!("configuration-auto-optimize")), Style::default().fg(palette().accent)),
+            Span::styled(opts.join(", "), Style::default().fg(palette().good)),
+        ]));
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  ✗ {} {}", tr!("configuration-auto-optimize"), tr!("configuration-disabled")), Style::default().fg(palette().muted)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {} ", tr!("configuration-vacuum-retention")), Style::default().fg(palette().accent)),
+        Span::styled(tr!("configuration-hours", hours = features.vacuum_retention_hours), Style::default().fg(palette().good)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {} ", tr!("configuration-log-retention")), Style::default().fg(palette().accent)),
+        Span::styled(tr!("configuration-hours", hours = features.log_retention_hours), Style::default().fg(palette().good)),
+        Span::styled(
+            format!(
+                " {}",
+                tr!("configuration-time-travel", hours = features.log_retention_hours.min(features.vacuum_retention_hours))
+            ),
+            Style::default().fg(palette().muted),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {} ", tr!("configuration-checkpoint-interval")), Style::default().fg(palette().accent)),
+        Span::styled(tr!("configuration-every-commits", commits = features.checkpoint_interval), Style::default().fg(palette().good)),
+    ]));
 
-            lines.push(Line::from(""));
+    // Constraints
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("configuration-constraints"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    let constraints = CheckConstraint::from_config(&features.check_constraints);
+    if constraints.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}", tr!("configuration-no-constraints")), Style::default().fg(palette().muted)),
+        ]));
+    } else {
+        for constraint in &constraints {
             lines.push(Line::from(vec![
-                Span::styled("  Vacuum Retention: ", Style::default().fg(Color::Cyan)),
-                Span::styled(format!("{} hours", features.vacuum_retention_hours), Style::default().fg(Color::Green)),
+                Span::styled(format!("  {}: ", constraint.name), Style::default().fg(palette().accent)),
+                Span::raw(format!("CHECK ({})", constraint.expression)),
             ]));
         }
-        Err(_) => {
-            lines.push(Line::from(vec![
-                Span::styled("Loading configuration...", Style::default().fg(Color::DarkGray)),
-            ]));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}", tr!("configuration-constraints-hint")), Style::default().fg(palette().muted)),
+        ]));
+    }
+
+    // Domain Metadata
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("configuration-domains"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    if config.domain_metadata.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}", tr!("configuration-no-domains")), Style::default().fg(palette().muted)),
+        ]));
+    }
+    for domain in &config.domain_metadata {
+        let origin = domain.version.map_or_else(|| tr!("configuration-from-checkpoint"), |version| tr!("configuration-version", version = version));
+        let mut spans = vec![
+            Span::styled(format!("  {}", domain.domain), Style::default().fg(palette().accent)),
+            Span::styled(format!(" ({})", origin), Style::default().fg(palette().muted)),
+        ];
+        if domain.removed {
+            spans.push(Span::styled(format!(" {}", tr!("configuration-removed")), Style::default().fg(palette().warning)));
+        }
+        lines.push(Line::from(spans));
+        if !domain.configuration.is_empty() {
+            lines.push(Line::from(vec![Span::raw(format!("      {}", domain.configuration))]));
         }
     }
 
+    // Checkpoint contents, read on request since checkpoints can be large
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("configuration-checkpoint"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    match &config.checkpoint_info.last_checkpoint {
+        Some(last) => {
+            let mut details = Vec::new();
+            if let Some(parts) = last.parts {
+                details.push(tr!("configuration-parts", parts = parts));
+            }
+            if let Some(size) = last.size {
+                details.push(tr!("configuration-actions", actions = size));
+            }
+            if let Some(size_in_bytes) = last.size_in_bytes {
+                details.push(format_bytes(size_in_bytes));
+            }
+            let mut spans = vec![
+                Span::styled("  _last_checkpoint: ", Style::default().fg(palette().accent)),
+                Span::styled(tr!("configuration-version", version = last.version), Style::default().fg(palette().good)),
+            ];
+            if !details.is_empty() {
+                spans.push(Span::styled(format!(" ({})", details.join(", ")), Style::default().fg(palette().muted)));
+            }
+            lines.push(Line::from(spans));
+        }
+        None => lines.push(Line::from(vec![
+            Span::styled("  _last_checkpoint: ", Style::default().fg(palette().accent)),
+            Span::styled(tr!("configuration-none"), Style::default().fg(palette().muted)),
+        ])),
+    }
+    if let Some(lag) = &config.checkpoint_info.lag {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("configuration-commits-since-checkpoint")), Style::default().fg(palette().accent)),
+            Span::styled(lag.commits_since_checkpoint.to_string(), Style::default().fg(palette().good)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    match checkpoint {
+        None => lines.push(Line::from(vec![
+            Span::styled(format!("  {}", tr!("configuration-checkpoint-hint")), Style::default().fg(palette().muted)),
+        ])),
+        Some(Loadable::Loading) => lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", spinner), Style::default().fg(palette().accent)),
+            Span::styled(tr!("configuration-reading-checkpoint"), Style::default().fg(palette().muted)),
+        ])),
+        Some(Loadable::Failed(e)) => lines.push(Line::from(vec![
+            Span::styled(format!("  {}", tr!("configuration-checkpoint-failed", error = e.to_string())), Style::default().fg(palette().critical)),
+        ])),
+        Some(Loadable::Ready(contents)) => lines.extend(TuiRenderer.render(&contents.document())),
+    }
+
     let paragraph = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL).title("Configuration [↑↓ scroll]"))
+        .block(Block::default().borders(Borders::ALL).title(tr!("configuration-block-title")))
         .scroll((scroll, 0));
 
     f.render_widget(paragraph, area);
 }
 
+/// Column mapping mode, each column's physical name and field ID, and the
+/// files still written under logical names.
+fn column_mapping_lines(mapping: &ColumnMappingInfo) -> Vec<Line<'static>> {
+    if !mapping.enabled {
+        return vec![Line::from(vec![
+            Span::styled(format!("  ✗ {} {}", tr!("configuration-column-mapping"), tr!("configuration-disabled")), Style::default().fg(palette().muted)),
+        ])];
+    }
+    let mut lines = vec![Line::from(vec![
+        Span::styled("  ✓", Style::default().fg(palette().good)),
+        Span::styled(format!(" {} ", tr!("configuration-column-mapping")), Style::default().fg(palette().accent)),
+        Span::styled(mapping.mode.clone(), Style::default().fg(palette().good)),
+    ])];
+    if let Some(enabled) = &mapping.enabled_at {
+        let when = enabled.timestamp.map(|t| format!(" {}", tr!("configuration-on", time = t.format("%Y-%m-%d %H:%M").to_string()))).unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled(format!("      {} ", tr!("configuration-enabled-at")), Style::default().fg(palette().muted)),
+            Span::raw(format!("{}{}", tr!("configuration-version", version = enabled.version), when)),
+        ]));
+    }
+    if let Some(max_id) = mapping.max_column_id {
+        lines.push(Line::from(vec![
+            Span::styled("      maxColumnId: ", Style::default().fg(palette().muted)),
+            Span::raw(max_id.to_string()),
+        ]));
+    }
+    if let Some(files) = mapping.files_before_mapping.filter(|files| *files > 0) {
+        lines.push(Line::from(Span::styled(
+            format!("      {}", tr!("configuration-files-before-mapping", files = files)),
+            Style::default().fg(palette().warning),
+        )));
+    }
+
+    let width = mapping.columns.iter().map(|c| c.logical_name.len()).max().unwrap_or(0);
+    for column in &mapping.columns {
+        let id = column.field_id.map_or("-".to_string(), |id| id.to_string());
+        let mut spans = vec![
+            Span::styled(format!("      {:>4} ", id), Style::default().fg(palette().muted)),
+            Span::styled(format!("{:<width$}", column.logical_name, width = width), Style::default().fg(palette().accent)),
+            Span::raw(" → "),
+            Span::raw(column.physical_name.clone().unwrap_or_else(|| tr!("configuration-no-physical-name"))),
+        ];
+        if column.predates_mapping() {
+            spans.push(Span::styled(format!(" {}", tr!("configuration-pre-mapping-name")), Style::default().fg(palette().muted)));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Liquid clustering state, the columns OPTIMIZE clusters by and the commit
+/// that last changed them.
+fn clustering_lines(clustering: &ClusteringInfo) -> Vec<Line<'static>> {
+    if !clustering.supported {
+        return vec![Line::from(vec![
+            Span::styled(format!("  ✗ {} {}", tr!("configuration-clustering"), tr!("configuration-disabled")), Style::default().fg(palette().muted)),
+        ])];
+    }
+    let mut lines = vec![Line::from(vec![
+        Span::styled("  ✓", Style::default().fg(palette().good)),
+        Span::styled(format!(" {} ", tr!("configuration-clustering")), Style::default().fg(palette().accent)),
+        if clustering.enabled() {
+            Span::styled(tr!("configuration-enabled"), Style::default().fg(palette().good))
+        } else {
+            Span::styled(tr!("configuration-clustering-none"), Style::default().fg(palette().warning))
+        },
+    ])];
+    if clustering.enabled() {
+        let source = if clustering.columns_from_history {
+            format!(" {}", tr!("configuration-from-history"))
+        } else {
+            String::new()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("      {} ", tr!("configuration-columns")), Style::default().fg(palette().muted)),
+            Span::raw(clustering.columns.join(", ")),
+            Span::styled(source, Style::default().fg(palette().muted)),
+        ]));
+    }
+    if let Some(commit) = &clustering.last_cluster_by {
+        let when = commit.timestamp.map(|t| format!(" {}", tr!("configuration-on", time = t.format("%Y-%m-%d %H:%M").to_string()))).unwrap_or_default();
+        let columns = if commit.columns.is_empty() { "NONE".to_string() } else { commit.columns.join(", ") };
+        lines.push(Line::from(vec![
+            Span::styled(format!("      {} ", tr!("configuration-last-cluster-by")), Style::default().fg(palette().muted)),
+            Span::raw(format!(
+                "{}{} ({})",
+                tr!("configuration-operation-at", operation = commit.operation.clone(), version = commit.version),
+                when,
+                columns
+            )),
+        ]));
+    }
+    lines
+}
+
+/// Row tracking state, the row IDs and commit versions files hand down, and
+/// what materializing them into data files costs.
+fn row_tracking_lines(row_tracking: &RowTrackingInfo) -> Vec<Line<'static>> {
+    if !row_tracking.supported {
+        return vec![Line::from(vec![
+            Span::styled(format!("  ✗ {} {}", tr!("configuration-row-tracking"), tr!("configuration-not-supported")), Style::default().fg(palette().muted)),
+        ])];
+    }
+    let (state, color) = match (row_tracking.enabled, row_tracking.suspended) {
+        (true, false) => (tr!("configuration-enabled"), palette().good),
+        (true, true) => (tr!("configuration-suspended"), palette().warning),
+        (false, _) => (tr!("configuration-row-tracking-off"), palette().warning),
+    };
+    let mut lines = vec![Line::from(vec![
+        Span::styled("  ✓", Style::default().fg(palette().good)),
+        Span::styled(format!(" {} ", tr!("configuration-row-tracking")), Style::default().fg(palette().accent)),
+        Span::styled(state, Style::default().fg(color)),
+    ])];
+
+    let detail = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("      {}: ", tr!(label)), Style::default().fg(palette().muted)),
+            Span::raw(value),
+        ])
+    };
+    if let Some(files) = &row_tracking.files {
+        let range = |min: Option<i64>, max: Option<i64>| match (min, max) {
+            (Some(min), Some(max)) => format!("{}..={}", min, max),
+            _ => tr!("configuration-none"),
+        };
+        lines.push(detail(
+            "configuration-files-with-row-ids",
+            tr!("configuration-of", count = files.files_with_row_ids, total = files.files),
+        ));
+        lines.push(detail("configuration-row-ids", range(files.min_base_row_id, files.max_row_id)));
+        lines.push(detail("configuration-row-commit-versions", range(files.min_row_commit_version, files.max_row_commit_version)));
+        if files.files_with_row_ids < files.files {
+            lines.push(Line::from(Span::styled(
+                format!("      {}", tr!("configuration-files-without-row-ids")),
+                Style::default().fg(palette().warning),
+            )));
+        }
+    }
+    for (label, column) in [
+        ("configuration-row-id-column", &row_tracking.materialized_row_id_column),
+        ("configuration-row-commit-version-column", &row_tracking.materialized_row_commit_version_column),
+    ] {
+        if let Some(column) = column {
+            lines.push(detail(label, column.clone()));
+        }
+    }
+    if let Some(overhead) = row_tracking.materialized_overhead_bytes() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "      {}",
+                tr!(
+                    "configuration-materialized-overhead",
+                    bytes = MATERIALIZED_COLUMN_BYTES,
+                    total = format_bytes(overhead),
+                    rows = row_tracking.files.as_ref().map_or(0, |f| f.rows)
+                )
+            ),
+            Style::default().fg(palette().muted),
+        )));
+    }
+    lines
+}
@@ -0,0 +1,80 @@
+use crate::tui_app::theme::palette;
+use deltective::preview::DataPreview;
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+const MAX_COLUMN_WIDTH: usize = 30;
+
+pub fn render(f: &mut Frame, area: Rect, preview: &DataPreview, scroll: u16) {
+    let mut lines = Vec::new();
+
+    lines.push(Line::from(vec![
+        Span::styled(tr!("data-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+
+    lines.push(Line::from(vec![
+        Span::styled(format!("{} ", tr!("data-source")), Style::default().fg(palette().accent)),
+        Span::raw(preview.source.describe()),
+        Span::styled(format!(" {}", tr!("data-rows", count = preview.rows.len())), Style::default().fg(palette().muted)),
+    ]));
+    lines.push(Line::from(""));
+
+    if preview.rows.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(tr!("data-empty"), Style::default().fg(palette().muted)),
+        ]));
+    } else {
+        let widths: Vec<usize> = preview
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                preview
+                    .rows
+                    .iter()
+                    .map(|row| row[idx].chars().count())
+                    .chain(std::iter::once(name.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+                    .min(MAX_COLUMN_WIDTH)
+            })
+            .collect();
+
+        let header: Vec<String> = preview
+            .columns
+            .iter()
+            .zip(&widths)
+            .map(|(name, &width)| pad(name, width))
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled(header.join(" │ "), Style::default().fg(palette().section).add_modifier(Modifier::BOLD)),
+        ]));
+
+        for row in &preview.rows {
+            let cells: Vec<String> = row.iter().zip(&widths).map(|(cell, &width)| pad(cell, width)).collect();
+            lines.push(Line::from(cells.join(" │ ")));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("data-block-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+fn pad(text: &str, width: usize) -> String {
+    let count = text.chars().count();
+    if count > width {
+        format!("{}…", text.chars().take(width.saturating_sub(1)).collect::<String>())
+    } else {
+        format!("{}{}", text, " ".repeat(width - count))
+    }
+}
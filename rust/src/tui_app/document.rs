@@ -0,0 +1,102 @@
+use crate::tui_app::theme::palette;
+use deltective::render::{Block, Document, Renderer, Tone};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Lays out a [`Document`] as styled lines in the same look as the hand-built tabs.
+pub struct TuiRenderer;
+
+impl Renderer for TuiRenderer {
+    type Output = Vec<Line<'static>>;
+
+    fn render(&self, document: &Document) -> Vec<Line<'static>> {
+        let heading = |text: &str| {
+            Line::from(vec![Span::styled(
+                format!("═══ {} ═══", text.to_uppercase()),
+                Style::default().fg(palette().accent).add_modifier(Modifier::BOLD),
+            )])
+        };
+
+        let mut lines = vec![heading(&document.title)];
+        for block in &document.blocks {
+            lines.push(Line::from(""));
+            match block {
+                Block::Heading(text) => lines.push(heading(text)),
+                Block::Text(text) => lines.push(Line::from(format!("  {}", text))),
+                Block::Fields(fields) => {
+                    let width = fields.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0) + 1;
+                    for (label, value) in fields {
+                        lines.push(Line::from(vec![
+                            Span::styled(
+                                format!("  {:width$} ", format!("{}:", label), width = width),
+                                Style::default().fg(palette().accent),
+                            ),
+                            Span::styled(value.clone(), Style::default().fg(palette().good)),
+                        ]));
+                    }
+                }
+                Block::Table { headers, rows } => {
+                    let widths: Vec<usize> = (0..headers.len())
+                        .map(|col| {
+                            rows.iter()
+                                .filter_map(|row| row.get(col))
+                                .chain(std::iter::once(&headers[col]))
+                                .map(|cell| cell.chars().count())
+                                .max()
+                                .unwrap_or(0)
+                        })
+                        .collect();
+                    let row_text = |cells: &[String]| {
+                        let padded: Vec<String> = cells
+                            .iter()
+                            .zip(&widths)
+                            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                            .collect();
+                        format!("  {}", padded.join("  "))
+                    };
+                    lines.push(Line::from(vec![Span::styled(
+                        row_text(headers),
+                        Style::default().fg(palette().section).add_modifier(Modifier::BOLD),
+                    )]));
+                    lines.extend(rows.iter().map(|row| Line::from(row_text(row))));
+                }
+                Block::List(items) => {
+                    for item in items {
+                        let color = tone_color(item.tone);
+                        let mut spans = vec![Span::raw("  ")];
+                        // Severity needs a marker as well as a color
+                        let marker = match (item.marker.as_str(), item.tone) {
+                            ("", Tone::Critical) => "✗",
+                            ("", Tone::Warning) => "!",
+                            (marker, _) => marker,
+                        };
+                        if !marker.is_empty() {
+                            spans.push(Span::styled(format!("{} ", marker), Style::default().fg(color)));
+                        }
+                        spans.push(Span::styled(item.text.clone(), Style::default().fg(color)));
+                        lines.push(Line::from(spans));
+                        if let Some(detail) = &item.detail {
+                            lines.push(Line::from(vec![Span::styled(
+                                format!("      {}", detail),
+                                Style::default().fg(palette().muted),
+                            )]));
+                        }
+                    }
+                }
+            }
+        }
+        lines
+    }
+}
+
+fn tone_color(tone: Tone) -> Color {
+    match tone {
+        Tone::Plain => palette().text,
+        Tone::Good => palette().good,
+        Tone::Info => palette().info,
+        Tone::Warning => palette().warning,
+        Tone::Critical => palette().critical,
+    }
+}
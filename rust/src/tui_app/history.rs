@@ -1,8 +1,15 @@
+use crate::tui_app::commit_detail::CommitDetail;
+use crate::tui_app::theme::palette;
 use chrono::DateTime;
+use crossterm::event::KeyCode;
 use deltalake::kernel::CommitInfo;
+use deltective::history::HistoryFilter;
+use deltective::inspector::commit_version;
+use deltective::run_links::{self, RunLinkTemplate};
+use deltective::tr;
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -10,50 +17,314 @@ use ratatui::{
 
 const PAGE_SIZE: usize = 10;
 
-pub fn render(
-    f: &mut Frame,
-    area: Rect,
-    history: &[CommitInfo],
-    scroll: u16,
-    current_page: usize,
-    total_pages: usize,
-    reversed: bool,
-) {
+/// Which prompt is capturing keystrokes.
+#[derive(Clone, Copy, PartialEq)]
+enum Prompt {
+    Message,
+    Dates,
+}
+
+/// Pagination, ordering, selection, filters and commit-message search for
+/// the History tab.
+#[derive(Default)]
+pub struct HistoryState {
+    pub page: usize,
+    pub reversed: bool,
+    /// Index of the highlighted commit among the filtered entries
+    selected: usize,
+    /// Commit opened with Enter, shown instead of the list
+    pub detail: Option<CommitDetail>,
+    /// Case-insensitive search over commit messages (userMetadata)
+    search: String,
+    /// Operation and date filters
+    filter: HistoryFilter,
+    /// Date range being typed after `d`
+    date_input: String,
+    /// Why the last date range typed was rejected
+    date_error: Option<String>,
+    prompt: Option<Prompt>,
+    /// Orchestrator run URL patterns from the config file
+    run_links: Vec<RunLinkTemplate>,
+    /// Why the last reload failed; the history shown is from before it
+    pub refresh_error: Option<String>,
+}
+
+impl HistoryState {
+    pub fn new(run_links: Vec<RunLinkTemplate>) -> Self {
+        Self {
+            run_links,
+            ..Self::default()
+        }
+    }
+
+    /// Back to the newest commits on the first page, as after a reload. The
+    /// search, filters and any open commit are kept.
+    pub fn reset(&mut self) {
+        self.page = 0;
+        self.reversed = false;
+        self.selected = 0;
+    }
+
+    /// Whether a prompt is capturing keystrokes.
+    pub fn is_editing(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    /// Entries passing the filters whose commit message matches the current
+    /// search.
+    pub fn filtered<'a>(&self, history: &'a [CommitInfo]) -> Vec<&'a CommitInfo> {
+        let needle = self.search.to_lowercase();
+        history
+            .iter()
+            .filter(|entry| {
+                needle.is_empty()
+                    || entry
+                        .user_metadata
+                        .as_deref()
+                        .is_some_and(|m| m.to_lowercase().contains(&needle))
+            })
+            .filter(|entry| self.filter.matches(entry))
+            .collect()
+    }
+
+    /// Step the operation filter through the operations in `history`, then
+    /// back to all operations.
+    fn cycle_operation(&mut self, history: &[CommitInfo]) {
+        let mut operations: Vec<&str> = history.iter().filter_map(|entry| entry.operation.as_deref()).collect();
+        operations.sort_unstable();
+        operations.dedup();
+        let next = match self.filter.operations.first() {
+            Some(current) => operations.iter().skip_while(|op| *op != current).nth(1),
+            None => operations.first(),
+        };
+        self.filter.operations = next.map(|op| vec![op.to_string()]).unwrap_or_default();
+    }
+
+    pub fn total_pages(&self, history: &[CommitInfo]) -> usize {
+        page_count(self.filtered(history).len())
+    }
+
+    /// Handle a key on the History tab. Returns true if the key was consumed.
+    pub fn handle_key(&mut self, key: KeyCode, history: &mut [CommitInfo]) -> bool {
+        if let Some(prompt) = self.prompt {
+            let input = match prompt {
+                Prompt::Message => &mut self.search,
+                Prompt::Dates => &mut self.date_input,
+            };
+            match key {
+                KeyCode::Enter => {
+                    self.prompt = None;
+                    if prompt == Prompt::Dates {
+                        // An empty range clears the dates
+                        self.date_error = self.filter.set_date_range(&self.date_input).err().map(|e| e.to_string());
+                    }
+                }
+                KeyCode::Esc => {
+                    self.prompt = None;
+                    if prompt == Prompt::Message {
+                        self.search.clear();
+                    }
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
+            }
+            self.page = 0;
+            self.selected = 0;
+            return true;
+        }
+
+        if self.detail.is_some() {
+            // Arrow keys scroll the detail
+            return match key {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Backspace => {
+                    self.detail = None;
+                    true
+                }
+                _ => false,
+            };
+        }
+
+        let matching = self.filtered(history).len();
+        match key {
+            KeyCode::Char('n') => {
+                // Next page
+                if self.page + 1 < self.total_pages(history) {
+                    self.page += 1;
+                    self.selected = self.page * PAGE_SIZE;
+                }
+            }
+            KeyCode::Char('p') => {
+                // Previous page
+                self.page = self.page.saturating_sub(1);
+                self.selected = self.page * PAGE_SIZE;
+            }
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                self.page = self.selected / PAGE_SIZE;
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(matching.saturating_sub(1));
+                self.page = self.selected / PAGE_SIZE;
+            }
+            KeyCode::Enter => {
+                let entry = self.filtered(history).get(self.selected).copied();
+                if let Some(entry) = entry {
+                    self.detail = Some(CommitDetail::new(commit_version(entry).unwrap_or(0), entry));
+                }
+            }
+            KeyCode::Char('r') => {
+                // Reverse sort
+                self.reversed = !self.reversed;
+                history.reverse();
+                self.page = 0;
+                self.selected = 0;
+            }
+            KeyCode::Char('m') => {
+                // Search commit messages
+                self.prompt = Some(Prompt::Message);
+            }
+            KeyCode::Char('f') => {
+                self.cycle_operation(history);
+                self.page = 0;
+                self.selected = 0;
+            }
+            KeyCode::Char('d') => {
+                self.prompt = Some(Prompt::Dates);
+                self.date_error = None;
+            }
+            KeyCode::Esc if !self.search.is_empty() || !self.filter.is_empty() => {
+                self.search.clear();
+                self.filter = HistoryFilter::default();
+                self.date_input.clear();
+                self.page = 0;
+                self.selected = 0;
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+fn page_count(entries: usize) -> usize {
+    entries.div_ceil(PAGE_SIZE)
+}
+
+pub fn render(f: &mut Frame, area: Rect, history: &[CommitInfo], state: &HistoryState, scroll: u16) {
+    let total_entries = history.len();
+    let history = state.filtered(history);
+    let current_page = state.page;
+    let total_pages = page_count(history.len());
+    let reversed = state.reversed;
     let mut lines = Vec::new();
 
     // Header with sort order indicator
-    let sort_indicator = if reversed { "oldest first" } else { "newest first" };
+    let sort_indicator = if reversed { tr!("history-oldest-first") } else { tr!("history-newest-first") };
     lines.push(Line::from(vec![
-        Span::styled("═══ OPERATION HISTORY ═══", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Span::styled(format!(" ({})", sort_indicator), Style::default().fg(Color::DarkGray)),
+        Span::styled(tr!("history-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+        Span::styled(format!(" {}", sort_indicator), Style::default().fg(palette().muted)),
     ]));
     lines.push(Line::from(""));
 
+    if let Some(error) = &state.refresh_error {
+        lines.push(Line::from(vec![
+            Span::styled(tr!("history-refresh-failed", error = error.as_str()), Style::default().fg(palette().critical)),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    let searching = state.prompt == Some(Prompt::Message);
+    if searching || !state.search.is_empty() {
+        let cursor = if searching { "▏" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", tr!("history-message-search")), Style::default().fg(palette().accent)),
+            Span::styled(format!("{}{}", state.search, cursor), Style::default().fg(palette().warning)),
+            Span::styled(
+                format!(" {}", tr!("history-matches", matching = history.len(), total = total_entries)),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    let mut filter_lines = Vec::new();
+    if state.prompt == Some(Prompt::Dates) {
+        filter_lines.push(Line::from(vec![
+            Span::styled(format!("{} ", tr!("history-dates-prompt")), Style::default().fg(palette().accent)),
+            Span::styled(format!("{}▏", state.date_input), Style::default().fg(palette().warning)),
+        ]));
+    } else if let Some(error) = &state.date_error {
+        filter_lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(palette().critical))));
+    }
+    if !state.filter.is_empty() {
+        filter_lines.push(Line::from(vec![
+            Span::styled(format!("{} ", tr!("history-filter")), Style::default().fg(palette().accent)),
+            Span::styled(state.filter.describe(), Style::default().fg(palette().warning)),
+            Span::styled(
+                format!(" {}", tr!("history-filter-matches", matching = history.len(), total = total_entries)),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+    }
+    if !filter_lines.is_empty() {
+        lines.extend(filter_lines);
+        lines.push(Line::from(""));
+    }
+
     // Calculate page bounds
     let start_idx = current_page * PAGE_SIZE;
     let end_idx = std::cmp::min(start_idx + PAGE_SIZE, history.len());
 
     if history.is_empty() {
         lines.push(Line::from(vec![
-            Span::styled("No history entries found.", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                if total_entries == 0 { tr!("history-empty") } else { tr!("history-no-matches") },
+                Style::default().fg(palette().muted),
+            ),
         ]));
     } else {
         // Show entries for current page
-        for entry in history.iter().skip(start_idx).take(PAGE_SIZE) {
-            let version = entry.read_version.unwrap_or(0);
-            let operation = entry.operation.as_deref().unwrap_or("Unknown");
+        for (idx, entry) in history.iter().enumerate().skip(start_idx).take(PAGE_SIZE) {
+            let version = commit_version(entry).unwrap_or(0);
+            let operation = entry.operation.clone().unwrap_or_else(|| tr!("history-unknown-operation"));
             let timestamp = DateTime::from_timestamp(entry.timestamp.unwrap_or(0) / 1000, 0)
                 .unwrap_or_default()
                 .format("%Y-%m-%d %H:%M:%S")
                 .to_string();
 
-            lines.push(Line::from(vec![
-                Span::styled(format!("Version {}", version), Style::default().fg(Color::Yellow)),
+            let selected = idx == state.selected;
+            let mut heading = Line::from(vec![
+                Span::styled(if selected { "▶ " } else { "  " }, Style::default().fg(palette().accent)),
+                Span::styled(tr!("history-version", version = version), Style::default().fg(palette().warning)),
                 Span::raw(" - "),
-                Span::styled(operation.to_string(), Style::default().fg(Color::Cyan)),
+                Span::styled(operation, Style::default().fg(palette().accent)),
                 Span::raw(" - "),
-                Span::styled(timestamp, Style::default().fg(Color::Green)),
-            ]));
+                Span::styled(timestamp, Style::default().fg(palette().good)),
+            ]);
+            if selected {
+                heading = heading.style(Style::default().bg(palette().selection));
+            }
+            lines.push(heading);
+
+            // Custom commit message set by the writer, e.g. a job run ID
+            if let Some(message) = entry.user_metadata.as_deref().filter(|m| !m.is_empty()) {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {} ", tr!("history-message")), Style::default().fg(palette().section)),
+                    Span::raw(message.to_string()),
+                ]));
+            }
+
+            // Links to the orchestrator run that wrote this commit
+            if let Ok(commit_info) = serde_json::to_value(entry) {
+                for link in run_links::resolve(&state.run_links, &commit_info) {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("  ↗ {}: ", link.name), Style::default().fg(palette().link)),
+                        Span::styled(link.url, Style::default().fg(palette().link).add_modifier(Modifier::UNDERLINED)),
+                    ]));
+                }
+            }
 
             // Add operation parameters
             if let Some(params) = &entry.operation_parameters {
@@ -63,15 +334,15 @@ pub fn render(
                         .filter_map(|(k, v): (&String, &serde_json::Value)| {
                             match k.as_str() {
                                 "mode" => Some(format!("mode={}", v)),
-                                "partitionBy" => Some("partitioned".to_string()),
-                                "predicate" => Some(format!("where: {}", v)),
+                                "partitionBy" => Some(tr!("history-partitioned")),
+                                "predicate" => Some(tr!("history-predicate", predicate = v.to_string())),
                                 _ => None,
                             }
                         })
                         .collect();
                     if !param_strs.is_empty() {
                         lines.push(Line::from(vec![
-                            Span::styled("  ", Style::default().fg(Color::DarkGray)),
+                            Span::styled("  ", Style::default().fg(palette().muted)),
                             Span::raw(param_strs.join(", ")),
                         ]));
                     }
@@ -84,22 +355,18 @@ pub fn render(
         // Pagination info
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
-            Span::styled("───────────────────────────────────────", Style::default().fg(Color::DarkGray)),
+            Span::styled("───────────────────────────────────────", Style::default().fg(palette().muted)),
         ]));
         lines.push(Line::from(vec![
             Span::styled(
-                format!("Showing {}-{} of {} entries", start_idx + 1, end_idx, history.len()),
-                Style::default().fg(Color::DarkGray),
+                tr!("history-showing", first = start_idx + 1, last = end_idx, total = history.len()),
+                Style::default().fg(palette().muted),
             ),
         ]));
     }
 
     // Build title with navigation hints
-    let title = format!(
-        "History [Page {}/{} | n:next p:prev r:reverse | ↑↓:scroll]",
-        current_page + 1,
-        total_pages.max(1)
-    );
+    let title = tr!("history-block-title", page = current_page + 1, pages = total_pages.max(1));
 
     let paragraph = Paragraph::new(lines)
         .block(Block::default().borders(Borders::ALL).title(title))
@@ -1,24 +1,69 @@
-use crate::inspector::TableStatistics;
-use crate::insights::{DeltaTableAnalyzer, Insight};
+use crate::tui_app::theme::{palette, severity_marker};
+use deltective::config::Config;
+use deltective::diagnosis;
+use deltective::insights::{Confidence, Insight};
+use deltective::inspector::TableStatistics;
+use deltective::tr;
 use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
+    layout::{Constraint, Layout, Rect},
+    style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph},
     Frame,
 };
 
-pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
-    let analyzer = DeltaTableAnalyzer::new(stats.clone());
+const MB: i64 = 1024 * 1024;
+/// Upper bound (exclusive) and label of each bar in the file size chart
+const SIZE_BUCKETS: [(i64, &str); 5] = [
+    (MB, "<1MB"),
+    (10 * MB, "1–10MB"),
+    (64 * MB, "10–64MB"),
+    (128 * MB, "64–128MB"),
+    (i64::MAX, ">128MB"),
+];
+/// Rows taken by the chart above the insights, borders included
+const CHART_HEIGHT: u16 = 10;
+
+pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, config: &Config, scroll: u16) {
+    let analyzer = config.analyzer(stats.clone());
     let insights = analyzer.analyze();
 
+    // The chart only goes above the insights when both still have room
+    let area = if !stats.files.is_empty() && area.height >= CHART_HEIGHT * 2 {
+        let [chart, rest] = Layout::vertical([Constraint::Length(CHART_HEIGHT), Constraint::Min(0)]).areas(area);
+        render_file_sizes(f, chart, stats, config.thresholds.small_file_mb);
+        rest
+    } else {
+        area
+    };
+
     let mut lines = Vec::new();
 
     lines.push(Line::from(vec![
-        Span::styled("═══ TABLE HEALTH & RECOMMENDATIONS ═══", Style::default().fg(Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(tr!("insights-heading"), Style::default().fg(palette().accent).add_modifier(ratatui::style::Modifier::BOLD)),
     ]));
     lines.push(Line::from(""));
 
+    // Chains linking the findings below, root cause first
+    let diagnoses = diagnosis::diagnose(stats, &insights);
+    if !diagnoses.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(tr!("insights-diagnosis"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+        ]));
+        lines.push(Line::from(""));
+        for diagnosis in &diagnoses {
+            lines.push(Line::from(vec![
+                Span::styled(diagnosis.title.clone(), Style::default().fg(palette().warning).add_modifier(ratatui::style::Modifier::BOLD)),
+            ]));
+            lines.push(Line::from(format!("  {}", diagnosis.narrative)));
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", tr!("insights-recommendation")), Style::default().fg(palette().accent)),
+                Span::raw(diagnosis.recommendation.clone()),
+            ]));
+            lines.push(Line::from(""));
+        }
+    }
+
     // Group by severity
     let critical: Vec<&Insight> = insights.iter().filter(|i| i.severity == "critical").collect();
     let warnings: Vec<&Insight> = insights.iter().filter(|i| i.severity == "warning").collect();
@@ -28,7 +73,7 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     // Display critical issues first
     if !critical.is_empty() {
         lines.push(Line::from(vec![
-            Span::styled("🔴 CRITICAL ISSUES", Style::default().fg(Color::Red).add_modifier(ratatui::style::Modifier::BOLD)),
+            Span::styled(tr!("insights-critical"), Style::default().fg(palette().critical).add_modifier(ratatui::style::Modifier::BOLD)),
         ]));
         lines.push(Line::from(""));
         for insight in &critical {
@@ -40,7 +85,7 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     // Display warnings
     if !warnings.is_empty() {
         lines.push(Line::from(vec![
-            Span::styled("⚠️  WARNINGS", Style::default().fg(Color::Yellow).add_modifier(ratatui::style::Modifier::BOLD)),
+            Span::styled(tr!("insights-warnings"), Style::default().fg(palette().warning).add_modifier(ratatui::style::Modifier::BOLD)),
         ]));
         lines.push(Line::from(""));
         for insight in &warnings {
@@ -52,7 +97,7 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     // Display info/recommendations
     if !info.is_empty() {
         lines.push(Line::from(vec![
-            Span::styled("ℹ️  RECOMMENDATIONS", Style::default().fg(Color::Green).add_modifier(ratatui::style::Modifier::BOLD)),
+            Span::styled(tr!("insights-recommendations"), Style::default().fg(palette().info).add_modifier(ratatui::style::Modifier::BOLD)),
         ]));
         lines.push(Line::from(""));
         for insight in &info {
@@ -64,7 +109,7 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     // Display positive feedback
     if !good.is_empty() {
         lines.push(Line::from(vec![
-            Span::styled("✅ GOOD CONFIGURATION", Style::default().fg(Color::Green).add_modifier(ratatui::style::Modifier::BOLD)),
+            Span::styled(tr!("insights-good"), Style::default().fg(palette().good).add_modifier(ratatui::style::Modifier::BOLD)),
         ]));
         lines.push(Line::from(""));
         for insight in &good {
@@ -76,49 +121,91 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     // Summary
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("═══ SUMMARY ═══", Style::default().fg(Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(tr!("insights-summary"), Style::default().fg(palette().accent).add_modifier(ratatui::style::Modifier::BOLD)),
     ]));
     lines.push(Line::from(vec![
-        Span::styled("  Critical: ", Style::default().fg(Color::Red)),
+        Span::styled(format!("  {} ", tr!("insights-summary-critical")), Style::default().fg(palette().critical)),
         Span::raw(format!("{}", critical.len())),
     ]));
     lines.push(Line::from(vec![
-        Span::styled("  Warnings: ", Style::default().fg(Color::Yellow)),
+        Span::styled(format!("  {} ", tr!("insights-summary-warnings")), Style::default().fg(palette().warning)),
         Span::raw(format!("{}", warnings.len())),
     ]));
     lines.push(Line::from(vec![
-        Span::styled("  Info: ", Style::default().fg(Color::Green)),
+        Span::styled(format!("  {} ", tr!("insights-summary-info")), Style::default().fg(palette().info)),
         Span::raw(format!("{}", info.len())),
     ]));
 
     let paragraph = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL).title("Insights [↑↓ scroll]"))
+        .block(Block::default().borders(Borders::ALL).title(tr!("insights-block-title")))
         .scroll((scroll, 0));
 
     f.render_widget(paragraph, area);
 }
 
-fn format_insight(insight: &Insight) -> Vec<Line> {
+/// Bar chart of the table's file count per size bucket. Buckets below the
+/// small-file threshold are drawn in the warning color.
+fn render_file_sizes(f: &mut Frame, area: Rect, stats: &TableStatistics, small_file_mb: f64) {
+    let mut counts = [0u64; SIZE_BUCKETS.len()];
+    for file in &stats.files {
+        let idx = SIZE_BUCKETS.iter().position(|(upper, _)| file.size_bytes < *upper).unwrap_or(SIZE_BUCKETS.len() - 1);
+        counts[idx] += 1;
+    }
+
+    let small_bytes = (small_file_mb * MB as f64) as i64;
+    let bars: Vec<Bar> = SIZE_BUCKETS
+        .iter()
+        .zip(counts)
+        .map(|((upper, label), count)| {
+            let color = if *upper <= small_bytes { palette().warning } else { palette().good };
+            Bar::default()
+                .value(count)
+                .label(Line::from(*label))
+                .text_value(count.to_string())
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(palette().text).bg(color))
+        })
+        .collect();
+
+    let gap = 2;
+    let inner_width = area.width.saturating_sub(2);
+    let bar_width = (inner_width / SIZE_BUCKETS.len() as u16).saturating_sub(gap).max(1);
+    let chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(tr!("insights-file-sizes-title", files = stats.files.len())))
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(gap)
+        .label_style(Style::default().fg(palette().accent));
+
+    f.render_widget(chart, area);
+}
+
+fn format_insight(insight: &Insight) -> Vec<Line<'_>> {
     let mut lines = Vec::new();
 
-    let (icon, title_color) = match insight.severity.as_str() {
-        "critical" => ("🚨", Color::Red),
-        "warning" => ("⚠️", Color::Yellow),
-        "info" => ("💡", Color::Green),
-        _ => ("✓", Color::Green),
-    };
+    let title_color = palette().severity(&insight.severity);
 
     lines.push(Line::from(vec![
-        Span::styled(format!("{} {}", icon, insight.title), Style::default().fg(title_color).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(format!("{}  {}", severity_marker(&insight.severity), insight.title), Style::default().fg(title_color).add_modifier(ratatui::style::Modifier::BOLD)),
     ]));
     lines.push(Line::from(vec![
-        Span::styled(format!("Category: {}", insight.category), Style::default().fg(Color::DarkGray)),
+        Span::styled(tr!("insights-category", category = insight.category.as_str()), Style::default().fg(palette().muted)),
     ]));
+    // Only findings resting on partial data carry a confidence line
+    if !insight.assumptions.is_empty() {
+        let color = if insight.confidence == Confidence::Low { palette().warning } else { palette().muted };
+        lines.push(Line::from(vec![
+            Span::styled(tr!("insights-confidence", confidence = insight.confidence.as_str()), Style::default().fg(color)),
+        ]));
+        for assumption in &insight.assumptions {
+            lines.push(Line::from(Span::styled(format!("  • {}", assumption), Style::default().fg(palette().muted))));
+        }
+    }
     lines.push(Line::from(""));
     lines.push(Line::from(insight.description.clone()));
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("→ Recommendation: ", Style::default().fg(Color::Cyan)),
+        Span::styled(format!("{} ", tr!("insights-recommendation")), Style::default().fg(palette().accent)),
         Span::raw(insight.recommendation.clone()),
     ]));
 
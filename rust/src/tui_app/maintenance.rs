@@ -0,0 +1,260 @@
+use crate::tui_app::document::TuiRenderer;
+use crate::tui_app::format_bytes;
+use crate::tui_app::theme::palette;
+use crossterm::event::KeyCode;
+use deltective::maintenance::{CheckpointPlan, OptimizePlan, VacuumPlan, VacuumSimulation};
+use deltective::render::{Document, Renderable, Renderer};
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::time::Instant;
+
+/// Table maintenance the TUI can run on the open table.
+pub enum MaintenanceAction {
+    Optimize(OptimizePlan),
+    Vacuum(VacuumPlan),
+    Checkpoint(CheckpointPlan),
+}
+
+impl MaintenanceAction {
+    /// Name shown while the action runs.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaintenanceAction::Optimize(_) => "OPTIMIZE",
+            MaintenanceAction::Vacuum(_) => "VACUUM",
+            MaintenanceAction::Checkpoint(_) => "CHECKPOINT",
+        }
+    }
+
+    /// What the action will do, shown for confirmation.
+    pub fn plan(&self) -> Document {
+        match self {
+            MaintenanceAction::Optimize(plan) => plan.document(),
+            MaintenanceAction::Vacuum(plan) => plan.document(),
+            MaintenanceAction::Checkpoint(plan) => plan.document(),
+        }
+    }
+}
+
+/// Retentions offered for VACUUM, in hours, besides the table's own.
+pub const RETENTION_CHOICES: [i64; 6] = [24, 72, 168, 336, 720, 2160];
+
+/// Modal dialog around a maintenance action: confirm, then progress, then
+/// the outcome. VACUUM first asks for a retention and runs a dry run to
+/// confirm against. While shown it captures every key.
+pub enum MaintenanceDialog {
+    /// Simulation of every offered retention, filled in once computed
+    VacuumRetention {
+        hours: i64,
+        table_default: i64,
+        simulation: Option<Result<VacuumSimulation, String>>,
+    },
+    Confirm(MaintenanceAction),
+    Running { name: &'static str, plan: Option<Document>, started: Instant },
+    Finished(Result<Document, String>),
+}
+
+/// What the app should do after a key press in the dialog.
+pub enum DialogResponse {
+    /// Keep the dialog open
+    Stay,
+    Close,
+    /// The user confirmed; run the action
+    Start,
+    /// List what VACUUM would delete with this retention, in hours
+    DryRun(i64),
+}
+
+impl MaintenanceDialog {
+    pub fn handle_key(&mut self, key: KeyCode) -> DialogResponse {
+        match self {
+            MaintenanceDialog::VacuumRetention { hours, table_default, .. } => {
+                let mut choices = RETENTION_CHOICES.to_vec();
+                choices.push(*table_default);
+                choices.sort_unstable();
+                choices.dedup();
+                match key {
+                    KeyCode::Left | KeyCode::Char('-') | KeyCode::Char('h') => {
+                        *hours = choices.iter().rev().find(|c| **c < *hours).copied().unwrap_or(*hours);
+                    }
+                    KeyCode::Right | KeyCode::Char('+') | KeyCode::Char('l') => {
+                        *hours = choices.iter().find(|c| **c > *hours).copied().unwrap_or(*hours);
+                    }
+                    KeyCode::Enter => return DialogResponse::DryRun(*hours),
+                    KeyCode::Esc => return DialogResponse::Close,
+                    _ => {}
+                }
+                DialogResponse::Stay
+            }
+            MaintenanceDialog::Confirm(_) => match key {
+                KeyCode::Char('y') | KeyCode::Char('Y') => DialogResponse::Start,
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => DialogResponse::Close,
+                _ => DialogResponse::Stay,
+            },
+            // The commit can't be cancelled halfway; wait for the outcome
+            MaintenanceDialog::Running { .. } => DialogResponse::Stay,
+            MaintenanceDialog::Finished(_) => DialogResponse::Close,
+        }
+    }
+}
+
+/// Draw the dialog centered over `area`.
+pub fn render(f: &mut Frame, area: Rect, dialog: &MaintenanceDialog, spinner: &str) {
+    let (title, border, mut lines, hint) = match dialog {
+        MaintenanceDialog::VacuumRetention { hours, table_default, simulation } => (
+            tr!("maintenance-vacuum-title"),
+            palette().warning,
+            retention_lines(*hours, *table_default, simulation.as_ref(), spinner),
+            tr!("maintenance-vacuum-hint"),
+        ),
+        MaintenanceDialog::Confirm(action) => (
+            tr!("maintenance-confirm-title", action = action.name()),
+            palette().warning,
+            TuiRenderer.render(&action.plan()),
+            tr!("maintenance-confirm-hint"),
+        ),
+        MaintenanceDialog::Running { name, plan, started } => {
+            let mut lines = vec![Line::from(vec![
+                Span::styled(format!("{} ", spinner), Style::default().fg(palette().accent)),
+                Span::styled(
+                    tr!("maintenance-running", action = *name, seconds = started.elapsed().as_secs()),
+                    Style::default().fg(palette().accent).add_modifier(Modifier::BOLD),
+                ),
+            ])];
+            if let Some(plan) = plan {
+                lines.extend(TuiRenderer.render(plan).into_iter().skip(1));
+            }
+            (
+                tr!("maintenance-running-title", action = *name),
+                palette().accent,
+                lines,
+                tr!("maintenance-running-hint"),
+            )
+        }
+        MaintenanceDialog::Finished(Ok(outcome)) => (
+            tr!("maintenance-done-title"),
+            palette().good,
+            TuiRenderer.render(outcome),
+            tr!("maintenance-close-hint"),
+        ),
+        MaintenanceDialog::Finished(Err(e)) => (
+            tr!("maintenance-failed-title"),
+            palette().critical,
+            vec![Line::from(Span::styled(e.clone(), Style::default().fg(palette().critical)))],
+            tr!("maintenance-close-hint"),
+        ),
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(hint, Style::default().fg(palette().muted))));
+
+    let width = area.width.saturating_sub(4).min(90);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup);
+    f.render_widget(
+        Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border))
+                .title(format!(" {} ", title)),
+        ),
+        popup,
+    );
+}
+
+/// The retention chooser: the selected retention, then what VACUUM would do
+/// at each offered retention, so they can be compared before the dry run.
+fn retention_lines(
+    hours: i64,
+    table_default: i64,
+    simulation: Option<&Result<VacuumSimulation, String>>,
+    spinner: &str,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{} ", tr!("maintenance-vacuum-retention")), Style::default().fg(palette().accent)),
+            Span::styled(
+                format!("◀ {} ▶", tr!("maintenance-vacuum-hours", hours = hours, days = hours / 24)),
+                Style::default().fg(palette().warning).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(Span::styled(
+            tr!("maintenance-vacuum-table-default", hours = table_default),
+            Style::default().fg(palette().muted),
+        )),
+        Line::from(""),
+        Line::from(tr!("maintenance-vacuum-explain")),
+        Line::from(""),
+    ];
+
+    let simulation = match simulation {
+        None => {
+            lines.push(Line::from(Span::styled(
+                format!("{} {}", spinner, tr!("maintenance-vacuum-simulating")),
+                Style::default().fg(palette().muted),
+            )));
+            return lines;
+        }
+        Some(Err(e)) => {
+            lines.push(Line::from(Span::styled(e.clone(), Style::default().fg(palette().critical))));
+            return lines;
+        }
+        Some(Ok(simulation)) => simulation,
+    };
+
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  {:<12}{:>10}{:>14}  {}",
+            tr!("maintenance-vacuum-col-retention"),
+            tr!("maintenance-vacuum-col-files"),
+            tr!("maintenance-vacuum-col-space"),
+            tr!("maintenance-vacuum-col-unreadable")
+        ),
+        Style::default().fg(palette().section),
+    )));
+    for scenario in &simulation.scenarios {
+        let selected = scenario.retention_hours == hours;
+        let style = match (selected, scenario.below_table_retention) {
+            (true, _) => Style::default().bg(palette().selection).add_modifier(Modifier::BOLD),
+            (false, true) => Style::default().fg(palette().warning),
+            (false, false) => Style::default(),
+        };
+        let unreadable = scenario
+            .unreadable_through
+            .map_or(tr!("maintenance-vacuum-none"), |v| format!("≤ v{}", v));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} {:<12}{:>10}{:>14}  {}",
+                if selected { "▸" } else { " " },
+                format!("{}h{}", scenario.retention_hours, if scenario.below_table_retention { " !" } else { "" }),
+                scenario.files_freed,
+                format_bytes(scenario.bytes_freed),
+                unreadable
+            ),
+            style,
+        )));
+    }
+    if let Some(version) = simulation.log_expired_through {
+        lines.push(Line::from(Span::styled(
+            tr!("maintenance-vacuum-log-expired", version = version, hours = simulation.log_retention_hours),
+            Style::default().fg(palette().muted),
+        )));
+    }
+    if simulation.scenarios.iter().any(|s| s.below_table_retention) {
+        lines.push(Line::from(Span::styled(
+            tr!("maintenance-vacuum-below-table"),
+            Style::default().fg(palette().warning),
+        )));
+    }
+    lines
+}
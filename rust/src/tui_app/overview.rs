@@ -1,8 +1,10 @@
-use crate::inspector::TableStatistics;
 use crate::tui_app::format_bytes;
+use crate::tui_app::theme::palette;
+use deltective::inspector::TableStatistics;
+use deltective::tr;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -13,62 +15,98 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
 
     // Table Overview
     lines.push(Line::from(vec![
-        Span::styled("═══ TABLE OVERVIEW ═══", Style::default().fg(Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(tr!("overview-heading"), Style::default().fg(palette().accent).add_modifier(ratatui::style::Modifier::BOLD)),
     ]));
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("Table Path: ", Style::default().fg(Color::Cyan)),
+        label("overview-table-path"),
         Span::raw(&stats.table_path),
     ]));
     lines.push(Line::from(vec![
-        Span::styled("Current Version: ", Style::default().fg(Color::Cyan)),
+        label("overview-current-version"),
         Span::raw(format!("{}", stats.version)),
-        Span::styled(format!(" (of {} total)", stats.total_versions), Style::default().fg(Color::DarkGray)),
+        Span::styled(format!(" {}", tr!("overview-of-total", total = stats.total_versions)), Style::default().fg(palette().muted)),
     ]));
+    if let Some(activity) = &stats.write_activity {
+        lines.push(Line::from(Span::styled(
+            tr!("overview-write-activity", version = activity.pinned_version),
+            Style::default().fg(palette().warning),
+        )));
+    }
     lines.push(Line::from(vec![
-        Span::styled("Oldest Available Version: ", Style::default().fg(Color::Cyan)),
+        label("overview-oldest-version"),
         Span::raw(format!("{}", stats.oldest_version)),
     ]));
+    if let Some(window) = &stats.time_travel {
+        let since = window
+            .oldest_readable_time
+            .map_or("none".to_string(), |t| t.format("%Y-%m-%d %H:%M").to_string());
+        lines.push(Line::from(vec![
+            label("overview-time-travel"),
+            Span::raw(tr!(
+                "overview-time-travel-window",
+                oldest = window.oldest_readable_version,
+                latest = window.version,
+                count = window.readable_versions(),
+                since = since
+            )),
+        ]));
+        let mut notes = vec![tr!(
+            "overview-time-travel-retention",
+            log_hours = window.log_retention_hours,
+            file_hours = window.deleted_file_retention_hours
+        )];
+        if window.unreadable_logged_versions() > 0 {
+            notes.push(tr!("overview-time-travel-vacuumed", versions = window.unreadable_logged_versions()));
+        }
+        // Files kept past the log are storage no version can use
+        let color = if window.log_retention_hours < window.deleted_file_retention_hours {
+            palette().warning
+        } else {
+            palette().muted
+        };
+        lines.push(Line::from(Span::styled(format!("  {}", notes.join("; ")), Style::default().fg(color))));
+    }
     lines.push(Line::from(vec![
-        Span::styled("Number of Files: ", Style::default().fg(Color::Cyan)),
+        label("overview-num-files"),
         Span::raw(format!("{}", stats.num_files)),
     ]));
     lines.push(Line::from(vec![
-        Span::styled("Total Size: ", Style::default().fg(Color::Cyan)),
+        label("overview-total-size"),
         Span::raw(format_bytes(stats.total_size_bytes)),
     ]));
 
     if let Some(num_rows) = stats.num_rows {
         lines.push(Line::from(vec![
-            Span::styled("Number of Rows: ", Style::default().fg(Color::Cyan)),
+            label("overview-num-rows"),
             Span::raw(format!("{}", num_rows)),
         ]));
     }
 
     if !stats.partition_columns.is_empty() {
         lines.push(Line::from(vec![
-            Span::styled("Partition Columns: ", Style::default().fg(Color::Cyan)),
+            label("overview-partition-columns"),
             Span::raw(stats.partition_columns.join(", ")),
         ]));
     }
 
     if let Some(created_time) = stats.created_time {
         lines.push(Line::from(vec![
-            Span::styled("Created: ", Style::default().fg(Color::Cyan)),
+            label("overview-created"),
             Span::raw(created_time.format("%Y-%m-%d %H:%M:%S").to_string()),
         ]));
     }
 
     if let Some(name) = &stats.metadata.name {
         lines.push(Line::from(vec![
-            Span::styled("Table Name: ", Style::default().fg(Color::Cyan)),
+            label("overview-table-name"),
             Span::raw(name),
         ]));
     }
 
     if let Some(description) = &stats.metadata.description {
         lines.push(Line::from(vec![
-            Span::styled("Description: ", Style::default().fg(Color::Cyan)),
+            label("overview-description"),
             Span::raw(description),
         ]));
     }
@@ -76,22 +114,22 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     // Delta Protocol & History
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("═══ DELTA PROTOCOL & HISTORY ═══", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(tr!("overview-protocol-heading"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
     ]));
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("Min Reader Version: ", Style::default().fg(Color::Cyan)),
+        label("overview-min-reader"),
         Span::raw(format!("{}", stats.min_reader_version)),
     ]));
     lines.push(Line::from(vec![
-        Span::styled("Min Writer Version: ", Style::default().fg(Color::Cyan)),
+        label("overview-min-writer"),
         Span::raw(format!("{}", stats.min_writer_version)),
     ]));
 
     if !stats.reader_features.is_empty() {
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
-            Span::styled("Reader Features: ", Style::default().fg(Color::Cyan)),
+            label("overview-reader-features"),
         ]));
         for feature in &stats.reader_features {
             lines.push(Line::from(vec![
@@ -104,7 +142,7 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     if !stats.writer_features.is_empty() {
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
-            Span::styled("Writer Features: ", Style::default().fg(Color::Cyan)),
+            label("overview-writer-features"),
         ]));
         for feature in &stats.writer_features {
             lines.push(Line::from(vec![
@@ -117,53 +155,119 @@ pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, scroll: u16) {
     if let Some(last_op) = &stats.last_operation {
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
-            Span::styled("Last Operation: ", Style::default().fg(Color::Cyan)),
+            label("overview-last-operation"),
             Span::raw(&last_op.operation),
         ]));
         lines.push(Line::from(vec![
-            Span::styled("  Time: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("  {} ", tr!("overview-time")), Style::default().fg(palette().muted)),
             Span::raw(last_op.timestamp.format("%Y-%m-%d %H:%M:%S").to_string()),
         ]));
     }
 
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("Last Vacuum: ", Style::default().fg(Color::Cyan)),
+        label("overview-last-vacuum"),
         Span::raw(
             stats.last_vacuum
                 .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                .unwrap_or_else(|| "Never".to_string())
+                .unwrap_or_else(|| tr!("overview-never"))
         ),
     ]));
+    if let Some(dvs) = &stats.deletion_vectors {
+        let density = dvs.density_pct().map_or("none".to_string(), |pct| format!("{:.1}", pct));
+        lines.push(Line::from(vec![
+            label("overview-deletion-vectors"),
+            Span::raw(tr!(
+                "overview-deletion-vectors-detail",
+                files = dvs.files_with_dvs,
+                deleted_rows = dvs.deleted_rows,
+                density = density,
+                dv_bytes = format_bytes(dvs.dv_bytes),
+                inline = dvs.inline_dvs
+            )),
+        ]));
+    }
+    if let Some((amplification, ratio)) = stats.write_amplification.as_ref().and_then(|a| Some((a, a.ratio()?))) {
+        lines.push(Line::from(vec![
+            label("overview-write-amplification"),
+            Span::raw(format!("{:.1}×", ratio)),
+            Span::styled(
+                format!(" {}", tr!("overview-write-amplification-detail", commits = amplification.commits(), rewritten = format_bytes(amplification.bytes_rewritten))),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+    }
 
-    // Schema
-    lines.push(Line::from(""));
+    // The columns themselves are on the Schema tab
+    let nested = stats.schema.columns().len() - stats.schema.len();
     lines.push(Line::from(vec![
-        Span::styled("═══ SCHEMA ═══", Style::default().fg(Color::Green).add_modifier(ratatui::style::Modifier::BOLD)),
+        label("overview-schema"),
+        Span::raw(tr!("overview-schema-columns", count = stats.schema.len(), nested = nested)),
+        Span::styled(format!(" {}", tr!("overview-schema-see-tab")), Style::default().fg(palette().muted)),
     ]));
-    lines.push(Line::from(""));
 
-    for (col_name, col_type) in &stats.schema {
-        if stats.partition_columns.contains(col_name) {
+    if let Some(cost) = &stats.cost {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(tr!("overview-cost-heading"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+        ]));
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            label("overview-cost-price"),
+            Span::raw(tr!(
+                "overview-cost-price-detail",
+                price = format!("{}{}", cost.currency, cost.price_per_gb_month),
+                storage_class = cost.storage_class.as_str()
+            )),
+        ]));
+        lines.push(Line::from(vec![
+            label("overview-cost-data"),
+            Span::raw(tr!("overview-cost-monthly", amount = cost.format(cost.data_monthly))),
+            Span::styled(format!(" ({})", format_bytes(cost.data_bytes)), Style::default().fg(palette().muted)),
+        ]));
+        if let (Some(bytes), Some(monthly)) = (cost.tombstoned_bytes, cost.tombstoned_monthly) {
             lines.push(Line::from(vec![
-                Span::styled(format!("  {}", col_name), Style::default().fg(Color::Yellow)),
-                Span::styled(" (partition)", Style::default().fg(Color::DarkGray)),
-                Span::raw(": "),
-                Span::styled(col_type, Style::default().fg(Color::Green)),
+                label("overview-cost-tombstoned"),
+                Span::raw(tr!("overview-cost-monthly", amount = cost.format(monthly))),
+                Span::styled(format!(" ({})", format_bytes(bytes)), Style::default().fg(palette().muted)),
             ]));
-        } else {
+        }
+        // Only what VACUUM would delete today is money left on the table
+        if let Some(monthly) = cost.reclaimable_monthly.filter(|m| *m > 0.0) {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", tr!("overview-cost-reclaimable", amount = cost.format(monthly))),
+                Style::default().fg(palette().warning),
+            )));
+        }
+        lines.push(Line::from(vec![
+            label("overview-cost-total"),
+            Span::styled(
+                tr!("overview-cost-monthly", amount = cost.format(cost.total_monthly())),
+                Style::default().add_modifier(ratatui::style::Modifier::BOLD),
+            ),
+        ]));
+        if !cost.projections.is_empty() {
+            let projections: Vec<String> = cost
+                .projections
+                .iter()
+                .map(|p| tr!("overview-cost-projection", days = p.days, amount = cost.format(p.monthly)))
+                .collect();
             lines.push(Line::from(vec![
-                Span::styled(format!("  {}", col_name), Style::default().fg(Color::Cyan)),
-                Span::raw(": "),
-                Span::styled(col_type, Style::default().fg(Color::Green)),
+                label("overview-cost-projected"),
+                Span::raw(projections.join(" · ")),
             ]));
         }
     }
 
     let paragraph = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL).title("Overview [↑↓ scroll]"))
+        .block(Block::default().borders(Borders::ALL).title(tr!("overview-block-title")))
         .scroll((scroll, 0));
 
     f.render_widget(paragraph, area);
 }
 
+
+/// Field label in the active locale, followed by a space for its value.
+fn label(id: &str) -> Span<'static> {
+    Span::styled(format!("{} ", tr!(id)), Style::default().fg(palette().accent))
+}
@@ -0,0 +1,440 @@
+use crate::tui_app::document::TuiRenderer;
+use crate::tui_app::theme::palette;
+use crate::tui_app::{format_bytes, Loadable};
+use deltective::inspector::TableStatistics;
+use deltective::parquet_footer::FileFooter;
+use deltective::partitions::{
+    build_partition_tree, summarize_levels, summarize_partitions, PartitionLevelSummary,
+    PartitionNode, PartitionSummary,
+};
+use deltective::preview::PreviewSource;
+use deltective::render::{Renderable, Renderer};
+use deltective::tr;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionView {
+    Tree,
+    List,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionSort {
+    Size,
+    Files,
+    Rows,
+    Modified,
+    Name,
+}
+
+impl PartitionSort {
+    fn next(self) -> Self {
+        match self {
+            PartitionSort::Size => PartitionSort::Files,
+            PartitionSort::Files => PartitionSort::Rows,
+            PartitionSort::Rows => PartitionSort::Modified,
+            PartitionSort::Modified => PartitionSort::Name,
+            PartitionSort::Name => PartitionSort::Size,
+        }
+    }
+
+    fn label(self) -> String {
+        tr!(match self {
+            PartitionSort::Size => "partitions-sort-size",
+            PartitionSort::Files => "partitions-sort-files",
+            PartitionSort::Rows => "partitions-sort-rows",
+            PartitionSort::Modified => "partitions-sort-modified",
+            PartitionSort::Name => "partitions-sort-name",
+        })
+    }
+}
+
+/// Everything the Partitions tab needs, computed once per table load.
+pub struct PartitionsState {
+    tree: Vec<PartitionNode>,
+    levels: Vec<PartitionLevelSummary>,
+    partitions: Vec<PartitionSummary>,
+    view: PartitionView,
+    sort: PartitionSort,
+    cursor: usize,
+    expanded: HashSet<String>,
+    /// Index into `partitions` whose files are being shown
+    open_partition: Option<usize>,
+    file_cursor: usize,
+    /// Set when the user asks to preview rows; consumed by the app
+    pending_preview: Option<PreviewSource>,
+    /// File whose Parquet footer the user asked for with `f`; consumed by the app
+    pending_footer: Option<String>,
+}
+
+impl PartitionsState {
+    pub fn new(stats: &TableStatistics) -> Self {
+        let tree = build_partition_tree(stats);
+        let levels = summarize_levels(stats, &tree);
+        let mut state = Self {
+            tree,
+            levels,
+            partitions: summarize_partitions(stats),
+            view: PartitionView::Tree,
+            sort: PartitionSort::Size,
+            cursor: 0,
+            expanded: HashSet::new(),
+            open_partition: None,
+            file_cursor: 0,
+            pending_preview: None,
+            pending_footer: None,
+        };
+        state.sort_partitions();
+        state
+    }
+
+    fn sort_partitions(&mut self) {
+        match self.sort {
+            PartitionSort::Size => self.partitions.sort_by_key(|p| Reverse(p.total_size_bytes)),
+            PartitionSort::Files => self.partitions.sort_by_key(|p| Reverse(p.file_count)),
+            PartitionSort::Rows => self.partitions.sort_by_key(|p| Reverse(p.num_rows)),
+            PartitionSort::Modified => self.partitions.sort_by_key(|p| Reverse(p.last_modified)),
+            PartitionSort::Name => self.partitions.sort_by(|a, b| a.key.cmp(&b.key)),
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        match self.view {
+            PartitionView::Tree => visible_nodes(&self.tree, &self.expanded).len(),
+            PartitionView::List => self.partitions.len(),
+        }
+    }
+
+    /// Take the preview the user requested with `d`, if any.
+    pub fn take_preview_request(&mut self) -> Option<PreviewSource> {
+        self.pending_preview.take()
+    }
+
+    /// Take the file whose footer the user requested with `f`, if any.
+    pub fn take_footer_request(&mut self) -> Option<String> {
+        self.pending_footer.take()
+    }
+
+    /// Handle a key press. Returns true if the key was consumed.
+    pub fn handle_key(&mut self, key: KeyCode, stats: &TableStatistics) -> bool {
+        if let Some(idx) = self.open_partition {
+            let files = &self.partitions[idx].file_indices;
+            match key {
+                KeyCode::Esc | KeyCode::Backspace => self.open_partition = None,
+                KeyCode::Up | KeyCode::Char('k') => self.file_cursor = self.file_cursor.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if self.file_cursor + 1 < files.len() {
+                        self.file_cursor += 1;
+                    }
+                }
+                KeyCode::Char('d') | KeyCode::Enter => {
+                    if let Some(&file_idx) = files.get(self.file_cursor) {
+                        self.pending_preview = Some(PreviewSource::File(stats.files[file_idx].path.clone()));
+                    }
+                }
+                KeyCode::Char('f') => {
+                    if let Some(&file_idx) = files.get(self.file_cursor) {
+                        self.pending_footer = Some(stats.files[file_idx].path.clone());
+                    }
+                }
+                _ => return false,
+            }
+            return true;
+        }
+
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.cursor + 1 < self.row_count() {
+                    self.cursor += 1;
+                }
+            }
+            KeyCode::Char('v') => {
+                self.view = match self.view {
+                    PartitionView::Tree => PartitionView::List,
+                    PartitionView::List => PartitionView::Tree,
+                };
+                self.cursor = 0;
+            }
+            KeyCode::Char('s') if self.view == PartitionView::List => {
+                self.sort = self.sort.next();
+                self.sort_partitions();
+                self.cursor = 0;
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => match self.view {
+                PartitionView::Tree => {
+                    let rows = visible_nodes(&self.tree, &self.expanded);
+                    if let Some(node) = rows.get(self.cursor) {
+                        let path = node.path.clone();
+                        if !self.expanded.remove(&path) {
+                            self.expanded.insert(path);
+                        }
+                    }
+                }
+                PartitionView::List => {
+                    if self.cursor < self.partitions.len() {
+                        self.open_partition = Some(self.cursor);
+                        self.file_cursor = 0;
+                    }
+                }
+            },
+            KeyCode::Char('d') if self.view == PartitionView::List => {
+                if let Some(partition) = self.partitions.get(self.cursor) {
+                    self.pending_preview = Some(PreviewSource::Partition(partition.values.clone()));
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Flatten the tree into the rows currently visible given the expanded set.
+pub fn visible_nodes<'a>(tree: &'a [PartitionNode], expanded: &HashSet<String>) -> Vec<&'a PartitionNode> {
+    let mut rows = Vec::new();
+    for node in tree {
+        push_visible(node, expanded, &mut rows);
+    }
+    rows
+}
+
+fn push_visible<'a>(node: &'a PartitionNode, expanded: &HashSet<String>, rows: &mut Vec<&'a PartitionNode>) {
+    rows.push(node);
+    if expanded.contains(&node.path) {
+        for child in &node.children {
+            push_visible(child, expanded, rows);
+        }
+    }
+}
+
+pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, state: &PartitionsState) {
+    if stats.partition_columns.is_empty() {
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(tr!("partitions-explorer-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(tr!("partitions-not-partitioned"), Style::default().fg(palette().muted)),
+            ]),
+        ];
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(tr!("partitions-title")));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    if let Some(idx) = state.open_partition {
+        render_files(f, area, stats, &state.partitions[idx], state.file_cursor);
+        return;
+    }
+
+    let mut lines = Vec::new();
+
+    lines.push(Line::from(vec![
+        Span::styled(tr!("partitions-hierarchy-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(format!("{} ", tr!("partitions-columns")), Style::default().fg(palette().accent)),
+        Span::raw(stats.partition_columns.join(" / ")),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(format!("{} ", tr!("partitions-count")), Style::default().fg(palette().accent)),
+        Span::raw(format!("{}", state.partitions.len())),
+    ]));
+    lines.push(Line::from(""));
+
+    for level in &state.levels {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("partitions-level", level = level.depth + 1)), Style::default().fg(palette().warning)),
+            Span::styled(format!("({:12})", level.column), Style::default().fg(palette().accent)),
+            Span::raw(format!(
+                " {}",
+                tr!(
+                    "partitions-level-summary",
+                    partitions = format!("{:6}", level.num_partitions),
+                    files = format!("{:8.1}", level.avg_files_per_partition),
+                    size = format!("{:>10}", format_bytes(level.avg_size_per_partition)),
+                )
+            )),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    let header_len;
+    let title;
+    match state.view {
+        PartitionView::Tree => {
+            lines.push(Line::from(vec![
+                Span::styled(tr!("partitions-tree-heading"), Style::default().fg(palette().good).add_modifier(Modifier::BOLD)),
+            ]));
+            lines.push(Line::from(""));
+            header_len = lines.len();
+
+            for (idx, node) in visible_nodes(&state.tree, &state.expanded).iter().enumerate() {
+                let marker = if node.children.is_empty() {
+                    "•"
+                } else if state.expanded.contains(&node.path) {
+                    "▾"
+                } else {
+                    "▸"
+                };
+                let row_style = row_style(idx == state.cursor);
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{}{} ", "  ".repeat(node.depth + 1), marker), row_style.fg(palette().muted)),
+                    Span::styled(format!("{}=", node.column), row_style.fg(palette().accent)),
+                    Span::styled(node.value.clone(), row_style.fg(palette().warning)),
+                    Span::styled(
+                        format!("  {}", tr!("partitions-node-summary", files = node.file_count, size = format_bytes(node.total_size_bytes))),
+                        row_style.fg(palette().good),
+                    ),
+                ]));
+            }
+            title = tr!("partitions-tree-title");
+        }
+        PartitionView::List => {
+            lines.push(Line::from(vec![
+                Span::styled(tr!("partitions-list-heading"), Style::default().fg(palette().good).add_modifier(Modifier::BOLD)),
+                Span::styled(format!(" {}", tr!("partitions-sorted-by", sort = state.sort.label())), Style::default().fg(palette().muted)),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "  {:50} {:>8} {:>12} {:>14}  {}",
+                        tr!("partitions-col-partition"),
+                        tr!("partitions-col-files"),
+                        tr!("partitions-col-size"),
+                        tr!("partitions-col-rows"),
+                        tr!("partitions-col-modified")
+                    ),
+                    Style::default().fg(palette().section).add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            header_len = lines.len();
+
+            for (idx, partition) in state.partitions.iter().enumerate() {
+                let rows = partition.num_rows.map(|r| r.to_string()).unwrap_or_else(|| "-".to_string());
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "  {:50} {:>8} {:>12} {:>14}  {}",
+                            truncate(&partition.key, 50),
+                            partition.file_count,
+                            format_bytes(partition.total_size_bytes),
+                            rows,
+                            partition.last_modified.format("%Y-%m-%d %H:%M:%S")
+                        ),
+                        row_style(idx == state.cursor),
+                    ),
+                ]));
+            }
+            title = tr!("partitions-list-title");
+        }
+    }
+
+    // Keep the cursor row in view
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let scroll = (header_len + state.cursor + 1).saturating_sub(visible_height) as u16;
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_files(f: &mut Frame, area: Rect, stats: &TableStatistics, partition: &PartitionSummary, cursor: usize) {
+    let mut lines = Vec::new();
+
+    lines.push(Line::from(vec![
+        Span::styled(tr!("partitions-files-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(format!("{} ", tr!("partitions-partition")), Style::default().fg(palette().accent)),
+        Span::styled(partition.key.clone(), Style::default().fg(palette().warning)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(format!("{} ", tr!("partitions-files")), Style::default().fg(palette().accent)),
+        Span::raw(format!("{}  ", partition.file_count)),
+        Span::styled(format!("{} ", tr!("partitions-size")), Style::default().fg(palette().accent)),
+        Span::raw(format_bytes(partition.total_size_bytes)),
+    ]));
+    lines.push(Line::from(""));
+    let header_len = lines.len();
+
+    for (idx, &file_idx) in partition.file_indices.iter().enumerate() {
+        let file = &stats.files[file_idx];
+        let rows = file.num_records.map(|r| tr!("partitions-file-rows", rows = r)).unwrap_or_default();
+        let style = row_style(idx == cursor);
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:>10}", format_bytes(file.size_bytes)), style.fg(palette().good)),
+            Span::styled(format!(" {:>14}", rows), style.fg(palette().muted)),
+            Span::styled(
+                format!("  {}  ", file.modification_time.format("%Y-%m-%d %H:%M:%S")),
+                style.fg(palette().muted),
+            ),
+            Span::styled(truncate(&file.path, 60), style),
+        ]));
+    }
+
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let scroll = (header_len + cursor + 1).saturating_sub(visible_height) as u16;
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("partitions-files-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+/// Row groups, codecs, encodings and column chunk sizes of the file opened
+/// with `f`, in place of the file list.
+pub fn render_footer(f: &mut Frame, area: Rect, footer: &Loadable<FileFooter>, scroll: u16, spinner: &str) {
+    let lines = match footer {
+        Loadable::Loading => vec![Line::from(vec![
+            Span::styled(format!("{} ", spinner), Style::default().fg(palette().accent)),
+            Span::styled(tr!("footer-reading"), Style::default().fg(palette().muted)),
+        ])],
+        Loadable::Failed(e) => vec![Line::from(vec![
+            Span::styled(tr!("footer-read-failed", error = e.as_str()), Style::default().fg(palette().critical)),
+        ])],
+        Loadable::Ready(footer) => TuiRenderer.render(&footer.document()),
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("footer-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+fn row_style(selected: bool) -> Style {
+    if selected {
+        Style::default().bg(palette().selection).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    }
+}
+
+/// Truncate long paths and keys from the left, keeping the distinctive tail.
+fn truncate(text: &str, max: usize) -> String {
+    let count = text.chars().count();
+    if count <= max {
+        text.to_string()
+    } else {
+        format!("…{}", text.chars().skip(count - max + 1).collect::<String>())
+    }
+}
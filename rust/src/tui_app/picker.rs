@@ -0,0 +1,159 @@
+use crate::tui_app::theme::palette;
+use crossterm::event::KeyCode;
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::path::Path;
+
+/// Table picker shown when the TUI is started on a directory containing
+/// several Delta tables rather than on a table itself.
+pub struct PickerState {
+    root: String,
+    /// Discovered table paths, sorted
+    tables: Vec<String>,
+    cursor: usize,
+    /// Case-insensitive substring filter over table paths
+    filter: String,
+    editing: bool,
+    /// Set when the user opens a table; consumed by the app
+    chosen: Option<String>,
+}
+
+impl PickerState {
+    pub fn new(root: &str, tables: Vec<String>) -> Self {
+        Self {
+            root: root.to_string(),
+            tables,
+            cursor: 0,
+            filter: String::new(),
+            editing: false,
+            chosen: None,
+        }
+    }
+
+    /// Whether the filter prompt is capturing keystrokes.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Tables matching the current filter.
+    fn filtered(&self) -> Vec<&String> {
+        let needle = self.filter.to_lowercase();
+        self.tables
+            .iter()
+            .filter(|table| needle.is_empty() || self.label(table).to_lowercase().contains(&needle))
+            .collect()
+    }
+
+    /// Table path relative to the directory being browsed.
+    fn label<'a>(&self, table: &'a str) -> &'a str {
+        Path::new(table)
+            .strip_prefix(&self.root)
+            .ok()
+            .and_then(|relative| relative.to_str())
+            .filter(|relative| !relative.is_empty())
+            .unwrap_or(table)
+    }
+
+    /// Take the table the user opened with `Enter`, if any.
+    pub fn take_choice(&mut self) -> Option<String> {
+        self.chosen.take()
+    }
+
+    /// Handle a key press. Returns true if the key was consumed.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        if self.editing {
+            match key {
+                KeyCode::Enter => self.editing = false,
+                KeyCode::Esc => {
+                    self.editing = false;
+                    self.filter.clear();
+                }
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                }
+                KeyCode::Char(c) => self.filter.push(c),
+                _ => {}
+            }
+            self.cursor = 0;
+            return true;
+        }
+
+        let matches = self.filtered().len();
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.cursor + 1 < matches {
+                    self.cursor += 1;
+                }
+            }
+            KeyCode::PageUp => self.cursor = self.cursor.saturating_sub(10),
+            KeyCode::PageDown => self.cursor = (self.cursor + 10).min(matches.saturating_sub(1)),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = matches.saturating_sub(1),
+            KeyCode::Char('/') => self.editing = true,
+            KeyCode::Esc if !self.filter.is_empty() => {
+                self.filter.clear();
+                self.cursor = 0;
+            }
+            KeyCode::Enter => self.chosen = self.filtered().get(self.cursor).map(|table| table.to_string()),
+            _ => return false,
+        }
+        true
+    }
+}
+
+pub fn render(f: &mut Frame, area: Rect, state: &PickerState) {
+    let tables = state.filtered();
+    let mut lines = Vec::new();
+
+    lines.push(Line::from(vec![
+        Span::styled(tr!("picker-heading"), Style::default().fg(palette().accent).add_modifier(Modifier::BOLD)),
+        Span::styled(
+            format!(" {}", tr!("picker-count", count = state.tables.len())),
+            Style::default().fg(palette().muted),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    if state.editing || !state.filter.is_empty() {
+        let cursor = if state.editing { "▏" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", tr!("picker-filter")), Style::default().fg(palette().accent)),
+            Span::styled(format!("{}{}", state.filter, cursor), Style::default().fg(palette().warning)),
+            Span::styled(
+                format!(" {}", tr!("picker-matches", matches = tables.len(), total = state.tables.len())),
+                Style::default().fg(palette().muted),
+            ),
+        ]));
+        lines.push(Line::from(""));
+    }
+    let header_len = lines.len();
+
+    if tables.is_empty() {
+        lines.push(Line::from(Span::styled(tr!("picker-no-matches"), Style::default().fg(palette().muted))));
+    }
+    for (idx, table) in tables.iter().enumerate() {
+        let style = if idx == state.cursor {
+            Style::default().bg(palette().selection).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(format!("  {}", state.label(table)), style)));
+    }
+
+    // Keep the cursor row in view
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let scroll = (header_len + state.cursor + 1).saturating_sub(visible_height) as u16;
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("picker-block-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,127 @@
+use crate::tui_app::theme::palette;
+use crossterm::event::KeyCode;
+use deltective::tr;
+use ratatui::{
+    style::{Modifier, Style},
+    text::Span,
+};
+use std::time::{Duration, Instant};
+
+const MIN_INTERVAL: Duration = Duration::from_millis(250);
+const MAX_INTERVAL: Duration = Duration::from_secs(60);
+
+/// `--replay` state: steps the TUI through the table's versions from oldest
+/// to latest, loading each one in turn, so the tabs show how the table
+/// changed over time.
+pub struct ReplayState {
+    interval: Duration,
+    paused: bool,
+    /// Version to start from; the oldest available version when `None`
+    start: Option<i64>,
+    /// Oldest and latest version, known once the latest statistics arrive
+    range: Option<(i64, i64)>,
+    /// Version the tabs are showing or loading
+    current: Option<i64>,
+    last_step: Instant,
+}
+
+impl ReplayState {
+    pub fn new(interval: Duration, start: Option<i64>) -> Self {
+        Self {
+            interval: interval.clamp(MIN_INTERVAL, MAX_INTERVAL),
+            paused: false,
+            start,
+            range: None,
+            current: None,
+            last_step: Instant::now(),
+        }
+    }
+
+    /// Whether the first version has been requested yet.
+    pub fn is_started(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Record the versions available once the latest snapshot is loaded and
+    /// return the version to start the replay from.
+    pub fn begin(&mut self, oldest: i64, latest: i64) -> i64 {
+        self.range = Some((oldest, latest));
+        let first = self.start.unwrap_or(oldest).clamp(oldest, latest);
+        self.jump(first)
+    }
+
+    /// Version being replayed, if started.
+    pub fn current(&self) -> Option<i64> {
+        self.current
+    }
+
+    /// Next version to load when the step interval has passed; pauses at the
+    /// latest version. Callers only ask once the previous version has loaded.
+    pub fn due(&mut self) -> Option<i64> {
+        let ((_, latest), Some(current)) = (self.range?, self.current) else {
+            return None;
+        };
+        if self.paused || self.last_step.elapsed() < self.interval {
+            return None;
+        }
+        if current >= latest {
+            self.paused = true;
+            return None;
+        }
+        Some(self.jump(current + 1))
+    }
+
+    /// Handle a replay key. Returns whether the key was consumed and the
+    /// version to load, if it changed.
+    pub fn handle_key(&mut self, key: KeyCode) -> (bool, Option<i64>) {
+        let (Some((oldest, latest)), Some(current)) = (self.range, self.current) else {
+            return (false, None);
+        };
+        match key {
+            KeyCode::Char(' ') => {
+                // Resuming at the end starts over
+                if self.paused && current >= latest {
+                    self.paused = false;
+                    return (true, Some(self.jump(oldest)));
+                }
+                self.paused = !self.paused;
+                self.last_step = Instant::now();
+            }
+            KeyCode::Char(']') if current < latest => {
+                self.paused = true;
+                return (true, Some(self.jump(current + 1)));
+            }
+            KeyCode::Char('[') if current > oldest => {
+                self.paused = true;
+                return (true, Some(self.jump(current - 1)));
+            }
+            KeyCode::Char('+') => self.interval = (self.interval / 2).max(MIN_INTERVAL),
+            KeyCode::Char('-') => self.interval = (self.interval * 2).min(MAX_INTERVAL),
+            _ => return (false, None),
+        }
+        (true, None)
+    }
+
+    fn jump(&mut self, version: i64) -> i64 {
+        self.current = Some(version);
+        self.last_step = Instant::now();
+        version
+    }
+
+    /// Status shown in the title bar, e.g. "▶ v12 of 40 · every 2.0s".
+    pub fn status(&self) -> Span<'static> {
+        let text = match (self.range, self.current) {
+            (Some((_, latest)), Some(current)) => tr!(
+                if self.paused { "replay-paused" } else { "replay-playing" },
+                version = current,
+                latest = latest,
+                seconds = format!("{:.1}", self.interval.as_secs_f64())
+            ),
+            _ => tr!("replay-preparing"),
+        };
+        Span::styled(
+            format!(" {} ", text),
+            Style::default().fg(palette().warning).add_modifier(Modifier::BOLD),
+        )
+    }
+}
@@ -0,0 +1,229 @@
+use crate::tui_app::theme::palette;
+use crossterm::event::KeyCode;
+use deltective::inspector::TableStatistics;
+use deltective::schema::SchemaField;
+use deltective::tr;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::collections::HashSet;
+
+/// Column name search, type filter and tree expansion for the Schema tab.
+#[derive(Default)]
+pub struct SchemaState {
+    /// Case-insensitive substring of the dotted column path
+    search: String,
+    /// Search being typed after `s`
+    editing: bool,
+    /// Outermost type kind columns must have, cycled with `f`
+    type_filter: Option<String>,
+    /// The unfiltered tree shows nested struct fields
+    expanded: bool,
+}
+
+impl SchemaState {
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    fn is_filtered(&self) -> bool {
+        !self.search.is_empty() || self.type_filter.is_some()
+    }
+
+    /// Move the type filter to the next kind present in the schema, then
+    /// back to no filter.
+    fn cycle_type(&mut self, stats: &TableStatistics) {
+        let mut kinds: Vec<&str> = Vec::new();
+        for (_, _, field) in stats.schema.columns() {
+            let kind = field.data_type.kind();
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+        let next = match &self.type_filter {
+            None => kinds.first(),
+            Some(current) => kinds.iter().position(|k| k == current).and_then(|idx| kinds.get(idx + 1)),
+        };
+        self.type_filter = next.map(|kind| kind.to_string());
+    }
+
+    /// Handle a key on the Schema tab. Returns true if the key was consumed.
+    pub fn handle_key(&mut self, key: KeyCode, stats: &TableStatistics) -> bool {
+        if self.editing {
+            match key {
+                KeyCode::Enter => self.editing = false,
+                KeyCode::Esc => {
+                    self.editing = false;
+                    self.search.clear();
+                }
+                KeyCode::Backspace => {
+                    self.search.pop();
+                }
+                KeyCode::Char(c) => self.search.push(c),
+                _ => {}
+            }
+            return true;
+        }
+        match key {
+            KeyCode::Char('s') => self.editing = true,
+            KeyCode::Char('f') => self.cycle_type(stats),
+            KeyCode::Char('e') => self.expanded = !self.expanded,
+            KeyCode::Esc if self.is_filtered() => {
+                self.search.clear();
+                self.type_filter = None;
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+pub fn render(f: &mut Frame, area: Rect, stats: &TableStatistics, state: &SchemaState, scroll: u16) {
+    let mut lines = Vec::new();
+    let columns = stats.schema.columns();
+    let indexed: HashSet<String> = stats
+        .schema
+        .data_skipping_columns(&stats.properties, &stats.partition_columns)
+        .into_iter()
+        .collect();
+
+    lines.push(Line::from(Span::styled(
+        tr!("schema-heading"),
+        Style::default().fg(palette().accent).add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        tr!(
+            "schema-summary",
+            columns = stats.schema.len(),
+            nested = columns.len() - stats.schema.len(),
+            partitions = stats.partition_columns.len(),
+            indexed = indexed.len(),
+            with_stats = stats.column_stats.iter().filter(|c| c.files_with_stats > 0).count()
+        ),
+        Style::default().fg(palette().muted),
+    )));
+    lines.push(Line::from(""));
+
+    let needle = state.search.to_lowercase();
+    let shown: Vec<_> = if state.is_filtered() {
+        columns
+            .iter()
+            .filter(|(path, _, field)| {
+                path.to_lowercase().contains(&needle)
+                    && state.type_filter.as_deref().is_none_or(|kind| field.data_type.kind() == kind)
+            })
+            .collect()
+    } else {
+        columns.iter().filter(|(_, depth, _)| *depth == 0 || state.expanded).collect()
+    };
+
+    if state.editing || state.is_filtered() {
+        let cursor = if state.editing { "▏" } else { "" };
+        let mut spans = vec![
+            Span::styled(format!("{} ", tr!("schema-search")), Style::default().fg(palette().accent)),
+            Span::styled(format!("{}{}", state.search, cursor), Style::default().fg(palette().warning)),
+        ];
+        if let Some(kind) = &state.type_filter {
+            spans.push(Span::styled(format!("  {} ", tr!("schema-type-filter")), Style::default().fg(palette().accent)));
+            spans.push(Span::styled(kind.clone(), Style::default().fg(palette().warning)));
+        }
+        spans.push(Span::styled(
+            format!(" {}", tr!("schema-matches", matching = shown.len(), total = columns.len())),
+            Style::default().fg(palette().muted),
+        ));
+        lines.push(Line::from(spans));
+        lines.push(Line::from(""));
+    }
+
+    if shown.is_empty() {
+        lines.push(Line::from(Span::styled(tr!("schema-no-match"), Style::default().fg(palette().muted))));
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{:40} {:28} {:8} {}",
+                tr!("schema-column"),
+                tr!("schema-type"),
+                tr!("schema-nullable"),
+                tr!("schema-annotations")
+            ),
+            Style::default().fg(palette().section).add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    for (path, depth, field) in shown {
+        // Filtered rows stand alone, so they show the full path
+        let name = if state.is_filtered() {
+            path.clone()
+        } else {
+            let marker = match (field.data_type.children().is_empty(), state.expanded) {
+                (true, _) => "  ",
+                (false, true) => "▾ ",
+                (false, false) => "▸ ",
+            };
+            format!("{}{}{}", "  ".repeat(*depth), marker, field.name)
+        };
+        let partition = *depth == 0 && stats.partition_columns.contains(&field.name);
+        let mut spans = vec![
+            Span::styled(
+                format!("{:40} ", name),
+                Style::default().fg(if partition { palette().warning } else { palette().accent }),
+            ),
+            Span::styled(format!("{:28} ", type_label(field, state)), Style::default().fg(palette().good)),
+            Span::styled(
+                format!("{:8}", if field.nullable { "yes" } else { "no" }),
+                Style::default().fg(palette().muted),
+            ),
+        ];
+        spans.extend(annotations(path, *depth, field, stats, &indexed));
+        lines.push(Line::from(spans));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(tr!("schema-block-title")))
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+/// Type summary, with the field count of collapsed nested types.
+fn type_label(field: &SchemaField, state: &SchemaState) -> String {
+    let children = field.data_type.children();
+    if children.is_empty() || state.expanded || state.is_filtered() {
+        field.data_type.summary()
+    } else {
+        format!("{} {}", field.data_type.summary(), tr!("schema-fields", count = children.len()))
+    }
+}
+
+/// Partition column, data skipping, file statistics and null fraction,
+/// generated expression and comment of the column at `path`.
+fn annotations(path: &str, depth: usize, field: &SchemaField, stats: &TableStatistics, indexed: &HashSet<String>) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut tag = |text: String, color| spans.push(Span::styled(format!(" {}", text), Style::default().fg(color)));
+
+    if depth == 0 && stats.partition_columns.contains(&field.name) {
+        tag(tr!("schema-partition"), palette().warning);
+    }
+    if indexed.contains(path) {
+        tag(tr!("schema-indexed"), palette().good);
+    }
+    if let Some(column) = stats.column_stats.iter().find(|c| c.name == path) {
+        let color = if column.files_with_stats < stats.num_files { palette().warning } else { palette().info };
+        tag(tr!("schema-stats", files = column.files_with_stats, total = stats.num_files), color);
+        if let Some(fraction) = column.null_fraction() {
+            tag(tr!("schema-nulls", pct = format!("{:.1}", fraction * 100.0)), palette().muted);
+        }
+    }
+    if let Some(generated) = stats.generated_columns.iter().find(|c| depth == 0 && c.name == field.name) {
+        tag(generated.describe(), palette().muted);
+    }
+    if let Some(comment) = &field.comment {
+        tag(format!("-- {}", comment), palette().muted);
+    }
+    spans
+}
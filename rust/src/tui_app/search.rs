@@ -0,0 +1,175 @@
+use crate::tui_app::theme::palette;
+use deltective::tr;
+use crossterm::event::KeyCode;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Lines kept above a match scrolled to, so it isn't flush with the border.
+const CONTEXT_LINES: usize = 2;
+
+/// Vim-style `/` search over the text the active tab renders. Matches are
+/// found in the rendered cells rather than in each tab's data, so every tab
+/// is searchable the same way.
+#[derive(Default)]
+pub struct SearchState {
+    query: String,
+    editing: bool,
+    /// Tab the search was started on; it only applies there
+    tab: usize,
+    /// Content line of the match last jumped to
+    current: Option<usize>,
+    /// Content lines with a match, as of the last jump
+    matches: Vec<usize>,
+}
+
+impl SearchState {
+    pub fn start(&mut self, tab: usize) {
+        *self = Self {
+            editing: true,
+            tab,
+            ..Self::default()
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether the query prompt is capturing keystrokes.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Whether matches are highlighted on `tab`.
+    pub fn is_active(&self, tab: usize) -> bool {
+        self.tab == tab && !self.query.is_empty()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Handle a key while the query is typed. Returns true when a query was
+    /// submitted with Enter.
+    pub fn handle_edit_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Enter => {
+                self.editing = false;
+                if self.query.is_empty() {
+                    self.clear();
+                }
+                return !self.query.is_empty();
+            }
+            KeyCode::Esc => self.clear(),
+            KeyCode::Backspace => {
+                self.query.pop();
+            }
+            KeyCode::Char(c) => self.query.push(c),
+            _ => {}
+        }
+        false
+    }
+
+    /// Move to the next (or previous) of `lines`, the content lines that
+    /// match, wrapping around. The first jump goes to the first match at or
+    /// below `scroll`. Returns the scroll position that shows the match.
+    pub fn advance(&mut self, lines: Vec<usize>, forward: bool, scroll: u16) -> Option<u16> {
+        self.matches = lines;
+        let next = match (self.current, forward) {
+            (Some(current), true) => self.matches.iter().find(|&&line| line > current).or(self.matches.first()),
+            (Some(current), false) => self.matches.iter().rev().find(|&&line| line < current).or(self.matches.last()),
+            (None, _) => self
+                .matches
+                .iter()
+                .find(|&&line| line >= scroll as usize)
+                .or(self.matches.first()),
+        };
+        self.current = next.copied();
+        self.current.map(|line| line.saturating_sub(CONTEXT_LINES) as u16)
+    }
+}
+
+/// Content lines (0 being the first line inside the tab's border) of `area`
+/// that contain `query`, ignoring ASCII case.
+pub fn matching_lines(buffer: &Buffer, area: Rect, query: &str) -> Vec<usize> {
+    (area.top() + 1..area.bottom())
+        .filter(|&y| !find(&row_text(buffer, area, y).0, query).is_empty())
+        .map(|y| (y - area.top() - 1) as usize)
+        .collect()
+}
+
+/// Highlight every visible match of the search, the current one more
+/// strongly. `scroll` is the tab's scroll position.
+pub fn highlight(buffer: &mut Buffer, area: Rect, state: &SearchState, scroll: u16) {
+    let current_row = state
+        .current
+        .and_then(|line| line.checked_sub(scroll as usize))
+        .map(|offset| area.top() as usize + 1 + offset);
+    for y in area.top()..area.bottom() {
+        let (text, offsets) = row_text(buffer, area, y);
+        let style = if current_row == Some(y as usize) {
+            Style::default().bg(palette().accent).fg(Color::Black).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().bg(palette().warning).fg(Color::Black)
+        };
+        for (start, end) in find(&text, &state.query) {
+            for (x, _) in offsets.iter().filter(|(_, offset)| (start..end).contains(offset)) {
+                buffer.get_mut(*x, y).set_style(style);
+            }
+        }
+    }
+}
+
+/// The query prompt, or the match count once submitted, drawn over the
+/// bottom border of the tab.
+pub fn render_status(f: &mut Frame, area: Rect, state: &SearchState) {
+    if area.height < 2 || area.width < 4 {
+        return;
+    }
+    let mut spans = vec![Span::styled(format!(" /{}", state.query), Style::default().fg(palette().warning))];
+    if state.editing {
+        spans.push(Span::styled("▏", Style::default().fg(palette().warning)));
+    } else if state.matches.is_empty() {
+        spans.push(Span::styled(format!("  {} ", tr!("search-no-matches")), Style::default().fg(palette().critical)));
+    } else {
+        let position = state
+            .current
+            .and_then(|current| state.matches.iter().position(|&line| line == current))
+            .map_or(0, |i| i + 1);
+        spans.push(Span::styled(
+            format!("  {} ", tr!("search-position", position = position, matches = state.matches.len())),
+            Style::default().fg(palette().muted),
+        ));
+    }
+    let status = Rect::new(area.x + 1, area.bottom() - 1, area.width - 2, 1);
+    f.render_widget(Paragraph::new(Line::from(spans)), status);
+}
+
+/// Text of row `y` within `area`, with the byte offset in it where each
+/// cell's symbol starts.
+fn row_text(buffer: &Buffer, area: Rect, y: u16) -> (String, Vec<(u16, usize)>) {
+    let mut text = String::new();
+    let mut offsets = Vec::with_capacity(area.width as usize);
+    for x in area.left()..area.right() {
+        offsets.push((x, text.len()));
+        text.push_str(buffer.get(x, y).symbol());
+    }
+    (text, offsets)
+}
+
+/// Byte ranges of `query` in `text`, ignoring ASCII case. ASCII lowercasing
+/// keeps byte offsets unchanged.
+fn find(text: &str, query: &str) -> Vec<(usize, usize)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let text = text.to_ascii_lowercase();
+    let query = query.to_ascii_lowercase();
+    text.match_indices(&query).map(|(start, m)| (start, start + m.len())).collect()
+}
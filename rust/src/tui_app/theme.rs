@@ -0,0 +1,124 @@
+use anyhow::{bail, Result};
+use deltective::tr;
+use ratatui::style::Color;
+use std::sync::OnceLock;
+
+/// Built-in palettes, selectable with `--palette` or `palette:` in the config file.
+pub const PALETTE_NAMES: [&str; 4] = ["default", "high-contrast", "deuteranopia", "protanopia"];
+
+/// Colors the TUI draws with, by role. Severity is never shown by color
+/// alone: every severity-colored line also carries [`severity_marker`].
+pub struct Palette {
+    pub critical: Color,
+    pub warning: Color,
+    pub info: Color,
+    pub good: Color,
+    /// Headings and field labels
+    pub accent: Color,
+    /// Section titles and table headers
+    pub section: Color,
+    /// Secondary text: hints, timestamps' context, disabled features
+    pub muted: Color,
+    pub text: Color,
+    pub link: Color,
+    /// Background of the selected row or tab
+    pub selection: Color,
+}
+
+impl Palette {
+    pub fn named(name: &str) -> Option<Self> {
+        let palette = match name {
+            "default" => Self {
+                critical: Color::Red,
+                warning: Color::Yellow,
+                info: Color::Green,
+                good: Color::Green,
+                accent: Color::Cyan,
+                section: Color::Magenta,
+                muted: Color::DarkGray,
+                text: Color::White,
+                link: Color::Blue,
+                selection: Color::Blue,
+            },
+            // Bright colors only; dim grays become light gray
+            "high-contrast" => Self {
+                critical: Color::LightRed,
+                warning: Color::LightYellow,
+                info: Color::LightCyan,
+                good: Color::LightGreen,
+                accent: Color::White,
+                section: Color::LightMagenta,
+                muted: Color::Gray,
+                text: Color::White,
+                link: Color::LightBlue,
+                selection: Color::Blue,
+            },
+            // Okabe-Ito colors: blue/orange contrasts instead of red/green
+            "deuteranopia" => Self {
+                critical: Color::Rgb(213, 94, 0),
+                warning: Color::Rgb(240, 228, 66),
+                info: Color::Rgb(86, 180, 233),
+                good: Color::Rgb(0, 114, 178),
+                accent: Color::Rgb(86, 180, 233),
+                section: Color::Rgb(204, 121, 167),
+                muted: Color::Gray,
+                text: Color::White,
+                link: Color::Rgb(0, 114, 178),
+                selection: Color::Rgb(0, 114, 178),
+            },
+            // Reds look dark to protanopes, so critical uses the brighter orange
+            "protanopia" => Self {
+                critical: Color::Rgb(230, 159, 0),
+                warning: Color::Rgb(240, 228, 66),
+                info: Color::Rgb(86, 180, 233),
+                good: Color::Rgb(0, 114, 178),
+                accent: Color::Rgb(86, 180, 233),
+                section: Color::Rgb(204, 121, 167),
+                muted: Color::Gray,
+                text: Color::White,
+                link: Color::Rgb(0, 114, 178),
+                selection: Color::Rgb(0, 114, 178),
+            },
+            _ => return None,
+        };
+        Some(palette)
+    }
+
+    /// Color for an insight severity.
+    pub fn severity(&self, severity: &str) -> Color {
+        match severity {
+            "critical" => self.critical,
+            "warning" => self.warning,
+            "info" => self.info,
+            _ => self.good,
+        }
+    }
+}
+
+static ACTIVE: OnceLock<Palette> = OnceLock::new();
+
+/// Choose the palette for this run. Only the first call takes effect.
+pub fn set_palette(name: &str) -> Result<()> {
+    let Some(palette) = Palette::named(name) else {
+        bail!("Unknown palette '{}' (available: {})", name, PALETTE_NAMES.join(", "));
+    };
+    let _ = ACTIVE.set(palette);
+    Ok(())
+}
+
+/// The active palette.
+pub fn palette() -> &'static Palette {
+    ACTIVE.get_or_init(|| Palette::named("default").expect("default palette exists"))
+}
+
+/// Icon and label that identify a severity without relying on color,
+/// e.g. "🚨 CRITICAL".
+pub fn severity_marker(severity: &str) -> String {
+    let (icon, id) = match severity {
+        "critical" => ("🚨", "severity-critical"),
+        "warning" => ("⚠️", "severity-warning"),
+        "info" => ("💡", "severity-info"),
+        _ => ("✓", "severity-good"),
+    };
+    format!("{} {}", icon, tr!(id))
+}
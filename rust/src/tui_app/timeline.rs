@@ -1,172 +1,474 @@
-use crate::inspector::DeltaTableInspector;
-use chrono::{DateTime, Utc};
+use crate::tui_app::document::TuiRenderer;
+use crate::tui_app::theme::palette;
+use crate::tui_app::format_bytes;
+use deltective::attribution::Attribution;
+use deltective::growth::GrowthTrend;
+use deltective::inspector::{TableStatistics, TimelineAnalysis};
+use deltective::render::{Renderable, Renderer};
+use deltective::tr;
+use chrono::{DateTime, Datelike, Days, NaiveDate, Timelike};
 use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
+    layout::{Constraint, Layout, Rect},
+    style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    symbols::Marker,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph, Sparkline},
     Frame,
 };
 
-pub fn render(f: &mut Frame, area: Rect, table_path: &str, inspector: &DeltaTableInspector, scroll: u16) {
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    let timeline_result = rt.block_on(inspector.get_timeline_analysis());
+/// Most days the activity sparkline covers; fewer when the tab is narrower
+const ACTIVITY_DAYS: usize = 90;
+/// Rows taken by the sparkline, borders included
+const ACTIVITY_HEIGHT: u16 = 6;
+/// Rows taken by the growth chart, borders included
+const GROWTH_HEIGHT: u16 = 12;
+/// How far past the latest commit the growth forecast is drawn, as a share
+/// of the days charted
+const FORECAST_SHARE: f64 = 0.5;
+const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+const WEEKDAYS: [&str; 7] = [
+    "timeline-mon",
+    "timeline-tue",
+    "timeline-wed",
+    "timeline-thu",
+    "timeline-fri",
+    "timeline-sat",
+    "timeline-sun",
+];
+/// Engines, users and jobs listed per group in the attribution section
+const TOP_CONTRIBUTORS: usize = 5;
+/// Heatmap cells from no commits to the busiest hour
+const HEAT_SHADES: [&str; 5] = ["··", "░░", "▒▒", "▓▓", "██"];
+
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    timeline: &TimelineAnalysis,
+    stats: Option<&TableStatistics>,
+    size_forecast_gb: &[f64],
+    scroll: u16,
+) {
+    let area = if timeline.total_operations > 0 && area.height >= ACTIVITY_HEIGHT * 2 {
+        let [chart, rest] = Layout::vertical([Constraint::Length(ACTIVITY_HEIGHT), Constraint::Min(0)]).areas(area);
+        render_activity(f, chart, timeline);
+        rest
+    } else {
+        area
+    };
+    let growth = stats.and_then(|stats| stats.growth.as_ref());
+    let area = match growth {
+        Some(growth) if area.height >= GROWTH_HEIGHT * 2 => {
+            let [chart, rest] = Layout::vertical([Constraint::Length(GROWTH_HEIGHT), Constraint::Min(0)]).areas(area);
+            render_growth(f, chart, growth);
+            rest
+        }
+        _ => area,
+    };
 
     let mut lines = Vec::new();
 
     lines.push(Line::from(vec![
-        Span::styled("═══ TABLE TIMELINE & ACTIVITY ═══", Style::default().fg(Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(tr!("timeline-heading"), Style::default().fg(palette().accent).add_modifier(ratatui::style::Modifier::BOLD)),
     ]));
     lines.push(Line::from(""));
 
-    match timeline_result {
-        Ok(timeline) => {
-            // Activity Summary
-            lines.push(Line::from(vec![
-                Span::styled("📊 Activity Summary", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
-            ]));
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![
-                Span::styled("  Total Operations: ", Style::default().fg(Color::Cyan)),
-                Span::styled(format!("{}", timeline.total_operations), Style::default().fg(Color::Green)),
-            ]));
-            lines.push(Line::from(vec![
-                Span::styled("  Version Creation Rate: ", Style::default().fg(Color::Cyan)),
-                Span::styled(format!("{:.2} versions/day", timeline.version_creation_rate), Style::default().fg(Color::Green)),
-            ]));
+    // Activity Summary
+    lines.push(Line::from(vec![
+        Span::styled(tr!("timeline-activity-summary"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {} ", tr!("timeline-total-operations")), Style::default().fg(palette().accent)),
+        Span::styled(format!("{}", timeline.total_operations), Style::default().fg(palette().good)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {} ", tr!("timeline-version-rate")), Style::default().fg(palette().accent)),
+        Span::styled(tr!("timeline-versions-per-day", rate = format!("{:.2}", timeline.version_creation_rate)), Style::default().fg(palette().good)),
+    ]));
 
-            // First and Latest Operations
-            if let Some(first_op) = &timeline.first_operation {
-                let first_time = DateTime::from_timestamp(first_op.timestamp.unwrap_or(0) / 1000, 0)
-                    .unwrap_or_default();
-                let op_name = first_op.operation.as_deref().unwrap_or("Unknown");
-                lines.push(Line::from(vec![
-                    Span::styled("  First Operation: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(first_time.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(Color::Green)),
-                    Span::styled(format!(" ({})", op_name), Style::default().fg(Color::DarkGray)),
-                ]));
-            }
+    if let Some(growth) = growth {
+        lines.extend(growth_lines(growth, size_forecast_gb));
+    }
 
-            if let Some(latest_op) = &timeline.latest_operation {
-                let latest_time = DateTime::from_timestamp(latest_op.timestamp.unwrap_or(0) / 1000, 0)
-                    .unwrap_or_default();
-                let op_name = latest_op.operation.as_deref().unwrap_or("Unknown");
-                lines.push(Line::from(vec![
-                    Span::styled("  Latest Operation: ", Style::default().fg(Color::Cyan)),
-                    Span::styled(latest_time.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(Color::Green)),
-                    Span::styled(format!(" ({})", op_name), Style::default().fg(Color::DarkGray)),
-                ]));
-            }
+    // First and Latest Operations
+    if let Some(first_op) = &timeline.first_operation {
+        let first_time = DateTime::from_timestamp(first_op.timestamp.unwrap_or(0) / 1000, 0)
+            .unwrap_or_default();
+        let op_name = first_op.operation.clone().unwrap_or_else(|| tr!("timeline-unknown-operation"));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("timeline-first-operation")), Style::default().fg(palette().accent)),
+            Span::styled(first_time.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(palette().good)),
+            Span::styled(format!(" ({})", op_name), Style::default().fg(palette().muted)),
+        ]));
+    }
 
-            // Operations by Type
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![
-                Span::styled("📈 Operations by Type", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
-            ]));
-            lines.push(Line::from(""));
-
-            if !timeline.operations_by_type.is_empty() {
-                let mut sorted_ops: Vec<_> = timeline.operations_by_type.iter().collect();
-                sorted_ops.sort_by_key(|(_, count)| **count);
-                sorted_ops.reverse();
-
-                let max_count = sorted_ops.first().map(|(_, c)| **c).unwrap_or(1) as f64;
-
-                for (op_type, count) in sorted_ops.iter().take(10) {
-                    let bar_width = ((**count as f64 / max_count) * 30.0) as usize;
-                    let bar = "█".repeat(bar_width);
-                    let pct = if timeline.total_operations > 0 {
-                        (**count as f64 / timeline.total_operations as f64) * 100.0
-                    } else {
-                        0.0
-                    };
-                    lines.push(Line::from(vec![
-                        Span::styled(format!("  {:15}", op_type), Style::default().fg(Color::Cyan)),
-                        Span::styled(bar, Style::default().fg(Color::Green)),
-                        Span::raw(format!(" {:4} ({:.1}%)", count, pct)),
-                    ]));
-                }
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("  No operation data available", Style::default().fg(Color::DarkGray)),
-                ]));
-            }
+    if let Some(latest_op) = &timeline.latest_operation {
+        let latest_time = DateTime::from_timestamp(latest_op.timestamp.unwrap_or(0) / 1000, 0)
+            .unwrap_or_default();
+        let op_name = latest_op.operation.clone().unwrap_or_else(|| tr!("timeline-unknown-operation"));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("timeline-latest-operation")), Style::default().fg(palette().accent)),
+            Span::styled(latest_time.format("%Y-%m-%d %H:%M:%S").to_string(), Style::default().fg(palette().good)),
+            Span::styled(format!(" ({})", op_name), Style::default().fg(palette().muted)),
+        ]));
+    }
 
-            // Write Patterns Analysis
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![
-                Span::styled("🔍 Write Pattern Analysis", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
-            ]));
-            lines.push(Line::from(""));
+    // Operations by Type
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("timeline-operations-by-type"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
 
-            if timeline.write_patterns.is_empty() {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✓", Style::default().fg(Color::Green)),
-                    Span::raw(" No unusual write patterns detected"),
-                ]));
-            } else {
-                for pattern in &timeline.write_patterns {
-                    lines.push(Line::from(vec![
-                        Span::raw("  • "),
-                        Span::styled(pattern, Style::default().fg(Color::Yellow)),
-                    ]));
-                }
-            }
+    if !timeline.operations_by_type.is_empty() {
+        let mut sorted_ops: Vec<_> = timeline.operations_by_type.iter().collect();
+        sorted_ops.sort_by_key(|(_, count)| **count);
+        sorted_ops.reverse();
+
+        let max_count = sorted_ops.first().map(|(_, c)| **c).unwrap_or(1) as f64;
 
-            // Timeline Insights
-            lines.push(Line::from(""));
+        for (op_type, count) in sorted_ops.iter().take(10) {
+            let bar_width = ((**count as f64 / max_count) * 30.0) as usize;
+            let bar = "█".repeat(bar_width);
+            let pct = if timeline.total_operations > 0 {
+                (**count as f64 / timeline.total_operations as f64) * 100.0
+            } else {
+                0.0
+            };
             lines.push(Line::from(vec![
-                Span::styled("💡 Timeline Insights", Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD)),
+                Span::styled(format!("  {:15}", op_type), Style::default().fg(palette().accent)),
+                Span::styled(bar, Style::default().fg(palette().good)),
+                Span::raw(format!(" {:4} ({:.1}%)", count, pct)),
             ]));
-            lines.push(Line::from(""));
-
-            if timeline.version_creation_rate > 100.0 {
-                lines.push(Line::from(vec![
-                    Span::styled("  ⚠️", Style::default().fg(Color::Yellow)),
-                    Span::styled("  Very high version creation rate", Style::default().fg(Color::Yellow)),
-                ]));
-                lines.push(Line::from(vec![
-                    Span::styled("     ", Style::default().fg(Color::DarkGray)),
-                    Span::raw("Consider running OPTIMIZE more frequently to manage file growth"),
-                ]));
-            } else if timeline.version_creation_rate > 10.0 {
-                lines.push(Line::from(vec![
-                    Span::styled("  ℹ️", Style::default().fg(Color::Cyan)),
-                    Span::styled("  Moderate version creation rate", Style::default().fg(Color::Cyan)),
-                ]));
-                lines.push(Line::from(vec![
-                    Span::styled("     ", Style::default().fg(Color::DarkGray)),
-                    Span::raw("Regular OPTIMIZE operations recommended"),
-                ]));
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("  ✓", Style::default().fg(Color::Green)),
-                    Span::styled("  Normal version creation rate", Style::default().fg(Color::Green)),
-                ]));
-            }
-
-            if timeline.total_operations > 100 {
-                lines.push(Line::from(vec![
-                    Span::styled("  ℹ️", Style::default().fg(Color::Cyan)),
-                    Span::styled(format!("  Table has extensive history ({} operations)", timeline.total_operations), Style::default().fg(Color::Cyan)),
-                ]));
-                lines.push(Line::from(vec![
-                    Span::styled("     ", Style::default().fg(Color::DarkGray)),
-                    Span::raw("Consider periodic VACUUM to manage storage costs"),
-                ]));
-            }
         }
-        Err(_) => {
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}", tr!("timeline-no-operations")), Style::default().fg(palette().muted)),
+        ]));
+    }
+
+    // Engines, users and jobs
+    if timeline.total_operations > 0 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(tr!("timeline-who-writes"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+        ]));
+        lines.extend(attribution_lines(&timeline.attribution));
+    }
+
+    // Commit Heatmap
+    if timeline.total_operations > 0 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(tr!("timeline-heatmap-heading"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+        ]));
+        lines.push(Line::from(""));
+        lines.extend(heatmap_lines(timeline));
+    }
+
+    // Write Patterns Analysis
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("timeline-write-patterns"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+
+    if timeline.write_patterns.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  ✓", Style::default().fg(palette().good)),
+            Span::raw(format!(" {}", tr!("timeline-no-write-patterns"))),
+        ]));
+    } else {
+        for pattern in &timeline.write_patterns {
             lines.push(Line::from(vec![
-                Span::styled("Loading timeline data...", Style::default().fg(Color::DarkGray)),
+                Span::raw("  • "),
+                Span::styled(pattern, Style::default().fg(palette().warning)),
             ]));
         }
     }
 
+    // CDF consumers declared in the config file
+    let consumers = stats.map_or(&[][..], |stats| stats.cdf_consumers.as_slice());
+    if !consumers.is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(TuiRenderer.render(&consumers.document()));
+    }
+
+    // Applications recording progress in txn actions
+    let writers = stats.map_or(&[][..], |stats| stats.streaming_writers.as_slice());
+    if !writers.is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(TuiRenderer.render(&writers.document()));
+    }
+
+    // Timeline Insights
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(tr!("timeline-insights"), Style::default().fg(palette().section).add_modifier(ratatui::style::Modifier::BOLD)),
+    ]));
+    lines.push(Line::from(""));
+
+    if timeline.version_creation_rate > 100.0 {
+        lines.push(Line::from(vec![
+            Span::styled("  ⚠️", Style::default().fg(palette().warning)),
+            Span::styled(format!("  {}", tr!("timeline-rate-very-high")), Style::default().fg(palette().warning)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("     ", Style::default().fg(palette().muted)),
+            Span::raw(tr!("timeline-rate-very-high-hint")),
+        ]));
+    } else if timeline.version_creation_rate > 10.0 {
+        lines.push(Line::from(vec![
+            Span::styled("  ℹ️", Style::default().fg(palette().accent)),
+            Span::styled(format!("  {}", tr!("timeline-rate-moderate")), Style::default().fg(palette().accent)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("     ", Style::default().fg(palette().muted)),
+            Span::raw(tr!("timeline-rate-moderate-hint")),
+        ]));
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled("  ✓", Style::default().fg(palette().good)),
+            Span::styled(format!("  {}", tr!("timeline-rate-normal")), Style::default().fg(palette().good)),
+        ]));
+    }
+
+    if timeline.total_operations > 100 {
+        lines.push(Line::from(vec![
+            Span::styled("  ℹ️", Style::default().fg(palette().accent)),
+            Span::styled(format!("  {}", tr!("timeline-extensive-history", operations = timeline.total_operations)), Style::default().fg(palette().accent)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("     ", Style::default().fg(palette().muted)),
+            Span::raw(tr!("timeline-extensive-history-hint")),
+        ]));
+    }
+
     let paragraph = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL).title("Timeline [↑↓ scroll]"))
+        .block(Block::default().borders(Borders::ALL).title(tr!("timeline-block-title")))
         .scroll((scroll, 0));
 
     f.render_widget(paragraph, area);
 }
 
+/// Commits per day as a sparkline, one column per day up to the day of the
+/// latest commit. Days without commits are included, so gaps show.
+fn render_activity(f: &mut Frame, area: Rect, timeline: &TimelineAnalysis) {
+    let days = ACTIVITY_DAYS.min(area.width.saturating_sub(2) as usize).max(1);
+    let Some((first_day, counts)) = daily_commits(timeline, days) else {
+        return;
+    };
+    let last_day = first_day + Days::new(counts.len() as u64 - 1);
+    let peak = counts.iter().max().copied().unwrap_or(0);
+    let title = tr!(
+        "timeline-activity-title",
+        from = first_day.format("%Y-%m-%d").to_string(),
+        to = last_day.format("%Y-%m-%d").to_string(),
+        peak = peak
+    );
+
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(&counts)
+        .style(Style::default().fg(palette().good));
+
+    f.render_widget(sparkline, area);
+}
+
+/// Commit counts for the `days` days ending at the latest day with a commit,
+/// and the first of those days.
+fn daily_commits(timeline: &TimelineAnalysis, days: usize) -> Option<(NaiveDate, Vec<u64>)> {
+    let counts: Vec<(NaiveDate, u64)> = timeline
+        .operations_by_day
+        .iter()
+        .filter_map(|(day, commits)| Some((NaiveDate::parse_from_str(day, "%Y-%m-%d").ok()?, commits.len() as u64)))
+        .collect();
+    let last_day = counts.iter().map(|(day, _)| *day).max()?;
+    let first_day = last_day - Days::new(days as u64 - 1);
+
+    let mut series = vec![0; days];
+    for (day, count) in counts {
+        if let Ok(offset) = usize::try_from((day - first_day).num_days()) {
+            if offset < days {
+                series[offset] = count;
+            }
+        }
+    }
+    Some((first_day, series))
+}
+
+/// Hour-of-day × day-of-week grid of commit counts, shaded relative to the
+/// busiest cell, followed by the quietest hour as a maintenance window hint.
+fn heatmap_lines(timeline: &TimelineAnalysis) -> Vec<Line<'static>> {
+    let mut grid = [[0usize; 24]; 7];
+    for commit in timeline.operations_by_day.values().flatten() {
+        if let Some(time) = commit.timestamp.and_then(DateTime::from_timestamp_millis) {
+            grid[time.weekday().num_days_from_monday() as usize][time.hour() as usize] += 1;
+        }
+    }
+    let peak = grid.iter().flatten().max().copied().unwrap_or(0).max(1);
+
+    let mut lines = Vec::new();
+    let hours: String = (0..24).map(|hour| if hour % 3 == 0 { format!("{:<6}", format!("{:02}", hour)) } else { String::new() }).collect();
+    lines.push(Line::from(Span::styled(format!("       {}", hours), Style::default().fg(palette().muted))));
+    for (day, counts) in WEEKDAYS.iter().zip(&grid) {
+        let mut spans = vec![Span::styled(format!("  {:<3}  ", tr!(*day)), Style::default().fg(palette().accent))];
+        for &count in counts {
+            // Any commit at all shows at least the lightest shade
+            let level = if count == 0 { 0 } else { 1 + (count - 1) * (HEAT_SHADES.len() - 1) / peak };
+            let color = if count == 0 { palette().muted } else { palette().good };
+            spans.push(Span::styled(HEAT_SHADES[level], Style::default().fg(color)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let by_hour: Vec<usize> = (0..24).map(|hour| grid.iter().map(|day| day[hour]).sum()).collect();
+    if let Some((hour, count)) = by_hour.iter().enumerate().min_by_key(|(_, count)| **count) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("timeline-quietest-hour")), Style::default().fg(palette().accent)),
+            Span::styled(tr!("timeline-hour-range", from = format!("{:02}", hour), to = format!("{:02}", (hour + 1) % 24)), Style::default().fg(palette().good)),
+            Span::styled(format!(" {}", tr!("timeline-quietest-hour-hint", commits = count)), Style::default().fg(palette().muted)),
+        ]));
+    }
+    lines
+}
+
+/// Table size in GB after each recent commit, with the linear trend drawn on
+/// past the latest commit.
+fn render_growth(f: &mut Frame, area: Rect, growth: &GrowthTrend) {
+    let Some(origin) = growth.points.first().map(|p| p.timestamp) else {
+        return;
+    };
+    let days = |t: DateTime<chrono::Utc>| (t - origin).num_milliseconds() as f64 / (24.0 * 60.0 * 60.0 * 1000.0);
+    let sizes: Vec<(f64, f64)> = growth.points.iter().map(|p| (days(p.timestamp), p.size_bytes as f64 / GB)).collect();
+
+    let span = growth.span_days().max(1.0);
+    let (last_day, last_size) = sizes.last().copied().unwrap_or_default();
+    let end_day = last_day + span * FORECAST_SHARE;
+    let forecast = [(last_day, last_size), (end_day, (last_size + growth.bytes_per_day / GB * span * FORECAST_SHARE).max(0.0))];
+
+    let max_size = sizes.iter().chain(&forecast).map(|(_, size)| *size).fold(0.0, f64::max).max(f64::EPSILON);
+    let datasets = vec![
+        Dataset::default()
+            .name(tr!("timeline-size-series"))
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(palette().good))
+            .data(&sizes),
+        Dataset::default()
+            .name(tr!("timeline-forecast-series"))
+            .marker(Marker::Dot)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(palette().warning))
+            .data(&forecast),
+    ];
+    let date_label = |day: f64| {
+        (origin + chrono::Duration::milliseconds((day * 24.0 * 60.0 * 60.0 * 1000.0) as i64)).format("%Y-%m-%d").to_string()
+    };
+    let chart = Chart::new(datasets)
+        .block(Block::default().borders(Borders::ALL).title(tr!("timeline-growth-title", commits = growth.points.len())))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(palette().muted))
+                .bounds([0.0, end_day])
+                .labels(vec![Span::raw(date_label(0.0)), Span::raw(date_label(last_day)), Span::raw(date_label(end_day))]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(palette().muted))
+                .bounds([0.0, max_size * 1.1])
+                .labels(vec![Span::raw(tr!("timeline-gb", size = "0")), Span::raw(tr!("timeline-gb", size = format!("{:.1}", max_size * 1.1)))]),
+        );
+
+    f.render_widget(chart, area);
+}
+
+/// Growth rates and when each of `size_forecast_gb` is reached at the
+/// overall rate.
+fn growth_lines(growth: &GrowthTrend, size_forecast_gb: &[f64]) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!("  {} ", tr!("timeline-growth")), Style::default().fg(palette().accent)),
+        Span::styled(
+            tr!("timeline-growth-rate", bytes = signed_bytes(growth.bytes_per_day), files = format!("{:+.1}", growth.files_per_day)),
+            Style::default().fg(palette().good),
+        ),
+        Span::styled(
+            format!(" {}", tr!("timeline-growth-window", commits = growth.points.len(), days = format!("{:.0}", growth.span_days()))),
+            Style::default().fg(palette().muted),
+        ),
+    ])];
+    if let (Some(earlier), Some(recent)) = (growth.earlier_bytes_per_day, growth.recent_bytes_per_day) {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("timeline-recent-vs-earlier")), Style::default().fg(palette().accent)),
+            Span::raw(tr!("timeline-rate-comparison", recent = signed_bytes(recent), earlier = signed_bytes(earlier))),
+        ]));
+    }
+
+    let mut thresholds = size_forecast_gb.to_vec();
+    thresholds.sort_by(f64::total_cmp);
+    for gb in thresholds {
+        let Some(forecast) = growth.forecast((gb * GB) as i64) else {
+            continue;
+        };
+        let (text, color) = match forecast.reached_at {
+            _ if forecast.already_reached => (tr!("timeline-already-reached"), palette().muted),
+            Some(at) => (tr!("timeline-reached-around", date = at.format("%Y-%m-%d").to_string()), palette().warning),
+            None => (tr!("timeline-not-reached"), palette().muted),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!("timeline-reaches", size = format_bytes(forecast.threshold_bytes))), Style::default().fg(palette().accent)),
+            Span::styled(text, Style::default().fg(color)),
+        ]));
+    }
+    lines
+}
+
+/// A byte rate that may be negative, for tables shrinking over the window.
+fn signed_bytes(bytes: f64) -> String {
+    if bytes < 0.0 {
+        format!("-{}", format_bytes(-bytes as i64))
+    } else {
+        format_bytes(bytes as i64)
+    }
+}
+
+/// The busiest engines, users and jobs with their share of the commits and
+/// the bytes they wrote.
+fn attribution_lines(attribution: &Attribution) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for (title, contributors) in [
+        ("timeline-engines", &attribution.engines),
+        ("timeline-users", &attribution.users),
+        ("timeline-jobs", &attribution.jobs),
+    ] {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(format!("  {}", tr!(title)), Style::default().fg(palette().accent))));
+        for contributor in contributors.iter().take(TOP_CONTRIBUTORS) {
+            let share = attribution.share(contributor);
+            let color = if contributor.name == "unknown" { palette().muted } else { palette().text };
+            lines.push(Line::from(vec![
+                Span::styled(format!("    {:<40}", truncate(&contributor.name, 40)), Style::default().fg(color)),
+                Span::styled("█".repeat((share / 100.0 * 20.0).ceil() as usize), Style::default().fg(palette().good)),
+                Span::raw(format!(" {} ({:.1}%)", contributor.commits, share)),
+                Span::styled(format!(", {}", format_bytes(contributor.bytes_written)), Style::default().fg(palette().muted)),
+            ]));
+        }
+        if contributors.len() > TOP_CONTRIBUTORS {
+            lines.push(Line::from(Span::styled(
+                format!("    {}", tr!("timeline-more-contributors", count = contributors.len() - TOP_CONTRIBUTORS)),
+                Style::default().fg(palette().muted),
+            )));
+        }
+    }
+    lines
+}
+
+/// Truncate long names from the right, keeping the engine or user prefix.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max - 1).collect();
+    format!("{}…", kept)
+}
@@ -0,0 +1,189 @@
+use crate::tui_app::insights;
+use crate::tui_app::theme::palette;
+use crossterm::event::KeyCode;
+use deltective::config::Config;
+use deltective::insights::{AnalyzerThresholds, Insight};
+use deltective::inspector::TableStatistics;
+use deltective::tr;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Values offered for the file size thresholds, in MB.
+const SIZE_STEPS_MB: [f64; 12] = [1.0, 2.0, 4.0, 8.0, 10.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0, 1024.0];
+/// Values offered for the file count threshold.
+const FILE_COUNT_STEPS: [usize; 10] = [100, 250, 500, 1000, 2500, 5000, 10_000, 25_000, 50_000, 100_000];
+
+#[derive(Clone, Copy)]
+enum Field {
+    SmallFileMb,
+    TargetFileMb,
+    MaxFiles,
+}
+
+const FIELDS: [Field; 3] = [Field::SmallFileMb, Field::TargetFileMb, Field::MaxFiles];
+
+impl Field {
+    fn label(self) -> String {
+        match self {
+            Field::SmallFileMb => tr!("what-if-small-file"),
+            Field::TargetFileMb => tr!("what-if-target-file"),
+            Field::MaxFiles => tr!("what-if-max-files"),
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Field::SmallFileMb => "small_file_mb",
+            Field::TargetFileMb => "target_file_mb",
+            Field::MaxFiles => "max_files",
+        }
+    }
+
+    fn value(self, thresholds: &AnalyzerThresholds) -> String {
+        match self {
+            Field::SmallFileMb => thresholds.small_file_mb.to_string(),
+            Field::TargetFileMb => thresholds.target_file_mb.to_string(),
+            Field::MaxFiles => thresholds.max_files.to_string(),
+        }
+    }
+
+    /// Move to the next offered value above (`up`) or below the current one.
+    fn step(self, thresholds: &mut AnalyzerThresholds, up: bool) {
+        fn next<T: PartialOrd + Copy>(steps: &[T], current: T, up: bool) -> T {
+            let found = if up {
+                steps.iter().find(|s| **s > current)
+            } else {
+                steps.iter().rev().find(|s| **s < current)
+            };
+            found.copied().unwrap_or(current)
+        }
+        match self {
+            Field::SmallFileMb => thresholds.small_file_mb = next(&SIZE_STEPS_MB, thresholds.small_file_mb, up),
+            Field::TargetFileMb => thresholds.target_file_mb = next(&SIZE_STEPS_MB, thresholds.target_file_mb, up),
+            Field::MaxFiles => thresholds.max_files = next(&FILE_COUNT_STEPS, thresholds.max_files, up),
+        }
+    }
+}
+
+/// What-if panel on the Insights tab: a working copy of the analyzer
+/// thresholds that the insights are re-evaluated against as it changes,
+/// for calibrating values before putting them in the config file.
+pub struct WhatIfState {
+    thresholds: AnalyzerThresholds,
+    selected: usize,
+}
+
+impl WhatIfState {
+    pub fn new(configured: &AnalyzerThresholds) -> Self {
+        Self {
+            thresholds: configured.clone(),
+            selected: 0,
+        }
+    }
+
+    /// Handle a panel key. Returns whether the key was consumed.
+    pub fn handle_key(&mut self, key: KeyCode, configured: &AnalyzerThresholds) -> bool {
+        let field = FIELDS[self.selected];
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(FIELDS.len() - 1),
+            KeyCode::Left | KeyCode::Char('-') | KeyCode::Char('h') => field.step(&mut self.thresholds, false),
+            KeyCode::Right | KeyCode::Char('+') | KeyCode::Char('l') => field.step(&mut self.thresholds, true),
+            KeyCode::Char('0') => self.thresholds = configured.clone(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Draw the threshold panel beside the insights as they would be with its
+/// values.
+pub fn render(f: &mut Frame, area: Rect, state: &WhatIfState, stats: &TableStatistics, config: &Config, scroll: u16) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(46), Constraint::Min(0)])
+        .split(area);
+
+    let what_if = Config {
+        thresholds: state.thresholds.clone(),
+        ..config.clone()
+    };
+    let before = config.analyzer(stats.clone()).analyze();
+    let after = what_if.analyzer(stats.clone()).analyze();
+
+    let mut lines = Vec::new();
+    for (i, field) in FIELDS.iter().enumerate() {
+        let selected = i == state.selected;
+        let value = field.value(&state.thresholds);
+        let configured = field.value(&config.thresholds);
+        let value_style = if value != configured {
+            Style::default().fg(palette().warning).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(palette().text)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} {} ", if selected { "▸" } else { " " }, field.label()),
+                Style::default().fg(palette().accent),
+            ),
+            Span::styled(if selected { format!("◀ {} ▶", value) } else { value }, value_style),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("    {}", tr!("what-if-configured", value = configured)),
+            Style::default().fg(palette().muted),
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        tr!("what-if-effect"),
+        Style::default().fg(palette().section).add_modifier(Modifier::BOLD),
+    )));
+    let severities = [
+        ("critical", "insights-summary-critical"),
+        ("warning", "insights-summary-warnings"),
+        ("info", "insights-summary-info"),
+    ];
+    for (severity, message) in severities {
+        let count = |insights: &[Insight]| insights.iter().filter(|i| i.severity == severity).count();
+        let (was, now) = (count(&before), count(&after));
+        let style = if was != now { Style::default().fg(palette().warning) } else { Style::default() };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", tr!(message)), Style::default().fg(palette().severity(severity))),
+            Span::styled(format!("{} → {}", was, now), style),
+        ]));
+    }
+    for insight in after.iter().filter(|a| !before.iter().any(|b| b.title == a.title)) {
+        lines.push(Line::from(Span::styled(format!("  + {}", insight.title), Style::default().fg(palette().warning))));
+    }
+    for insight in before.iter().filter(|b| !after.iter().any(|a| a.title == b.title)) {
+        lines.push(Line::from(Span::styled(format!("  − {}", insight.title), Style::default().fg(palette().good))));
+    }
+
+    // Ready to paste into the config file
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        tr!("what-if-snippet"),
+        Style::default().fg(palette().section).add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from("  thresholds:"));
+    for field in FIELDS {
+        lines.push(Line::from(format!("    {}: {}", field.key(), field.value(&state.thresholds))));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(tr!("what-if-hint"), Style::default().fg(palette().muted))));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(tr!("what-if-title"))),
+        chunks[0],
+    );
+    insights::render(f, chunks[1], stats, &what_if, scroll);
+}
@@ -0,0 +1,468 @@
+use crate::inspector::ConfigurationInfo;
+use crate::render::{Document, Item, Renderable, Tone};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// Reader/writer versions that enable table features instead of fixed feature sets.
+const TABLE_FEATURES_READER_VERSION: i32 = 3;
+const TABLE_FEATURES_WRITER_VERSION: i32 = 7;
+
+/// What the Delta protocol requires to turn on one table feature.
+pub struct FeatureSpec {
+    pub name: &'static str,
+    /// Property that enables the feature, if it is switched on by a property
+    pub property: Option<(&'static str, &'static str)>,
+    /// Legacy (reader, writer) versions that imply the feature, if any
+    pub legacy_versions: Option<(i32, i32)>,
+    /// Whether readers must understand the feature (reader + writer feature)
+    pub reader_feature: bool,
+    /// Features that must be enabled first
+    pub requires: &'static [&'static str],
+    pub engines: &'static str,
+    pub irreversible: &'static str,
+    pub notes: &'static str,
+}
+
+pub const FEATURES: &[FeatureSpec] = &[
+    FeatureSpec {
+        name: "appendOnly",
+        property: Some(("delta.appendOnly", "true")),
+        legacy_versions: Some((1, 2)),
+        reader_feature: false,
+        requires: &[],
+        engines: "Any Delta writer supporting writer version 2",
+        irreversible: "",
+        notes: "UPDATE, DELETE and MERGE will be rejected while the property is set.",
+    },
+    FeatureSpec {
+        name: "checkConstraints",
+        property: None,
+        legacy_versions: Some((1, 3)),
+        reader_feature: false,
+        requires: &[],
+        engines: "Delta Lake 0.8+, Databricks Runtime 7.4+, delta-rs 0.17+",
+        irreversible: "",
+        notes: "Add constraints with ALTER TABLE ... ADD CONSTRAINT; existing rows are validated.",
+    },
+    FeatureSpec {
+        name: "changeDataFeed",
+        property: Some(("delta.enableChangeDataFeed", "true")),
+        legacy_versions: Some((1, 4)),
+        reader_feature: false,
+        requires: &[],
+        engines: "Delta Lake 2.0+, Databricks Runtime 8.4+, delta-rs 0.17+",
+        irreversible: "",
+        notes: "Only changes committed after enabling are captured; writes produce extra _change_data files.",
+    },
+    FeatureSpec {
+        name: "generatedColumns",
+        property: None,
+        legacy_versions: Some((1, 4)),
+        reader_feature: false,
+        requires: &[],
+        engines: "Delta Lake 1.0+, Databricks Runtime 8.3+, delta-rs 0.17+",
+        irreversible: "",
+        notes: "Generated columns can only be defined when a column is added or the table is created.",
+    },
+    FeatureSpec {
+        name: "columnMapping",
+        property: Some(("delta.columnMapping.mode", "name")),
+        legacy_versions: Some((2, 5)),
+        reader_feature: true,
+        requires: &[],
+        engines: "Delta Lake 1.2+, Databricks Runtime 10.2+, delta-rs 0.19+ (reads)",
+        irreversible: "Column mapping cannot be turned off again without rewriting the table.",
+        notes: "Enables column rename/drop without rewriting data files.",
+    },
+    FeatureSpec {
+        name: "identityColumns",
+        property: None,
+        legacy_versions: Some((1, 6)),
+        reader_feature: false,
+        requires: &[],
+        engines: "Delta Lake 3.0+, Databricks Runtime 10.4+",
+        irreversible: "",
+        notes: "Identity columns are declared with GENERATED ... AS IDENTITY when adding the column.",
+    },
+    FeatureSpec {
+        name: "deletionVectors",
+        property: Some(("delta.enableDeletionVectors", "true")),
+        legacy_versions: None,
+        reader_feature: true,
+        requires: &[],
+        engines: "Delta Lake 2.3+ (read) / 2.4+ (write), Databricks Runtime 12.2 LTS+, delta-rs 0.17+ (read)",
+        irreversible: "Readers without deletion vector support can no longer read the table. Removing the feature needs ALTER TABLE ... DROP FEATURE (Delta 3.1+/DBR 14.1+) and history truncation.",
+        notes: "Existing files are not rewritten; subsequent DELETE/UPDATE/MERGE write deletion vectors. Run REORG TABLE ... APPLY (PURGE) to materialize them.",
+    },
+    FeatureSpec {
+        name: "timestampNtz",
+        property: None,
+        legacy_versions: None,
+        reader_feature: true,
+        requires: &[],
+        engines: "Delta Lake 2.4+, Databricks Runtime 13.3 LTS+, delta-rs 0.17+",
+        irreversible: "Readers without TIMESTAMP_NTZ support can no longer read the table.",
+        notes: "Enabled automatically when a TIMESTAMP_NTZ column is added.",
+    },
+    FeatureSpec {
+        name: "domainMetadata",
+        property: None,
+        legacy_versions: None,
+        reader_feature: false,
+        requires: &[],
+        engines: "Delta Lake 3.0+, Databricks Runtime 13.3 LTS+, delta-rs 0.18+",
+        irreversible: "",
+        notes: "Usually enabled implicitly by features that store domain metadata.",
+    },
+    FeatureSpec {
+        name: "v2Checkpoint",
+        property: Some(("delta.checkpointPolicy", "v2")),
+        legacy_versions: None,
+        reader_feature: true,
+        requires: &[],
+        engines: "Delta Lake 3.0+, Databricks Runtime 13.3 LTS+",
+        irreversible: "Readers without V2 checkpoint support can no longer read the table.",
+        notes: "",
+    },
+    FeatureSpec {
+        name: "rowTracking",
+        property: Some(("delta.enableRowTracking", "true")),
+        legacy_versions: None,
+        reader_feature: false,
+        requires: &["domainMetadata"],
+        engines: "Delta Lake 3.2+, Databricks Runtime 14.1+",
+        irreversible: "",
+        notes: "Row IDs are only assigned to files written after enabling; backfill runs as part of the upgrade.",
+    },
+    FeatureSpec {
+        name: "clustering",
+        property: None,
+        legacy_versions: None,
+        reader_feature: false,
+        requires: &["domainMetadata"],
+        engines: "Delta Lake 3.1+, Databricks Runtime 13.3 LTS+",
+        irreversible: "",
+        notes: "Enable liquid clustering with ALTER TABLE ... CLUSTER BY (col, ...); not compatible with partitioning.",
+    },
+    FeatureSpec {
+        name: "typeWidening",
+        property: Some(("delta.enableTypeWidening", "true")),
+        legacy_versions: None,
+        reader_feature: true,
+        requires: &[],
+        engines: "Delta Lake 3.2+, Databricks Runtime 15.2+",
+        irreversible: "Readers without type widening support can no longer read the table.",
+        notes: "",
+    },
+    FeatureSpec {
+        name: "inCommitTimestamp",
+        property: Some(("delta.enableInCommitTimestamps", "true")),
+        legacy_versions: None,
+        reader_feature: false,
+        requires: &[],
+        engines: "Delta Lake 3.3+, Databricks Runtime 14.3 LTS+",
+        irreversible: "",
+        notes: "",
+    },
+];
+
+pub fn feature_names() -> Vec<&'static str> {
+    FEATURES.iter().map(|f| f.name).collect()
+}
+
+fn spec(name: &str) -> Option<&'static FeatureSpec> {
+    FEATURES.iter().find(|f| f.name == name)
+}
+
+/// Whether the table already has `spec` enabled, either as a listed table
+/// feature or through legacy protocol versions, with its property set. Once
+/// the table is on table features (writer 7), only listed features count.
+fn has_feature(config: &ConfigurationInfo, spec: &FeatureSpec) -> bool {
+    let protocol = &config.protocol;
+    let listed = protocol.writer_features.iter().any(|f| f == spec.name)
+        || protocol.reader_features.iter().any(|f| f == spec.name);
+    let legacy = protocol.min_writer_version < TABLE_FEATURES_WRITER_VERSION
+        && spec.legacy_versions.is_some_and(|(reader, writer)| {
+            protocol.min_reader_version >= reader && protocol.min_writer_version >= writer
+        });
+    let property_set = spec.property.is_none_or(|(key, value)| {
+        config.table_properties.get(key).map(String::as_str) == Some(value)
+    });
+    (listed || legacy) && property_set
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepKind {
+    EngineRequirement,
+    Prerequisite,
+    ProtocolUpgrade,
+    PropertyChange,
+    Irreversible,
+    FollowUp,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpgradeStep {
+    pub kind: StepKind,
+    pub description: String,
+    /// Statement that performs the step, where there is one
+    pub sql: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpgradePlan {
+    pub table_path: String,
+    pub target_feature: String,
+    pub current_reader_version: i32,
+    pub current_writer_version: i32,
+    pub already_enabled: bool,
+    pub steps: Vec<UpgradeStep>,
+}
+
+impl Renderable for UpgradePlan {
+    fn document(&self) -> Document {
+        let mut doc = Document::new(format!("Upgrade plan: {}", self.target_feature));
+        doc.fields([
+            ("Table", self.table_path.clone()),
+            (
+                "Protocol",
+                format!("reader {} / writer {}", self.current_reader_version, self.current_writer_version),
+            ),
+        ]);
+        if self.already_enabled {
+            doc.text(format!("{} is already enabled; nothing to do.", self.target_feature));
+            return doc;
+        }
+
+        let steps = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| {
+                let (label, tone) = match step.kind {
+                    StepKind::EngineRequirement => ("CHECK", Tone::Info),
+                    StepKind::Prerequisite => ("PREREQUISITE", Tone::Plain),
+                    StepKind::ProtocolUpgrade => ("PROTOCOL", Tone::Warning),
+                    StepKind::PropertyChange => ("ENABLE", Tone::Good),
+                    StepKind::Irreversible => ("⚠ IRREVERSIBLE", Tone::Critical),
+                    StepKind::FollowUp => ("NOTE", Tone::Plain),
+                };
+                let item = Item::new(format!("{:>2}. [{}]", idx + 1, label), step.description.clone()).tone(tone);
+                match &step.sql {
+                    Some(sql) => item.detail(format!("{};", sql)),
+                    None => item,
+                }
+            })
+            .collect();
+        doc.list(steps);
+        doc
+    }
+}
+
+/// `name` as a backtick-quoted Spark SQL identifier.
+pub(crate) fn quote_identifier(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
+/// Spark SQL reference to the Delta table at `path`.
+pub(crate) fn table_reference(path: &str) -> String {
+    format!("delta.{}", quote_identifier(path))
+}
+
+/// Ordered steps to enable `feature` on a table with the given protocol.
+pub fn plan(table_path: &str, config: &ConfigurationInfo, feature: &str) -> Result<UpgradePlan> {
+    let Some(target) = spec(feature) else {
+        bail!("Unknown table feature '{}'; expected one of {}", feature, feature_names().join(", "));
+    };
+
+    let protocol = &config.protocol;
+    let mut plan = UpgradePlan {
+        table_path: table_path.to_string(),
+        target_feature: feature.to_string(),
+        current_reader_version: protocol.min_reader_version,
+        current_writer_version: protocol.min_writer_version,
+        already_enabled: has_feature(config, target),
+        steps: Vec::new(),
+    };
+    if plan.already_enabled {
+        return Ok(plan);
+    }
+
+    let table = table_reference(table_path);
+    let steps = &mut plan.steps;
+
+    steps.push(UpgradeStep {
+        kind: StepKind::EngineRequirement,
+        description: format!(
+            "Confirm every {} of this table supports {}: {}",
+            if target.reader_feature { "reader and writer" } else { "writer" },
+            target.name,
+            target.engines
+        ),
+        sql: None,
+    });
+
+    for prerequisite in target.requires.iter().filter_map(|name| spec(name)) {
+        if !has_feature(config, prerequisite) {
+            steps.push(UpgradeStep {
+                kind: StepKind::Prerequisite,
+                description: format!("Enable {} first ({})", prerequisite.name, prerequisite.engines),
+                sql: Some(format!(
+                    "ALTER TABLE {} SET TBLPROPERTIES ('delta.feature.{}' = 'supported')",
+                    table, prerequisite.name
+                )),
+            });
+        }
+    }
+
+    // Warn before the statements that can't be taken back
+    if !target.irreversible.is_empty() {
+        steps.push(UpgradeStep {
+            kind: StepKind::Irreversible,
+            description: target.irreversible.to_string(),
+            sql: None,
+        });
+    }
+
+    let (reader, writer) = match target.legacy_versions {
+        Some(versions) => versions,
+        None if target.reader_feature => (TABLE_FEATURES_READER_VERSION, TABLE_FEATURES_WRITER_VERSION),
+        None => (protocol.min_reader_version.max(1), TABLE_FEATURES_WRITER_VERSION),
+    };
+    if protocol.min_reader_version < reader || protocol.min_writer_version < writer {
+        let reader = reader.max(protocol.min_reader_version);
+        let writer = writer.max(protocol.min_writer_version);
+        steps.push(UpgradeStep {
+            kind: StepKind::ProtocolUpgrade,
+            description: format!(
+                "Upgrade the protocol from reader {} / writer {} to reader {} / writer {}. Protocol versions can't be lowered again.",
+                protocol.min_reader_version, protocol.min_writer_version, reader, writer
+            ),
+            sql: Some(format!(
+                "ALTER TABLE {} SET TBLPROPERTIES ('delta.minReaderVersion' = '{}', 'delta.minWriterVersion' = '{}')",
+                table, reader, writer
+            )),
+        });
+    }
+
+    match target.property {
+        Some((key, value)) => steps.push(UpgradeStep {
+            kind: StepKind::PropertyChange,
+            description: format!("Set {} = {}", key, value),
+            sql: Some(format!("ALTER TABLE {} SET TBLPROPERTIES ('{}' = '{}')", table, key, value)),
+        }),
+        None if writer >= TABLE_FEATURES_WRITER_VERSION => steps.push(UpgradeStep {
+            kind: StepKind::PropertyChange,
+            description: format!("Mark {} as supported", target.name),
+            sql: Some(format!(
+                "ALTER TABLE {} SET TBLPROPERTIES ('delta.feature.{}' = 'supported')",
+                table, target.name
+            )),
+        }),
+        None => {}
+    }
+
+    if !target.notes.is_empty() {
+        steps.push(UpgradeStep {
+            kind: StepKind::FollowUp,
+            description: target.notes.to_string(),
+            sql: None,
+        });
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn config(reader: i32, writer: i32, writer_features: &[&str], properties: &[(&str, &str)]) -> ConfigurationInfo {
+        serde_json::from_value(json!({
+            "table_properties": properties.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<HashMap<_, _>>(),
+            "table_id": null,
+            "table_name": null,
+            "description": null,
+            "created_time": null,
+            "partition_columns": [],
+            "protocol": {
+                "min_reader_version": reader,
+                "min_writer_version": writer,
+                "reader_features": [],
+                "writer_features": writer_features,
+            },
+            "checkpoint_info": { "has_checkpoints": false, "latest_checkpoint": null, "checkpoint_size_bytes": 0 },
+            "transaction_log": { "num_json_files": 1, "num_checkpoints": 0, "log_size_bytes": 0 },
+            "advanced_features": {
+                "deletion_vectors": false,
+                "column_mapping": { "enabled": false, "mode": "none" },
+                "liquid_clustering": false,
+                "timestamp_ntz": false,
+                "check_constraints": {},
+                "auto_optimize": { "enabled": false, "auto_compact": false, "optimize_write": false },
+                "data_skipping": { "enabled": true, "num_indexed_cols": 32 },
+                "change_data_feed": false,
+                "vacuum_retention_hours": 168,
+            },
+        }))
+        .expect("valid configuration")
+    }
+
+    fn kinds(plan: &UpgradePlan) -> Vec<&'static str> {
+        plan.steps
+            .iter()
+            .map(|step| match step.kind {
+                StepKind::EngineRequirement => "engine",
+                StepKind::Prerequisite => "prerequisite",
+                StepKind::ProtocolUpgrade => "protocol",
+                StepKind::PropertyChange => "property",
+                StepKind::Irreversible => "irreversible",
+                StepKind::FollowUp => "follow-up",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn upgrades_the_protocol_before_setting_the_property() {
+        let plan = plan("s3://lake/orders", &config(1, 2, &[], &[]), "deletionVectors").expect("known feature");
+        assert!(!plan.already_enabled);
+        let kinds = kinds(&plan);
+        let protocol = kinds.iter().position(|k| *k == "protocol").expect("protocol upgrade");
+        let property = kinds.iter().position(|k| *k == "property").expect("property change");
+        assert_eq!(kinds[0], "engine");
+        assert!(protocol < property);
+        let sql = plan.steps[property].sql.as_deref().expect("statement");
+        assert_eq!(sql, "ALTER TABLE delta.`s3://lake/orders` SET TBLPROPERTIES ('delta.enableDeletionVectors' = 'true')");
+    }
+
+    #[test]
+    fn legacy_protocol_versions_count_as_enabled() {
+        let config = config(1, 2, &[], &[("delta.appendOnly", "true")]);
+        let plan = plan("/lake/orders", &config, "appendOnly").expect("known feature");
+        assert!(plan.already_enabled);
+        assert!(plan.steps.is_empty());
+    }
+
+    #[test]
+    fn listed_features_need_their_property_set() {
+        let listed = config(3, 7, &["deletionVectors"], &[]);
+        assert!(!plan("/t", &listed, "deletionVectors").unwrap().already_enabled);
+        let enabled = config(3, 7, &["deletionVectors"], &[("delta.enableDeletionVectors", "true")]);
+        assert!(plan("/t", &enabled, "deletionVectors").unwrap().already_enabled);
+    }
+
+    #[test]
+    fn skips_the_protocol_upgrade_when_versions_suffice() {
+        let plan = plan("/t", &config(3, 7, &[], &[]), "changeDataFeed").expect("known feature");
+        assert!(!kinds(&plan).contains(&"protocol"));
+    }
+
+    #[test]
+    fn rejects_unknown_features_and_quotes_paths() {
+        assert!(plan("/t", &config(1, 2, &[], &[]), "timeTravel").is_err());
+        assert_eq!(table_reference("/data/we`ird"), "delta.`/data/we``ird`");
+    }
+}
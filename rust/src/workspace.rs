@@ -0,0 +1,75 @@
+//! Scheduled fleet scans declared in the config file, run by `run-workspace`.
+//!
+//! A workspace ties the per-feature config sections together: `tables` says
+//! which tables exist and how to reach them (tags, credential profiles),
+//! `rules` and `thresholds` what healthy means, `alerts` who to tell, and
+//! `scans` when to look.
+
+use crate::config::{matches_tags, Config};
+use crate::discovery::{discover_tables, Discovery, UnreadableDirectory};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// One entry of `scans:` in the config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceScan {
+    pub name: String,
+    /// Directories to find tables under; defaults to the `tables` entries
+    #[serde(default)]
+    pub roots: Vec<String>,
+    /// Only scan tables carrying all of these tags
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+    /// How often to run, e.g. "15m" or "24h"; runs once when unset
+    pub every: Option<String>,
+    /// Tag to roll the report up by
+    pub group_by: Option<String>,
+    /// Send alerts through the `alerts` routes after each run
+    #[serde(default)]
+    pub alert: bool,
+    /// Write each run's JSON report to this file, replacing the previous one
+    pub output: Option<String>,
+}
+
+/// Tables `scan` covers: those under its roots, or else every `path` entry
+/// and every table under a `prefix` entry, filtered by the scan's tags. Each
+/// root is listed with its entry's profile and `overrides`.
+pub async fn resolve_tables(
+    config: &Config,
+    scan: &WorkspaceScan,
+    overrides: &HashMap<String, String>,
+) -> Result<Discovery> {
+    let mut resolved = Discovery::default();
+    let mut roots = Vec::new();
+    if scan.roots.is_empty() {
+        for entry in &config.tables {
+            if let Some(path) = &entry.path {
+                resolved.tables.push(path.trim_end_matches('/').to_string());
+            }
+            roots.extend(entry.prefix.clone());
+        }
+    } else {
+        roots.extend(scan.roots.iter().cloned());
+    }
+    // One root that can't be listed doesn't stop the others
+    for root in &roots {
+        match discover_tables(root, config.storage_options_for(root, overrides)).await {
+            Ok(discovery) => {
+                resolved.tables.extend(discovery.tables);
+                resolved.unreadable.extend(discovery.unreadable);
+            }
+            Err(e) => resolved.unreadable.push(UnreadableDirectory {
+                path: root.clone(),
+                error: format!("{:#}", e),
+            }),
+        }
+    }
+    resolved.tables.sort();
+    resolved.tables.dedup();
+
+    let filters: Vec<(String, String)> = scan.tags.clone().into_iter().collect();
+    resolved.tables.retain(|path| matches_tags(&config.tags_for(path), &filters));
+    Ok(resolved)
+}